@@ -48,7 +48,8 @@ export const navigation: NavSection[] = [
 		items: [
 			{ title: 'Subscriber', href: '/nats/subscriber' },
 			{ title: 'Publisher', href: '/nats/publisher' },
-			{ title: 'KV Store', href: '/nats/kv-store' }
+			{ title: 'KV Store', href: '/nats/kv-store' },
+			{ title: 'Core Subscriber', href: '/nats/core-subscriber' }
 		]
 	},
 	{
@@ -78,7 +79,9 @@ export const navigation: NavSection[] = [
 		items: [
 			{ title: 'BigQuery Query', href: '/gcp/bigquery-query' },
 			{ title: 'BigQuery Storage', href: '/gcp/bigquery-storage' },
-			{ title: 'BigQuery Jobs', href: '/gcp/bigquery-jobs' }
+			{ title: 'BigQuery Streaming Insert', href: '/gcp/bigquery-insert-all' },
+			{ title: 'BigQuery Jobs', href: '/gcp/bigquery-jobs' },
+			{ title: 'Pub/Sub Subscriber', href: '/gcp/pubsub-subscriber' }
 		]
 	},
 	{
@@ -86,7 +89,8 @@ export const navigation: NavSection[] = [
 		icon: '/icons/http.svg',
 		items: [
 			{ title: 'Webhook', href: '/http/webhook' },
-			{ title: 'Request', href: '/http/request' }
+			{ title: 'Request', href: '/http/request' },
+			{ title: 'WebSocket', href: '/http/websocket' }
 		]
 	},
 	{
@@ -99,6 +103,24 @@ export const navigation: NavSection[] = [
 		icon: '/icons/mssql.svg',
 		items: [{ title: 'Query', href: '/mssql/query' }]
 	},
+	{
+		title: 'ClickHouse',
+		icon: '/icons/clickhouse.svg',
+		items: [{ title: 'Publisher', href: '/clickhouse/publisher' }]
+	},
+	{
+		title: 'Redis',
+		icon: '/icons/redis.svg',
+		items: [{ title: 'Streams Publisher', href: '/redis/streams-publisher' }]
+	},
+	{
+		title: 'Arrow Flight',
+		icon: '/icons/arrow-flight.svg',
+		items: [
+			{ title: 'Subscriber', href: '/arrow-flight/subscriber' },
+			{ title: 'Publisher', href: '/arrow-flight/publisher' }
+		]
+	},
 	{
 		title: 'AI',
 		icon: '/icons/ai.svg',
@@ -113,6 +135,11 @@ export const navigation: NavSection[] = [
 		icon: '/icons/git.svg',
 		items: [{ title: 'Git Sync', href: '/git/sync' }]
 	},
+	{
+		title: 'File',
+		icon: '/icons/file.svg',
+		items: [{ title: 'SFTP Subscriber', href: '/file/sftp-subscriber' }]
+	},
 	{
 		title: 'Core Tasks',
 		icon: '/icons/core.svg',
@@ -121,6 +148,17 @@ export const navigation: NavSection[] = [
 			{ title: 'Convert', href: '/core/convert' },
 			{ title: 'Iterate', href: '/core/iterate' },
 			{ title: 'Buffer', href: '/core/buffer' },
+			{ title: 'Aggregate', href: '/core/aggregate' },
+			{ title: 'Window', href: '/core/window' },
+			{ title: 'Split', href: '/core/split' },
+			{ title: 'Mask', href: '/core/mask' },
+			{ title: 'Project', href: '/core/project' },
+			{ title: 'Cast', href: '/core/cast' },
+			{ title: 'Rename', href: '/core/rename' },
+			{ title: 'Timestamp', href: '/core/timestamp' },
+			{ title: 'Dedup', href: '/core/dedup' },
+			{ title: 'Encrypt / Decrypt', href: '/core/encrypt' },
+			{ title: 'Router', href: '/core/router' },
 			{ title: 'Generate', href: '/core/generate' },
 			{ title: 'Log', href: '/core/log' }
 		]
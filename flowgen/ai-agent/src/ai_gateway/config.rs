@@ -70,6 +70,9 @@ pub struct Processor {
     /// Optional retry configuration.
     #[serde(default)]
     pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
 }
 
 impl ConfigExt for Processor {}
@@ -116,6 +116,9 @@ pub struct Processor {
     /// Optional retry configuration (overrides app-level retry config).
     #[serde(default)]
     pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
 }
 
 /// Configuration for connecting to an MCP server.
@@ -205,6 +208,7 @@ mod tests {
             sandbox: Default::default(),
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         assert_eq!(processor.name, "test");
@@ -244,6 +248,7 @@ mod tests {
             sandbox: Default::default(),
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         let json = serde_json::to_string(&processor).unwrap();
@@ -289,6 +294,7 @@ mod tests {
             sandbox: Default::default(),
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
         let _: &dyn ConfigExt = &processor;
     }
@@ -312,6 +318,7 @@ mod tests {
             sandbox: Default::default(),
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         let json = serde_json::to_string(&processor).unwrap();
@@ -342,6 +349,7 @@ mod tests {
             sandbox: Default::default(),
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         let json = serde_json::to_string(&processor).unwrap();
@@ -376,6 +384,7 @@ mod tests {
             sandbox: Default::default(),
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         let json = serde_json::to_string(&processor).unwrap();
@@ -167,6 +167,53 @@ pub enum Error {
         source: config::ConfigError,
     },
 }
+
+/// Expands `flows.path` into the glob patterns used to discover flow files.
+///
+/// A path containing `*` is used as-is; otherwise every supported flow
+/// config extension is searched recursively below the directory.
+pub fn flow_glob_patterns(flows_path: &std::path::Path) -> Result<Vec<String>, Error> {
+    let flows_path_str = flows_path.to_str().ok_or(Error::InvalidFlowsPath)?;
+
+    // Check if path contains wildcards (backward compatibility).
+    if flows_path_str.contains('*') {
+        return Ok(vec![flows_path_str.to_string()]);
+    }
+    Ok(crate::config::FLOW_CONFIG_EXTENSIONS
+        .iter()
+        .map(|ext| format!("{}/**/*.{}", flows_path_str.trim_end_matches('/'), ext))
+        .collect())
+}
+
+/// Reads, parses and deserializes a single flow configuration file.
+///
+/// The format is picked from the file extension, defaulting to JSON.
+pub fn load_flow_file(path: &std::path::Path) -> Result<FlowConfig, Error> {
+    let contents = std::fs::read_to_string(path).map_err(|source| Error::FlowFileRead {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    let file_format = match path.extension().and_then(|s| s.to_str()) {
+        Some("yaml") | Some("yml") => config::FileFormat::Yaml,
+        Some("json") => config::FileFormat::Json,
+        _ => config::FileFormat::Json,
+    };
+
+    Config::builder()
+        .add_source(config::File::from_str(&contents, file_format))
+        .build()
+        .map_err(|source| Error::FlowConfigParse {
+            path: path.to_path_buf(),
+            source,
+        })?
+        .try_deserialize::<FlowConfig>()
+        .map_err(|source| Error::FlowConfigDeserialize {
+            path: path.to_path_buf(),
+            source,
+        })
+}
+
 /// Main application that loads and runs flows concurrently.
 pub struct App {
     /// Global application configuration.
@@ -182,9 +229,14 @@ impl App {
     ) -> Result<Arc<dyn flowgen_core::cache::Cache>, Error> {
         let cache_config = app_config.cache.as_ref().ok_or(Error::InvalidFlowsPath)?;
 
-        let nats_cache = flowgen_nats::cache::CacheBuilder::new()
+        let mut cache_builder = flowgen_nats::cache::CacheBuilder::new()
             .credentials_path(cache_config.credentials_path.clone())
             .url(cache_config.url.clone())
+            .tls(cache_config.tls.clone().unwrap_or_default());
+        if let Some(retry) = app_config.worker.as_ref().and_then(|w| w.retry.as_ref()) {
+            cache_builder = cache_builder.retry(retry.clone());
+        }
+        let nats_cache = cache_builder
             .build()
             .and_then(|builder| futures::executor::block_on(async { builder.init(db_name).await }))
             .map_err(|source| Error::SystemCacheInit { source })?;
@@ -250,7 +302,12 @@ impl App {
                         );
                         continue;
                     }
-                    info!(flow = %flow_config.flow.name, key = %key, "Loaded flow from cache");
+                    info!(
+                        flow = %flow_config.flow.name,
+                        key = %key,
+                        tasks = %flow_config.task_summary(),
+                        "Loaded flow from cache"
+                    );
                     flow_configs.push(flow_config);
                 }
                 Err(source) => {
@@ -278,17 +335,7 @@ impl App {
             None => return Ok(Vec::new()),
         };
 
-        let flows_path_str = flows_path.to_str().ok_or(Error::InvalidFlowsPath)?;
-
-        // Check if path contains wildcards (backward compatibility).
-        let glob_patterns: Vec<String> = if flows_path_str.contains('*') {
-            vec![flows_path_str.to_string()]
-        } else {
-            crate::config::FLOW_CONFIG_EXTENSIONS
-                .iter()
-                .map(|ext| format!("{}/**/*.{}", flows_path_str.trim_end_matches('/'), ext))
-                .collect()
-        };
+        let glob_patterns = flow_glob_patterns(flows_path)?;
 
         let mut flow_configs: Vec<FlowConfig> = Vec::new();
         let mut seen_paths = std::collections::HashSet::new();
@@ -315,40 +362,7 @@ impl App {
                         }
                         seen_paths.insert(canonical_path.clone());
 
-                        let contents = match std::fs::read_to_string(&path) {
-                            Ok(c) => c,
-                            Err(source) => {
-                                let err = Error::FlowFileRead {
-                                    path: path.clone(),
-                                    source,
-                                };
-                                error!("{}. Skipping this flow.", err);
-                                return None;
-                            }
-                        };
-
-                        let file_format = match path.extension().and_then(|s| s.to_str()) {
-                            Some("yaml") | Some("yml") => config::FileFormat::Yaml,
-                            Some("json") => config::FileFormat::Json,
-                            _ => config::FileFormat::Json,
-                        };
-
-                        let config = match Config::builder()
-                            .add_source(config::File::from_str(&contents, file_format))
-                            .build()
-                        {
-                            Ok(c) => c,
-                            Err(source) => {
-                                let err = Error::FlowConfigParse {
-                                    path: path.clone(),
-                                    source,
-                                };
-                                error!("{}. Skipping this flow.", err);
-                                return None;
-                            }
-                        };
-
-                        match config.try_deserialize::<FlowConfig>() {
+                        match load_flow_file(&path) {
                             Ok(flow_config) => {
                                 if let Err(reason) = flow_config.validate() {
                                     error!(
@@ -358,14 +372,14 @@ impl App {
                                     );
                                     return None;
                                 }
-                                info!(flow = %flow_config.flow.name, "Loaded flow");
+                                info!(
+                                    flow = %flow_config.flow.name,
+                                    tasks = %flow_config.task_summary(),
+                                    "Loaded flow"
+                                );
                                 Some(flow_config)
                             }
-                            Err(source) => {
-                                let err = Error::FlowConfigDeserialize {
-                                    path: path.clone(),
-                                    source,
-                                };
+                            Err(err) => {
                                 error!("{}. Skipping this flow.", err);
                                 None
                             }
@@ -583,13 +597,20 @@ impl App {
 
                     let mut cache_builder = flowgen_nats::cache::CacheBuilder::new()
                         .credentials_path(cache_config.credentials_path.clone())
-                        .url(cache_config.url.clone());
+                        .url(cache_config.url.clone())
+                        .tls(cache_config.tls.clone().unwrap_or_default());
                     if let Some(history) = cache_config.history {
                         cache_builder = cache_builder.history(history);
                     }
                     if let Some(ttl) = cache_config.tombstone_ttl {
                         cache_builder = cache_builder.tombstone_ttl(ttl);
                     }
+                    if let Some(key_prefix) = &cache_config.key_prefix {
+                        cache_builder = cache_builder.key_prefix(key_prefix.clone());
+                    }
+                    if let Some(retry) = app_config.worker.as_ref().and_then(|w| w.retry.as_ref()) {
+                        cache_builder = cache_builder.retry(retry.clone());
+                    }
                     match cache_builder.build().and_then(|builder| {
                         futures::executor::block_on(async { builder.init(db_name).await })
                     }) {
@@ -37,6 +37,16 @@ impl FlowConfig {
         }
         Ok(())
     }
+
+    /// Describes the tasks of the flow in order as `name (type)`, for startup logs.
+    pub fn task_summary(&self) -> String {
+        self.flow
+            .tasks
+            .iter()
+            .map(|task| format!("{} ({})", task.name(), task.as_str()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
 }
 
 /// Default value for parallel_instances.
@@ -61,6 +71,12 @@ pub struct Flow {
     /// Best used with leader election enabled. Defaults to 1.
     #[serde(default = "default_parallel_instances")]
     pub parallel_instances: usize,
+    /// Per-edge event channel capacity for this flow.
+    ///
+    /// Overrides `worker.event_buffer_size`. Raise it for bursty sources such
+    /// as large file batches, lower it in memory-constrained environments.
+    #[serde(default)]
+    pub channel_capacity: Option<usize>,
 }
 
 /// Available task types in the flowgen ecosystem.
@@ -81,6 +97,30 @@ pub enum TaskType {
     script(flowgen_core::task::script::config::Processor),
     /// Buffer task for accumulating events into batches.
     buffer(flowgen_core::task::buffer::config::Processor),
+    /// Aggregate task for grouped statistics over time windows.
+    aggregate(flowgen_core::task::aggregate::config::Processor),
+    /// Window task for concatenating batches over time windows.
+    window(flowgen_core::task::window::config::Processor),
+    /// Split task for breaking record batches into smaller chunks.
+    split(flowgen_core::task::split::config::Processor),
+    /// Mask task for redacting PII columns.
+    mask(flowgen_core::task::mask::config::Processor),
+    /// Cast task for changing column data types.
+    cast(flowgen_core::task::cast::config::Processor),
+    /// Project task for keeping a subset of columns.
+    project(flowgen_core::task::project::config::Processor),
+    /// Rename task for changing column names.
+    rename(flowgen_core::task::rename::config::Processor),
+    /// Timestamp task for parsing string columns into UTC timestamps.
+    timestamp(flowgen_core::task::timestamp::config::Processor),
+    /// Dedup task for dropping records seen within a window.
+    dedup(flowgen_core::task::dedup::config::Processor),
+    /// Encrypt task for column-level encryption.
+    encrypt(flowgen_core::task::encrypt::config::Processor),
+    /// Decrypt task for columns written by the encrypt task.
+    decrypt(flowgen_core::task::decrypt::config::Processor),
+    /// Router task for sending events to subjects by condition.
+    router(flowgen_core::task::router::config::Processor),
     /// Object store operations (read, write, list, move).
     object_store(flowgen_object_store::config::Processor),
     /// Data generation task.
@@ -95,6 +135,8 @@ pub enum TaskType {
     nats_jetstream_subscriber(flowgen_nats::jetstream::config::Subscriber),
     /// NATS JetStream publisher task.
     nats_jetstream_publisher(flowgen_nats::jetstream::config::Publisher),
+    /// Core NATS (non-JetStream) subscriber task.
+    nats_core_subscriber(flowgen_nats::core::config::Subscriber),
     /// Salesforce Pub/Sub API subscriber task.
     salesforce_pubsubapi_subscriber(flowgen_salesforce::pubsubapi::config::Subscriber),
     /// Salesforce Pub/Sub API publisher task.
@@ -107,6 +149,8 @@ pub enum TaskType {
     salesforce_restapi_composite(flowgen_salesforce::restapi::config::Composite),
     /// Salesforce REST API SOSL search operations.
     salesforce_restapi_search(flowgen_salesforce::restapi::config::Search),
+    /// Salesforce REST API SOQL query operations.
+    salesforce_restapi_query(flowgen_salesforce::restapi::config::Query),
     /// Salesforce Tooling API operations for metadata management.
     salesforce_toolingapi(flowgen_salesforce::toolingapi::config::Tooling),
     /// GCP BigQuery query task.
@@ -117,8 +161,20 @@ pub enum TaskType {
     gcp_bigquery_job(flowgen_gcp::bigquery::config::Job),
     /// GCP BigQuery Storage Write API task for high-throughput streaming inserts.
     gcp_bigquery_storage_write(flowgen_gcp::bigquery::config::StorageWrite),
+    /// GCP BigQuery streaming insert task using the legacy `tabledata.insertAll` API.
+    gcp_bigquery_insert_all(flowgen_gcp::bigquery::config::InsertAll),
+    /// GCP Pub/Sub subscriber task.
+    gcp_pubsub_subscriber(flowgen_gcp::pubsub::config::Subscriber),
     /// Microsoft SQL Server query task.
     mssql_query(flowgen_mssql::config::Query),
+    /// ClickHouse publisher task inserting rows over HTTP.
+    clickhouse_publisher(flowgen_clickhouse::config::Publisher),
+    /// Redis Streams publisher task appending entries with XADD.
+    redis_streams_publisher(flowgen_redis::streams::config::Publisher),
+    /// Arrow Flight subscriber task serving DoPut for record batches from other workers.
+    arrow_flight_subscriber(flowgen_arrow_flight::config::Subscriber),
+    /// Arrow Flight publisher task sending record batches with DoPut.
+    arrow_flight_publisher(flowgen_arrow_flight::config::Publisher),
     /// NATS Key-Value store operations (get, put, list, delete).
     nats_kv_store(flowgen_nats::jetstream::kv_store::Config),
     /// AI completion task for generating responses using LLMs.
@@ -129,6 +185,10 @@ pub enum TaskType {
     ai_gateway(flowgen_ai_agent::ai_gateway::config::Processor),
     /// Git sync task for syncing flows and resources from a Git repository to the cache.
     git_sync(flowgen_git::sync::config::Processor),
+    /// WebSocket subscriber task for consuming real-time feeds.
+    websocket_subscriber(flowgen_websocket::config::Subscriber),
+    /// SFTP subscriber task for picking up files from an SFTP server.
+    sftp_subscriber(flowgen_file::sftp::config::Subscriber),
 }
 
 impl TaskType {
@@ -140,6 +200,18 @@ impl TaskType {
             TaskType::log(_) => "log",
             TaskType::script(_) => "script",
             TaskType::buffer(_) => "buffer",
+            TaskType::aggregate(_) => "aggregate",
+            TaskType::window(_) => "window",
+            TaskType::split(_) => "split",
+            TaskType::mask(_) => "mask",
+            TaskType::cast(_) => "cast",
+            TaskType::project(_) => "project",
+            TaskType::rename(_) => "rename",
+            TaskType::timestamp(_) => "timestamp",
+            TaskType::dedup(_) => "dedup",
+            TaskType::encrypt(_) => "encrypt",
+            TaskType::decrypt(_) => "decrypt",
+            TaskType::router(_) => "router",
             TaskType::object_store(_) => "object_store",
             TaskType::generate(_) => "generate",
             TaskType::http_request(_) => "http_request",
@@ -147,23 +219,33 @@ impl TaskType {
             TaskType::html_scrape(_) => "html_scrape",
             TaskType::nats_jetstream_subscriber(_) => "nats_jetstream_subscriber",
             TaskType::nats_jetstream_publisher(_) => "nats_jetstream_publisher",
+            TaskType::nats_core_subscriber(_) => "nats_core_subscriber",
             TaskType::salesforce_pubsubapi_subscriber(_) => "salesforce_pubsubapi_subscriber",
             TaskType::salesforce_pubsubapi_publisher(_) => "salesforce_pubsubapi_publisher",
             TaskType::salesforce_bulkapi_query_job(_) => "salesforce_bulkapi_query_job",
             TaskType::salesforce_restapi_sobject(_) => "salesforce_restapi_sobject",
             TaskType::salesforce_restapi_composite(_) => "salesforce_restapi_composite",
             TaskType::salesforce_restapi_search(_) => "salesforce_restapi_search",
+            TaskType::salesforce_restapi_query(_) => "salesforce_restapi_query",
             TaskType::salesforce_toolingapi(_) => "salesforce_toolingapi",
             TaskType::gcp_bigquery_query(_) => "gcp_bigquery_query",
             TaskType::gcp_bigquery_storage_read(_) => "gcp_bigquery_storage_read",
             TaskType::gcp_bigquery_job(_) => "gcp_bigquery_job",
             TaskType::gcp_bigquery_storage_write(_) => "gcp_bigquery_storage_write",
+            TaskType::gcp_bigquery_insert_all(_) => "gcp_bigquery_insert_all",
+            TaskType::gcp_pubsub_subscriber(_) => "gcp_pubsub_subscriber",
             TaskType::mssql_query(_) => "mssql_query",
+            TaskType::clickhouse_publisher(_) => "clickhouse_publisher",
+            TaskType::redis_streams_publisher(_) => "redis_streams_publisher",
+            TaskType::arrow_flight_subscriber(_) => "arrow_flight_subscriber",
+            TaskType::arrow_flight_publisher(_) => "arrow_flight_publisher",
             TaskType::nats_kv_store(_) => "nats_kv_store",
             TaskType::ai_completion(_) => "ai_completion",
             TaskType::mcp_tool(_) => "mcp_tool",
             TaskType::ai_gateway(_) => "ai_gateway",
             TaskType::git_sync(_) => "git_sync",
+            TaskType::websocket_subscriber(_) => "websocket_subscriber",
+            TaskType::sftp_subscriber(_) => "sftp_subscriber",
         }
     }
 
@@ -175,6 +257,18 @@ impl TaskType {
             TaskType::log(c) => &c.name,
             TaskType::script(c) => &c.name,
             TaskType::buffer(c) => &c.name,
+            TaskType::aggregate(c) => &c.name,
+            TaskType::window(c) => &c.name,
+            TaskType::split(c) => &c.name,
+            TaskType::mask(c) => &c.name,
+            TaskType::cast(c) => &c.name,
+            TaskType::project(c) => &c.name,
+            TaskType::rename(c) => &c.name,
+            TaskType::timestamp(c) => &c.name,
+            TaskType::dedup(c) => &c.name,
+            TaskType::encrypt(c) => &c.name,
+            TaskType::decrypt(c) => &c.name,
+            TaskType::router(c) => &c.name,
             TaskType::object_store(c) => &c.name,
             TaskType::generate(c) => &c.name,
             TaskType::http_request(c) => &c.name,
@@ -182,23 +276,33 @@ impl TaskType {
             TaskType::html_scrape(c) => &c.name,
             TaskType::nats_jetstream_subscriber(c) => &c.name,
             TaskType::nats_jetstream_publisher(c) => &c.name,
+            TaskType::nats_core_subscriber(c) => &c.name,
             TaskType::salesforce_pubsubapi_subscriber(c) => &c.name,
             TaskType::salesforce_pubsubapi_publisher(c) => &c.name,
             TaskType::salesforce_bulkapi_query_job(c) => &c.name,
             TaskType::salesforce_restapi_sobject(c) => &c.name,
             TaskType::salesforce_restapi_composite(c) => &c.name,
             TaskType::salesforce_restapi_search(c) => &c.name,
+            TaskType::salesforce_restapi_query(c) => &c.name,
             TaskType::salesforce_toolingapi(c) => &c.name,
             TaskType::gcp_bigquery_query(c) => &c.name,
             TaskType::gcp_bigquery_storage_read(c) => &c.name,
             TaskType::gcp_bigquery_job(c) => &c.name,
             TaskType::gcp_bigquery_storage_write(c) => &c.name,
+            TaskType::gcp_bigquery_insert_all(c) => &c.name,
+            TaskType::gcp_pubsub_subscriber(c) => &c.name,
             TaskType::mssql_query(c) => &c.name,
+            TaskType::clickhouse_publisher(c) => &c.name,
+            TaskType::redis_streams_publisher(c) => &c.name,
+            TaskType::arrow_flight_subscriber(c) => &c.name,
+            TaskType::arrow_flight_publisher(c) => &c.name,
             TaskType::nats_kv_store(c) => &c.name,
             TaskType::ai_completion(c) => &c.name,
             TaskType::mcp_tool(c) => &c.name,
             TaskType::ai_gateway(c) => &c.name,
             TaskType::git_sync(c) => &c.name,
+            TaskType::websocket_subscriber(c) => &c.name,
+            TaskType::sftp_subscriber(c) => &c.name,
         }
     }
 
@@ -210,6 +314,18 @@ impl TaskType {
             TaskType::log(c) => c.depends_on.as_ref(),
             TaskType::script(c) => c.depends_on.as_ref(),
             TaskType::buffer(c) => c.depends_on.as_ref(),
+            TaskType::aggregate(c) => c.depends_on.as_ref(),
+            TaskType::window(c) => c.depends_on.as_ref(),
+            TaskType::split(c) => c.depends_on.as_ref(),
+            TaskType::mask(c) => c.depends_on.as_ref(),
+            TaskType::cast(c) => c.depends_on.as_ref(),
+            TaskType::project(c) => c.depends_on.as_ref(),
+            TaskType::rename(c) => c.depends_on.as_ref(),
+            TaskType::timestamp(c) => c.depends_on.as_ref(),
+            TaskType::dedup(c) => c.depends_on.as_ref(),
+            TaskType::encrypt(c) => c.depends_on.as_ref(),
+            TaskType::decrypt(c) => c.depends_on.as_ref(),
+            TaskType::router(c) => c.depends_on.as_ref(),
             TaskType::object_store(c) => c.depends_on.as_ref(),
             TaskType::generate(c) => c.depends_on.as_ref(),
             TaskType::http_request(c) => c.depends_on.as_ref(),
@@ -217,23 +333,174 @@ impl TaskType {
             TaskType::html_scrape(c) => c.depends_on.as_ref(),
             TaskType::nats_jetstream_subscriber(c) => c.depends_on.as_ref(),
             TaskType::nats_jetstream_publisher(c) => c.depends_on.as_ref(),
+            TaskType::nats_core_subscriber(c) => c.depends_on.as_ref(),
             TaskType::salesforce_pubsubapi_subscriber(c) => c.depends_on.as_ref(),
             TaskType::salesforce_pubsubapi_publisher(c) => c.depends_on.as_ref(),
             TaskType::salesforce_bulkapi_query_job(c) => c.depends_on.as_ref(),
             TaskType::salesforce_restapi_sobject(c) => c.depends_on.as_ref(),
             TaskType::salesforce_restapi_composite(c) => c.depends_on.as_ref(),
             TaskType::salesforce_restapi_search(c) => c.depends_on.as_ref(),
+            TaskType::salesforce_restapi_query(c) => c.depends_on.as_ref(),
             TaskType::salesforce_toolingapi(c) => c.depends_on.as_ref(),
             TaskType::gcp_bigquery_query(c) => c.depends_on.as_ref(),
             TaskType::gcp_bigquery_storage_read(c) => c.depends_on.as_ref(),
             TaskType::gcp_bigquery_job(c) => c.depends_on.as_ref(),
             TaskType::gcp_bigquery_storage_write(c) => c.depends_on.as_ref(),
+            TaskType::gcp_bigquery_insert_all(c) => c.depends_on.as_ref(),
+            TaskType::gcp_pubsub_subscriber(c) => c.depends_on.as_ref(),
             TaskType::mssql_query(c) => c.depends_on.as_ref(),
+            TaskType::clickhouse_publisher(c) => c.depends_on.as_ref(),
+            TaskType::redis_streams_publisher(c) => c.depends_on.as_ref(),
+            TaskType::arrow_flight_subscriber(c) => c.depends_on.as_ref(),
+            TaskType::arrow_flight_publisher(c) => c.depends_on.as_ref(),
             TaskType::nats_kv_store(c) => c.depends_on.as_ref(),
             TaskType::ai_completion(c) => c.depends_on.as_ref(),
             TaskType::mcp_tool(c) => c.depends_on.as_ref(),
             TaskType::ai_gateway(c) => c.depends_on.as_ref(),
             TaskType::git_sync(c) => c.depends_on.as_ref(),
+            TaskType::websocket_subscriber(c) => c.depends_on.as_ref(),
+            TaskType::sftp_subscriber(c) => c.depends_on.as_ref(),
+        }
+    }
+
+    /// Returns the error recovery policy if configured on the task.
+    pub fn error_policy(&self) -> Option<&flowgen_core::error_policy::ErrorPolicy> {
+        match self {
+            TaskType::convert(c) => c.error_policy.as_ref(),
+            TaskType::iterate(c) => c.error_policy.as_ref(),
+            TaskType::log(c) => c.error_policy.as_ref(),
+            TaskType::script(c) => c.error_policy.as_ref(),
+            TaskType::buffer(c) => c.error_policy.as_ref(),
+            TaskType::aggregate(c) => c.error_policy.as_ref(),
+            TaskType::window(c) => c.error_policy.as_ref(),
+            TaskType::split(c) => c.error_policy.as_ref(),
+            TaskType::mask(c) => c.error_policy.as_ref(),
+            TaskType::cast(c) => c.error_policy.as_ref(),
+            TaskType::project(c) => c.error_policy.as_ref(),
+            TaskType::rename(c) => c.error_policy.as_ref(),
+            TaskType::timestamp(c) => c.error_policy.as_ref(),
+            TaskType::dedup(c) => c.error_policy.as_ref(),
+            TaskType::encrypt(c) => c.error_policy.as_ref(),
+            TaskType::decrypt(c) => c.error_policy.as_ref(),
+            TaskType::router(c) => c.error_policy.as_ref(),
+            TaskType::object_store(c) => c.error_policy.as_ref(),
+            TaskType::generate(c) => c.error_policy.as_ref(),
+            TaskType::http_request(c) => c.error_policy.as_ref(),
+            TaskType::http_webhook(c) => c.error_policy.as_ref(),
+            TaskType::html_scrape(c) => c.error_policy.as_ref(),
+            TaskType::nats_jetstream_subscriber(c) => c.error_policy.as_ref(),
+            TaskType::nats_jetstream_publisher(c) => c.error_policy.as_ref(),
+            TaskType::nats_core_subscriber(c) => c.error_policy.as_ref(),
+            TaskType::salesforce_pubsubapi_subscriber(c) => c.error_policy.as_ref(),
+            TaskType::salesforce_pubsubapi_publisher(c) => c.error_policy.as_ref(),
+            TaskType::salesforce_bulkapi_query_job(c) => c.error_policy.as_ref(),
+            TaskType::salesforce_restapi_sobject(c) => c.error_policy.as_ref(),
+            TaskType::salesforce_restapi_composite(c) => c.error_policy.as_ref(),
+            TaskType::salesforce_restapi_search(c) => c.error_policy.as_ref(),
+            TaskType::salesforce_restapi_query(c) => c.error_policy.as_ref(),
+            TaskType::salesforce_toolingapi(c) => c.error_policy.as_ref(),
+            TaskType::gcp_bigquery_query(c) => c.error_policy.as_ref(),
+            TaskType::gcp_bigquery_storage_read(c) => c.error_policy.as_ref(),
+            TaskType::gcp_bigquery_job(c) => c.error_policy.as_ref(),
+            TaskType::gcp_bigquery_storage_write(c) => c.error_policy.as_ref(),
+            TaskType::gcp_bigquery_insert_all(c) => c.error_policy.as_ref(),
+            TaskType::gcp_pubsub_subscriber(c) => c.error_policy.as_ref(),
+            TaskType::mssql_query(c) => c.error_policy.as_ref(),
+            TaskType::clickhouse_publisher(c) => c.error_policy.as_ref(),
+            TaskType::redis_streams_publisher(c) => c.error_policy.as_ref(),
+            TaskType::arrow_flight_subscriber(c) => c.error_policy.as_ref(),
+            TaskType::arrow_flight_publisher(c) => c.error_policy.as_ref(),
+            TaskType::nats_kv_store(c) => c.error_policy.as_ref(),
+            TaskType::ai_completion(c) => c.error_policy.as_ref(),
+            TaskType::mcp_tool(c) => c.error_policy.as_ref(),
+            TaskType::ai_gateway(c) => c.error_policy.as_ref(),
+            TaskType::git_sync(c) => c.error_policy.as_ref(),
+            TaskType::websocket_subscriber(c) => c.error_policy.as_ref(),
+            TaskType::sftp_subscriber(c) => c.error_policy.as_ref(),
+        }
+    }
+
+    /// Returns true if the task writes to an external target system.
+    ///
+    /// Used by dry-run mode to decide which tasks are replaced with a
+    /// logging publisher.
+    pub fn is_publisher(&self) -> bool {
+        use flowgen_gcp::bigquery::config::JobOperation;
+        use flowgen_http::config::Method;
+        use flowgen_nats::jetstream::kv_store::Operation as KvOperation;
+        use flowgen_object_store::config::Operation;
+        use flowgen_salesforce::bulkapi::config::QueryJobOperation;
+        use flowgen_salesforce::restapi::config::{CompositeOperation, SObjectOperation};
+
+        // Matched exhaustively so every new task type has to be classified.
+        match self {
+            TaskType::nats_jetstream_publisher(_)
+            | TaskType::salesforce_pubsubapi_publisher(_)
+            | TaskType::salesforce_toolingapi(_)
+            | TaskType::gcp_bigquery_storage_write(_)
+            | TaskType::gcp_bigquery_insert_all(_)
+            | TaskType::mssql_query(_)
+            | TaskType::clickhouse_publisher(_)
+            | TaskType::redis_streams_publisher(_)
+            | TaskType::arrow_flight_publisher(_)
+            | TaskType::git_sync(_) => true,
+            TaskType::object_store(c) => {
+                matches!(c.operation, Operation::Write | Operation::Move)
+            }
+            TaskType::http_request(c) => !matches!(c.method, Method::Get | Method::Head),
+            TaskType::nats_kv_store(c) => {
+                matches!(c.operation, KvOperation::Put | KvOperation::Delete)
+            }
+            TaskType::salesforce_bulkapi_query_job(c) => matches!(
+                c.operation,
+                QueryJobOperation::Delete | QueryJobOperation::Abort
+            ),
+            TaskType::salesforce_restapi_sobject(c) => !matches!(
+                c.operation,
+                SObjectOperation::Get | SObjectOperation::GetByExternalId
+            ),
+            TaskType::salesforce_restapi_composite(c) => {
+                !matches!(c.operation, CompositeOperation::Get)
+            }
+            TaskType::gcp_bigquery_job(c) => !matches!(c.operation, JobOperation::Get),
+            // Sources are never replaced since a dry run needs their events.
+            // Sources with side effects of their own, such as the SFTP
+            // subscriber moving processed files, check the dry-run flag on
+            // the task context instead.
+            TaskType::convert(_)
+            | TaskType::iterate(_)
+            | TaskType::log(_)
+            | TaskType::script(_)
+            | TaskType::buffer(_)
+            | TaskType::aggregate(_)
+            | TaskType::window(_)
+            | TaskType::split(_)
+            | TaskType::mask(_)
+            | TaskType::cast(_)
+            | TaskType::project(_)
+            | TaskType::rename(_)
+            | TaskType::timestamp(_)
+            | TaskType::dedup(_)
+            | TaskType::encrypt(_)
+            | TaskType::decrypt(_)
+            | TaskType::router(_)
+            | TaskType::generate(_)
+            | TaskType::http_webhook(_)
+            | TaskType::html_scrape(_)
+            | TaskType::nats_jetstream_subscriber(_)
+            | TaskType::nats_core_subscriber(_)
+            | TaskType::salesforce_pubsubapi_subscriber(_)
+            | TaskType::salesforce_restapi_search(_)
+            | TaskType::salesforce_restapi_query(_)
+            | TaskType::gcp_bigquery_query(_)
+            | TaskType::gcp_bigquery_storage_read(_)
+            | TaskType::gcp_pubsub_subscriber(_)
+            | TaskType::arrow_flight_subscriber(_)
+            | TaskType::ai_completion(_)
+            | TaskType::mcp_tool(_)
+            | TaskType::ai_gateway(_)
+            | TaskType::websocket_subscriber(_)
+            | TaskType::sftp_subscriber(_) => false,
         }
     }
 }
@@ -307,6 +574,13 @@ pub struct CacheOptions {
     /// Defaults to 1 hour.
     #[serde(default, with = "humantime_serde")]
     pub tombstone_ttl: Option<std::time::Duration>,
+    /// Optional prefix prepended to every cache key.
+    /// Allows several workers to share one KV bucket without key collisions.
+    #[serde(default)]
+    pub key_prefix: Option<String>,
+    /// Optional TLS settings for the NATS connection.
+    #[serde(default)]
+    pub tls: Option<flowgen_nats::client::TlsOptions>,
 }
 
 /// Flow loading configuration.
@@ -481,6 +755,7 @@ mod tests {
                 tasks: vec![],
                 require_leader_election: None,
                 parallel_instances: 1,
+                channel_capacity: None,
             },
         };
 
@@ -501,6 +776,7 @@ mod tests {
                 tasks: vec![],
                 require_leader_election: None,
                 parallel_instances: 1,
+                channel_capacity: None,
             },
         };
 
@@ -520,6 +796,7 @@ mod tests {
             tasks: vec![],
             require_leader_election: None,
             parallel_instances: 1,
+            channel_capacity: None,
         };
 
         assert_eq!(flow.name, "test_flow");
@@ -538,6 +815,7 @@ mod tests {
             tasks: vec![task],
             require_leader_election: None,
             parallel_instances: 1,
+            channel_capacity: None,
         };
 
         assert_eq!(flow.name, "flow_with_tasks");
@@ -560,6 +838,7 @@ mod tests {
             tasks: vec![],
             require_leader_election: None,
             parallel_instances: 1,
+            channel_capacity: None,
         };
 
         let serialized = serde_json::to_string(&flow).unwrap();
@@ -575,12 +854,41 @@ mod tests {
             tasks: vec![],
             require_leader_election: None,
             parallel_instances: 1,
+            channel_capacity: None,
         };
 
         let cloned = flow.clone();
         assert_eq!(flow, cloned);
     }
 
+    #[test]
+    fn test_flow_config_task_summary() {
+        let flow_config = FlowConfig {
+            flow: Flow {
+                name: "summary_flow".to_string(),
+                labels: None,
+                tasks: vec![
+                    TaskType::generate(flowgen_core::task::generate::config::Subscriber {
+                        name: "tick".to_string(),
+                        ..Default::default()
+                    }),
+                    TaskType::convert(flowgen_core::task::convert::config::Processor {
+                        name: "to_json".to_string(),
+                        ..Default::default()
+                    }),
+                ],
+                require_leader_election: None,
+                parallel_instances: 1,
+                channel_capacity: None,
+            },
+        };
+
+        assert_eq!(
+            flow_config.task_summary(),
+            "tick (generate), to_json (convert)"
+        );
+    }
+
     #[test]
     fn test_task_variants() {
         let convert_task =
@@ -592,6 +900,40 @@ mod tests {
         assert!(matches!(generate_task, TaskType::generate(_)));
     }
 
+    #[test]
+    fn test_task_type_is_publisher() {
+        let publisher = TaskType::nats_jetstream_publisher(
+            flowgen_nats::jetstream::config::Publisher::default(),
+        );
+        let convert = TaskType::convert(flowgen_core::task::convert::config::Processor::default());
+        let read = TaskType::object_store(flowgen_object_store::config::Processor::default());
+        let write = TaskType::object_store(flowgen_object_store::config::Processor {
+            operation: flowgen_object_store::config::Operation::Write,
+            ..Default::default()
+        });
+
+        assert!(publisher.is_publisher());
+        assert!(!convert.is_publisher());
+        assert!(!read.is_publisher());
+        assert!(write.is_publisher());
+
+        let get = TaskType::http_request(flowgen_http::config::Processor::default());
+        let post = TaskType::http_request(flowgen_http::config::Processor {
+            method: flowgen_http::config::Method::Post,
+            ..Default::default()
+        });
+        let kv_get = TaskType::nats_kv_store(flowgen_nats::jetstream::kv_store::Config {
+            operation: flowgen_nats::jetstream::kv_store::Operation::Get,
+            ..Default::default()
+        });
+        let kv_put = TaskType::nats_kv_store(flowgen_nats::jetstream::kv_store::Config::default());
+
+        assert!(!get.is_publisher());
+        assert!(post.is_publisher());
+        assert!(!kv_get.is_publisher());
+        assert!(kv_put.is_publisher());
+    }
+
     #[test]
     fn test_app_config_creation() {
         let app_config = AppConfig {
@@ -603,6 +945,8 @@ mod tests {
                 db_name: None,
                 history: None,
                 tombstone_ttl: None,
+                key_prefix: None,
+                tls: None,
             }),
             flows: FlowOptions {
                 path: Some(PathBuf::from("/test/flows/*")),
@@ -666,6 +1010,8 @@ mod tests {
                 db_name: Some("test_db".to_string()),
                 history: None,
                 tombstone_ttl: None,
+                key_prefix: None,
+                tls: None,
             }),
             flows: FlowOptions {
                 path: Some(PathBuf::from("/serialize/flows/*")),
@@ -697,6 +1043,8 @@ mod tests {
                 db_name: None,
                 history: None,
                 tombstone_ttl: None,
+                key_prefix: None,
+                tls: None,
             }),
             flows: FlowOptions {
                 path: None,
@@ -726,6 +1074,8 @@ mod tests {
             db_name: None,
             history: None,
             tombstone_ttl: None,
+            key_prefix: None,
+            tls: None,
         };
 
         assert!(cache_options.enabled);
@@ -745,6 +1095,8 @@ mod tests {
             db_name: Some("custom_db".to_string()),
             history: None,
             tombstone_ttl: None,
+            key_prefix: None,
+            tls: None,
         };
 
         assert!(!cache_options.enabled);
@@ -764,6 +1116,8 @@ mod tests {
             db_name: None,
             history: None,
             tombstone_ttl: None,
+            key_prefix: None,
+            tls: None,
         };
 
         let serialized = serde_json::to_string(&cache_options).unwrap();
@@ -829,6 +1183,7 @@ mod tests {
                 ],
                 require_leader_election: None,
                 parallel_instances: 1,
+                channel_capacity: None,
             },
         };
 
@@ -31,15 +31,16 @@
 //! while background tasks receive from a different channel.
 
 use crate::config::{FlowConfig, TaskType};
-use flowgen_core::{event::Event, task::runner::Runner};
+use flowgen_core::{error_policy::ErrorPolicy, event::Event, task::runner::Runner};
 use std::sync::Arc;
 use tokio::{sync::mpsc, task::JoinHandle};
-use tracing::{debug, error, info, warn, Instrument};
+use tracing::{debug, error, info, trace, warn, Instrument};
 
 // Per-edge event buffer capacity. Sized to absorb burst jitter (e.g. iterate
 // fan-out) without pinning the producer. When full the sender awaits — this is
 // intentional backpressure that bounds memory. Override per-worker via
-// `worker.event_buffer_size` in the config file.
+// `worker.event_buffer_size` in the config file, or per flow via
+// `flow.channel_capacity`.
 const DEFAULT_EVENT_BUFFER_SIZE: usize = 10_000;
 
 /// Errors that can occur during flow execution.
@@ -61,6 +62,42 @@ pub enum Error {
     /// Error in buffer processor task.
     #[error(transparent)]
     BufferProcessor(#[from] flowgen_core::task::buffer::processor::Error),
+    /// Error in aggregate processor task.
+    #[error(transparent)]
+    AggregateProcessor(#[from] flowgen_core::task::aggregate::processor::Error),
+    /// Error in window processor task.
+    #[error(transparent)]
+    WindowProcessor(#[from] flowgen_core::task::window::processor::Error),
+    /// Error in split processor task.
+    #[error(transparent)]
+    SplitProcessor(#[from] flowgen_core::task::split::processor::Error),
+    /// Error in mask processor task.
+    #[error(transparent)]
+    MaskProcessor(#[from] flowgen_core::task::mask::processor::Error),
+    /// Error in cast processor task.
+    #[error(transparent)]
+    CastProcessor(#[from] flowgen_core::task::cast::processor::Error),
+    /// Error in project processor task.
+    #[error(transparent)]
+    ProjectProcessor(#[from] flowgen_core::task::project::processor::Error),
+    /// Error in rename processor task.
+    #[error(transparent)]
+    RenameProcessor(#[from] flowgen_core::task::rename::processor::Error),
+    /// Error in timestamp processor task.
+    #[error(transparent)]
+    TimestampProcessor(#[from] flowgen_core::task::timestamp::processor::Error),
+    /// Error in dedup processor task.
+    #[error(transparent)]
+    DedupProcessor(#[from] flowgen_core::task::dedup::processor::Error),
+    /// Error in encrypt processor task.
+    #[error(transparent)]
+    EncryptProcessor(#[from] flowgen_core::task::encrypt::processor::Error),
+    /// Error in decrypt processor task.
+    #[error(transparent)]
+    DecryptProcessor(#[from] flowgen_core::task::decrypt::processor::Error),
+    /// Error in router processor task.
+    #[error(transparent)]
+    RouterProcessor(#[from] flowgen_core::task::router::processor::Error),
     /// Error in Salesforce Pub/Sub API subscriber task.
     #[error(transparent)]
     SalesforcePubSubSubscriber(#[from] flowgen_salesforce::pubsubapi::subscriber::Error),
@@ -85,6 +122,9 @@ pub enum Error {
     /// Error in NATS JetStream subscriber task.
     #[error(transparent)]
     NatsJetStreamSubscriber(#[from] flowgen_nats::jetstream::subscriber::Error),
+    /// Error in core NATS subscriber task.
+    #[error(transparent)]
+    NatsCoreSubscriber(#[from] flowgen_nats::core::subscriber::Error),
     /// Error in object store read task.
     #[error(transparent)]
     ObjectStoreRead(#[from] flowgen_object_store::read::Error),
@@ -136,9 +176,15 @@ pub enum Error {
     /// Error in Salesforce REST API SOSL search operations.
     #[error(transparent)]
     SalesforceRestApiSearch(#[from] flowgen_salesforce::restapi::search::Error),
+    /// Error in Salesforce REST API SOQL query operations.
+    #[error(transparent)]
+    SalesforceRestApiQuery(#[from] flowgen_salesforce::restapi::query::Error),
     /// Error in Salesforce Tooling API operations.
     #[error(transparent)]
     SalesforceTooling(#[from] flowgen_salesforce::toolingapi::processor::Error),
+    /// Error in dry-run publisher task.
+    #[error(transparent)]
+    DryRunPublisher(#[from] flowgen_core::task::dry_run::publisher::Error),
     /// Error in GCP BigQuery query task.
     #[error(transparent)]
     GcpBigQueryQuery(#[from] flowgen_gcp::bigquery::query::Error),
@@ -151,9 +197,27 @@ pub enum Error {
     /// Error in GCP BigQuery Storage Write task.
     #[error(transparent)]
     GcpBigQueryStorageWrite(#[from] flowgen_gcp::bigquery::storage_write::Error),
+    /// Error in GCP BigQuery streaming insert task.
+    #[error(transparent)]
+    GcpBigQueryInsertAll(#[from] flowgen_gcp::bigquery::insert_all::Error),
+    /// Error in GCP Pub/Sub subscriber task.
+    #[error(transparent)]
+    GcpPubSubSubscriber(#[from] flowgen_gcp::pubsub::subscriber::Error),
     /// Error in Microsoft SQL Server query task.
     #[error(transparent)]
     MssqlQuery(#[from] flowgen_mssql::query::Error),
+    /// Error in ClickHouse publisher task.
+    #[error(transparent)]
+    ClickHousePublisher(#[from] flowgen_clickhouse::publisher::Error),
+    /// Error in Redis Streams publisher task.
+    #[error(transparent)]
+    RedisStreamsPublisher(#[from] flowgen_redis::streams::publisher::Error),
+    /// Error in Arrow Flight subscriber task.
+    #[error(transparent)]
+    ArrowFlightSubscriber(#[from] flowgen_arrow_flight::subscriber::Error),
+    /// Error in Arrow Flight publisher task.
+    #[error(transparent)]
+    ArrowFlightPublisher(#[from] flowgen_arrow_flight::publisher::Error),
     /// Error in AI completion task.
     #[error(transparent)]
     AiCompletion(#[from] flowgen_ai_agent::completion::processor::Error),
@@ -169,6 +233,12 @@ pub enum Error {
     /// Error in Git sync task.
     #[error(transparent)]
     GitSync(#[from] flowgen_git::sync::processor::Error),
+    /// Error in WebSocket subscriber task.
+    #[error(transparent)]
+    WebSocketSubscriber(#[from] flowgen_websocket::subscriber::Error),
+    /// Error in SFTP subscriber task.
+    #[error(transparent)]
+    SftpSubscriber(#[from] flowgen_file::sftp::subscriber::Error),
     /// Failed to store background task handles for later monitoring.
     #[error("Error storing background task handles")]
     BackgroundHandlesStoreFailed,
@@ -181,6 +251,12 @@ pub enum Error {
     /// Flow cannot be initialized because MCP server is not enabled.
     #[error("Flow cannot be initialized as mcp_server is not configured. Add worker.mcp_server to config or remove mcp_tool tasks.")]
     McpServerNotEnabled,
+    /// Task panicked or was aborted before completing.
+    #[error("Task terminated abnormally: {source}")]
+    TaskJoin {
+        #[source]
+        source: tokio::task::JoinError,
+    },
     /// Flow configuration error (duplicate task names, invalid dependencies, etc.).
     #[error("Flow configuration error: {0}")]
     ConfigError(String),
@@ -513,6 +589,14 @@ pub struct Flow {
     task_manager: Option<Arc<flowgen_core::task::manager::TaskManager>>,
     /// Background task handles spawned by start_tasks for monitor_tasks to monitor.
     background_handles: Arc<std::sync::Mutex<Option<Vec<TaskHandle>>>>,
+    /// When true, publisher tasks are replaced with a dry-run publisher that only logs events.
+    dry_run: bool,
+    /// Number of events after which a dry-run flow is stopped.
+    sample_count: Option<u64>,
+    /// Events received by dry-run publishers across all instances of this flow.
+    dry_run_received: Arc<std::sync::atomic::AtomicU64>,
+    /// Throughput counters shared by all tasks in this flow.
+    metrics: flowgen_core::metrics::FlowMetrics,
 }
 
 impl Flow {
@@ -521,6 +605,23 @@ impl Flow {
         &self.config.flow.name
     }
 
+    /// Returns the flow's throughput counters.
+    pub fn metrics(&self) -> &flowgen_core::metrics::FlowMetrics {
+        &self.metrics
+    }
+
+    /// Returns the per-edge event channel capacity for this flow.
+    ///
+    /// The flow's `channel_capacity` takes precedence over the worker-level
+    /// `event_buffer_size`, which in turn overrides the default.
+    fn channel_capacity(&self) -> usize {
+        self.config
+            .flow
+            .channel_capacity
+            .or(self.event_buffer_size)
+            .unwrap_or(DEFAULT_EVENT_BUFFER_SIZE)
+    }
+
     /// Returns a reference to the task manager if initialized.
     pub fn task_manager(&self) -> Option<Arc<flowgen_core::task::manager::TaskManager>> {
         self.task_manager.as_ref().map(Arc::clone)
@@ -559,6 +660,14 @@ impl Flow {
             return Ok(()); // Already initialized
         }
 
+        // Bounded channels cannot be created with zero capacity.
+        if self.channel_capacity() == 0 {
+            return Err(Error::ConfigError(format!(
+                "Flow {} has a channel capacity of zero; channel_capacity must be at least 1",
+                self.config.flow.name
+            )));
+        }
+
         // Validate: Flow with http_webhook tasks requires HTTP server to be configured.
         let has_webhook_tasks = self
             .config
@@ -588,6 +697,13 @@ impl Flow {
         let executor = flowgen_core::executor::Executor::new(self.cache.clone(), lease_config)?;
         let executor = Arc::new(executor);
 
+        // Expose this flow's counters on the HTTP server's metrics endpoint.
+        if let Some(http_server) = &self.http_server {
+            http_server
+                .register_metrics(self.config.flow.name.clone(), self.metrics.clone())
+                .await;
+        }
+
         let task_manager = flowgen_core::task::manager::TaskManagerBuilder::new()
             .executor(executor)
             .build()?;
@@ -622,6 +738,8 @@ impl Flow {
             .mcp_server(self.mcp_server.clone())
             .response_registry(response_registry)
             .resource_loader(self.resource_loader.clone())
+            .metrics(self.metrics.clone())
+            .dry_run(self.dry_run)
             .cancellation_token(cancellation_token);
 
         if let Some(retry_config) = &self.retry {
@@ -669,7 +787,7 @@ impl Flow {
         let task_context = self.create_task_context()?;
 
         // Build task registry with all tasks properly wired.
-        let buffer_size = self.event_buffer_size.unwrap_or(DEFAULT_EVENT_BUFFER_SIZE);
+        let buffer_size = self.channel_capacity();
         let registry = TaskRegistry::builder(self.config.clone(), buffer_size).build()?;
 
         // Separate blocking (setup) tasks from background tasks.
@@ -678,14 +796,14 @@ impl Flow {
         // Spawn all blocking tasks (webhooks).
         let mut blocking_handles = Vec::new();
         for task_desc in blocking_tasks {
-            let handle = spawn_task(task_desc, task_context.clone()).await?;
+            let handle = self.dispatch_task(task_desc, task_context.clone()).await?;
             blocking_handles.push(handle);
         }
 
         // Spawn all background tasks.
         let mut background_handles = Vec::new();
         for task_desc in background_tasks {
-            let handle = spawn_task(task_desc, task_context.clone()).await?;
+            let handle = self.dispatch_task(task_desc, task_context.clone()).await?;
             background_handles.push(handle);
         }
 
@@ -696,13 +814,33 @@ impl Flow {
         })
     }
 
+    /// Spawns a single task under its error policy supervisor.
+    ///
+    /// In dry-run mode publishers are swapped for a dry-run publisher instead.
+    async fn dispatch_task(
+        &self,
+        task_desc: TaskDescriptor,
+        task_context: Arc<flowgen_core::task::context::TaskContext>,
+    ) -> Result<TaskHandle, Error> {
+        if self.dry_run && task_desc.task_type.is_publisher() {
+            return spawn_dry_run_publisher(
+                task_desc,
+                task_context,
+                Arc::clone(&self.dry_run_received),
+                self.sample_count,
+            )
+            .await;
+        }
+        Ok(supervise_task(task_desc, task_context))
+    }
+
     /// Spawns multiple independent flow pipelines for higher throughput.
     ///
     /// Creates N separate task registries, each with its own event channels.
     /// All instances share the same cancellation token for coordinated shutdown.
     async fn spawn_parallel_instances(&self, count: usize) -> Result<TaskHandles, Error> {
         let task_context = self.create_task_context()?;
-        let buffer_size = self.event_buffer_size.unwrap_or(DEFAULT_EVENT_BUFFER_SIZE);
+        let buffer_size = self.channel_capacity();
 
         let mut all_blocking_tasks = Vec::new();
         let mut all_background_tasks = Vec::new();
@@ -718,14 +856,14 @@ impl Flow {
         // Spawn all blocking tasks.
         let mut blocking_handles = Vec::new();
         for task_desc in all_blocking_tasks {
-            let handle = spawn_task(task_desc, task_context.clone()).await?;
+            let handle = self.dispatch_task(task_desc, task_context.clone()).await?;
             blocking_handles.push(handle);
         }
 
         // Spawn all background tasks.
         let mut background_handles = Vec::new();
         for task_desc in all_background_tasks {
-            let handle = spawn_task(task_desc, task_context.clone()).await?;
+            let handle = self.dispatch_task(task_desc, task_context.clone()).await?;
             background_handles.push(handle);
         }
 
@@ -997,6 +1135,282 @@ impl Flow {
     }
 }
 
+/// Spawns a task under a supervisor that applies the task's error policy.
+///
+/// `Abort` cancels the rest of the flow, `Skip` logs the failure and lets the
+/// remaining tasks continue, and `Restart` respawns the task with the same
+/// channel endpoints. A task that exhausts its restarts aborts the flow.
+fn supervise_task(
+    task_desc: TaskDescriptor,
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+) -> TaskHandle {
+    let policy = task_desc
+        .task_type
+        .error_policy()
+        .cloned()
+        .unwrap_or_default();
+    let task_name = task_desc.task_type.name().to_string();
+    let span = tracing::Span::current();
+
+    tokio::spawn(
+        async move {
+            let result = match policy {
+                ErrorPolicy::Restart {
+                    max_attempts,
+                    delay_ms,
+                } => {
+                    run_with_restarts(
+                        task_desc,
+                        Arc::clone(&task_context),
+                        max_attempts,
+                        std::time::Duration::from_millis(delay_ms),
+                    )
+                    .await
+                }
+                ErrorPolicy::Abort | ErrorPolicy::Skip { .. } => {
+                    match spawn_task(task_desc, Arc::clone(&task_context)).await {
+                        Ok(handle) => join_task(handle).await,
+                        Err(e) => Err(e),
+                    }
+                }
+            };
+
+            let Err(e) = result else {
+                return Ok(());
+            };
+
+            match policy {
+                ErrorPolicy::Skip { .. } => {
+                    match policy.log_level() {
+                        tracing::Level::TRACE => {
+                            trace!(task = %task_name, error = %e, "Task failed, skipping")
+                        }
+                        tracing::Level::DEBUG => {
+                            debug!(task = %task_name, error = %e, "Task failed, skipping")
+                        }
+                        tracing::Level::INFO => {
+                            info!(task = %task_name, error = %e, "Task failed, skipping")
+                        }
+                        tracing::Level::WARN => {
+                            warn!(task = %task_name, error = %e, "Task failed, skipping")
+                        }
+                        _ => {
+                            error!(task = %task_name, error = %e, "Task failed, skipping")
+                        }
+                    }
+                    Ok(())
+                }
+                ErrorPolicy::Abort | ErrorPolicy::Restart { .. } => {
+                    error!(task = %task_name, error = %e, "Task failed, cancelling flow");
+                    task_context.cancellation_token.cancel();
+                    Err(e)
+                }
+            }
+        }
+        .instrument(span),
+    )
+}
+
+/// Aborts the wrapped task when dropped.
+///
+/// The supervisor holds the tasks it spawns through this guard, so aborting
+/// the supervisor (for example on leadership loss) also stops the task itself
+/// instead of leaving it detached and running.
+struct AbortOnDrop(TaskHandle);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Awaits a task handle, flattening panics into a task error.
+async fn join_task(handle: TaskHandle) -> Result<(), Error> {
+    let mut guard = AbortOnDrop(handle);
+    (&mut guard.0)
+        .await
+        .map_err(|source| Error::TaskJoin { source })?
+}
+
+/// Runs a task, respawning it on failure up to `max_attempts` times.
+///
+/// A failed task drops the receiver it was built with, so each attempt gets a
+/// fresh input channel fed from the original one. The attempt channel holds a
+/// single event and the next event is only taken from upstream once the task
+/// has received the previous one, so at most one event is lost with a failed
+/// attempt. That event is redelivered to the next attempt.
+async fn run_with_restarts(
+    task_desc: TaskDescriptor,
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+    max_attempts: u32,
+    delay: std::time::Duration,
+) -> Result<(), Error> {
+    let TaskDescriptor {
+        id,
+        task_type,
+        input_rx,
+        output_tx,
+        is_blocking,
+        downstream_leaves,
+    } = task_desc;
+    let mut input_rx = input_rx;
+    let mut pending: Option<Event> = None;
+    let mut attempt = 0;
+
+    loop {
+        let (attempt_tx, attempt_rx) = match input_rx {
+            Some(_) => {
+                let (tx, rx) = mpsc::channel(1);
+                (Some(tx), Some(rx))
+            }
+            None => (None, None),
+        };
+
+        let attempt_desc = TaskDescriptor {
+            id,
+            task_type: task_type.clone(),
+            input_rx: attempt_rx,
+            output_tx: output_tx.clone(),
+            is_blocking,
+            downstream_leaves,
+        };
+
+        let result = match spawn_task(attempt_desc, Arc::clone(&task_context)).await {
+            Ok(handle) => match (input_rx.as_mut(), attempt_tx) {
+                (Some(rx), Some(attempt_tx)) => {
+                    let mut guard = AbortOnDrop(handle);
+                    forward_until_exit(rx, attempt_tx, &mut pending, &mut guard.0).await
+                }
+                _ => join_task(handle).await,
+            },
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(()) => return Ok(()),
+            Err(e) if attempt < max_attempts && !task_context.cancellation_token.is_cancelled() => {
+                attempt += 1;
+                warn!(
+                    task = %task_type.name(),
+                    attempt,
+                    max_attempts,
+                    error = %e,
+                    "Task failed, restarting"
+                );
+                tokio::time::sleep(delay).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Forwards events from `rx` to a task attempt until the task exits.
+///
+/// `attempt_tx` must have a capacity of one. A copy of the event in its buffer
+/// is kept until the task takes it, and when the task exits first that copy is
+/// stored in `pending` to be sent to the next attempt. A `pending` event from a
+/// previous attempt is sent before anything new is read from upstream. When
+/// upstream closes, the attempt sender is dropped so the task can drain and
+/// finish.
+async fn forward_until_exit(
+    rx: &mut mpsc::Receiver<Event>,
+    attempt_tx: mpsc::Sender<Event>,
+    pending: &mut Option<Event>,
+    handle: &mut TaskHandle,
+) -> Result<(), Error> {
+    let mut attempt_tx = Some(attempt_tx);
+    let mut in_flight: Option<Event> = None;
+
+    let result = loop {
+        let Some(tx) = attempt_tx.as_ref() else {
+            break (&mut *handle).await;
+        };
+
+        tokio::select! {
+            result = &mut *handle => break result,
+            permit = tx.reserve() => {
+                let Ok(permit) = permit else {
+                    attempt_tx = None;
+                    continue;
+                };
+                // The buffer holds a single event, so a free slot means the
+                // task has received the previous one.
+                in_flight = None;
+
+                let event = match pending.take() {
+                    Some(event) => Some(event),
+                    None => tokio::select! {
+                        result = &mut *handle => break result,
+                        event = rx.recv() => event,
+                    },
+                };
+                match event {
+                    Some(event) => {
+                        in_flight = Some(event.clone());
+                        permit.send(event);
+                    }
+                    None => {
+                        drop(permit);
+                        attempt_tx = None;
+                    }
+                }
+            }
+        }
+    };
+
+    if let Some(event) = in_flight {
+        *pending = Some(event);
+    }
+    result.map_err(|source| Error::TaskJoin { source })?
+}
+
+/// Spawns a dry-run publisher in place of the task described by `task_desc`.
+///
+/// The replacement receives the same channel endpoints as the original task,
+/// so upstream tasks run unchanged and downstream tasks still see the events.
+async fn spawn_dry_run_publisher(
+    task_desc: TaskDescriptor,
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+    received: Arc<std::sync::atomic::AtomicU64>,
+    sample_count: Option<u64>,
+) -> Result<TaskHandle, Error> {
+    let name = task_desc.task_type.name().to_string();
+    let task_type_str = task_desc.task_type.as_str();
+    let span = tracing::Span::current();
+
+    let Some(rx) = task_desc.input_rx else {
+        // A publisher without upstream input has nothing to log.
+        info!(task = %name, "Dry run: skipping publisher without input");
+        return Ok(tokio::spawn(async { Ok(()) }));
+    };
+
+    let task_context = {
+        let mut ctx = (*task_context).clone();
+        ctx.leaf_count = task_desc.downstream_leaves;
+        Arc::new(ctx)
+    };
+
+    let mut builder = flowgen_core::task::dry_run::publisher::PublisherBuilder::new()
+        .name(name)
+        .receiver(rx)
+        .task_id(task_desc.id)
+        .task_type(task_type_str)
+        .received(received)
+        .sample_count(sample_count)
+        .task_context(task_context);
+    if let Some(tx) = task_desc.output_tx {
+        builder = builder.sender(tx);
+    }
+
+    Ok(tokio::spawn(
+        async move {
+            builder.build().await?.run().await?;
+            Ok(())
+        }
+        .instrument(span),
+    ))
+}
+
 /// Spawns a single task based on its descriptor with proper channel wiring.
 ///
 /// Returns a JoinHandle for the spawned task.
@@ -1108,12 +1522,12 @@ async fn spawn_task(
                 .instrument(span),
             )
         }
-        TaskType::script(config) => {
+        TaskType::aggregate(config) => {
             let config = Arc::new(config);
             tokio::spawn(
                 async move {
                     let mut builder =
-                        flowgen_core::task::script::processor::ProcessorBuilder::new()
+                        flowgen_core::task::aggregate::processor::ProcessorBuilder::new()
                             .config(config)
                             .task_id(task_id)
                             .task_type(task_type_str)
@@ -1130,16 +1544,19 @@ async fn spawn_task(
                 .instrument(span),
             )
         }
-        TaskType::generate(config) => {
+        TaskType::window(config) => {
             let config = Arc::new(config);
             tokio::spawn(
                 async move {
                     let mut builder =
-                        flowgen_core::task::generate::subscriber::SubscriberBuilder::new()
+                        flowgen_core::task::window::processor::ProcessorBuilder::new()
                             .config(config)
                             .task_id(task_id)
                             .task_type(task_type_str)
                             .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
                     if let Some(tx) = tx {
                         builder = builder.sender(tx);
                     }
@@ -1149,11 +1566,11 @@ async fn spawn_task(
                 .instrument(span),
             )
         }
-        TaskType::http_request(config) => {
+        TaskType::split(config) => {
             let config = Arc::new(config);
             tokio::spawn(
                 async move {
-                    let mut builder = flowgen_http::request::ProcessorBuilder::new()
+                    let mut builder = flowgen_core::task::split::processor::ProcessorBuilder::new()
                         .config(config)
                         .task_id(task_id)
                         .task_type(task_type_str)
@@ -1170,15 +1587,18 @@ async fn spawn_task(
                 .instrument(span),
             )
         }
-        TaskType::http_webhook(config) => {
+        TaskType::mask(config) => {
             let config = Arc::new(config);
             tokio::spawn(
                 async move {
-                    let mut builder = flowgen_http::webhook::ProcessorBuilder::new()
+                    let mut builder = flowgen_core::task::mask::processor::ProcessorBuilder::new()
                         .config(config)
                         .task_id(task_id)
                         .task_type(task_type_str)
                         .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
                     if let Some(tx) = tx {
                         builder = builder.sender(tx);
                     }
@@ -1188,11 +1608,11 @@ async fn spawn_task(
                 .instrument(span),
             )
         }
-        TaskType::html_scrape(config) => {
+        TaskType::cast(config) => {
             let config = Arc::new(config);
             tokio::spawn(
                 async move {
-                    let mut builder = flowgen_html::scrape::processor::ProcessorBuilder::new()
+                    let mut builder = flowgen_core::task::cast::processor::ProcessorBuilder::new()
                         .config(config)
                         .task_id(task_id)
                         .task_type(task_type_str)
@@ -1209,15 +1629,19 @@ async fn spawn_task(
                 .instrument(span),
             )
         }
-        TaskType::nats_jetstream_subscriber(config) => {
+        TaskType::project(config) => {
             let config = Arc::new(config);
             tokio::spawn(
                 async move {
-                    let mut builder = flowgen_nats::jetstream::subscriber::SubscriberBuilder::new()
-                        .config(config)
-                        .task_id(task_id)
-                        .task_type(task_type_str)
-                        .task_context(task_context);
+                    let mut builder =
+                        flowgen_core::task::project::processor::ProcessorBuilder::new()
+                            .config(config)
+                            .task_id(task_id)
+                            .task_type(task_type_str)
+                            .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
                     if let Some(tx) = tx {
                         builder = builder.sender(tx);
                     }
@@ -1227,11 +1651,55 @@ async fn spawn_task(
                 .instrument(span),
             )
         }
-        TaskType::nats_jetstream_publisher(config) => {
+        TaskType::rename(config) => {
             let config = Arc::new(config);
             tokio::spawn(
                 async move {
-                    let mut builder = flowgen_nats::jetstream::publisher::PublisherBuilder::new()
+                    let mut builder =
+                        flowgen_core::task::rename::processor::ProcessorBuilder::new()
+                            .config(config)
+                            .task_id(task_id)
+                            .task_type(task_type_str)
+                            .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build().await?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::timestamp(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder =
+                        flowgen_core::task::timestamp::processor::ProcessorBuilder::new()
+                            .config(config)
+                            .task_id(task_id)
+                            .task_type(task_type_str)
+                            .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build().await?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::dedup(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder = flowgen_core::task::dedup::processor::ProcessorBuilder::new()
                         .config(config)
                         .task_id(task_id)
                         .task_type(task_type_str)
@@ -1248,16 +1716,19 @@ async fn spawn_task(
                 .instrument(span),
             )
         }
-        TaskType::salesforce_pubsubapi_subscriber(config) => {
+        TaskType::encrypt(config) => {
             let config = Arc::new(config);
             tokio::spawn(
                 async move {
                     let mut builder =
-                        flowgen_salesforce::pubsubapi::subscriber::SubscriberBuilder::new()
+                        flowgen_core::task::encrypt::processor::ProcessorBuilder::new()
                             .config(config)
                             .task_id(task_id)
                             .task_type(task_type_str)
                             .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
                     if let Some(tx) = tx {
                         builder = builder.sender(tx);
                     }
@@ -1267,12 +1738,12 @@ async fn spawn_task(
                 .instrument(span),
             )
         }
-        TaskType::salesforce_pubsubapi_publisher(config) => {
+        TaskType::decrypt(config) => {
             let config = Arc::new(config);
             tokio::spawn(
                 async move {
                     let mut builder =
-                        flowgen_salesforce::pubsubapi::publisher::PublisherBuilder::new()
+                        flowgen_core::task::decrypt::processor::ProcessorBuilder::new()
                             .config(config)
                             .task_id(task_id)
                             .task_type(task_type_str)
@@ -1289,12 +1760,12 @@ async fn spawn_task(
                 .instrument(span),
             )
         }
-        TaskType::salesforce_bulkapi_query_job(config) => {
+        TaskType::router(config) => {
             let config = Arc::new(config);
             tokio::spawn(
                 async move {
                     let mut builder =
-                        flowgen_salesforce::bulkapi::query_job::ProcessorBuilder::new()
+                        flowgen_core::task::router::processor::ProcessorBuilder::new()
                             .config(config)
                             .task_id(task_id)
                             .task_type(task_type_str)
@@ -1305,60 +1776,58 @@ async fn spawn_task(
                     if let Some(tx) = tx {
                         builder = builder.sender(tx);
                     }
-                    builder.build()?.run().await?;
+                    builder.build().await?.run().await?;
                     Ok(())
                 }
                 .instrument(span),
             )
         }
-        TaskType::salesforce_restapi_sobject(config) => {
+        TaskType::script(config) => {
             let config = Arc::new(config);
             tokio::spawn(
                 async move {
-                    let mut builder = flowgen_salesforce::restapi::sobject::ProcessorBuilder::new()
-                        .config(config)
-                        .task_id(task_id)
-                        .task_type(task_type_str)
-                        .task_context(task_context);
+                    let mut builder =
+                        flowgen_core::task::script::processor::ProcessorBuilder::new()
+                            .config(config)
+                            .task_id(task_id)
+                            .task_type(task_type_str)
+                            .task_context(task_context);
                     if let Some(rx) = rx {
                         builder = builder.receiver(rx);
                     }
                     if let Some(tx) = tx {
                         builder = builder.sender(tx);
                     }
-                    builder.build()?.run().await?;
+                    builder.build().await?.run().await?;
                     Ok(())
                 }
                 .instrument(span),
             )
         }
-        TaskType::salesforce_restapi_composite(config) => {
+        TaskType::generate(config) => {
             let config = Arc::new(config);
             tokio::spawn(
                 async move {
                     let mut builder =
-                        flowgen_salesforce::restapi::composite::ProcessorBuilder::new()
+                        flowgen_core::task::generate::subscriber::SubscriberBuilder::new()
                             .config(config)
                             .task_id(task_id)
                             .task_type(task_type_str)
                             .task_context(task_context);
-                    if let Some(rx) = rx {
-                        builder = builder.receiver(rx);
-                    }
                     if let Some(tx) = tx {
                         builder = builder.sender(tx);
                     }
-                    builder.build()?.run().await?;
+                    builder.build().await?.run().await?;
                     Ok(())
                 }
                 .instrument(span),
             )
         }
-        TaskType::salesforce_restapi_search(config) => {
+        TaskType::http_request(config) => {
             let config = Arc::new(config);
             tokio::spawn(
                 async move {
-                    let mut builder = flowgen_salesforce::restapi::search::ProcessorBuilder::new()
+                    let mut builder = flowgen_http::request::ProcessorBuilder::new()
                         .config(config)
                         .task_id(task_id)
                         .task_type(task_type_str)
@@ -1369,38 +1838,318 @@ async fn spawn_task(
                     if let Some(tx) = tx {
                         builder = builder.sender(tx);
                     }
-                    builder.build()?.run().await?;
+                    builder.build().await?.run().await?;
                     Ok(())
                 }
                 .instrument(span),
             )
         }
-        TaskType::salesforce_toolingapi(config) => {
+        TaskType::http_webhook(config) => {
             let config = Arc::new(config);
             tokio::spawn(
                 async move {
-                    let mut builder =
-                        flowgen_salesforce::toolingapi::processor::ProcessorBuilder::new()
-                            .config(config)
-                            .task_id(task_id)
-                            .task_type(task_type_str)
-                            .task_context(task_context);
-                    if let Some(rx) = rx {
-                        builder = builder.receiver(rx);
-                    }
+                    let mut builder = flowgen_http::webhook::ProcessorBuilder::new()
+                        .config(config)
+                        .task_id(task_id)
+                        .task_type(task_type_str)
+                        .task_context(task_context);
                     if let Some(tx) = tx {
                         builder = builder.sender(tx);
                     }
-                    builder.build()?.run().await?;
+                    builder.build().await?.run().await?;
                     Ok(())
                 }
                 .instrument(span),
             )
         }
-        TaskType::object_store(config) => {
-            let operation = config.operation.clone();
+        TaskType::html_scrape(config) => {
             let config = Arc::new(config);
-            match operation {
+            tokio::spawn(
+                async move {
+                    let mut builder = flowgen_html::scrape::processor::ProcessorBuilder::new()
+                        .config(config)
+                        .task_id(task_id)
+                        .task_type(task_type_str)
+                        .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build().await?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::nats_jetstream_subscriber(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder = flowgen_nats::jetstream::subscriber::SubscriberBuilder::new()
+                        .config(config)
+                        .task_id(task_id)
+                        .task_type(task_type_str)
+                        .task_context(task_context);
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build().await?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::nats_core_subscriber(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder = flowgen_nats::core::subscriber::SubscriberBuilder::new()
+                        .config(config)
+                        .task_id(task_id)
+                        .task_type(task_type_str)
+                        .task_context(task_context);
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build().await?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::websocket_subscriber(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder = flowgen_websocket::subscriber::SubscriberBuilder::new()
+                        .config(config)
+                        .task_id(task_id)
+                        .task_type(task_type_str)
+                        .task_context(task_context);
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build().await?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::sftp_subscriber(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder = flowgen_file::sftp::subscriber::SubscriberBuilder::new()
+                        .config(config)
+                        .task_id(task_id)
+                        .task_type(task_type_str)
+                        .task_context(task_context);
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build().await?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::nats_jetstream_publisher(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder = flowgen_nats::jetstream::publisher::PublisherBuilder::new()
+                        .config(config)
+                        .task_id(task_id)
+                        .task_type(task_type_str)
+                        .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build().await?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::salesforce_pubsubapi_subscriber(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder =
+                        flowgen_salesforce::pubsubapi::subscriber::SubscriberBuilder::new()
+                            .config(config)
+                            .task_id(task_id)
+                            .task_type(task_type_str)
+                            .task_context(task_context);
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build().await?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::salesforce_pubsubapi_publisher(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder =
+                        flowgen_salesforce::pubsubapi::publisher::PublisherBuilder::new()
+                            .config(config)
+                            .task_id(task_id)
+                            .task_type(task_type_str)
+                            .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build().await?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::salesforce_bulkapi_query_job(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder =
+                        flowgen_salesforce::bulkapi::query_job::ProcessorBuilder::new()
+                            .config(config)
+                            .task_id(task_id)
+                            .task_type(task_type_str)
+                            .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build()?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::salesforce_restapi_sobject(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder = flowgen_salesforce::restapi::sobject::ProcessorBuilder::new()
+                        .config(config)
+                        .task_id(task_id)
+                        .task_type(task_type_str)
+                        .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build()?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::salesforce_restapi_composite(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder =
+                        flowgen_salesforce::restapi::composite::ProcessorBuilder::new()
+                            .config(config)
+                            .task_id(task_id)
+                            .task_type(task_type_str)
+                            .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build()?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::salesforce_restapi_search(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder = flowgen_salesforce::restapi::search::ProcessorBuilder::new()
+                        .config(config)
+                        .task_id(task_id)
+                        .task_type(task_type_str)
+                        .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build()?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::salesforce_restapi_query(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder = flowgen_salesforce::restapi::query::ProcessorBuilder::new()
+                        .config(config)
+                        .task_id(task_id)
+                        .task_type(task_type_str)
+                        .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build()?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::salesforce_toolingapi(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder =
+                        flowgen_salesforce::toolingapi::processor::ProcessorBuilder::new()
+                            .config(config)
+                            .task_id(task_id)
+                            .task_type(task_type_str)
+                            .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build()?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::object_store(config) => {
+            let operation = config.operation.clone();
+            let config = Arc::new(config);
+            match operation {
                 flowgen_object_store::config::Operation::Read => tokio::spawn(
                     async move {
                         let mut builder = flowgen_object_store::read::ReadProcessorBuilder::new()
@@ -1559,6 +2308,45 @@ async fn spawn_task(
                 .instrument(span),
             )
         }
+        TaskType::gcp_bigquery_insert_all(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder = flowgen_gcp::bigquery::insert_all::ProcessorBuilder::new()
+                        .config(config)
+                        .task_id(task_id)
+                        .task_type(task_type_str)
+                        .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build().await?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::gcp_pubsub_subscriber(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder = flowgen_gcp::pubsub::subscriber::SubscriberBuilder::new()
+                        .config(config)
+                        .task_id(task_id)
+                        .task_type(task_type_str)
+                        .task_context(task_context);
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build().await?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
         TaskType::mssql_query(config) => {
             let config = Arc::new(config);
             tokio::spawn(
@@ -1580,6 +2368,87 @@ async fn spawn_task(
                 .instrument(span),
             )
         }
+        TaskType::clickhouse_publisher(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder = flowgen_clickhouse::publisher::PublisherBuilder::new()
+                        .config(config)
+                        .task_id(task_id)
+                        .task_type(task_type_str)
+                        .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build().await?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::redis_streams_publisher(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder = flowgen_redis::streams::publisher::PublisherBuilder::new()
+                        .config(config)
+                        .task_id(task_id)
+                        .task_type(task_type_str)
+                        .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build().await?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::arrow_flight_subscriber(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder = flowgen_arrow_flight::subscriber::SubscriberBuilder::new()
+                        .config(config)
+                        .task_id(task_id)
+                        .task_type(task_type_str)
+                        .task_context(task_context);
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build().await?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
+        TaskType::arrow_flight_publisher(config) => {
+            let config = Arc::new(config);
+            tokio::spawn(
+                async move {
+                    let mut builder = flowgen_arrow_flight::publisher::PublisherBuilder::new()
+                        .config(config)
+                        .task_id(task_id)
+                        .task_type(task_type_str)
+                        .task_context(task_context);
+                    if let Some(rx) = rx {
+                        builder = builder.receiver(rx);
+                    }
+                    if let Some(tx) = tx {
+                        builder = builder.sender(tx);
+                    }
+                    builder.build().await?.run().await?;
+                    Ok(())
+                }
+                .instrument(span),
+            )
+        }
         TaskType::nats_kv_store(config) => {
             let config = Arc::new(config);
             tokio::spawn(
@@ -1703,6 +2572,10 @@ pub struct FlowBuilder {
     retry: Option<flowgen_core::retry::RetryConfig>,
     /// Resource loader for loading external files.
     resource_loader: Option<flowgen_core::resource::ResourceLoader>,
+    /// Whether publishers are replaced with a logging dry-run publisher.
+    dry_run: bool,
+    /// Number of events after which a dry-run flow is stopped.
+    sample_count: Option<u64>,
 }
 
 impl FlowBuilder {
@@ -1756,6 +2629,21 @@ impl FlowBuilder {
         self
     }
 
+    /// Enables dry-run mode.
+    ///
+    /// Sources and processors run normally while publishers only log and
+    /// count the events they receive.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Sets the number of events after which a dry-run flow is stopped.
+    pub fn sample_count(mut self, sample_count: u64) -> Self {
+        self.sample_count = Some(sample_count);
+        self
+    }
+
     /// Builds a Flow instance from the configured options.
     ///
     /// # Errors
@@ -1775,6 +2663,10 @@ impl FlowBuilder {
             resource_loader: self.resource_loader,
             task_manager: None,
             background_handles: Arc::new(std::sync::Mutex::new(None)),
+            dry_run: self.dry_run,
+            sample_count: self.sample_count,
+            dry_run_received: Arc::new(std::sync::atomic::AtomicU64::new(0)),
+            metrics: flowgen_core::metrics::FlowMetrics::new(),
         })
     }
 }
@@ -1806,6 +2698,7 @@ mod tests {
                 tasks: vec![],
                 require_leader_election: None,
                 parallel_instances: 1,
+                channel_capacity: None,
             },
         });
 
@@ -1841,6 +2734,7 @@ mod tests {
                 tasks: vec![],
                 require_leader_election: None,
                 parallel_instances: 1,
+                channel_capacity: None,
             },
         });
         let cache = Arc::new(flowgen_core::cache::memory::MemoryCache::new())
@@ -1862,6 +2756,7 @@ mod tests {
                 tasks: vec![],
                 require_leader_election: None,
                 parallel_instances: 1,
+                channel_capacity: None,
             },
         });
         let server = Arc::new(flowgen_http::server::HttpServerBuilder::new().build());
@@ -1878,6 +2773,76 @@ mod tests {
         let flow = result.unwrap();
         assert_eq!(flow.config, flow_config);
         assert!(flow.task_manager.is_none());
+        assert!(!flow.dry_run);
+        assert!(flow.sample_count.is_none());
+    }
+
+    #[test]
+    fn test_flow_channel_capacity_precedence() {
+        let flow_config = |channel_capacity| {
+            Arc::new(FlowConfig {
+                flow: Flow {
+                    name: "capacity_flow".to_string(),
+                    labels: None,
+                    tasks: vec![],
+                    require_leader_election: None,
+                    parallel_instances: 1,
+                    channel_capacity,
+                },
+            })
+        };
+        let cache = Arc::new(flowgen_core::cache::memory::MemoryCache::new())
+            as Arc<dyn flowgen_core::cache::Cache>;
+
+        let flow = FlowBuilder::new()
+            .config(flow_config(None))
+            .cache(Arc::clone(&cache))
+            .build()
+            .unwrap();
+        assert_eq!(flow.channel_capacity(), DEFAULT_EVENT_BUFFER_SIZE);
+
+        let flow = FlowBuilder::new()
+            .config(flow_config(None))
+            .cache(Arc::clone(&cache))
+            .event_buffer_size(500)
+            .build()
+            .unwrap();
+        assert_eq!(flow.channel_capacity(), 500);
+
+        let flow = FlowBuilder::new()
+            .config(flow_config(Some(50_000)))
+            .cache(cache)
+            .event_buffer_size(500)
+            .build()
+            .unwrap();
+        assert_eq!(flow.channel_capacity(), 50_000);
+    }
+
+    #[test]
+    fn test_flow_builder_dry_run() {
+        let flow_config = Arc::new(FlowConfig {
+            flow: Flow {
+                name: "dry_run_flow".to_string(),
+                labels: None,
+                tasks: vec![],
+                require_leader_election: None,
+                parallel_instances: 1,
+                channel_capacity: None,
+            },
+        });
+        let cache = Arc::new(flowgen_core::cache::memory::MemoryCache::new())
+            as Arc<dyn flowgen_core::cache::Cache>;
+
+        let flow = FlowBuilder::new()
+            .config(flow_config)
+            .cache(cache)
+            .dry_run(true)
+            .sample_count(5)
+            .build()
+            .unwrap();
+
+        assert!(flow.dry_run);
+        assert_eq!(flow.sample_count, Some(5));
     }
 
     #[test]
@@ -1895,6 +2860,7 @@ mod tests {
                 ],
                 require_leader_election: None,
                 parallel_instances: 1,
+                channel_capacity: None,
             },
         });
 
@@ -1934,6 +2900,7 @@ mod tests {
                 )],
                 require_leader_election: None,
                 parallel_instances: 1,
+                channel_capacity: None,
             },
         });
 
@@ -1964,6 +2931,7 @@ mod tests {
                 ],
                 require_leader_election: None,
                 parallel_instances: 1,
+                channel_capacity: None,
             },
         });
 
@@ -2002,6 +2970,7 @@ mod tests {
                 tasks: vec![],
                 require_leader_election: None,
                 parallel_instances: 1,
+                channel_capacity: None,
             },
         });
 
@@ -2025,6 +2994,7 @@ mod tests {
                 ],
                 require_leader_election: None,
                 parallel_instances: 1,
+                channel_capacity: None,
             },
         });
 
@@ -2034,4 +3004,167 @@ mod tests {
         assert_eq!(registry.tasks[1].id, 1);
         assert_eq!(registry.tasks[2].id, 2);
     }
+
+    fn create_test_task_context() -> Arc<flowgen_core::task::context::TaskContext> {
+        let task_manager = Arc::new(
+            flowgen_core::task::manager::TaskManagerBuilder::new()
+                .build()
+                .unwrap(),
+        );
+        let cache = Arc::new(flowgen_core::cache::memory::MemoryCache::new())
+            as Arc<dyn flowgen_core::cache::Cache>;
+        Arc::new(
+            flowgen_core::task::context::TaskContextBuilder::new()
+                .flow_name("test_flow".to_string())
+                .task_manager(task_manager)
+                .cache(cache)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    /// A log task without an input channel fails to build, which makes it a
+    /// convenient failing task for supervisor tests.
+    fn failing_task(error_policy: Option<ErrorPolicy>) -> TaskDescriptor {
+        TaskDescriptor {
+            id: 0,
+            task_type: TaskType::log(flowgen_core::task::log::config::Processor {
+                name: "failing".to_string(),
+                error_policy,
+                ..Default::default()
+            }),
+            input_rx: None,
+            output_tx: None,
+            is_blocking: false,
+            downstream_leaves: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_abort_on_drop_stops_task() {
+        let (alive_tx, alive_rx) = tokio::sync::oneshot::channel::<()>();
+        let handle: TaskHandle = tokio::spawn(async move {
+            let _alive = alive_tx;
+            std::future::pending::<()>().await;
+            Ok(())
+        });
+
+        drop(AbortOnDrop(handle));
+
+        // The sender is only dropped once the aborted task is torn down.
+        assert!(alive_rx.await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_supervise_task_skip_by_default() {
+        let task_context = create_test_task_context();
+
+        let result = supervise_task(failing_task(None), Arc::clone(&task_context)).await;
+
+        assert!(matches!(result, Ok(Ok(()))));
+        assert!(!task_context.cancellation_token.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_supervise_task_abort_cancels_flow() {
+        let task_context = create_test_task_context();
+
+        let result = supervise_task(
+            failing_task(Some(ErrorPolicy::Abort)),
+            Arc::clone(&task_context),
+        )
+        .await;
+
+        assert!(matches!(result, Ok(Err(Error::LogProcessor(_)))));
+        assert!(task_context.cancellation_token.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_supervise_task_restart_exhausts_attempts() {
+        let task_context = create_test_task_context();
+        let policy = ErrorPolicy::Restart {
+            max_attempts: 2,
+            delay_ms: 1,
+        };
+
+        let result = supervise_task(failing_task(Some(policy)), Arc::clone(&task_context)).await;
+
+        assert!(matches!(result, Ok(Err(Error::LogProcessor(_)))));
+        assert!(task_context.cancellation_token.is_cancelled());
+    }
+
+    fn test_event(id: i64) -> Event {
+        flowgen_core::event::EventBuilder::new()
+            .data(flowgen_core::event::EventData::Json(
+                serde_json::json!({"id": id}),
+            ))
+            .subject("test".to_string())
+            .task_type("test")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_forward_until_exit_keeps_undelivered_event() {
+        let (upstream_tx, mut upstream_rx) = mpsc::channel(10);
+        for id in 1..=3 {
+            upstream_tx.send(test_event(id)).await.unwrap();
+        }
+
+        // The attempt takes the first event, then fails while the second is
+        // still in its buffer.
+        let (attempt_tx, mut attempt_rx) = mpsc::channel(1);
+        let mut handle: TaskHandle = tokio::spawn(async move {
+            let first = attempt_rx.recv().await.unwrap();
+            assert_eq!(
+                serde_json::Value::try_from(&first.data).unwrap(),
+                serde_json::json!({"id": 1})
+            );
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            Err(Error::ConfigError("boom".to_string()))
+        });
+        let mut pending = None;
+
+        let result =
+            forward_until_exit(&mut upstream_rx, attempt_tx, &mut pending, &mut handle).await;
+
+        assert!(matches!(result, Err(Error::ConfigError(_))));
+        let pending_event = pending.take().unwrap();
+        assert_eq!(
+            serde_json::Value::try_from(&pending_event.data).unwrap(),
+            serde_json::json!({"id": 2})
+        );
+        let next = upstream_rx.recv().await.unwrap();
+        assert_eq!(
+            serde_json::Value::try_from(&next.data).unwrap(),
+            serde_json::json!({"id": 3})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_forward_until_exit_redelivers_pending_first() {
+        let (upstream_tx, mut upstream_rx) = mpsc::channel(10);
+        upstream_tx.send(test_event(2)).await.unwrap();
+        drop(upstream_tx);
+
+        let (attempt_tx, mut attempt_rx) = mpsc::channel(1);
+        let (seen_tx, mut seen_rx) = mpsc::unbounded_channel();
+        let mut handle: TaskHandle = tokio::spawn(async move {
+            while let Some(event) = attempt_rx.recv().await {
+                seen_tx
+                    .send(serde_json::Value::try_from(&event.data).unwrap())
+                    .unwrap();
+            }
+            Ok(())
+        });
+        let mut pending = Some(test_event(1));
+
+        let result =
+            forward_until_exit(&mut upstream_rx, attempt_tx, &mut pending, &mut handle).await;
+
+        assert!(result.is_ok());
+        assert!(pending.is_none());
+        assert_eq!(seen_rx.recv().await.unwrap(), serde_json::json!({"id": 1}));
+        assert_eq!(seen_rx.recv().await.unwrap(), serde_json::json!({"id": 2}));
+    }
 }
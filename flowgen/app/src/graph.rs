@@ -0,0 +1,226 @@
+//! Diagram export for flow configurations.
+//!
+//! Renders the task graph of a flow as Graphviz DOT or Mermaid so complex
+//! flows with fan-out and fan-in can be inspected visually. Edges follow the
+//! same rules as the runtime wiring: a task receives from the tasks named in
+//! its `depends_on`, or from the previous task in the list when it has none.
+
+use crate::config::FlowConfig;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// A single task in the flow graph.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// Task type identifier (e.g., "nats_jetstream_subscriber").
+    pub task_type: String,
+    /// Task name from its configuration.
+    pub name: String,
+}
+
+/// Task graph of a single flow.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlowGraph {
+    /// Flow name, used as the graph title.
+    name: String,
+    /// Tasks in declaration order.
+    nodes: Vec<Node>,
+    /// Edges as `(parent, child)` indices into `nodes`.
+    edges: Vec<(usize, usize)>,
+}
+
+impl FlowGraph {
+    /// Builds the graph for a flow configuration.
+    ///
+    /// Dependencies on unknown tasks are skipped; flow validation reports
+    /// them when the flow is started.
+    pub fn new(config: &FlowConfig) -> Self {
+        let tasks = &config.flow.tasks;
+        let nodes: Vec<Node> = tasks
+            .iter()
+            .map(|task| Node {
+                task_type: task.as_str().to_string(),
+                name: task.name().to_string(),
+            })
+            .collect();
+
+        let name_to_idx: HashMap<&str, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(idx, node)| (node.name.as_str(), idx))
+            .collect();
+
+        let mut edges = Vec::new();
+        for (idx, task) in tasks.iter().enumerate() {
+            match task.depends_on() {
+                Some(deps) => {
+                    for dep in deps {
+                        if let Some(&parent) = name_to_idx.get(dep.as_str()) {
+                            edges.push((parent, idx));
+                        }
+                    }
+                }
+                None if idx > 0 => edges.push((idx - 1, idx)),
+                None => {}
+            }
+        }
+
+        Self {
+            name: config.flow.name.clone(),
+            nodes,
+            edges,
+        }
+    }
+
+    /// Returns the tasks in declaration order.
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    /// Returns the edges as `(parent, child)` indices into [`FlowGraph::nodes`].
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+
+    /// Renders the graph in Graphviz DOT format (e.g., for `dot -Tpng`).
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "digraph \"{}\" {{", escape_dot(&self.name));
+        let _ = writeln!(out, "    rankdir=LR;");
+        let _ = writeln!(out, "    label=\"{}\";", escape_dot(&self.name));
+        let _ = writeln!(out, "    node [shape=box];");
+        for (idx, node) in self.nodes.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "    t{idx} [label=\"{}\\n{}\"];",
+                escape_dot(&node.task_type),
+                escape_dot(&node.name)
+            );
+        }
+        for (parent, child) in &self.edges {
+            let _ = writeln!(out, "    t{parent} -> t{child};");
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Renders the graph as a Mermaid flowchart.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "---");
+        let _ = writeln!(out, "title: {}", self.name);
+        let _ = writeln!(out, "---");
+        let _ = writeln!(out, "flowchart LR");
+        for (idx, node) in self.nodes.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "    t{idx}[\"{}<br/>{}\"]",
+                escape_mermaid(&node.task_type),
+                escape_mermaid(&node.name)
+            );
+        }
+        for (parent, child) in &self.edges {
+            let _ = writeln!(out, "    t{parent} --> t{child}");
+        }
+        out
+    }
+}
+
+/// Escapes a string for use inside a double-quoted DOT identifier.
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Escapes a string for use inside a double-quoted Mermaid label.
+fn escape_mermaid(value: &str) -> String {
+    value.replace('"', "#quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Flow, TaskType};
+    use flowgen_core::task::log::config::Processor as LogProcessor;
+
+    fn log_task(name: &str, depends_on: Option<Vec<&str>>) -> TaskType {
+        TaskType::log(LogProcessor {
+            name: name.to_string(),
+            depends_on: depends_on.map(|deps| deps.into_iter().map(String::from).collect()),
+            ..Default::default()
+        })
+    }
+
+    fn flow_config(tasks: Vec<TaskType>) -> FlowConfig {
+        FlowConfig {
+            flow: Flow {
+                name: "test_flow".to_string(),
+                labels: None,
+                tasks,
+                require_leader_election: None,
+                parallel_instances: 1,
+                channel_capacity: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_linear_flow_edges() {
+        let config = flow_config(vec![
+            log_task("a", None),
+            log_task("b", None),
+            log_task("c", None),
+        ]);
+        let graph = FlowGraph::new(&config);
+
+        assert_eq!(graph.nodes().len(), 3);
+        assert_eq!(graph.edges(), &[(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_dag_flow_edges() {
+        let config = flow_config(vec![
+            log_task("source", None),
+            log_task("left", Some(vec!["source"])),
+            log_task("right", Some(vec!["source"])),
+            log_task("sink", Some(vec!["left", "right"])),
+            log_task("after_sink", None),
+        ]);
+        let graph = FlowGraph::new(&config);
+
+        assert_eq!(graph.edges(), &[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]);
+    }
+
+    #[test]
+    fn test_unknown_dependency_is_skipped() {
+        let config = flow_config(vec![
+            log_task("a", None),
+            log_task("b", Some(vec!["missing"])),
+        ]);
+        let graph = FlowGraph::new(&config);
+
+        assert!(graph.edges().is_empty());
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let config = flow_config(vec![log_task("a", None), log_task("say \"hi\"", None)]);
+        let dot = FlowGraph::new(&config).to_dot();
+
+        assert!(dot.starts_with("digraph \"test_flow\" {\n"));
+        assert!(dot.contains("    t0 [label=\"log\\na\"];\n"));
+        assert!(dot.contains("    t1 [label=\"log\\nsay \\\"hi\\\"\"];\n"));
+        assert!(dot.contains("    t0 -> t1;\n"));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_to_mermaid() {
+        let config = flow_config(vec![log_task("a", None), log_task("say \"hi\"", None)]);
+        let mermaid = FlowGraph::new(&config).to_mermaid();
+
+        assert!(mermaid.contains("flowchart LR\n"));
+        assert!(mermaid.contains("    t0[\"log<br/>a\"]\n"));
+        assert!(mermaid.contains("    t1[\"log<br/>say #quot;hi#quot;\"]\n"));
+        assert!(mermaid.contains("    t0 --> t1\n"));
+    }
+}
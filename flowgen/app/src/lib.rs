@@ -11,3 +11,7 @@ pub mod app;
 pub mod config;
 /// Flow execution and task management.
 pub mod flow;
+/// DOT and Mermaid diagram export for flow configurations.
+pub mod graph;
+/// Offline validation of app and flow configurations.
+pub mod validate;
@@ -2,10 +2,13 @@ use clap::Parser;
 use config::Config;
 use flowgen::app::App;
 use flowgen::config::AppConfig;
+use flowgen::validate::{Finding, Severity};
 use std::env;
 use std::process;
 use tokio::sync::oneshot;
 use tracing::{error, info};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
 
 #[derive(Parser)]
 #[command(name = "flowgen", version, about = "Data activation with a blast 💥")]
@@ -13,6 +16,19 @@ struct Cli {
     /// Path to configuration file.
     #[arg(short, long, env = "CONFIG_PATH")]
     config: String,
+    /// Validate the app and flow configs and exit without running them.
+    #[arg(short = 'n', long)]
+    validate: bool,
+    /// Output format of validation results.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    output_format: OutputFormat,
+}
+
+/// Output format of validation results.
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum OutputFormat {
+    Text,
+    Json,
 }
 
 enum LogFormat {
@@ -43,46 +59,102 @@ fn init_tracing() {
         Err(_) => tracing_subscriber::EnvFilter::new("info"),
     };
 
+    // The OpenTelemetry layer stays empty until telemetry is enabled in the app config.
+    let registry = tracing_subscriber::registry()
+        .with(flowgen_core::telemetry::tracing_layer())
+        .with(env_filter);
+
     match format {
         LogFormat::Compact => {
-            tracing_subscriber::fmt()
-                .compact()
-                .with_env_filter(env_filter)
+            registry
+                .with(tracing_subscriber::fmt::layer().compact())
                 .init();
         }
         LogFormat::Json => {
-            tracing_subscriber::fmt()
-                .json()
-                .with_env_filter(env_filter)
+            registry
+                .with(tracing_subscriber::fmt::layer().json())
                 .init();
         }
     }
 }
 
+/// Builds the app config from the config file and `APP_` environment variables.
+fn load_app_config(path: &str) -> Result<AppConfig, String> {
+    let config = Config::builder()
+        .add_source(config::File::with_name(path))
+        .add_source(config::Environment::with_prefix("APP"))
+        .build()
+        .map_err(|e| format!("Failed to build config from {path}: {e}"))?;
+
+    config
+        .try_deserialize::<AppConfig>()
+        .map_err(|e| format!("Failed to deserialize app config: {e}"))
+}
+
+/// Validates the app and flow configs, prints the findings to stderr and
+/// returns the process exit code: 1 when any error was found, 0 otherwise.
+fn validate(cli: &Cli) -> i32 {
+    let findings = match load_app_config(&cli.config) {
+        Ok(app_config) => flowgen::validate::validate_app(&app_config),
+        Err(message) => vec![Finding {
+            severity: Severity::Error,
+            path: Some(cli.config.clone().into()),
+            flow: None,
+            message,
+        }],
+    };
+    let has_errors = flowgen::validate::has_errors(&findings);
+
+    match cli.output_format {
+        OutputFormat::Json => {
+            let report = serde_json::json!({ "valid": !has_errors, "findings": findings });
+            eprintln!("{report}");
+        }
+        OutputFormat::Text => {
+            for finding in &findings {
+                let severity = match finding.severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                };
+                let location = match (&finding.path, &finding.flow) {
+                    (Some(path), Some(flow)) => format!("{} ({flow}): ", path.display()),
+                    (Some(path), None) => format!("{}: ", path.display()),
+                    (None, Some(flow)) => format!("{flow}: "),
+                    (None, None) => String::new(),
+                };
+                eprintln!("{severity}: {location}{}", finding.message);
+            }
+            let errors = findings
+                .iter()
+                .filter(|f| f.severity == Severity::Error)
+                .count();
+            eprintln!(
+                "{} error(s), {} warning(s)",
+                errors,
+                findings.len() - errors
+            );
+        }
+    }
+
+    i32::from(has_errors)
+}
+
 #[tokio::main]
 async fn main() {
     let _ = rustls::crypto::ring::default_provider().install_default();
 
-    init_tracing();
-
     let cli = Cli::parse();
 
-    let config = match Config::builder()
-        .add_source(config::File::with_name(&cli.config))
-        .add_source(config::Environment::with_prefix("APP"))
-        .build()
-    {
-        Ok(config) => config,
-        Err(e) => {
-            error!("Failed to build config from {}: {}", cli.config, e);
-            process::exit(1);
-        }
-    };
+    if cli.validate {
+        process::exit(validate(&cli));
+    }
+
+    init_tracing();
 
-    let app_config = match config.try_deserialize::<AppConfig>() {
+    let app_config = match load_app_config(&cli.config) {
         Ok(config) => config,
         Err(e) => {
-            error!("Failed to deserialize app config: {}", e);
+            error!("{}", e);
             process::exit(1);
         }
     };
@@ -0,0 +1,296 @@
+//! Offline validation of app and flow configurations.
+//!
+//! Checks flow files the same way the worker does when it loads and wires
+//! them, without connecting to any external system, so configs can be
+//! rejected in CI or by an admission webhook before they are deployed.
+
+use crate::config::{AppConfig, FlowConfig, TaskType};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+/// Severity of a validation finding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The config would be rejected or fail at startup.
+    Error,
+    /// The config runs, but likely not as intended.
+    Warning,
+}
+
+/// A single problem found while validating configs.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Finding {
+    /// Whether the finding fails validation.
+    pub severity: Severity,
+    /// Flow file the finding refers to, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+    /// Flow name the finding refers to, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flow: Option<String>,
+    /// Human-readable description of the problem.
+    pub message: String,
+}
+
+impl Finding {
+    fn error(flow: &FlowConfig, message: String) -> Self {
+        Self {
+            severity: Severity::Error,
+            path: None,
+            flow: Some(flow.flow.name.clone()),
+            message,
+        }
+    }
+
+    fn warning(flow: &FlowConfig, message: String) -> Self {
+        Self {
+            severity: Severity::Warning,
+            path: None,
+            flow: Some(flow.flow.name.clone()),
+            message,
+        }
+    }
+}
+
+/// Returns true when any finding is an error.
+pub fn has_errors(findings: &[Finding]) -> bool {
+    findings.iter().any(|f| f.severity == Severity::Error)
+}
+
+/// Validates every flow file discovered through `flows.path`.
+///
+/// Files that cannot be read, parsed or deserialized are reported as
+/// errors instead of being skipped, and flow names must be unique across
+/// files.
+pub fn validate_app(app_config: &AppConfig) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    let Some(flows_path) = app_config.flows.path.as_ref() else {
+        findings.push(Finding {
+            severity: Severity::Warning,
+            path: None,
+            flow: None,
+            message: "flows.path is not configured, only flows from the system cache would run"
+                .to_string(),
+        });
+        return findings;
+    };
+
+    let patterns = match crate::app::flow_glob_patterns(flows_path) {
+        Ok(patterns) => patterns,
+        Err(e) => {
+            findings.push(Finding {
+                severity: Severity::Error,
+                path: Some(flows_path.clone()),
+                flow: None,
+                message: e.to_string(),
+            });
+            return findings;
+        }
+    };
+
+    let mut seen_paths = HashSet::new();
+    let mut flow_paths: HashMap<String, PathBuf> = HashMap::new();
+    for pattern in patterns {
+        let paths = match glob::glob(&pattern) {
+            Ok(paths) => paths,
+            Err(e) => {
+                findings.push(Finding {
+                    severity: Severity::Error,
+                    path: Some(flows_path.clone()),
+                    flow: None,
+                    message: e.to_string(),
+                });
+                continue;
+            }
+        };
+
+        for path in paths.flatten() {
+            let canonical = std::fs::canonicalize(&path).unwrap_or_else(|_| path.clone());
+            if !seen_paths.insert(canonical) {
+                continue;
+            }
+
+            let flow_config = match crate::app::load_flow_file(&path) {
+                Ok(flow_config) => flow_config,
+                Err(e) => {
+                    findings.push(Finding {
+                        severity: Severity::Error,
+                        path: Some(path),
+                        flow: None,
+                        message: e.to_string(),
+                    });
+                    continue;
+                }
+            };
+
+            if let Some(first) = flow_paths.get(&flow_config.flow.name) {
+                findings.push(Finding {
+                    severity: Severity::Error,
+                    path: Some(path.clone()),
+                    flow: Some(flow_config.flow.name.clone()),
+                    message: format!("Flow name is already used by {}", first.display()),
+                });
+            } else {
+                flow_paths.insert(flow_config.flow.name.clone(), path.clone());
+            }
+
+            findings.extend(validate_flow(&flow_config).into_iter().map(|mut f| {
+                f.path = Some(path.clone());
+                f
+            }));
+        }
+    }
+
+    findings
+}
+
+/// Validates a single flow: names, task wiring and ignored settings.
+pub fn validate_flow(flow_config: &FlowConfig) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    if let Err(e) = flow_config.validate() {
+        findings.push(Finding::error(flow_config, e.to_string()));
+    }
+
+    let tasks = &flow_config.flow.tasks;
+    if tasks.is_empty() {
+        findings.push(Finding::warning(
+            flow_config,
+            "Flow has no tasks".to_string(),
+        ));
+    }
+
+    // Mirrors the checks the flow builder applies when wiring tasks.
+    let mut name_to_idx = HashMap::new();
+    for (idx, task) in tasks.iter().enumerate() {
+        if name_to_idx.insert(task.name(), idx).is_some() {
+            findings.push(Finding::error(
+                flow_config,
+                format!("Duplicate task name '{}' in flow", task.name()),
+            ));
+        }
+    }
+    for (idx, task) in tasks.iter().enumerate() {
+        for dep in task.depends_on().into_iter().flatten() {
+            match name_to_idx.get(dep.as_str()) {
+                None => findings.push(Finding::error(
+                    flow_config,
+                    format!("Task '{}' depends on unknown task '{dep}'", task.name()),
+                )),
+                Some(&parent) if parent >= idx => findings.push(Finding::error(
+                    flow_config,
+                    format!(
+                        "Task '{}' depends on '{dep}' which appears later in the list",
+                        task.name()
+                    ),
+                )),
+                Some(_) => {}
+            }
+        }
+    }
+
+    let has_blocking_tasks = tasks.iter().any(|task| {
+        matches!(
+            task,
+            TaskType::http_webhook(_) | TaskType::mcp_tool(_) | TaskType::ai_gateway(_)
+        )
+    });
+    if has_blocking_tasks && flow_config.flow.require_leader_election.unwrap_or(false) {
+        findings.push(Finding::warning(
+            flow_config,
+            "require_leader_election is ignored for flows with blocking tasks (webhook, mcp_tool or ai_gateway)".to_string(),
+        ));
+    }
+
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Flow;
+
+    fn log_task(name: &str, depends_on: Option<Vec<&str>>) -> TaskType {
+        TaskType::log(flowgen_core::task::log::config::Processor {
+            name: name.to_string(),
+            depends_on: depends_on.map(|deps| deps.into_iter().map(String::from).collect()),
+            ..Default::default()
+        })
+    }
+
+    fn flow_config(tasks: Vec<TaskType>) -> FlowConfig {
+        FlowConfig {
+            flow: Flow {
+                name: "validate_flow".to_string(),
+                labels: None,
+                tasks,
+                require_leader_election: None,
+                parallel_instances: 1,
+                channel_capacity: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_validate_flow_valid() {
+        let config = flow_config(vec![log_task("a", None), log_task("b", Some(vec!["a"]))]);
+
+        assert!(validate_flow(&config).is_empty());
+    }
+
+    #[test]
+    fn test_validate_flow_wiring_errors() {
+        let config = flow_config(vec![
+            log_task("a", Some(vec!["b"])),
+            log_task("b", Some(vec!["missing"])),
+            log_task("b", None),
+        ]);
+
+        let findings = validate_flow(&config);
+        let messages: Vec<_> = findings.iter().map(|f| f.message.as_str()).collect();
+
+        assert!(has_errors(&findings));
+        assert!(messages.contains(&"Duplicate task name 'b' in flow"));
+        assert!(messages.contains(&"Task 'a' depends on 'b' which appears later in the list"));
+        assert!(messages.contains(&"Task 'b' depends on unknown task 'missing'"));
+    }
+
+    #[test]
+    fn test_validate_flow_empty_is_warning() {
+        let findings = validate_flow(&flow_config(vec![]));
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert!(!has_errors(&findings));
+    }
+
+    #[test]
+    fn test_validate_app_reports_unparseable_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("good.yaml"),
+            "flow:\n  name: good\n  tasks:\n    - log:\n        name: out\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("bad.yaml"), "flow: [not, a, flow]\n").unwrap();
+        let app_config = AppConfig {
+            cache: None,
+            flows: crate::config::FlowOptions {
+                path: Some(dir.path().to_path_buf()),
+                cache: None,
+            },
+            resources: None,
+            worker: None,
+            telemetry: None,
+        };
+
+        let findings = validate_app(&app_config);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Error);
+        assert!(findings[0].path.as_ref().unwrap().ends_with("bad.yaml"));
+    }
+}
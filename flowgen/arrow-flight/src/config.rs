@@ -0,0 +1,166 @@
+//! Configuration for the `arrow_flight_subscriber` and `arrow_flight_publisher` tasks.
+//!
+//! The subscriber runs a Flight server that accepts `DoPut` streams, the
+//! publisher connects to it as a Flight client.
+
+use flowgen_core::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Default address the subscriber listens on.
+pub const DEFAULT_HOST: &str = "0.0.0.0";
+
+/// Default port the subscriber listens on.
+pub const DEFAULT_PORT: u16 = 50051;
+
+/// Default maximum size of a single Flight message, in bytes.
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 64 * 1024 * 1024;
+
+/// Default timeout for a single publisher `DoPut` call.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default host function for serde.
+fn default_host() -> String {
+    DEFAULT_HOST.to_string()
+}
+
+/// Default port function for serde.
+fn default_port() -> u16 {
+    DEFAULT_PORT
+}
+
+/// Default maximum message size function for serde.
+fn default_max_message_size() -> usize {
+    DEFAULT_MAX_MESSAGE_SIZE
+}
+
+/// Default timeout function for serde.
+fn default_timeout() -> Option<Duration> {
+    Some(DEFAULT_TIMEOUT)
+}
+
+/// Configuration for the Arrow Flight subscriber.
+///
+/// # Example YAML
+///
+/// ```yaml
+/// - arrow_flight_subscriber:
+///     name: receive_orders
+///     port: 50051
+///     ack_timeout: 30s
+/// ```
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct Subscriber {
+    /// The unique name / identifier of the task.
+    pub name: String,
+    /// Address the Flight server listens on. Defaults to "0.0.0.0".
+    #[serde(default = "default_host")]
+    pub host: String,
+    /// Port the Flight server listens on. Defaults to 50051.
+    #[serde(default = "default_port")]
+    pub port: u16,
+    /// Maximum time to wait for the flow to complete a received batch before
+    /// the `DoPut` call fails. Waits indefinitely when omitted.
+    #[serde(default, with = "humantime_serde")]
+    pub ack_timeout: Option<Duration>,
+    /// Maximum size of a single Flight message, in bytes. Defaults to 64 MiB.
+    #[serde(default = "default_max_message_size")]
+    pub max_message_size: usize,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    #[serde(default)]
+    pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Subscriber {}
+
+impl Subscriber {
+    /// Returns the `host:port` address the Flight server binds to.
+    pub fn address(&self) -> String {
+        format!("{}:{}", self.host, self.port)
+    }
+}
+
+/// Configuration for the Arrow Flight publisher.
+///
+/// # Example YAML
+///
+/// ```yaml
+/// - arrow_flight_publisher:
+///     name: send_orders
+///     endpoint: http://worker-b:50051
+///     timeout: 30s
+/// ```
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Publisher {
+    /// The unique name / identifier of the task.
+    pub name: String,
+    /// Flight server endpoint (e.g., "http://worker-b:50051").
+    pub endpoint: String,
+    /// Timeout for a single `DoPut` call, including the wait for the remote
+    /// flow to acknowledge the batch (e.g., "30s"). Defaults to 30 seconds.
+    #[serde(default = "default_timeout", with = "humantime_serde")]
+    pub timeout: Option<Duration>,
+    /// Maximum size of a single Flight message, in bytes. Defaults to 64 MiB.
+    #[serde(default = "default_max_message_size")]
+    pub max_message_size: usize,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    #[serde(default)]
+    pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Publisher {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscriber_config_defaults() {
+        let json = r#"{"name": "receive_orders"}"#;
+        let config: Subscriber = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.address(), "0.0.0.0:50051");
+        assert!(config.ack_timeout.is_none());
+        assert_eq!(config.max_message_size, DEFAULT_MAX_MESSAGE_SIZE);
+    }
+
+    #[test]
+    fn test_publisher_config_defaults() {
+        let json = r#"{"name": "send_orders", "endpoint": "http://worker-b:50051"}"#;
+        let config: Publisher = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.endpoint, "http://worker-b:50051");
+        assert_eq!(config.timeout, Some(DEFAULT_TIMEOUT));
+        assert_eq!(config.max_message_size, DEFAULT_MAX_MESSAGE_SIZE);
+    }
+
+    #[test]
+    fn test_publisher_config_full() {
+        let json = r#"{
+            "name": "send_orders",
+            "endpoint": "http://worker-b:50051",
+            "timeout": "5s",
+            "max_message_size": 1048576
+        }"#;
+        let config: Publisher = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.timeout, Some(Duration::from_secs(5)));
+        assert_eq!(config.max_message_size, 1_048_576);
+    }
+}
@@ -0,0 +1,13 @@
+//! Arrow Flight integration for flowgen workers.
+//!
+//! Provides a subscriber that serves the Flight `DoPut` call and emits one
+//! event per received record batch, and a publisher that sends record batches
+//! to such a subscriber. Together they move Arrow data between workers without
+//! re-encoding it.
+
+/// Configuration structures for Arrow Flight tasks.
+pub mod config;
+/// Arrow Flight publisher sending record batches with `DoPut`.
+pub mod publisher;
+/// Arrow Flight subscriber serving `DoPut` and emitting received record batches.
+pub mod subscriber;
@@ -0,0 +1,403 @@
+//! Arrow Flight publisher sending record batches with `DoPut`.
+//!
+//! Each event is sent as a single `DoPut` call to an Arrow Flight subscriber,
+//! typically running on another worker. Arrow events are sent as-is and Avro
+//! events are decoded into a record batch first. The call succeeds once the
+//! subscriber acknowledges the batch, which it does after its flow has
+//! completed it.
+
+use arrow::record_batch::RecordBatch;
+use arrow_flight::{
+    encode::FlightDataEncoderBuilder, error::FlightError,
+    flight_service_client::FlightServiceClient, FlightClient, FlightDescriptor, PutResult,
+};
+use flowgen_core::{
+    config::ConfigExt,
+    event::{Event, EventBuilder, EventData, EventExt},
+};
+use futures::TryStreamExt;
+use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tonic::transport::Channel;
+use tracing::{error, Instrument};
+
+/// Errors that can occur while publishing over Arrow Flight.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Error reading event data: {source}")]
+    EventData {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Config template rendering error: {source}")]
+    ConfigRender {
+        #[source]
+        source: flowgen_core::config::Error,
+    },
+    #[error("Invalid Flight endpoint '{endpoint}': {source}")]
+    InvalidEndpoint {
+        endpoint: String,
+        #[source]
+        source: tonic::transport::Error,
+    },
+    #[error("Error connecting to Flight endpoint '{endpoint}': {source}")]
+    Connect {
+        endpoint: String,
+        #[source]
+        source: tonic::transport::Error,
+    },
+    #[error("Flight error: {source}")]
+    Flight {
+        #[source]
+        source: FlightError,
+    },
+    #[error("Flight publisher requires Arrow or Avro data, convert the event to Arrow first.")]
+    NotRecordBatch,
+    #[error("Timed out waiting for the Flight subscriber to acknowledge the batch")]
+    Timeout,
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+}
+
+/// Returns the event data as a record batch.
+fn event_batch(data: &EventData) -> Result<RecordBatch, Error> {
+    match data {
+        EventData::ArrowRecordBatch(batch) => Ok(batch.clone()),
+        EventData::Avro(avro) => avro
+            .to_record_batch()
+            .map_err(|source| Error::EventData { source }),
+        EventData::Json(_) => Err(Error::NotRecordBatch),
+    }
+}
+
+/// Event handler sending each event's record batch with `DoPut`.
+pub struct EventHandler {
+    client: FlightServiceClient<Channel>,
+    task_id: usize,
+    tx: Option<Sender<Event>>,
+    config: Arc<super::config::Publisher>,
+    task_type: &'static str,
+}
+
+impl EventHandler {
+    /// Sends a record batch and returns the acknowledgements from the subscriber.
+    async fn put(&self, subject: &str, batch: RecordBatch) -> Result<Vec<PutResult>, Error> {
+        let flight_data = FlightDataEncoderBuilder::new()
+            .with_flight_descriptor(Some(FlightDescriptor::new_path(vec![subject.to_string()])))
+            .build(futures::stream::iter([Ok::<_, FlightError>(batch)]));
+
+        let mut client = FlightClient::new_from_inner(self.client.clone());
+        client
+            .do_put(flight_data)
+            .await
+            .map_err(|source| Error::Flight { source })?
+            .try_collect()
+            .await
+            .map_err(|source| Error::Flight { source })
+    }
+
+    #[tracing::instrument(skip(self, event), name = "task.handle")]
+    async fn handle(&self, event: Event) -> Result<(), Error> {
+        let event = Arc::new(event);
+        let completion_tx_arc = Arc::clone(&event).completion_tx.clone();
+
+        flowgen_core::event::with_event_context(&Arc::clone(&event), async move {
+            let batch = event_batch(&event.data)?;
+            let num_rows = batch.num_rows();
+
+            let acks = match self.config.timeout {
+                Some(timeout) => tokio::time::timeout(timeout, self.put(&event.subject, batch))
+                    .await
+                    .map_err(|_| Error::Timeout)??,
+                None => self.put(&event.subject, batch).await?,
+            };
+
+            let result = serde_json::json!({
+                "endpoint": self.config.endpoint,
+                "num_rows": num_rows,
+                "batches_acknowledged": acks.len(),
+            });
+
+            let mut result_event = EventBuilder::new()
+                .data(EventData::Json(result))
+                .subject(format!("{}.{}", event.subject, self.config.name))
+                .task_id(self.task_id)
+                .task_type(self.task_type)
+                .build()
+                .map_err(|source| Error::EventBuilder { source })?;
+
+            // Signal completion or pass through to next task.
+            match self.tx {
+                None => {
+                    // Leaf task: signal completion.
+                    if let Some(arc) = completion_tx_arc.as_ref() {
+                        arc.signal_completion(result_event.data_as_json().ok());
+                    }
+                }
+                Some(_) => {
+                    // Pass through completion_tx to next task.
+                    result_event.completion_tx = completion_tx_arc.clone();
+                }
+            }
+
+            result_event
+                .send_with_logging(self.tx.as_ref())
+                .context("num_records", num_rows)
+                .await
+                .map_err(|source| Error::SendMessage { source })?;
+
+            Ok(())
+        })
+        .await
+    }
+}
+
+/// Arrow Flight publisher that sends record batches to a Flight subscriber.
+#[derive(Debug)]
+pub struct Publisher {
+    /// Publisher configuration including the Flight endpoint.
+    config: Arc<super::config::Publisher>,
+    /// Receiver for incoming events to process.
+    rx: Receiver<Event>,
+    /// Channel sender for result events.
+    tx: Option<Sender<Event>>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl flowgen_core::task::runner::Runner for Publisher {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Initializes the publisher by connecting to the Flight endpoint.
+    async fn init(&self) -> Result<EventHandler, Error> {
+        let init_config = self
+            .config
+            .render(&serde_json::json!({}))
+            .map_err(|source| Error::ConfigRender { source })?;
+
+        let endpoint = init_config.endpoint.clone();
+        let channel = tonic::transport::Endpoint::from_shared(endpoint.clone())
+            .map_err(|source| Error::InvalidEndpoint {
+                endpoint: endpoint.clone(),
+                source,
+            })?
+            .connect()
+            .await
+            .map_err(|source| Error::Connect { endpoint, source })?;
+
+        let client = FlightServiceClient::new(channel)
+            .max_decoding_message_size(init_config.max_message_size)
+            .max_encoding_message_size(init_config.max_message_size);
+
+        Ok(EventHandler {
+            client,
+            task_id: self.task_id,
+            tx: self.tx.clone(),
+            config: Arc::clone(&self.config),
+            task_type: self.task_type,
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(mut self) -> Result<(), Self::Error> {
+        let retry_config =
+            flowgen_core::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        let event_handler = match tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+            match self.init().await {
+                Ok(handler) => Ok(handler),
+                Err(e @ Error::InvalidEndpoint { .. }) => {
+                    error!(error = %e, "Permanent initialization error");
+                    Err(tokio_retry::RetryError::permanent(e))
+                }
+                Err(e) => {
+                    error!(error = %e, "Failed to initialize Arrow Flight publisher");
+                    Err(tokio_retry::RetryError::transient(e))
+                }
+            }
+        })
+        .await
+        {
+            Ok(handler) => Arc::new(handler),
+            Err(e) => {
+                return Err(e);
+            }
+        };
+
+        loop {
+            match self.rx.recv().await {
+                Some(event) => {
+                    let event_handler = Arc::clone(&event_handler);
+                    let retry_strategy = retry_config.strategy();
+                    let metrics = self.task_context.metrics.clone();
+                    tokio::spawn(
+                        async move {
+                            let bytes = event.data_size() as u64;
+                            let result = tokio_retry::Retry::spawn(retry_strategy, || async {
+                                match event_handler.handle(event.clone()).await {
+                                    Ok(result) => Ok(result),
+                                    // Data that cannot be turned into a record batch fails on every attempt.
+                                    Err(e @ (Error::NotRecordBatch | Error::EventData { .. })) => {
+                                        error!(error = %e, "Non-retriable publish error");
+                                        Err(tokio_retry::RetryError::permanent(e))
+                                    }
+                                    Err(e) => {
+                                        error!(error = %e, "Failed to publish over Arrow Flight");
+                                        Err(tokio_retry::RetryError::transient(e))
+                                    }
+                                }
+                            })
+                            .await;
+
+                            match result {
+                                Ok(()) => metrics.record_processed(bytes),
+                                Err(e) => {
+                                    metrics.record_failed();
+                                    error!(error = %e, "Failed to publish over Arrow Flight after all retry attempts");
+                                    // Emit error event downstream for error handling.
+                                    let mut error_event = event;
+                                    error_event.error = Some(e.to_string());
+                                    if let Some(ref tx) = event_handler.tx {
+                                        tx.send(error_event).await.ok();
+                                    }
+                                }
+                            }
+                        }
+                        .instrument(tracing::Span::current()),
+                    );
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Builder for creating Arrow Flight publisher instances.
+pub struct PublisherBuilder {
+    config: Option<Arc<super::config::Publisher>>,
+    rx: Option<Receiver<Event>>,
+    tx: Option<Sender<Event>>,
+    task_id: Option<usize>,
+    task_context: Option<Arc<flowgen_core::task::context::TaskContext>>,
+    task_type: Option<&'static str>,
+}
+
+impl PublisherBuilder {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            rx: None,
+            tx: None,
+            task_id: None,
+            task_context: None,
+            task_type: None,
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Publisher>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn receiver(mut self, rx: Receiver<Event>) -> Self {
+        self.rx = Some(rx);
+        self
+    }
+
+    pub fn sender(mut self, tx: Sender<Event>) -> Self {
+        self.tx = Some(tx);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = Some(task_id);
+        self
+    }
+
+    pub fn task_context(
+        mut self,
+        task_context: Arc<flowgen_core::task::context::TaskContext>,
+    ) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Publisher, Error> {
+        Ok(Publisher {
+            config: self
+                .config
+                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            rx: self
+                .rx
+                .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
+            tx: self.tx,
+            task_id: self
+                .task_id
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_id".to_string()))?,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+impl Default for PublisherBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::Int64Array;
+    use arrow::datatypes::{DataType, Field, Schema};
+
+    #[test]
+    fn test_event_batch() {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+        let batch =
+            RecordBatch::try_new(schema, vec![Arc::new(Int64Array::from(vec![1, 2]))]).unwrap();
+
+        let result = event_batch(&EventData::ArrowRecordBatch(batch.clone())).unwrap();
+        assert_eq!(result, batch);
+
+        assert!(matches!(
+            event_batch(&EventData::Json(serde_json::json!({"id": 1}))),
+            Err(Error::NotRecordBatch)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_publisher_builder_missing_config() {
+        let result = PublisherBuilder::new().build().await;
+        assert!(
+            matches!(result.unwrap_err(), Error::MissingBuilderAttribute(attr) if attr == "config")
+        );
+    }
+}
@@ -0,0 +1,471 @@
+//! Arrow Flight subscriber that serves `DoPut` and emits received record batches.
+//!
+//! Runs a Flight server on the configured address. Every record batch of an
+//! incoming `DoPut` stream becomes one event. A `PutResult` is sent back for a
+//! batch only after the flow has completed it, so a publisher on another worker
+//! knows its batch was processed. If the flow fails or the acknowledgement times
+//! out, the call fails and the publisher retries the batch.
+
+use arrow::record_batch::RecordBatch;
+use arrow_flight::{
+    decode::FlightRecordBatchStream,
+    error::FlightError,
+    flight_service_server::{FlightService, FlightServiceServer},
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PollInfo, PutResult, SchemaResult, Ticket,
+};
+use flowgen_core::{
+    config::ConfigExt,
+    event::{new_completion_channel, Event, EventBuilder, EventData, EventExt},
+};
+use futures::stream::{BoxStream, StreamExt, TryStreamExt};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::sync::mpsc::{self, Sender};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status, Streaming};
+use tracing::{error, warn, Instrument};
+
+/// Errors that can occur while serving Arrow Flight.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Config template rendering error: {source}")]
+    ConfigRender {
+        #[source]
+        source: flowgen_core::config::Error,
+    },
+    #[error("Invalid listen address '{address}': {source}")]
+    InvalidAddress {
+        address: String,
+        #[source]
+        source: std::net::AddrParseError,
+    },
+    #[error("Flight server error: {source}")]
+    Transport {
+        #[source]
+        source: tonic::transport::Error,
+    },
+    #[error("Flow failed to process the record batch")]
+    CompletionFailed,
+    #[error("Timed out waiting for the flow to process the record batch")]
+    AckTimeout,
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+}
+
+/// Flight service forwarding record batches received with `DoPut` into the flow.
+#[derive(Clone)]
+struct FlightReceiver {
+    tx: Option<Sender<Event>>,
+    task_id: usize,
+    config: Arc<super::config::Subscriber>,
+    task_type: &'static str,
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+}
+
+impl FlightReceiver {
+    /// Emits a record batch as an event and waits until the flow has completed it.
+    async fn forward(&self, batch: RecordBatch) -> Result<(), Error> {
+        // Size the completion channel to the number of leaves in this flow's
+        // directed acyclic graph, so the batch is acknowledged only after
+        // every leaf has signalled completion.
+        let (completion_state, completion_rx) =
+            new_completion_channel(self.task_context.leaf_count);
+
+        // Publishers may send empty batches, e.g. for a query with no rows.
+        let mut e = EventBuilder::new()
+            .data(EventData::ArrowRecordBatch(batch))
+            .allow_empty(true)
+            .subject(self.config.name.to_owned())
+            .task_id(self.task_id)
+            .task_type(self.task_type)
+            .build()
+            .map_err(|source| Error::EventBuilder { source })?;
+        e.completion_tx = Some(completion_state);
+
+        e.send_with_logging(self.tx.as_ref())
+            .await
+            .map_err(|source| Error::SendMessage { source })?;
+        self.task_context.metrics.record_received();
+
+        let completion = match self.config.ack_timeout {
+            Some(timeout) => tokio::time::timeout(timeout, completion_rx)
+                .await
+                .map_err(|_| Error::AckTimeout)?,
+            None => completion_rx.await,
+        };
+        match completion {
+            Ok(Ok(_)) => Ok(()),
+            Ok(Err(_)) | Err(_) => Err(Error::CompletionFailed),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl FlightService for FlightReceiver {
+    type HandshakeStream = BoxStream<'static, Result<HandshakeResponse, Status>>;
+    type ListFlightsStream = BoxStream<'static, Result<FlightInfo, Status>>;
+    type DoGetStream = BoxStream<'static, Result<FlightData, Status>>;
+    type DoPutStream = BoxStream<'static, Result<PutResult, Status>>;
+    type DoActionStream = BoxStream<'static, Result<arrow_flight::Result, Status>>;
+    type ListActionsStream = BoxStream<'static, Result<ActionType, Status>>;
+    type DoExchangeStream = BoxStream<'static, Result<FlightData, Status>>;
+
+    async fn do_put(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        let mut batches = FlightRecordBatchStream::new_from_flight_data(
+            request.into_inner().map_err(FlightError::from),
+        );
+        let (result_tx, result_rx) = mpsc::channel(1);
+        let receiver = self.clone();
+
+        // Batches are forwarded one at a time, so a slow flow applies
+        // backpressure to the publisher instead of buffering batches here.
+        tokio::spawn(
+            async move {
+                while let Some(batch) = batches.next().await {
+                    let result = match batch {
+                        Ok(batch) => receiver.forward(batch).await.map_err(|e| {
+                            error!(error = %e, "Failed to process received record batch");
+                            Status::internal(e.to_string())
+                        }),
+                        Err(e) => Err(Status::from(e)),
+                    };
+                    let failed = result.is_err();
+                    let result = result.map(|()| PutResult::default());
+                    if result_tx.send(result).await.is_err() || failed {
+                        return;
+                    }
+                }
+            }
+            .instrument(tracing::Span::current()),
+        );
+
+        Ok(Response::new(ReceiverStream::new(result_rx).boxed()))
+    }
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        Err(Status::unimplemented("handshake is not supported"))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        Err(Status::unimplemented("list_flights is not supported"))
+    }
+
+    async fn get_flight_info(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("get_flight_info is not supported"))
+    }
+
+    async fn poll_flight_info(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<PollInfo>, Status> {
+        Err(Status::unimplemented("poll_flight_info is not supported"))
+    }
+
+    async fn get_schema(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        Err(Status::unimplemented("get_schema is not supported"))
+    }
+
+    async fn do_get(
+        &self,
+        _request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        Err(Status::unimplemented("do_get is not supported"))
+    }
+
+    async fn do_action(
+        &self,
+        _request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        Err(Status::unimplemented("do_action is not supported"))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        Err(Status::unimplemented("list_actions is not supported"))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("do_exchange is not supported"))
+    }
+}
+
+/// Event handler owning the Flight server.
+pub struct EventHandler {
+    address: SocketAddr,
+    max_message_size: usize,
+    receiver: FlightReceiver,
+}
+
+impl EventHandler {
+    /// Serves Flight requests until the server fails or the flow is cancelled.
+    async fn handle(self) -> Result<(), Error> {
+        let cancellation_token = self.receiver.task_context.cancellation_token.clone();
+        let service = FlightServiceServer::new(self.receiver)
+            .max_decoding_message_size(self.max_message_size)
+            .max_encoding_message_size(self.max_message_size);
+
+        tonic::transport::Server::builder()
+            .add_service(service)
+            .serve_with_shutdown(
+                self.address,
+                async move { cancellation_token.cancelled().await },
+            )
+            .await
+            .map_err(|source| Error::Transport { source })
+    }
+}
+
+/// Arrow Flight subscriber that receives record batches from other workers.
+#[derive(Debug)]
+pub struct Subscriber {
+    /// Subscriber configuration including the listen address.
+    config: Arc<super::config::Subscriber>,
+    /// Sender for forwarding received events.
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event tagging.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl flowgen_core::task::runner::Runner for Subscriber {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Resolves the listen address and prepares the Flight service.
+    async fn init(&self) -> Result<EventHandler, Error> {
+        let init_config = self
+            .config
+            .render(&serde_json::json!({}))
+            .map_err(|source| Error::ConfigRender { source })?;
+
+        let address = init_config.address();
+        let address = address
+            .parse()
+            .map_err(|source| Error::InvalidAddress { address, source })?;
+
+        Ok(EventHandler {
+            address,
+            max_message_size: init_config.max_message_size,
+            receiver: FlightReceiver {
+                tx: self.tx.clone(),
+                task_id: self.task_id,
+                config: Arc::clone(&self.config),
+                task_type: self.task_type,
+                task_context: Arc::clone(&self.task_context),
+            },
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(self) -> Result<(), Error> {
+        let retry_config =
+            flowgen_core::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        tokio::spawn(
+            async move {
+                // Restart loop: the server must keep accepting transfers until the flow stops.
+                loop {
+                    let event_handler = match tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+                        match self.init().await {
+                            Ok(handler) => Ok(handler),
+                            Err(e @ Error::InvalidAddress { .. }) => {
+                                error!(error = %e, "Permanent initialization error");
+                                Err(tokio_retry::RetryError::permanent(e))
+                            }
+                            Err(e) => {
+                                error!(error = %e, "Subscriber initialization failed");
+                                Err(tokio_retry::RetryError::transient(e))
+                            }
+                        }
+                    })
+                    .await
+                    {
+                        Ok(handler) => handler,
+                        Err(e) => {
+                            error!(error = %e, "Subscriber initialization exhausted retry attempts, will retry after backoff");
+                            tokio::time::sleep(retry_config.initial_backoff).await;
+                            continue;
+                        }
+                    };
+
+                    match event_handler.handle().await {
+                        Ok(()) => return,
+                        Err(e) => {
+                            if self.task_context.cancellation_token.is_cancelled() {
+                                return;
+                            }
+                            warn!(error = %e, "Flight server stopped, restarting");
+                        }
+                    }
+
+                    tokio::select! {
+                        _ = self.task_context.cancellation_token.cancelled() => return,
+                        _ = tokio::time::sleep(retry_config.initial_backoff) => {}
+                    }
+                }
+            }
+            .instrument(tracing::Span::current()),
+        );
+
+        Ok(())
+    }
+}
+
+/// Builder for configuring and creating Arrow Flight subscribers.
+#[derive(Default)]
+pub struct SubscriberBuilder {
+    /// Optional subscriber configuration.
+    config: Option<Arc<super::config::Subscriber>>,
+    /// Optional event sender.
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event processing.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Option<Arc<flowgen_core::task::context::TaskContext>>,
+    /// Task type for event categorization.
+    task_type: Option<&'static str>,
+}
+
+impl SubscriberBuilder {
+    pub fn new() -> SubscriberBuilder {
+        SubscriberBuilder {
+            ..Default::default()
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Subscriber>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = task_id;
+        self
+    }
+
+    pub fn task_context(
+        mut self,
+        task_context: Arc<flowgen_core::task::context::TaskContext>,
+    ) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Subscriber, Error> {
+        Ok(Subscriber {
+            config: self
+                .config
+                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            tx: self.tx,
+            task_id: self.task_id,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::datatypes::{DataType, Field, Schema};
+
+    #[tokio::test]
+    async fn test_subscriber_builder_missing_config() {
+        let result = SubscriberBuilder::new().build().await;
+        assert!(
+            matches!(result.unwrap_err(), Error::MissingBuilderAttribute(attr) if attr == "config")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_forward_empty_batch() {
+        let config: super::super::config::Subscriber =
+            serde_json::from_value(serde_json::json!({"name": "receive_orders"})).unwrap();
+        let task_manager = Arc::new(
+            flowgen_core::task::manager::TaskManagerBuilder::new()
+                .build()
+                .unwrap(),
+        );
+        let cache = Arc::new(flowgen_core::cache::memory::MemoryCache::new())
+            as Arc<dyn flowgen_core::cache::Cache>;
+        let task_context = Arc::new(
+            flowgen_core::task::context::TaskContextBuilder::new()
+                .flow_name("test".to_string())
+                .task_manager(task_manager)
+                .cache(cache)
+                .build()
+                .unwrap(),
+        );
+        let (tx, mut rx) = mpsc::channel(1);
+        let receiver = FlightReceiver {
+            tx: Some(tx),
+            task_id: 0,
+            config: Arc::new(config),
+            task_type: "arrow_flight_subscriber",
+            task_context,
+        };
+
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, true)]));
+        let forward =
+            tokio::spawn(async move { receiver.forward(RecordBatch::new_empty(schema)).await });
+
+        let event = rx.recv().await.unwrap();
+        match &event.data {
+            EventData::ArrowRecordBatch(batch) => assert_eq!(batch.num_rows(), 0),
+            _ => panic!("expected a record batch"),
+        }
+        event.completion_tx.unwrap().signal_completion(None);
+
+        assert!(forward.await.unwrap().is_ok());
+    }
+}
@@ -0,0 +1,189 @@
+//! Configuration for the `clickhouse_publisher` task.
+//!
+//! Rows are inserted through the ClickHouse HTTP interface with
+//! `INSERT INTO <database>.<table> FORMAT JSONEachRow`.
+
+use flowgen_core::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Default ClickHouse user.
+pub const DEFAULT_USERNAME: &str = "default";
+
+/// Default number of rows sent per insert request.
+pub const DEFAULT_BATCH_SIZE: usize = 10_000;
+
+/// Default timeout for a single insert request.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default username function for serde.
+fn default_username() -> String {
+    DEFAULT_USERNAME.to_string()
+}
+
+/// Default batch size function for serde.
+fn default_batch_size() -> usize {
+    DEFAULT_BATCH_SIZE
+}
+
+/// Default request timeout function for serde.
+fn default_timeout() -> Option<Duration> {
+    Some(DEFAULT_TIMEOUT)
+}
+
+/// Default function returning `true` for serde.
+fn default_true() -> bool {
+    true
+}
+
+/// Configuration for the ClickHouse publisher.
+///
+/// # Example YAML
+///
+/// ```yaml
+/// - clickhouse_publisher:
+///     name: insert_events
+///     url: http://clickhouse:8123
+///     database: analytics
+///     table: events
+///     username: flowgen
+///     password: "{{env.CLICKHOUSE_PASSWORD}}"
+///     batch_size: 5000
+///     async_insert: true
+/// ```
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Publisher {
+    /// The unique name / identifier of the task.
+    pub name: String,
+    /// ClickHouse HTTP interface URL (e.g., "http://localhost:8123").
+    pub url: String,
+    /// Target database.
+    pub database: String,
+    /// Target table.
+    pub table: String,
+    /// ClickHouse user. Defaults to "default".
+    #[serde(default = "default_username")]
+    pub username: String,
+    /// Optional password for the user.
+    #[serde(default)]
+    pub password: Option<String>,
+    /// Maximum number of rows sent per insert request. Defaults to 10000.
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+    /// Use ClickHouse asynchronous inserts, buffering rows on the server
+    /// before they are written to the table.
+    #[serde(default)]
+    pub async_insert: bool,
+    /// With `async_insert`, wait until the server has flushed the rows to the
+    /// table before the insert is reported as successful. Defaults to true.
+    /// When false, inserted rows become visible later and flush errors are
+    /// not reported back to the task.
+    #[serde(default = "default_true")]
+    pub wait_for_async_insert: bool,
+    /// Timeout for a single insert request (e.g., "30s"). Defaults to 30 seconds.
+    #[serde(default = "default_timeout", with = "humantime_serde")]
+    pub timeout: Option<Duration>,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    #[serde(default)]
+    pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Publisher {}
+
+impl Publisher {
+    /// Returns the configured batch size, treating zero as one row per request.
+    pub fn batch_size(&self) -> usize {
+        self.batch_size.max(1)
+    }
+
+    /// Returns the `INSERT` statement for the target table.
+    pub fn insert_query(&self) -> String {
+        format!(
+            "INSERT INTO {}.{} FORMAT JSONEachRow",
+            quote_identifier(&self.database),
+            quote_identifier(&self.table)
+        )
+    }
+}
+
+/// Quotes a ClickHouse identifier with backticks, escaping backslashes and backticks.
+fn quote_identifier(identifier: &str) -> String {
+    format!("`{}`", identifier.replace('\\', "\\\\").replace('`', "\\`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publisher_config_defaults() {
+        let json = r#"{
+            "name": "insert_events",
+            "url": "http://localhost:8123",
+            "database": "analytics",
+            "table": "events"
+        }"#;
+        let config: Publisher = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.username, DEFAULT_USERNAME);
+        assert!(config.password.is_none());
+        assert_eq!(config.batch_size, DEFAULT_BATCH_SIZE);
+        assert!(!config.async_insert);
+        assert!(config.wait_for_async_insert);
+        assert_eq!(config.timeout, Some(DEFAULT_TIMEOUT));
+    }
+
+    #[test]
+    fn test_publisher_config_full() {
+        let json = r#"{
+            "name": "insert_events",
+            "url": "http://clickhouse:8123",
+            "database": "analytics",
+            "table": "events",
+            "username": "flowgen",
+            "password": "secret",
+            "batch_size": 500,
+            "async_insert": true,
+            "wait_for_async_insert": false,
+            "timeout": "5s"
+        }"#;
+        let config: Publisher = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.username, "flowgen");
+        assert_eq!(config.password, Some("secret".to_string()));
+        assert_eq!(config.batch_size, 500);
+        assert!(config.async_insert);
+        assert!(!config.wait_for_async_insert);
+        assert_eq!(config.timeout, Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_insert_query_quotes_identifiers() {
+        let config = Publisher {
+            database: "analytics".to_string(),
+            table: "we`ird".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.insert_query(),
+            "INSERT INTO `analytics`.`we\\`ird` FORMAT JSONEachRow"
+        );
+    }
+
+    #[test]
+    fn test_batch_size_zero_is_one() {
+        let config = Publisher {
+            batch_size: 0,
+            ..Default::default()
+        };
+        assert_eq!(config.batch_size(), 1);
+    }
+}
@@ -0,0 +1,10 @@
+//! ClickHouse integration for flowgen workers.
+//!
+//! Provides a publisher that inserts event rows into ClickHouse tables over the
+//! HTTP interface using the `JSONEachRow` format, with optional server-side
+//! asynchronous inserts.
+
+/// Configuration structures for ClickHouse tasks.
+pub mod config;
+/// ClickHouse publisher for inserting rows over HTTP.
+pub mod publisher;
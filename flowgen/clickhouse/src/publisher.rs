@@ -0,0 +1,511 @@
+//! ClickHouse publisher using the HTTP interface.
+//!
+//! Converts each event into JSON rows and posts them to ClickHouse in
+//! `JSONEachRow` format, splitting large events into requests of at most
+//! `batch_size` rows. Each request is retried as a whole with the task's retry
+//! backoff. With `async_insert`, ClickHouse buffers rows on the server; unless
+//! `wait_for_async_insert` is disabled, the request only succeeds once the
+//! buffer has been flushed to the table.
+
+use flowgen_core::{
+    config::ConfigExt,
+    event::{Event, EventBuilder, EventData, EventExt},
+};
+use serde_json::Value as JsonValue;
+use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tracing::{error, warn, Instrument};
+
+/// Errors that can occur during ClickHouse inserts.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Error reading event data: {source}")]
+    EventData {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("JSON error: {source}")]
+    SerdeJson {
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("Arrow error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+    #[error("Config template rendering error: {source}")]
+    ConfigRender {
+        #[source]
+        source: flowgen_core::config::Error,
+    },
+    #[error("Failed to build HTTP client: {source}")]
+    ClientInit {
+        #[source]
+        source: reqwest::Error,
+    },
+    #[error("ClickHouse request failed: {source}")]
+    Request {
+        #[source]
+        source: reqwest::Error,
+    },
+    #[error("ClickHouse insert failed with status {status}: {body}")]
+    Insert { status: u16, body: String },
+    #[error("Row is not a JSON object: {0}")]
+    InvalidRow(String),
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+}
+
+/// Converts event data into JSON rows.
+///
+/// Record batches produce one row per record. JSON arrays produce one row per
+/// element and any other JSON value is treated as a single row.
+fn event_rows(event: &Event) -> Result<Vec<JsonValue>, Error> {
+    let rows = match &event.data {
+        EventData::ArrowRecordBatch(batch) => {
+            let mut json_writer = arrow_json::ArrayWriter::new(Vec::new());
+            json_writer
+                .write(batch)
+                .map_err(|source| Error::Arrow { source })?;
+            json_writer
+                .finish()
+                .map_err(|source| Error::Arrow { source })?;
+            serde_json::from_slice(&json_writer.into_inner())
+                .map_err(|source| Error::SerdeJson { source })?
+        }
+        _ => match event
+            .data_as_json()
+            .map_err(|source| Error::EventData { source })?
+        {
+            JsonValue::Array(rows) => rows,
+            row => vec![row],
+        },
+    };
+
+    if let Some(row) = rows.iter().find(|row| !row.is_object()) {
+        return Err(Error::InvalidRow(row.to_string()));
+    }
+    Ok(rows)
+}
+
+/// Serializes rows as newline-delimited JSON for `FORMAT JSONEachRow`.
+fn json_each_row(rows: &[JsonValue]) -> Result<Vec<u8>, Error> {
+    let mut body = Vec::new();
+    for row in rows {
+        serde_json::to_writer(&mut body, row).map_err(|source| Error::SerdeJson { source })?;
+        body.push(b'\n');
+    }
+    Ok(body)
+}
+
+/// Returns the HTTP query parameters for an insert request.
+fn insert_params(config: &super::config::Publisher) -> Vec<(&'static str, String)> {
+    let mut params = vec![("query", config.insert_query())];
+    if config.async_insert {
+        params.push(("async_insert", "1".to_string()));
+        let wait = if config.wait_for_async_insert {
+            "1"
+        } else {
+            "0"
+        };
+        params.push(("wait_for_async_insert", wait.to_string()));
+    }
+    params
+}
+
+/// Event handler for processing individual insert events.
+pub struct EventHandler {
+    client: Arc<reqwest::Client>,
+    task_id: usize,
+    tx: Option<Sender<Event>>,
+    config: Arc<super::config::Publisher>,
+    task_type: &'static str,
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+    retry_config: flowgen_core::retry::RetryConfig,
+}
+
+impl EventHandler {
+    /// Sends a single insert request.
+    async fn send_insert(
+        &self,
+        config: &super::config::Publisher,
+        body: Vec<u8>,
+    ) -> Result<(), Error> {
+        let response = self
+            .client
+            .post(&config.url)
+            .query(&insert_params(config))
+            .basic_auth(&config.username, config.password.as_deref())
+            .body(body)
+            .send()
+            .await
+            .map_err(|source| Error::Request { source })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(Error::Insert {
+                status: status.as_u16(),
+                body: body.trim().to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Inserts a single batch, retrying the whole request on failure.
+    async fn insert_batch(
+        &self,
+        config: &super::config::Publisher,
+        rows: &[JsonValue],
+    ) -> Result<(), Error> {
+        let body = json_each_row(rows)?;
+        let mut delays = self.retry_config.strategy();
+
+        loop {
+            match self.send_insert(config, body.clone()).await {
+                Ok(()) => return Ok(()),
+                Err(e) => match delays.next() {
+                    Some(delay) => {
+                        warn!(error = %e, "ClickHouse insert request failed, retrying");
+                        tokio::time::sleep(delay).await;
+                    }
+                    None => return Err(e),
+                },
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self, event), name = "task.handle")]
+    async fn handle(&self, event: Event) -> Result<(), Error> {
+        if self.task_context.cancellation_token.is_cancelled() {
+            return Ok(());
+        }
+
+        let event = Arc::new(event);
+        let completion_tx_arc = Arc::clone(&event).completion_tx.clone();
+
+        flowgen_core::event::with_event_context(&Arc::clone(&event), async move {
+            // Render config to support templates.
+            let event_value = JsonValue::try_from(event.as_ref())
+                .map_err(|source| Error::EventData { source })?;
+            let config = self
+                .config
+                .render(&event_value)
+                .map_err(|source| Error::ConfigRender { source })?;
+
+            let rows = event_rows(&event)?;
+            let rows_inserted = rows.len();
+
+            let mut request_count = 0;
+            for batch in rows.chunks(config.batch_size()) {
+                self.insert_batch(&config, batch).await?;
+                request_count += 1;
+            }
+
+            // Without waiting, async inserts are only queued on the server and
+            // become visible in the table after the next buffer flush.
+            let flushed = !config.async_insert || config.wait_for_async_insert;
+
+            let result = serde_json::json!({
+                "rows_inserted": rows_inserted,
+                "requests": request_count,
+                "table": format!("{}.{}", config.database, config.table),
+                "flushed": flushed,
+            });
+
+            let mut result_event = EventBuilder::new()
+                .data(EventData::Json(result))
+                .subject(format!("{}.{}", event.subject, config.name))
+                .task_id(self.task_id)
+                .task_type(self.task_type)
+                .build()
+                .map_err(|source| Error::EventBuilder { source })?;
+
+            // Signal completion or pass through to next task.
+            match self.tx {
+                None => {
+                    // Leaf task: signal completion.
+                    if let Some(arc) = completion_tx_arc.as_ref() {
+                        arc.signal_completion(result_event.data_as_json().ok());
+                    }
+                }
+                Some(_) => {
+                    // Pass through completion_tx to next task.
+                    result_event.completion_tx = completion_tx_arc.clone();
+                }
+            }
+
+            result_event
+                .send_with_logging(self.tx.as_ref())
+                .context("num_records", rows_inserted)
+                .await
+                .map_err(|source| Error::SendMessage { source })?;
+
+            Ok(())
+        })
+        .await
+    }
+}
+
+/// ClickHouse publisher that inserts rows over the HTTP interface.
+#[derive(Debug)]
+pub struct Publisher {
+    /// Publisher configuration including connection and table details.
+    config: Arc<super::config::Publisher>,
+    /// Receiver for incoming events to process.
+    rx: Receiver<Event>,
+    /// Channel sender for result events.
+    tx: Option<Sender<Event>>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl flowgen_core::task::runner::Runner for Publisher {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Initializes the publisher by building the HTTP client.
+    async fn init(&self) -> Result<EventHandler, Error> {
+        let mut builder = reqwest::ClientBuilder::new();
+        if let Some(timeout) = self.config.timeout {
+            builder = builder.timeout(timeout);
+        }
+        let client = builder
+            .build()
+            .map_err(|source| Error::ClientInit { source })?;
+
+        Ok(EventHandler {
+            client: Arc::new(client),
+            task_id: self.task_id,
+            tx: self.tx.clone(),
+            config: Arc::clone(&self.config),
+            task_type: self.task_type,
+            task_context: Arc::clone(&self.task_context),
+            retry_config: flowgen_core::retry::RetryConfig::merge(
+                &self.task_context.retry,
+                &self.config.retry,
+            ),
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(mut self) -> Result<(), Self::Error> {
+        let event_handler = Arc::new(self.init().await?);
+
+        loop {
+            match self.rx.recv().await {
+                Some(event) => {
+                    let event_handler = Arc::clone(&event_handler);
+                    let metrics = self.task_context.metrics.clone();
+                    tokio::spawn(
+                        async move {
+                            // Retries happen per batch inside the handler so accepted
+                            // batches are not inserted again.
+                            let bytes = event.data_size() as u64;
+                            match event_handler.handle(event.clone()).await {
+                                Ok(()) => metrics.record_processed(bytes),
+                                Err(e) => {
+                                    metrics.record_failed();
+                                    error!(error = %e, "ClickHouse insert failed after all retry attempts");
+                                    // Emit error event downstream for error handling.
+                                    let mut error_event = event;
+                                    error_event.error = Some(e.to_string());
+                                    if let Some(ref tx) = event_handler.tx {
+                                        tx.send(error_event).await.ok();
+                                    }
+                                }
+                            }
+                        }
+                        .instrument(tracing::Span::current()),
+                    );
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Builder for creating ClickHouse publisher instances.
+pub struct PublisherBuilder {
+    config: Option<Arc<super::config::Publisher>>,
+    rx: Option<Receiver<Event>>,
+    tx: Option<Sender<Event>>,
+    task_id: Option<usize>,
+    task_context: Option<Arc<flowgen_core::task::context::TaskContext>>,
+    task_type: Option<&'static str>,
+}
+
+impl PublisherBuilder {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            rx: None,
+            tx: None,
+            task_id: None,
+            task_context: None,
+            task_type: None,
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Publisher>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn receiver(mut self, rx: Receiver<Event>) -> Self {
+        self.rx = Some(rx);
+        self
+    }
+
+    pub fn sender(mut self, tx: Sender<Event>) -> Self {
+        self.tx = Some(tx);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = Some(task_id);
+        self
+    }
+
+    pub fn task_context(
+        mut self,
+        task_context: Arc<flowgen_core::task::context::TaskContext>,
+    ) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Publisher, Error> {
+        Ok(Publisher {
+            config: self
+                .config
+                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            rx: self
+                .rx
+                .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
+            tx: self.tx,
+            task_id: self
+                .task_id
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_id".to_string()))?,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+impl Default for PublisherBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int64Array, RecordBatch, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+
+    fn event(data: EventData) -> Event {
+        EventBuilder::new()
+            .data(data)
+            .subject("test".to_string())
+            .task_id(0)
+            .task_type("test")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_event_rows_from_record_batch() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec!["a", "b"])),
+            ],
+        )
+        .unwrap();
+
+        let rows = event_rows(&event(EventData::ArrowRecordBatch(batch))).unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                serde_json::json!({"id": 1, "name": "a"}),
+                serde_json::json!({"id": 2, "name": "b"}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_event_rows_rejects_non_object() {
+        let result = event_rows(&event(EventData::Json(serde_json::json!([1, 2]))));
+        assert!(matches!(result, Err(Error::InvalidRow(_))));
+    }
+
+    #[test]
+    fn test_json_each_row() {
+        let rows = vec![serde_json::json!({"id": 1}), serde_json::json!({"id": 2})];
+        assert_eq!(json_each_row(&rows).unwrap(), b"{\"id\":1}\n{\"id\":2}\n");
+    }
+
+    #[test]
+    fn test_insert_params() {
+        let mut config = super::super::config::Publisher {
+            database: "analytics".to_string(),
+            table: "events".to_string(),
+            wait_for_async_insert: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            insert_params(&config),
+            vec![(
+                "query",
+                "INSERT INTO `analytics`.`events` FORMAT JSONEachRow".to_string()
+            )]
+        );
+
+        config.async_insert = true;
+        config.wait_for_async_insert = false;
+        let params = insert_params(&config);
+        assert!(params.contains(&("async_insert", "1".to_string())));
+        assert!(params.contains(&("wait_for_async_insert", "0".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_publisher_builder_missing_config() {
+        let result = PublisherBuilder::new().build().await;
+        assert!(
+            matches!(result.unwrap_err(), Error::MissingBuilderAttribute(attr) if attr == "config")
+        );
+    }
+}
@@ -4,9 +4,20 @@
 //! formats. The `FromReader` trait streams parsed items one at a time via a
 //! callback, so callers never collect all items in memory.
 use std::io::{Read, Seek, Write};
+use std::path::Path;
+
+/// Default batch size for content types detected from a file extension.
+pub const DEFAULT_BATCH_SIZE: usize = 1000;
+
+/// Compression codecs supported for compressed content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionCodec {
+    /// Gzip compression (`.gz`).
+    Gzip,
+}
 
 /// Supported content types with their specific configuration options.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ContentType {
     /// JSON content format.
     Json,
@@ -30,6 +41,46 @@ pub enum ContentType {
         /// Number of records to process in each batch.
         batch_size: usize,
     },
+    /// Compressed content that is decompressed before being parsed as `inner`.
+    Compressed {
+        /// Content type of the decompressed data.
+        inner: Box<ContentType>,
+        /// Codec the content is compressed with.
+        codec: CompressionCodec,
+    },
+}
+
+impl ContentType {
+    /// Detects the content type from a file extension.
+    ///
+    /// Matching is case-insensitive. CSV files are assumed to have a header
+    /// row, and CSV and Parquet use [`DEFAULT_BATCH_SIZE`]. A trailing `.gz`
+    /// wraps the content type of the remaining extension, so `orders.csv.gz`
+    /// is gzip-compressed CSV. Returns `None` for unknown or missing extensions.
+    pub fn from_path(path: &Path) -> Option<ContentType> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "json" => Some(ContentType::Json),
+            "csv" => Some(ContentType::Csv {
+                batch_size: DEFAULT_BATCH_SIZE,
+                has_header: true,
+                delimiter: None,
+                infer_schema_max_records: None,
+            }),
+            "avro" => Some(ContentType::Avro),
+            "parquet" => Some(ContentType::Parquet {
+                batch_size: DEFAULT_BATCH_SIZE,
+            }),
+            "gz" => {
+                let inner = ContentType::from_path(Path::new(path.file_stem()?))?;
+                Some(ContentType::Compressed {
+                    inner: Box::new(inner),
+                    codec: CompressionCodec::Gzip,
+                })
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Boxed iterator that yields parsed items one at a time.
@@ -111,4 +162,46 @@ mod tests {
             _ => panic!("Clone should preserve variant"),
         }
     }
+
+    #[test]
+    fn test_content_type_from_path() {
+        assert_eq!(
+            ContentType::from_path(Path::new("data/orders.json")),
+            Some(ContentType::Json)
+        );
+        assert_eq!(
+            ContentType::from_path(Path::new("orders.CSV")),
+            Some(ContentType::Csv {
+                batch_size: DEFAULT_BATCH_SIZE,
+                has_header: true,
+                delimiter: None,
+                infer_schema_max_records: None,
+            })
+        );
+        assert_eq!(
+            ContentType::from_path(Path::new("orders.avro")),
+            Some(ContentType::Avro)
+        );
+        assert_eq!(
+            ContentType::from_path(Path::new("orders.parquet")),
+            Some(ContentType::Parquet {
+                batch_size: DEFAULT_BATCH_SIZE
+            })
+        );
+        assert_eq!(ContentType::from_path(Path::new("orders.txt")), None);
+        assert_eq!(ContentType::from_path(Path::new("orders")), None);
+    }
+
+    #[test]
+    fn test_content_type_from_path_compressed() {
+        assert_eq!(
+            ContentType::from_path(Path::new("orders.json.gz")),
+            Some(ContentType::Compressed {
+                inner: Box::new(ContentType::Json),
+                codec: CompressionCodec::Gzip,
+            })
+        );
+        assert_eq!(ContentType::from_path(Path::new("orders.gz")), None);
+        assert_eq!(ContentType::from_path(Path::new("orders.txt.gz")), None);
+    }
 }
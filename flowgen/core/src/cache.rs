@@ -100,6 +100,32 @@ pub trait Cache: Debug + Send + Sync + 'static {
     /// Ok if the key was deleted successfully, or an error if the operation failed
     async fn delete(&self, key: &str) -> Result<(), Error>;
 
+    /// Purges a key from the cache, removing its value and all historical revisions.
+    ///
+    /// Unlike `delete`, which may leave a tombstone and history behind, `purge`
+    /// discards everything stored for the key. The default implementation calls
+    /// `delete`, which is enough for backends that do not track history.
+    ///
+    /// # Arguments
+    /// * `key` - The key to purge
+    async fn purge(&self, key: &str) -> Result<(), Error> {
+        self.delete(key).await
+    }
+
+    /// Removes every key from the cache.
+    ///
+    /// # Use Case
+    /// Cache eviction and test teardown.
+    ///
+    /// The default implementation purges every key returned by `list_keys`;
+    /// backends override it when they can remove all keys at once.
+    async fn clear(&self) -> Result<(), Error> {
+        for key in self.list_keys("").await? {
+            self.purge(&key).await?;
+        }
+        Ok(())
+    }
+
     /// Creates a key-value pair only if the key does not already exist (atomic).
     ///
     /// # Arguments
@@ -194,6 +220,53 @@ pub trait Cache: Debug + Send + Sync + 'static {
     async fn list_keys(&self, prefix: &str) -> Result<Vec<String>, Error>;
 }
 
+/// Extension methods for caching Arrow record batches.
+///
+/// Batches are serialized with the Arrow IPC stream format, so any [`Cache`]
+/// backend can store them without processors duplicating the encoding logic.
+#[async_trait]
+pub trait CacheExt: Cache {
+    /// Serializes a record batch to Arrow IPC and stores it under the given key.
+    ///
+    /// # Arguments
+    /// * `key` - The key to store the batch under
+    /// * `batch` - The record batch to store
+    async fn put_batch(&self, key: &str, batch: &arrow::array::RecordBatch) -> Result<(), Error> {
+        let mut buffer = Vec::new();
+        {
+            let mut stream_writer =
+                arrow::ipc::writer::StreamWriter::try_new(&mut buffer, &batch.schema())
+                    .map_err(|e| CacheError::PutFailed(Box::new(e)))?;
+            stream_writer
+                .write(batch)
+                .map_err(|e| CacheError::PutFailed(Box::new(e)))?;
+            stream_writer
+                .finish()
+                .map_err(|e| CacheError::PutFailed(Box::new(e)))?;
+        }
+        self.put(key, bytes::Bytes::from(buffer), None).await
+    }
+
+    /// Retrieves and deserializes a record batch stored with [`CacheExt::put_batch`].
+    ///
+    /// # Returns
+    /// * `Ok(batch)` - Key exists and holds a valid Arrow IPC stream
+    /// * `Err(CacheError::NotFound)` - Key not found or the stream holds no batches
+    /// * `Err(e)` - Operation or deserialization failed
+    async fn get_batch(&self, key: &str) -> Result<arrow::array::RecordBatch, Error> {
+        let data = self.get(key).await?.ok_or(CacheError::NotFound)?;
+        let mut stream_reader =
+            arrow::ipc::reader::StreamReader::try_new(std::io::Cursor::new(data), None)
+                .map_err(|e| CacheError::GetFailed(Box::new(e)))?;
+        stream_reader
+            .next()
+            .ok_or(CacheError::NotFound)?
+            .map_err(|e| CacheError::GetFailed(Box::new(e)))
+    }
+}
+
+impl<T: Cache + ?Sized> CacheExt for T {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -221,4 +294,133 @@ mod tests {
 
         assert_eq!(options, deserialized);
     }
+
+    #[tokio::test]
+    async fn test_cache_ext_batch_roundtrip() {
+        use arrow::array::{Int64Array, RecordBatch, StringArray};
+        use arrow::datatypes::{DataType, Field, Schema};
+        use std::sync::Arc;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec![Some("a"), None])),
+            ],
+        )
+        .unwrap();
+
+        let cache: Arc<dyn Cache> = Arc::new(memory::MemoryCache::new());
+        cache.put_batch("batch", &batch).await.unwrap();
+        let restored = cache.get_batch("batch").await.unwrap();
+
+        assert_eq!(restored, batch);
+    }
+
+    /// Backend relying on the trait's default implementations.
+    #[derive(Debug, Default)]
+    struct DefaultsCache(memory::MemoryCache);
+
+    #[async_trait]
+    impl Cache for DefaultsCache {
+        async fn put(
+            &self,
+            key: &str,
+            value: bytes::Bytes,
+            ttl_secs: Option<u64>,
+        ) -> Result<(), Error> {
+            self.0.put(key, value, ttl_secs).await
+        }
+
+        async fn get(&self, key: &str) -> Result<Option<bytes::Bytes>, Error> {
+            self.0.get(key).await
+        }
+
+        async fn delete(&self, key: &str) -> Result<(), Error> {
+            self.0.delete(key).await
+        }
+
+        async fn create(
+            &self,
+            key: &str,
+            value: bytes::Bytes,
+            ttl_secs: Option<u64>,
+        ) -> Result<u64, Error> {
+            self.0.create(key, value, ttl_secs).await
+        }
+
+        async fn update(
+            &self,
+            key: &str,
+            value: bytes::Bytes,
+            expected_revision: u64,
+            ttl_secs: Option<u64>,
+        ) -> Result<u64, Error> {
+            self.0.update(key, value, expected_revision, ttl_secs).await
+        }
+
+        async fn get_with_revision(&self, key: &str) -> Result<Option<(bytes::Bytes, u64)>, Error> {
+            self.0.get_with_revision(key).await
+        }
+
+        async fn delete_with_revision(
+            &self,
+            key: &str,
+            expected_revision: u64,
+        ) -> Result<(), Error> {
+            self.0.delete_with_revision(key, expected_revision).await
+        }
+
+        async fn get_revision(&self, key: &str) -> Result<Option<u64>, Error> {
+            self.0.get_revision(key).await
+        }
+
+        async fn list_keys(&self, prefix: &str) -> Result<Vec<String>, Error> {
+            self.0.list_keys(prefix).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_default_purge_deletes_key() {
+        let cache = DefaultsCache::default();
+        cache
+            .put("key", bytes::Bytes::from("v"), None)
+            .await
+            .unwrap();
+
+        cache.purge("key").await.unwrap();
+        assert!(cache.get("key").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_default_clear_removes_all_keys() {
+        let cache = DefaultsCache::default();
+        cache.put("a", bytes::Bytes::from("1"), None).await.unwrap();
+        cache.put("b", bytes::Bytes::from("2"), None).await.unwrap();
+
+        cache.clear().await.unwrap();
+        assert!(cache.list_keys("").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cache_ext_get_batch_not_found() {
+        let cache = memory::MemoryCache::new();
+        let result = cache.get_batch("missing").await;
+        assert!(matches!(result, Err(CacheError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_cache_ext_get_batch_invalid_data() {
+        let cache = memory::MemoryCache::new();
+        cache
+            .put("invalid", bytes::Bytes::from("not arrow"), None)
+            .await
+            .unwrap();
+        let result = cache.get_batch("invalid").await;
+        assert!(matches!(result, Err(CacheError::GetFailed(_))));
+    }
 }
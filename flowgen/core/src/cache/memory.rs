@@ -3,19 +3,35 @@
 use bytes::Bytes;
 use dashmap::DashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// Entry with value and revision number for optimistic concurrency control.
 #[derive(Debug, Clone)]
 struct CacheEntry {
     value: Bytes,
     revision: u64,
+    /// Point in time after which the entry is treated as absent.
+    expires_at: Option<Instant>,
+}
+
+impl CacheEntry {
+    fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| Instant::now() >= expires_at)
+    }
+}
+
+/// Converts a TTL in seconds into an expiry instant.
+fn expires_at(ttl_secs: Option<u64>) -> Option<Instant> {
+    ttl_secs.map(|ttl| Instant::now() + Duration::from_secs(ttl))
 }
 
 /// Thread-safe in-memory cache implementation.
 ///
 /// Uses DashMap for concurrent access without requiring external coordination.
-/// Suitable for single-process deployments or as a fallback when distributed
-/// caching is unavailable.
+/// Suitable for single-process deployments, tests, or as a fallback when
+/// distributed caching is unavailable. Entries written with a TTL are treated
+/// as absent once it elapses and are evicted lazily on access.
 #[derive(Debug, Clone)]
 pub struct MemoryCache {
     data: Arc<DashMap<String, CacheEntry>>,
@@ -33,30 +49,44 @@ impl MemoryCache {
             data: Arc::new(DashMap::new()),
         }
     }
+
+    /// Returns a copy of the entry under `key`, evicting it if it has expired.
+    fn live_entry(&self, key: &str) -> Option<CacheEntry> {
+        let entry = self.data.get(key).map(|entry| entry.value().clone())?;
+        if entry.is_expired() {
+            self.data.remove_if(key, |_, entry| entry.is_expired());
+            return None;
+        }
+        Some(entry)
+    }
 }
 
 #[async_trait::async_trait]
 impl super::Cache for MemoryCache {
-    /// Note: In-memory cache does not support TTL expiration.
-    /// For TTL support, use a distributed cache like NATS.
     async fn put(
         &self,
         key: &str,
         value: Bytes,
-        _ttl_secs: Option<u64>,
+        ttl_secs: Option<u64>,
     ) -> Result<(), super::Error> {
+        let expires_at = expires_at(ttl_secs);
         self.data
             .entry(key.to_string())
             .and_modify(|e| {
                 e.value = value.clone();
                 e.revision += 1;
+                e.expires_at = expires_at;
             })
-            .or_insert(CacheEntry { value, revision: 1 });
+            .or_insert(CacheEntry {
+                value,
+                revision: 1,
+                expires_at,
+            });
         Ok(())
     }
 
     async fn get(&self, key: &str) -> Result<Option<Bytes>, super::Error> {
-        Ok(self.data.get(key).map(|entry| entry.value.clone()))
+        Ok(self.live_entry(key).map(|entry| entry.value))
     }
 
     async fn delete(&self, key: &str) -> Result<(), super::Error> {
@@ -64,16 +94,36 @@ impl super::Cache for MemoryCache {
         Ok(())
     }
 
+    /// In-memory cache keeps no history, so purging is equivalent to deleting.
+    async fn purge(&self, key: &str) -> Result<(), super::Error> {
+        self.data.remove(key);
+        Ok(())
+    }
+
+    async fn clear(&self) -> Result<(), super::Error> {
+        self.data.clear();
+        Ok(())
+    }
+
     async fn create(
         &self,
         key: &str,
         value: Bytes,
-        _ttl_secs: Option<u64>,
+        ttl_secs: Option<u64>,
     ) -> Result<u64, super::Error> {
+        let new_entry = CacheEntry {
+            value,
+            revision: 1,
+            expires_at: expires_at(ttl_secs),
+        };
         match self.data.entry(key.to_string()) {
+            dashmap::Entry::Occupied(mut entry) if entry.get().is_expired() => {
+                entry.insert(new_entry);
+                Ok(1)
+            }
             dashmap::Entry::Occupied(_) => Err(super::CacheError::AlreadyExists),
             dashmap::Entry::Vacant(entry) => {
-                entry.insert(CacheEntry { value, revision: 1 });
+                entry.insert(new_entry);
                 Ok(1)
             }
         }
@@ -84,9 +134,13 @@ impl super::Cache for MemoryCache {
         key: &str,
         value: Bytes,
         expected_revision: u64,
-        _ttl_secs: Option<u64>,
+        ttl_secs: Option<u64>,
     ) -> Result<u64, super::Error> {
         match self.data.entry(key.to_string()) {
+            dashmap::Entry::Occupied(entry) if entry.get().is_expired() => {
+                entry.remove();
+                Err(super::CacheError::NotFound)
+            }
             dashmap::Entry::Occupied(mut entry) => {
                 let current_revision = entry.get().revision;
                 if current_revision != expected_revision {
@@ -98,6 +152,7 @@ impl super::Cache for MemoryCache {
                 let new_revision = current_revision + 1;
                 entry.get_mut().value = value;
                 entry.get_mut().revision = new_revision;
+                entry.get_mut().expires_at = expires_at(ttl_secs);
                 Ok(new_revision)
             }
             dashmap::Entry::Vacant(_) => Err(super::CacheError::NotFound),
@@ -106,9 +161,8 @@ impl super::Cache for MemoryCache {
 
     async fn get_with_revision(&self, key: &str) -> Result<Option<(Bytes, u64)>, super::Error> {
         Ok(self
-            .data
-            .get(key)
-            .map(|entry| (entry.value.clone(), entry.revision)))
+            .live_entry(key)
+            .map(|entry| (entry.value, entry.revision)))
     }
 
     async fn delete_with_revision(
@@ -117,6 +171,10 @@ impl super::Cache for MemoryCache {
         expected_revision: u64,
     ) -> Result<(), super::Error> {
         match self.data.entry(key.to_string()) {
+            dashmap::Entry::Occupied(entry) if entry.get().is_expired() => {
+                entry.remove();
+                Err(super::CacheError::NotFound)
+            }
             dashmap::Entry::Occupied(entry) => {
                 let current_revision = entry.get().revision;
                 if current_revision != expected_revision {
@@ -133,14 +191,14 @@ impl super::Cache for MemoryCache {
     }
 
     async fn get_revision(&self, key: &str) -> Result<Option<u64>, super::Error> {
-        Ok(self.data.get(key).map(|entry| entry.revision))
+        Ok(self.live_entry(key).map(|entry| entry.revision))
     }
 
     async fn list_keys(&self, prefix: &str) -> Result<Vec<String>, super::Error> {
         Ok(self
             .data
             .iter()
-            .filter(|entry| entry.key().starts_with(prefix))
+            .filter(|entry| entry.key().starts_with(prefix) && !entry.value().is_expired())
             .map(|entry| entry.key().clone())
             .collect())
     }
@@ -183,6 +241,30 @@ mod tests {
         assert_eq!(result, None);
     }
 
+    #[tokio::test]
+    async fn test_memory_cache_purge() {
+        let cache = MemoryCache::new();
+        let key = "test_key";
+
+        cache.put(key, Bytes::from("value1"), None).await.unwrap();
+        cache.put(key, Bytes::from("value2"), None).await.unwrap();
+        cache.purge(key).await.unwrap();
+
+        assert_eq!(cache.get(key).await.unwrap(), None);
+        assert_eq!(cache.get_revision(key).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_memory_cache_clear() {
+        let cache = MemoryCache::new();
+
+        cache.put("a", Bytes::from("a"), None).await.unwrap();
+        cache.put("b", Bytes::from("b"), None).await.unwrap();
+        cache.clear().await.unwrap();
+
+        assert!(cache.list_keys("").await.unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn test_memory_cache_update() {
         let cache = MemoryCache::new();
@@ -225,6 +307,71 @@ mod tests {
         assert!(empty_keys.is_empty());
     }
 
+    /// Stores `key` with an expiry that has already passed.
+    fn insert_expired(cache: &MemoryCache, key: &str) {
+        cache.data.insert(
+            key.to_string(),
+            CacheEntry {
+                value: Bytes::from("stale"),
+                revision: 3,
+                expires_at: Some(Instant::now() - Duration::from_secs(1)),
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn test_memory_cache_ttl_not_elapsed() {
+        let cache = MemoryCache::new();
+        let value = Bytes::from("test_value");
+
+        cache
+            .put("test_key", value.clone(), Some(60))
+            .await
+            .unwrap();
+
+        assert_eq!(cache.get("test_key").await.unwrap(), Some(value));
+    }
+
+    #[tokio::test]
+    async fn test_memory_cache_expired_entry_is_absent() {
+        let cache = MemoryCache::new();
+        insert_expired(&cache, "test_key");
+
+        assert!(cache.list_keys("").await.unwrap().is_empty());
+        assert_eq!(cache.get("test_key").await.unwrap(), None);
+        assert_eq!(cache.get_revision("test_key").await.unwrap(), None);
+        assert!(cache.data.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_memory_cache_create_replaces_expired_entry() {
+        let cache = MemoryCache::new();
+        insert_expired(&cache, "test_key");
+
+        let revision = cache
+            .create("test_key", Bytes::from("fresh"), None)
+            .await
+            .unwrap();
+
+        assert_eq!(revision, 1);
+        assert_eq!(
+            cache.get("test_key").await.unwrap(),
+            Some(Bytes::from("fresh"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_memory_cache_update_expired_entry_not_found() {
+        let cache = MemoryCache::new();
+        insert_expired(&cache, "test_key");
+
+        let result = cache
+            .update("test_key", Bytes::from("fresh"), 3, None)
+            .await;
+
+        assert!(matches!(result, Err(crate::cache::CacheError::NotFound)));
+    }
+
     #[tokio::test]
     async fn test_memory_cache_clone() {
         let cache1 = MemoryCache::new();
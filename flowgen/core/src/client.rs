@@ -3,6 +3,29 @@
 //! Provides a unified interface for establishing connections to various external
 //! services used by flowgen workers such as databases, message brokers, and APIs.
 
+use crate::retry::RetryConfig;
+use std::time::Duration;
+use tokio::sync::{mpsc, Mutex};
+use tracing::warn;
+
+/// Default number of client instances in a [`PooledClient`].
+pub const DEFAULT_POOL_SIZE: usize = 1;
+
+/// Default time in milliseconds to wait for a pooled client to become available.
+pub const DEFAULT_ACQUIRE_TIMEOUT_MS: u64 = 30_000;
+
+/// Errors that can occur when building or acquiring from a client pool.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Timed out after {timeout_ms}ms waiting for a pooled client")]
+    AcquireTimeout { timeout_ms: u64 },
+    #[error("Client pool is closed")]
+    PoolClosed,
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+}
+
 /// Trait for establishing connections to external services.
 ///
 /// Defines a common interface for client connection logic that can be implemented
@@ -20,12 +43,198 @@ pub trait Client {
         Self: Sized;
 }
 
+/// Pool of client instances, each handed out to one holder at a time.
+///
+/// Wraps `pool_size` instances of a [`Client`] so high-throughput tasks are not
+/// bottlenecked on a single connection. Free instances are kept in a queue and
+/// handed out least recently used first; callers wait up to the acquire timeout
+/// for one to be released. Connecting the pool connects every instance.
+#[derive(Debug)]
+pub struct PooledClient<C> {
+    /// Client instances in the pool.
+    clients: Vec<C>,
+    /// Sender used by guards to return their client index to the free queue.
+    free_tx: mpsc::UnboundedSender<usize>,
+    /// Queue of indices of clients that are not currently borrowed.
+    free_rx: Mutex<mpsc::UnboundedReceiver<usize>>,
+    /// Maximum time to wait for a free client.
+    acquire_timeout: Duration,
+}
+
+/// Client borrowed from a [`PooledClient`].
+///
+/// The client is returned to the pool when the guard is dropped.
+#[derive(Debug)]
+pub struct PooledClientGuard<'a, C> {
+    client: &'a C,
+    index: usize,
+    free_tx: &'a mpsc::UnboundedSender<usize>,
+}
+
+impl<C> Drop for PooledClientGuard<'_, C> {
+    fn drop(&mut self) {
+        // The receiver lives in the pool, which outlives the guard.
+        let _ = self.free_tx.send(self.index);
+    }
+}
+
+impl<C> std::ops::Deref for PooledClientGuard<'_, C> {
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        self.client
+    }
+}
+
+impl<C> PooledClient<C> {
+    /// Acquires a client that no other holder is using.
+    ///
+    /// Waits for a client to be released if all clients are in use.
+    ///
+    /// # Returns
+    /// * `Ok(guard)` - Borrowed client, released on drop
+    /// * `Err(Error::AcquireTimeout)` - No client became available within the acquire timeout
+    /// * `Err(Error::PoolClosed)` - The free queue was closed
+    pub async fn acquire(&self) -> Result<PooledClientGuard<'_, C>, Error> {
+        let next_free = async { self.free_rx.lock().await.recv().await };
+        let index = tokio::time::timeout(self.acquire_timeout, next_free)
+            .await
+            .map_err(|_| Error::AcquireTimeout {
+                timeout_ms: self.acquire_timeout.as_millis() as u64,
+            })?
+            .ok_or(Error::PoolClosed)?;
+
+        Ok(PooledClientGuard {
+            client: &self.clients[index],
+            index,
+            free_tx: &self.free_tx,
+        })
+    }
+
+    /// Returns the number of client instances in the pool.
+    pub fn pool_size(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Returns mutable access to every client, e.g. to apply settings after connecting.
+    pub fn clients_mut(&mut self) -> &mut [C] {
+        &mut self.clients
+    }
+}
+
+impl<C> Client for PooledClient<C>
+where
+    C: Client + Send,
+    C::Error: Send,
+{
+    type Error = C::Error;
+
+    /// Connects every client in the pool concurrently.
+    async fn connect(self) -> Result<Self, Self::Error> {
+        let clients =
+            futures_util::future::try_join_all(self.clients.into_iter().map(C::connect)).await?;
+        Ok(PooledClient { clients, ..self })
+    }
+}
+
+/// Builder for [`PooledClient`] instances.
+#[derive(Debug)]
+pub struct PooledClientBuilder<C> {
+    /// Client template cloned into every pool slot.
+    client: Option<C>,
+    /// Number of client instances. Defaults to DEFAULT_POOL_SIZE.
+    pool_size: usize,
+    /// Acquire timeout in milliseconds. Defaults to DEFAULT_ACQUIRE_TIMEOUT_MS.
+    acquire_timeout_ms: u64,
+}
+
+impl<C> Default for PooledClientBuilder<C> {
+    fn default() -> Self {
+        PooledClientBuilder {
+            client: None,
+            pool_size: DEFAULT_POOL_SIZE,
+            acquire_timeout_ms: DEFAULT_ACQUIRE_TIMEOUT_MS,
+        }
+    }
+}
+
+impl<C: Clone> PooledClientBuilder<C> {
+    /// Creates a new builder with default pool size and acquire timeout.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the unconnected client cloned into every pool slot.
+    pub fn client(mut self, client: C) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the number of client instances. Values below 1 are treated as 1.
+    pub fn pool_size(mut self, pool_size: usize) -> Self {
+        self.pool_size = pool_size.max(1);
+        self
+    }
+
+    /// Sets how long `acquire` waits for a free client, in milliseconds.
+    pub fn acquire_timeout_ms(mut self, acquire_timeout_ms: u64) -> Self {
+        self.acquire_timeout_ms = acquire_timeout_ms;
+        self
+    }
+
+    /// Builds the pool. Clients are returned unconnected; call `connect()` to connect them.
+    pub fn build(self) -> Result<PooledClient<C>, Error> {
+        let client = self
+            .client
+            .ok_or_else(|| Error::MissingBuilderAttribute("client".to_string()))?;
+
+        let (free_tx, free_rx) = mpsc::unbounded_channel();
+        for index in 0..self.pool_size {
+            // The receiver is alive, so sending cannot fail.
+            let _ = free_tx.send(index);
+        }
+
+        Ok(PooledClient {
+            clients: vec![client; self.pool_size],
+            free_tx,
+            free_rx: Mutex::new(free_rx),
+            acquire_timeout: Duration::from_millis(self.acquire_timeout_ms),
+        })
+    }
+}
+
+/// Runs a connection attempt until it succeeds or the retry attempts are exhausted.
+///
+/// Waits with exponential backoff and jitter between attempts and logs every
+/// failed attempt. Returns the error of the last attempt when all have failed.
+pub async fn connect_with_retry<T, E, F, Fut>(retry: &RetryConfig, mut connect: F) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, E>>,
+    E: std::fmt::Display,
+{
+    let mut attempt = 0;
+    tokio_retry::Retry::spawn(retry.strategy(), || {
+        attempt += 1;
+        let connection = connect();
+        async move {
+            connection.await.map_err(|e| {
+                warn!(attempt, error = %e, "Connection attempt failed");
+                tokio_retry::RetryError::transient(e)
+            })
+        }
+    })
+    .await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
 
     /// Mock client implementation for testing.
-    #[derive(Debug)]
+    #[derive(Debug, Clone)]
     struct MockClient {
         should_error: bool,
         connected: bool,
@@ -88,4 +297,161 @@ mod tests {
         assert!(!client.should_error);
         assert!(!client.connected);
     }
+
+    #[tokio::test]
+    async fn test_pooled_client_connect_and_acquire() {
+        let pool = PooledClientBuilder::new()
+            .client(MockClient {
+                should_error: false,
+                connected: false,
+            })
+            .pool_size(2)
+            .build()
+            .unwrap()
+            .connect()
+            .await
+            .unwrap();
+
+        assert_eq!(pool.pool_size(), 2);
+        let first = pool.acquire().await.unwrap();
+        let second = pool.acquire().await.unwrap();
+        assert!(first.connected);
+        assert!(second.connected);
+        assert!(!std::ptr::eq(&*first, &*second));
+    }
+
+    #[tokio::test]
+    async fn test_pooled_client_exclusive_use() {
+        let pool = PooledClientBuilder::new()
+            .client(MockClient {
+                should_error: false,
+                connected: false,
+            })
+            .pool_size(2)
+            .build()
+            .unwrap();
+
+        let first = pool.acquire().await.unwrap();
+        let second = pool.acquire().await.unwrap();
+        let first_ptr: *const MockClient = &*first;
+        let second_ptr: *const MockClient = &*second;
+        drop(first);
+
+        // Only the released client may be handed out while `second` is held.
+        for _ in 0..3 {
+            let third = pool.acquire().await.unwrap();
+            assert!(std::ptr::eq(&*third, first_ptr));
+            assert!(!std::ptr::eq(&*third, second_ptr));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pooled_client_connect_error() {
+        let result = PooledClientBuilder::new()
+            .client(MockClient {
+                should_error: true,
+                connected: false,
+            })
+            .pool_size(3)
+            .build()
+            .unwrap()
+            .connect()
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_pooled_client_acquire_timeout() {
+        let pool = PooledClientBuilder::new()
+            .client(MockClient {
+                should_error: false,
+                connected: false,
+            })
+            .pool_size(1)
+            .acquire_timeout_ms(10)
+            .build()
+            .unwrap();
+
+        let _held = pool.acquire().await.unwrap();
+        let result = pool.acquire().await;
+        assert!(matches!(
+            result,
+            Err(Error::AcquireTimeout { timeout_ms: 10 })
+        ));
+    }
+
+    #[test]
+    fn test_pooled_client_builder_missing_client() {
+        let result = PooledClientBuilder::<MockClient>::new().build();
+        assert!(matches!(result, Err(Error::MissingBuilderAttribute(attr)) if attr == "client"));
+    }
+
+    /// Client that fails until it has been connected `failures` times.
+    #[derive(Debug, Clone)]
+    struct FlakyClient {
+        attempts: Arc<AtomicUsize>,
+        failures: usize,
+    }
+
+    impl Client for FlakyClient {
+        type Error = MockError;
+
+        async fn connect(self) -> Result<Self, Self::Error> {
+            if self.attempts.fetch_add(1, Ordering::SeqCst) < self.failures {
+                Err(MockError)
+            } else {
+                Ok(self)
+            }
+        }
+    }
+
+    fn fast_retry(max_attempts: usize) -> RetryConfig {
+        RetryConfig {
+            max_attempts: Some(max_attempts),
+            initial_backoff: Duration::from_millis(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connect_with_retry_recovers() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let client = FlakyClient {
+            attempts: Arc::clone(&attempts),
+            failures: 2,
+        };
+
+        let result = connect_with_retry(&fast_retry(3), || client.clone().connect()).await;
+
+        assert!(result.is_ok());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_connect_with_retry_exhausts_attempts() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let client = FlakyClient {
+            attempts: Arc::clone(&attempts),
+            failures: usize::MAX,
+        };
+
+        let result = connect_with_retry(&fast_retry(3), || client.clone().connect()).await;
+
+        assert!(matches!(result, Err(MockError)));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_pooled_client_builder_min_pool_size() {
+        let pool = PooledClientBuilder::new()
+            .client(MockClient {
+                should_error: false,
+                connected: false,
+            })
+            .pool_size(0)
+            .build()
+            .unwrap();
+
+        assert_eq!(pool.pool_size(), 1);
+    }
 }
@@ -1,7 +1,8 @@
 //! Configuration templating and rendering utilities.
 //!
 //! Provides template rendering capabilities for configuration files using Handlebars,
-//! allowing dynamic configuration generation with variable substitution.
+//! allowing dynamic configuration generation with variable substitution, plus
+//! validation, merging and environment overrides shared by all task configs.
 
 use handlebars::Handlebars;
 use serde::{de::DeserializeOwned, Serialize};
@@ -161,11 +162,83 @@ fn render_with_value(template: &str, data_value: &serde_json::Value) -> Result<S
         .map_err(|e| Error::Render { source: e })
 }
 
+/// Prefix of environment variables read by [`ConfigExt::with_env_overrides`].
+pub const ENV_OVERRIDE_PREFIX: &str = "FLOWGEN_";
+
+/// Parses an environment variable as JSON, falling back to a plain string.
+fn parse_env_value(value: String) -> serde_json::Value {
+    serde_json::from_str(&value).unwrap_or(serde_json::Value::String(value))
+}
+
 /// Extension trait for configuration types that support template rendering.
 ///
 /// Enables configuration structures to render themselves as Handlebars templates
-/// with dynamic variable substitution from provided data context.
+/// with dynamic variable substitution from provided data context. Also provides
+/// default validation, merge and environment override behaviour that works on the
+/// serialized form of the config, so implementors only override what they need.
 pub trait ConfigExt {
+    /// Validates the configuration, returning every problem found.
+    ///
+    /// The default implementation accepts any configuration. Implementors
+    /// override it to add field-specific checks.
+    fn validate(&self) -> Result<(), Vec<String>> {
+        Ok(())
+    }
+
+    /// Merges two configurations field by field.
+    ///
+    /// Top-level fields set in `other` win; fields that are `null` (`None`) in
+    /// `other` keep the value from `self`.
+    fn merge(&self, other: &Self) -> Result<Self, Error>
+    where
+        Self: Serialize + DeserializeOwned + Sized,
+    {
+        let base = serde_json::to_value(self).map_err(|e| Error::SerdeJson { source: e })?;
+        let overrides = serde_json::to_value(other).map_err(|e| Error::SerdeJson { source: e })?;
+
+        let merged = match (base, overrides) {
+            (serde_json::Value::Object(mut base_map), serde_json::Value::Object(override_map)) => {
+                for (key, value) in override_map {
+                    if !value.is_null() {
+                        base_map.insert(key, value);
+                    }
+                }
+                serde_json::Value::Object(base_map)
+            }
+            (_, overrides) => overrides,
+        };
+
+        serde_json::from_value(merged).map_err(|e| Error::SerdeJson { source: e })
+    }
+
+    /// Overrides top-level fields from `FLOWGEN_`-prefixed environment variables.
+    ///
+    /// A field named `max_retries` is overridden by `FLOWGEN_MAX_RETRIES`. Values for
+    /// string fields are used as-is; other values are parsed as JSON when possible
+    /// (numbers, booleans, arrays, objects) and used as strings otherwise.
+    fn with_env_overrides(&self) -> Result<Self, Error>
+    where
+        Self: Serialize + DeserializeOwned + Sized,
+    {
+        let mut config_value =
+            serde_json::to_value(self).map_err(|e| Error::SerdeJson { source: e })?;
+
+        if let serde_json::Value::Object(ref mut map) = config_value {
+            for (key, value) in map.iter_mut() {
+                let var_name = format!("{ENV_OVERRIDE_PREFIX}{}", key.to_uppercase());
+                if let Ok(env_value) = std::env::var(&var_name) {
+                    // String fields keep the raw value so "123" stays a string.
+                    *value = match value {
+                        serde_json::Value::String(_) => serde_json::Value::String(env_value),
+                        _ => parse_env_value(env_value),
+                    };
+                }
+            }
+        }
+
+        serde_json::from_value(config_value).map_err(|e| Error::SerdeJson { source: e })
+    }
+
     /// Renders the configuration as a Handlebars template with provided data.
     ///
     /// # Arguments
@@ -342,6 +415,92 @@ mod tests {
         std::env::remove_var("TEST_DATASET");
     }
 
+    #[derive(Serialize, Deserialize, PartialEq, Debug, Default)]
+    struct OptionalConfig {
+        name: String,
+        batch_size: Option<u32>,
+        endpoint: Option<String>,
+    }
+
+    impl ConfigExt for OptionalConfig {
+        fn validate(&self) -> Result<(), Vec<String>> {
+            let mut errors = Vec::new();
+            if self.name.is_empty() {
+                errors.push("name must not be empty".to_string());
+            }
+            if self.batch_size == Some(0) {
+                errors.push("batch_size must be greater than zero".to_string());
+            }
+            match errors.is_empty() {
+                true => Ok(()),
+                false => Err(errors),
+            }
+        }
+    }
+
+    #[test]
+    fn test_config_validate() {
+        let config = TestConfig {
+            name: "any".to_string(),
+            value: 0,
+            url: String::new(),
+        };
+        assert!(config.validate().is_ok());
+
+        let config = OptionalConfig {
+            batch_size: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(config.validate().unwrap_err().len(), 2);
+    }
+
+    #[test]
+    fn test_config_merge() {
+        let base = OptionalConfig {
+            name: "base".to_string(),
+            batch_size: Some(10),
+            endpoint: Some("https://base".to_string()),
+        };
+        let other = OptionalConfig {
+            name: "other".to_string(),
+            batch_size: None,
+            endpoint: Some("https://other".to_string()),
+        };
+
+        let merged = base.merge(&other).unwrap();
+        assert_eq!(merged.name, "other");
+        assert_eq!(merged.batch_size, Some(10));
+        assert_eq!(merged.endpoint, Some("https://other".to_string()));
+    }
+
+    #[test]
+    fn test_config_with_env_overrides() {
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct EnvConfig {
+            test_override_name: String,
+            test_override_size: Option<u32>,
+            test_override_untouched: bool,
+        }
+        impl ConfigExt for EnvConfig {}
+
+        std::env::set_var("FLOWGEN_TEST_OVERRIDE_NAME", "123");
+        std::env::set_var("FLOWGEN_TEST_OVERRIDE_SIZE", "25");
+
+        let config = EnvConfig {
+            test_override_name: "original".to_string(),
+            test_override_size: None,
+            test_override_untouched: true,
+        };
+        let overridden = config.with_env_overrides().unwrap();
+
+        assert_eq!(overridden.test_override_name, "123");
+        assert_eq!(overridden.test_override_size, Some(25));
+        assert!(overridden.test_override_untouched);
+
+        std::env::remove_var("FLOWGEN_TEST_OVERRIDE_NAME");
+        std::env::remove_var("FLOWGEN_TEST_OVERRIDE_SIZE");
+    }
+
     #[test]
     fn test_config_render_with_env_and_event_vars() {
         std::env::set_var("TEST_BASE_URL", "https://api.example.com");
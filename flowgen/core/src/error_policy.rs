@@ -0,0 +1,94 @@
+//! Error recovery policy for tasks that fail after exhausting their retries.
+//!
+//! Retries (see [`crate::retry`]) handle transient failures inside a task. The
+//! error policy decides what the flow does once a task stops with an error or
+//! panics: abort the whole flow, restart the task, or skip it and keep going.
+
+use serde::{Deserialize, Serialize};
+
+/// Default log level for skipped task failures.
+pub const DEFAULT_SKIP_LOG_LEVEL: &str = "error";
+
+/// Strategy applied by the flow supervisor when a task fails.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorPolicy {
+    /// Cancel all other tasks in the flow.
+    Abort,
+    /// Rebuild and respawn the task up to `max_attempts` times.
+    Restart {
+        /// Maximum number of restarts before the failure is treated as fatal.
+        max_attempts: u32,
+        /// Delay between restarts in milliseconds.
+        delay_ms: u64,
+    },
+    /// Log the failure and let the remaining tasks continue.
+    Skip {
+        /// Level the failure is logged at (trace, debug, info, warn, error).
+        log_level: String,
+    },
+}
+
+impl Default for ErrorPolicy {
+    fn default() -> Self {
+        Self::Skip {
+            log_level: DEFAULT_SKIP_LOG_LEVEL.to_string(),
+        }
+    }
+}
+
+impl ErrorPolicy {
+    /// Returns the tracing level for skipped failures.
+    ///
+    /// Unknown level names fall back to `ERROR` so failures are never hidden.
+    pub fn log_level(&self) -> tracing::Level {
+        match self {
+            Self::Skip { log_level } => log_level.parse().unwrap_or(tracing::Level::ERROR),
+            Self::Abort | Self::Restart { .. } => tracing::Level::ERROR,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_policy_default_is_skip() {
+        let policy = ErrorPolicy::default();
+        assert_eq!(
+            policy,
+            ErrorPolicy::Skip {
+                log_level: "error".to_string()
+            }
+        );
+        assert_eq!(policy.log_level(), tracing::Level::ERROR);
+    }
+
+    #[test]
+    fn test_error_policy_deserialization() {
+        let abort: ErrorPolicy = serde_json::from_str(r#""abort""#).unwrap();
+        assert_eq!(abort, ErrorPolicy::Abort);
+
+        let restart: ErrorPolicy =
+            serde_json::from_str(r#"{"restart": {"max_attempts": 3, "delay_ms": 500}}"#).unwrap();
+        assert_eq!(
+            restart,
+            ErrorPolicy::Restart {
+                max_attempts: 3,
+                delay_ms: 500
+            }
+        );
+
+        let skip: ErrorPolicy = serde_json::from_str(r#"{"skip": {"log_level": "warn"}}"#).unwrap();
+        assert_eq!(skip.log_level(), tracing::Level::WARN);
+    }
+
+    #[test]
+    fn test_error_policy_unknown_log_level_falls_back_to_error() {
+        let skip = ErrorPolicy::Skip {
+            log_level: "loud".to_string(),
+        };
+        assert_eq!(skip.log_level(), tracing::Level::ERROR);
+    }
+}
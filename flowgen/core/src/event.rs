@@ -3,7 +3,7 @@
 //! Provides event structures, data format handling, subject generation utilities,
 //! and logging functionality for the flowgen event processing pipeline.
 
-use crate::buffer::{ContentType, FromReader, ToWriter};
+use crate::buffer::{CompressionCodec, ContentType, FromReader, ToWriter};
 use apache_avro::{from_avro_datum, Reader as AvroReader};
 use arrow::{array::RecordBatchWriter, csv::reader::Format};
 use chrono::Utc;
@@ -13,7 +13,12 @@ use std::cell::RefCell;
 use std::io::{Read, Seek, Write};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
-use tracing::info;
+use tracing::{debug, info};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+pub mod filter;
+
+pub use filter::{AlwaysPass, EventFilter, FilteredReceiver, SubjectFilter, TaskIdFilter};
 
 /// One-shot channel used by a flow source to wait for end-to-end completion.
 /// Carries an optional result payload for request-response flows (HTTP webhook, MCP).
@@ -106,15 +111,121 @@ pub fn new_completion_channel(leaf_count: usize) -> (SharedCompletionTx, Complet
     (Arc::new(CompletionState::new(leaf_count, tx)), rx)
 }
 
+/// Coordinates completion signalling across all events emitted for a single
+/// upstream event (e.g. iterated array elements or routed copies).
+///
+/// Each emitted event carries its own per-element completion channel. The
+/// associated receivers are joined into a fan-in task which awaits all of
+/// them and only then signals the upstream source. This is required because
+/// downstream processors spawn a `tokio::task` per incoming event —
+/// attaching the upstream completion to only the last emitted event would
+/// signal completion while other elements are still in flight, leading to
+/// premature acknowledgements and silent data loss.
+///
+/// The upstream `CompletionState` was sized at flow build time to expect one
+/// signal per leaf reachable from the emitting task's subtree. Once every element
+/// finishes, the fan-in emits exactly that many upstream signals so the
+/// original source's leaf-count contract is satisfied.
+///
+/// Per-element errors are not propagated upstream: failed downstream tasks
+/// emit error events of their own, and the source falls back to its
+/// acknowledgement timeout when no completion arrives. Missing per-element
+/// signals are treated as success to avoid deadlocking the source on buggy
+/// downstream handlers.
+pub fn spawn_fan_in_completion(
+    upstream: SharedCompletionTx,
+    per_event_receivers: Vec<CompletionRx>,
+    upstream_leaf_share: usize,
+) {
+    tokio::spawn(async move {
+        let total = per_event_receivers.len();
+        let remaining = Arc::new(AtomicUsize::new(total));
+        let (done_tx, done_rx) = tokio::sync::oneshot::channel::<()>();
+        let done_tx = Arc::new(Mutex::new(Some(done_tx)));
+
+        for rx in per_event_receivers {
+            let remaining = Arc::clone(&remaining);
+            let done_tx = Arc::clone(&done_tx);
+            tokio::spawn(async move {
+                // Drop the result regardless of outcome; errors flow as
+                // downstream events, not through completion channels.
+                let _ = rx.await;
+                if remaining.fetch_sub(1, Ordering::SeqCst) == 1 {
+                    if let Ok(mut guard) = done_tx.lock() {
+                        if let Some(tx) = guard.take() {
+                            tx.send(()).ok();
+                        }
+                    }
+                }
+            });
+        }
+
+        // Wait for all per-event completions to report in.
+        done_rx.await.ok();
+
+        // Emit one upstream signal per leaf in the emitting task's subtree. The source
+        // sized its completion channel for the full leaf set; collapsing the
+        // entire subtree into a single signal would leave the source waiting
+        // for the rest forever.
+        for _ in 0..upstream_leaf_share.max(1) {
+            upstream.signal_completion(None);
+        }
+    });
+}
+
+/// Forwards completion for tasks that merge many input events into fewer
+/// output events, such as windows and aggregates.
+///
+/// The reverse of [`spawn_fan_in_completion`]: once every output event has
+/// reported in on `per_event_receivers`, each merged input event's upstream
+/// completion receives `upstream_leaf_share` signals. A leaf task passes no
+/// receivers, so the inputs are signalled right away. As in the fan-in,
+/// missing per-event signals count as success.
+pub fn spawn_merged_completion(
+    upstreams: Vec<SharedCompletionTx>,
+    per_event_receivers: Vec<CompletionRx>,
+    upstream_leaf_share: usize,
+) {
+    if upstreams.is_empty() {
+        return;
+    }
+
+    tokio::spawn(async move {
+        for rx in per_event_receivers {
+            let _ = rx.await;
+        }
+        for upstream in upstreams {
+            for _ in 0..upstream_leaf_share.max(1) {
+                upstream.signal_completion(None);
+            }
+        }
+    });
+}
+
 tokio::task_local! {
     /// Task-local storage for the current event context.
     /// Used by EventBuilder::new() to automatically preserve meta fields from the incoming event.
     /// Unlike thread_local!, this stays with the tokio task even when it migrates between threads.
     static CURRENT_EVENT_META: RefCell<Option<Map<String, Value>>>;
+
+    /// Task-local storage for the trace span of the current event.
+    /// Used by EventBuilder::new() so derived events stay in the trace started by the source.
+    static CURRENT_EVENT_SPAN: Option<tracing::Span>;
+}
+
+/// Attaches the current span (usually `task.handle`) to the trace of the given event.
+///
+/// Handlers wrapped in [`with_event_context`] are linked automatically. Without
+/// an OpenTelemetry layer installed this is a no-op.
+pub fn continue_trace(event: &Event) {
+    if let Some(span) = &event.span {
+        tracing::Span::current().set_parent(span.context());
+    }
 }
 
 /// Runs an async function with event context set for automatic meta preservation.
 /// Use this to wrap handler functions that should preserve event meta in EventBuilder::new() calls.
+/// The current span is also linked to the event's trace via [`continue_trace`].
 ///
 /// Takes an Arc<Event> to avoid cloning the event data, only the Arc pointer is cloned inside.
 ///
@@ -137,8 +248,12 @@ pub async fn with_event_context<F, R>(event: &Arc<Event>, f: F) -> R
 where
     F: std::future::Future<Output = R>,
 {
+    continue_trace(event);
     CURRENT_EVENT_META
-        .scope(RefCell::new(event.meta.clone()), f)
+        .scope(
+            RefCell::new(event.meta.clone()),
+            CURRENT_EVENT_SPAN.scope(event.span.clone(), f),
+        )
         .await
 }
 
@@ -253,7 +368,29 @@ impl EventExt for Event {
     }
 }
 
+/// Meta key holding the task type of the source that created the event chain.
+pub const META_SOURCE: &str = "_source";
+/// Meta key holding the RFC 3339 time the source received the event.
+pub const META_RECEIVED_AT: &str = "_received_at";
+/// Meta key holding the ID of the schema the source payload was encoded with.
+pub const META_SCHEMA_ID: &str = "_schema_id";
+/// Meta key holding the zero-based position of a chunk emitted by the split task.
+pub const META_CHUNK_INDEX: &str = "_chunk_index";
+
 impl Event {
+    /// Returns the meta value stored under `key`, if any.
+    pub fn get_meta(&self, key: &str) -> Option<&Value> {
+        self.meta.as_ref().and_then(|meta| meta.get(key))
+    }
+
+    /// Stores `value` under `key` in the event meta, creating the map if needed.
+    pub fn set_meta(&mut self, key: &str, value: Value) -> &mut Self {
+        self.meta
+            .get_or_insert_with(Map::new)
+            .insert(key.to_string(), value);
+        self
+    }
+
     /// Converts event data to a serde_json::Value for processing.
     ///
     /// This is a convenience method that extracts the raw data payload from EventData
@@ -269,10 +406,219 @@ impl Event {
         Value::try_from(&self.data)
     }
 
+    /// Returns the approximate payload size of the event data in bytes.
+    ///
+    /// Arrow batches report their in-memory buffer size, Avro its raw bytes,
+    /// and JSON an estimate of its serialized length that is computed without
+    /// serializing the value.
+    pub fn data_size(&self) -> usize {
+        match &self.data {
+            EventData::ArrowRecordBatch(batch) => batch.get_array_memory_size(),
+            EventData::Avro(data) => data.raw_bytes.len(),
+            EventData::Json(value) => estimate_json_size(value),
+        }
+    }
+
+    /// Returns a readable sample of the first `max_rows` rows for debug logging.
+    ///
+    /// Arrow and Avro data are rendered as an ASCII table, JSON arrays are
+    /// truncated to `max_rows` elements and pretty-printed.
+    pub fn display_sample(&self, max_rows: usize) -> String {
+        match &self.data {
+            EventData::ArrowRecordBatch(batch) => {
+                RecordBatchDisplay::new(batch, max_rows).to_string()
+            }
+            EventData::Avro(data) => match data.to_record_batch() {
+                Ok(batch) => RecordBatchDisplay::new(&batch, max_rows).to_string(),
+                Err(e) => format!("<unreadable Avro data: {e}>"),
+            },
+            EventData::Json(Value::Array(rows)) => {
+                let sample = Value::Array(rows.iter().take(max_rows).cloned().collect());
+                let mut output = serde_json::to_string_pretty(&sample).unwrap_or_default();
+                if rows.len() > max_rows {
+                    output.push_str(&format!("\n... {} more rows", rows.len() - max_rows));
+                }
+                output
+            }
+            EventData::Json(value) => serde_json::to_string_pretty(value).unwrap_or_default(),
+        }
+    }
+
     /// Returns true if this event carries an error from a failed upstream task.
     pub fn is_error(&self) -> bool {
         self.error.is_some()
     }
+
+    /// Returns a new event whose record batch holds the columns of this event
+    /// followed by the columns of `other`.
+    ///
+    /// Both events must carry record batches with the same number of rows.
+    /// Column names present on both sides get a `_1` (this event) and `_2`
+    /// (`other`) suffix. The result keeps this event's subject, id, task and
+    /// completion handle; metadata is merged, with this event's keys winning.
+    pub fn merge_columns(&self, other: &Event) -> Result<Event, Error> {
+        let (EventData::ArrowRecordBatch(left), EventData::ArrowRecordBatch(right)) =
+            (&self.data, &other.data)
+        else {
+            return Err(Error::MergeColumnsUnsupported);
+        };
+        if left.num_rows() != right.num_rows() {
+            return Err(Error::MergeColumnsRowMismatch {
+                left: left.num_rows(),
+                right: right.num_rows(),
+            });
+        }
+
+        let left_schema = left.schema();
+        let right_schema = right.schema();
+        let is_duplicate = |name: &str, schema: &arrow::datatypes::Schema| {
+            schema.fields().iter().any(|field| field.name() == name)
+        };
+        let rename = |field: &arrow::datatypes::Field, other: &arrow::datatypes::Schema, suffix| {
+            if is_duplicate(field.name(), other) {
+                field
+                    .clone()
+                    .with_name(format!("{}_{suffix}", field.name()))
+            } else {
+                field.clone()
+            }
+        };
+
+        let fields: Vec<arrow::datatypes::Field> = left_schema
+            .fields()
+            .iter()
+            .map(|field| rename(field, &right_schema, 1))
+            .chain(
+                right_schema
+                    .fields()
+                    .iter()
+                    .map(|field| rename(field, &left_schema, 2)),
+            )
+            .collect();
+        let columns = left
+            .columns()
+            .iter()
+            .chain(right.columns())
+            .cloned()
+            .collect();
+
+        let schema = Arc::new(arrow::datatypes::Schema::new(fields));
+        let batch = arrow::array::RecordBatch::try_new(schema, columns)
+            .map_err(|source| Error::Arrow { source })?;
+
+        let meta = match (&self.meta, &other.meta) {
+            (Some(left), Some(right)) => {
+                let mut meta = right.clone();
+                meta.extend(left.clone());
+                Some(meta)
+            }
+            (left, right) => left.clone().or_else(|| right.clone()),
+        };
+
+        let mut event = self.clone();
+        event.data = EventData::ArrowRecordBatch(batch);
+        event.meta = meta;
+        Ok(event)
+    }
+}
+
+/// Accumulates events until a row or byte limit is reached.
+///
+/// Tasks that write or emit in bulk push incoming events and flush once
+/// [`EventBatch::is_full`] returns true, or on their own schedule as the
+/// window processor does. Rows are counted per record batch row and per JSON
+/// array element; any other event counts as one row. Bytes are the sum of
+/// [`Event::data_size`].
+#[derive(Debug, Default)]
+pub struct EventBatch {
+    /// Buffered events in arrival order.
+    events: Vec<Event>,
+    /// Total number of rows across buffered events.
+    num_rows: usize,
+    /// Approximate total payload size of buffered events in bytes.
+    size_bytes: usize,
+    /// Row limit; zero disables the limit.
+    max_rows: usize,
+    /// Byte limit; zero disables the limit.
+    max_bytes: usize,
+}
+
+impl EventBatch {
+    /// Creates an empty batch with the given limits. A limit of zero is disabled.
+    pub fn new(max_rows: usize, max_bytes: usize) -> Self {
+        Self {
+            max_rows,
+            max_bytes,
+            ..Default::default()
+        }
+    }
+
+    /// Adds an event to the batch.
+    pub fn push(&mut self, event: Event) {
+        self.num_rows += match &event.data {
+            EventData::ArrowRecordBatch(batch) => batch.num_rows(),
+            EventData::Json(Value::Array(rows)) => rows.len(),
+            _ => 1,
+        };
+        self.size_bytes += event.data_size();
+        self.events.push(event);
+    }
+
+    /// Returns true once the batch has reached its row or byte limit.
+    pub fn is_full(&self) -> bool {
+        (self.max_rows > 0 && self.num_rows >= self.max_rows)
+            || (self.max_bytes > 0 && self.size_bytes >= self.max_bytes)
+    }
+
+    /// Returns true if the batch holds no events.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Returns the number of buffered events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns the total number of rows across buffered events.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Returns the approximate payload size of buffered events in bytes.
+    pub fn size_bytes(&self) -> usize {
+        self.size_bytes
+    }
+
+    /// Removes and returns all buffered events, resetting the counters.
+    pub fn drain(&mut self) -> Vec<Event> {
+        self.num_rows = 0;
+        self.size_bytes = 0;
+        std::mem::take(&mut self.events)
+    }
+
+    /// Concatenates the record batches of all buffered events into one.
+    ///
+    /// Fails if the batch is empty, holds non-Arrow events, or the record
+    /// batches do not share the same schema.
+    pub fn merge_to_record_batch(
+        &self,
+    ) -> Result<arrow::array::RecordBatch, arrow::error::ArrowError> {
+        let batches = self
+            .events
+            .iter()
+            .map(|event| match &event.data {
+                EventData::ArrowRecordBatch(batch) => Ok(batch),
+                _ => Err(arrow::error::ArrowError::InvalidArgumentError(
+                    "Event batch contains non-Arrow event data".to_string(),
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let schema = batches.first().map(|batch| batch.schema()).ok_or_else(|| {
+            arrow::error::ArrowError::InvalidArgumentError("Event batch is empty".to_string())
+        })?;
+        arrow::compute::concat_batches(&schema, batches)
+    }
 }
 
 /// Errors that can occur during event processing operations.
@@ -305,6 +651,14 @@ pub enum Error {
     UnsupportedContentTypeConversion { from: String, to: String },
     #[error("Error sending event to channel (receiver dropped)")]
     SendMessage,
+    #[error("Record batch has no rows; call allow_empty(true) to send empty batches")]
+    EmptyBatch,
+    #[error("Column merge requires both events to carry Arrow record batches")]
+    MergeColumnsUnsupported,
+    #[error("Cannot merge columns of record batches with {left} and {right} rows")]
+    MergeColumnsRowMismatch { left: usize, right: usize },
+    #[error("Avro schema cannot be converted to Arrow: {}", _0)]
+    UnsupportedAvroSchema(String),
 }
 
 /// Core event structure containing data and metadata for workflow processing.
@@ -337,6 +691,10 @@ pub struct Event {
     /// Leaf tasks call `signal_completion` on the shared state; the source is
     /// notified once every leaf has signalled.
     pub completion_tx: Option<SharedCompletionTx>,
+    /// Root `flow_step` span of the trace this event belongs to.
+    /// Created when a source builds the event and carried by every derived event,
+    /// so each task's `task.handle` span joins the same end-to-end trace.
+    pub span: Option<tracing::Span>,
 }
 
 impl Clone for Event {
@@ -351,6 +709,7 @@ impl Clone for Event {
             meta: self.meta.clone(),
             error: self.error.clone(),
             completion_tx: self.completion_tx.clone(),
+            span: self.span.clone(),
         }
     }
 }
@@ -374,6 +733,41 @@ impl TryFrom<&Event> for Value {
     }
 }
 
+/// Displays the first rows of a record batch as an ASCII table for debug logging.
+///
+/// Rows beyond `max_rows` are left out and summarized by a count, so large
+/// batches stay readable in logs.
+pub struct RecordBatchDisplay<'a> {
+    /// Batch to display.
+    batch: &'a arrow::array::RecordBatch,
+    /// Maximum number of rows rendered.
+    max_rows: usize,
+}
+
+impl<'a> RecordBatchDisplay<'a> {
+    /// Creates a display of at most `max_rows` rows of `batch`.
+    pub fn new(batch: &'a arrow::array::RecordBatch, max_rows: usize) -> Self {
+        Self { batch, max_rows }
+    }
+}
+
+impl std::fmt::Display for RecordBatchDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = self.batch.num_rows().min(self.max_rows);
+        match arrow::util::pretty::pretty_format_batches(&[self.batch.slice(0, rows)]) {
+            Ok(table) => write!(f, "{table}")?,
+            // Formatting must not fail, so report unprintable data inline.
+            Err(e) => write!(f, "<unprintable record batch: {e}>")?,
+        }
+
+        let remaining = self.batch.num_rows() - rows;
+        if remaining > 0 {
+            write!(f, "\n... {remaining} more rows")?;
+        }
+        Ok(())
+    }
+}
+
 impl std::fmt::Display for Event {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let event_data = serde_json::Value::try_from(&self.data)
@@ -417,6 +811,120 @@ pub struct AvroData {
     pub raw_bytes: Vec<u8>,
 }
 
+impl AvroData {
+    /// Decodes the payload into a single-row Arrow record batch.
+    ///
+    /// The Arrow schema is derived from the Avro record schema, so every event
+    /// of a topic yields the same columns. Nullable unions (`["null", T]`) map
+    /// to nullable fields.
+    pub fn to_record_batch(&self) -> Result<arrow::array::RecordBatch, Error> {
+        let schema =
+            apache_avro::Schema::parse_str(&self.schema).map_err(|e| Error::Avro { source: e })?;
+        let apache_avro::Schema::Record(record) = &schema else {
+            return Err(Error::UnsupportedAvroSchema(
+                "top-level schema must be a record".to_string(),
+            ));
+        };
+        let fields = record
+            .fields
+            .iter()
+            .map(|field| avro_to_arrow_field(&field.name, &field.schema))
+            .collect::<Result<Vec<_>, _>>()?;
+        let arrow_schema = Arc::new(arrow::datatypes::Schema::new(fields));
+
+        let avro_value = from_avro_datum(&schema, &mut &self.raw_bytes[..], None)
+            .map_err(|e| Error::Avro { source: e })?;
+        let value = Value::try_from(avro_value).map_err(|e| Error::Avro { source: e })?;
+
+        let mut decoder = arrow_json::ReaderBuilder::new(Arc::clone(&arrow_schema))
+            .build_decoder()
+            .map_err(|e| Error::Arrow { source: e })?;
+        decoder
+            .serialize(&[value])
+            .map_err(|e| Error::Arrow { source: e })?;
+        let batch = decoder.flush().map_err(|e| Error::Arrow { source: e })?;
+        Ok(batch.unwrap_or_else(|| arrow::array::RecordBatch::new_empty(arrow_schema)))
+    }
+}
+
+/// Maps an Avro field schema to an Arrow field.
+fn avro_to_arrow_field(
+    name: &str,
+    schema: &apache_avro::Schema,
+) -> Result<arrow::datatypes::Field, Error> {
+    use apache_avro::Schema as Avro;
+    use arrow::datatypes::{DataType, Field, Fields, TimeUnit};
+
+    let utc = || Some(Arc::from("UTC"));
+    let (data_type, nullable) = match schema {
+        Avro::Null => (DataType::Null, true),
+        Avro::Boolean => (DataType::Boolean, false),
+        Avro::Int => (DataType::Int32, false),
+        Avro::Long => (DataType::Int64, false),
+        Avro::Float => (DataType::Float32, false),
+        Avro::Double => (DataType::Float64, false),
+        Avro::String | Avro::Uuid | Avro::Enum(_) => (DataType::Utf8, false),
+        Avro::Date => (DataType::Date32, false),
+        Avro::TimeMillis => (DataType::Time32(TimeUnit::Millisecond), false),
+        Avro::TimeMicros => (DataType::Time64(TimeUnit::Microsecond), false),
+        Avro::TimestampMillis => (DataType::Timestamp(TimeUnit::Millisecond, utc()), false),
+        Avro::TimestampMicros => (DataType::Timestamp(TimeUnit::Microsecond, utc()), false),
+        Avro::TimestampNanos => (DataType::Timestamp(TimeUnit::Nanosecond, utc()), false),
+        Avro::LocalTimestampMillis => (DataType::Timestamp(TimeUnit::Millisecond, None), false),
+        Avro::LocalTimestampMicros => (DataType::Timestamp(TimeUnit::Microsecond, None), false),
+        Avro::LocalTimestampNanos => (DataType::Timestamp(TimeUnit::Nanosecond, None), false),
+        Avro::Array(array) => {
+            let item = avro_to_arrow_field("item", &array.items)?;
+            (DataType::List(Arc::new(item)), false)
+        }
+        Avro::Map(map) => {
+            let value = avro_to_arrow_field("values", &map.types)?;
+            let entries = Field::new(
+                "entries",
+                DataType::Struct(Fields::from(vec![
+                    Field::new("keys", DataType::Utf8, false),
+                    value,
+                ])),
+                false,
+            );
+            (DataType::Map(Arc::new(entries), false), false)
+        }
+        Avro::Record(record) => {
+            let fields = record
+                .fields
+                .iter()
+                .map(|field| avro_to_arrow_field(&field.name, &field.schema))
+                .collect::<Result<Vec<_>, _>>()?;
+            (DataType::Struct(Fields::from(fields)), false)
+        }
+        Avro::Union(union) => {
+            let mut variants = union
+                .variants()
+                .iter()
+                .filter(|variant| !matches!(variant, Avro::Null));
+            match (variants.next(), variants.next()) {
+                (Some(variant), None) => {
+                    let field = avro_to_arrow_field(name, variant)?;
+                    let nullable = union.is_nullable() || field.is_nullable();
+                    return Ok(field.with_nullable(nullable));
+                }
+                _ => {
+                    return Err(Error::UnsupportedAvroSchema(format!(
+                        "field '{name}' is a union of several non-null types"
+                    )))
+                }
+            }
+        }
+        other => {
+            return Err(Error::UnsupportedAvroSchema(format!(
+                "field '{name}' has unsupported type {:?}",
+                apache_avro::schema::SchemaKind::from(other)
+            )))
+        }
+    };
+    Ok(Field::new(name, data_type, nullable))
+}
+
 impl TryFrom<&EventData> for Value {
     type Error = Error;
 
@@ -463,8 +971,6 @@ impl Serialize for EventData {
 pub struct EventBuilder {
     /// Event data payload (required for build).
     pub data: Option<EventData>,
-    /// Arrow record batch extensions for metadata.
-    pub extensions: Option<arrow::array::RecordBatch>,
     /// Event subject for routing (required for build).
     pub subject: Option<String>,
     /// Optional unique event identifier.
@@ -479,11 +985,17 @@ pub struct EventBuilder {
     pub meta: Option<Map<String, Value>>,
     /// Completion notifier for end-to-end acknowledgment.
     pub completion_tx: Option<SharedCompletionTx>,
+    /// Whether a record batch with zero rows is accepted as event data.
+    pub allow_empty: bool,
+    /// Trace span inherited from the current event context.
+    /// A new root span is created on build when none is set.
+    pub span: Option<tracing::Span>,
 }
 
 impl EventBuilder {
     /// Creates a new EventBuilder.
-    /// Automatically preserves meta from the current event context (if set via with_event_context).
+    /// Automatically preserves meta and the trace span from the current event context
+    /// (if set via with_event_context).
     /// Generates a correlation_id in meta if one is not already present.
     pub fn new() -> Self {
         let mut meta = CURRENT_EVENT_META
@@ -503,9 +1015,12 @@ impl EventBuilder {
                 .insert("correlation_id".to_string(), Value::String(correlation_id));
         }
 
+        let span = CURRENT_EVENT_SPAN.try_with(|s| s.clone()).ok().flatten();
+
         EventBuilder {
             timestamp: Some(Utc::now().timestamp_micros()),
             meta,
+            span,
             ..Default::default()
         }
     }
@@ -544,27 +1059,73 @@ impl EventBuilder {
         self
     }
 
+    /// Accepts record batches with zero rows.
+    ///
+    /// Empty batches are rejected by default so downstream tasks do not receive
+    /// them by accident. Tasks that send an empty batch on purpose, for example
+    /// to signal that a query returned no rows, opt in here.
+    pub fn allow_empty(mut self, allow_empty: bool) -> Self {
+        self.allow_empty = allow_empty;
+        self
+    }
+
     pub fn build(self) -> Result<Event, Error> {
+        if let Some(EventData::ArrowRecordBatch(batch)) = &self.data {
+            if batch.num_rows() == 0 && !self.allow_empty {
+                return Err(Error::EmptyBatch);
+            }
+        }
+
+        let data = self
+            .data
+            .ok_or_else(|| Error::MissingBuilderAttribute("data".to_string()))?;
+        let subject = self
+            .subject
+            .ok_or_else(|| Error::MissingBuilderAttribute("subject".to_string()))?;
+        let task_type = self
+            .task_type
+            .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?;
+
+        // Events built outside an event context come from a source and start a
+        // new trace. The span closes once the last event referencing it is dropped.
+        let span = self.span.unwrap_or_else(|| {
+            tracing::info_span!(
+                parent: None,
+                "flow_step",
+                source = task_type,
+                event_id = self.id.as_deref().unwrap_or_default(),
+                event.subject = %subject,
+            )
+        });
+
+        let timestamp = self
+            .timestamp
+            .ok_or_else(|| Error::MissingBuilderAttribute("timestamp".to_string()))?;
+
+        // Stamp where and when the event chain started. Downstream events
+        // inherit both keys through the event context and keep them.
+        let mut meta = self.meta.unwrap_or_default();
+        meta.entry(META_SOURCE)
+            .or_insert_with(|| Value::String(task_type.to_string()));
+        meta.entry(META_RECEIVED_AT).or_insert_with(|| {
+            chrono::DateTime::from_timestamp_micros(timestamp).map_or(Value::Null, |received_at| {
+                Value::String(received_at.to_rfc3339())
+            })
+        });
+
         Ok(Event {
-            data: self
-                .data
-                .ok_or_else(|| Error::MissingBuilderAttribute("data".to_string()))?,
-            subject: self
-                .subject
-                .ok_or_else(|| Error::MissingBuilderAttribute("subject".to_string()))?,
+            data,
+            subject,
             id: self.id,
-            timestamp: self
-                .timestamp
-                .ok_or_else(|| Error::MissingBuilderAttribute("timestamp".to_string()))?,
+            timestamp,
             task_id: self
                 .task_id
                 .ok_or_else(|| Error::MissingBuilderAttribute("task_id".to_string()))?,
-            task_type: self
-                .task_type
-                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
-            meta: self.meta,
+            task_type,
+            meta: Some(meta),
             error: None,
             completion_tx: self.completion_tx,
+            span: Some(span),
         })
     }
 }
@@ -596,6 +1157,7 @@ impl<R: Read + Seek + Send + 'static> FromReader<R> for EventData {
                     .with_delimiter(delimiter_byte)
                     .infer_schema(&mut reader, infer_schema_max_records)
                     .map_err(|e| Error::Arrow { source: e })?;
+                debug!(schema = %schema, "Inferred CSV schema");
                 reader.rewind().map_err(|e| Error::IO { source: e })?;
 
                 let csv_reader = arrow::csv::ReaderBuilder::new(Arc::new(schema))
@@ -651,6 +1213,19 @@ impl<R: Read + Seek + Send + 'static> FromReader<R> for EventData {
                     }))
                 })))
             }
+
+            ContentType::Compressed { inner, codec } => {
+                let mut buffer = Vec::new();
+                match codec {
+                    CompressionCodec::Gzip => flate2::read::MultiGzDecoder::new(reader)
+                        .read_to_end(&mut buffer)
+                        .map_err(|e| Error::IO { source: e })?,
+                };
+                <EventData as FromReader<std::io::Cursor<Vec<u8>>>>::from_reader(
+                    std::io::Cursor::new(buffer),
+                    *inner,
+                )
+            }
         }
     }
 }
@@ -690,12 +1265,155 @@ impl<W: Write> ToWriter<W> for EventData {
     }
 }
 
+/// Differences between two Arrow schemas, keyed by field name.
+///
+/// Produced by [`diff_schemas`] to turn a generic schema mismatch into an
+/// actionable message listing exactly which fields differ.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SchemaDiff {
+    /// Fields present in the expected schema but missing from the current one.
+    pub added: Vec<arrow::datatypes::Field>,
+    /// Fields present in the current schema but missing from the expected one.
+    pub removed: Vec<arrow::datatypes::Field>,
+    /// Fields present in both schemas with different data types, as `(current, expected)`.
+    pub type_changed: Vec<(arrow::datatypes::Field, arrow::datatypes::Field)>,
+}
+
+impl SchemaDiff {
+    /// Returns true if both schemas have the same fields with the same data types.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.type_changed.is_empty()
+    }
+}
+
+impl std::fmt::Display for SchemaDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "schemas are identical");
+        }
+
+        let mut parts = Vec::new();
+        if !self.added.is_empty() {
+            let fields: Vec<String> = self
+                .added
+                .iter()
+                .map(|field| format!("{} ({})", field.name(), field.data_type()))
+                .collect();
+            parts.push(format!("added: [{}]", fields.join(", ")));
+        }
+        if !self.removed.is_empty() {
+            let fields: Vec<String> = self
+                .removed
+                .iter()
+                .map(|field| format!("{} ({})", field.name(), field.data_type()))
+                .collect();
+            parts.push(format!("removed: [{}]", fields.join(", ")));
+        }
+        if !self.type_changed.is_empty() {
+            let fields: Vec<String> = self
+                .type_changed
+                .iter()
+                .map(|(current, expected)| {
+                    format!(
+                        "{} ({} -> {})",
+                        current.name(),
+                        current.data_type(),
+                        expected.data_type()
+                    )
+                })
+                .collect();
+            parts.push(format!("type changed: [{}]", fields.join(", ")));
+        }
+        write!(f, "{}", parts.join("; "))
+    }
+}
+
+/// Compares two Arrow schemas and reports added, removed and retyped fields.
+///
+/// Field names are matched case-insensitively. Field order and nullability are
+/// ignored; only presence and data type are compared. Results are listed in the
+/// field order of the schema they originate from.
+pub fn diff_schemas(
+    current: &arrow::datatypes::Schema,
+    expected: &arrow::datatypes::Schema,
+) -> SchemaDiff {
+    let find = |schema: &arrow::datatypes::Schema, name: &str| {
+        schema
+            .fields()
+            .iter()
+            .find(|field| field.name().eq_ignore_ascii_case(name))
+            .cloned()
+    };
+
+    let mut diff = SchemaDiff::default();
+
+    for current_field in current.fields() {
+        match find(expected, current_field.name()) {
+            Some(expected_field) => {
+                if current_field.data_type() != expected_field.data_type() {
+                    diff.type_changed.push((
+                        current_field.as_ref().clone(),
+                        expected_field.as_ref().clone(),
+                    ));
+                }
+            }
+            None => diff.removed.push(current_field.as_ref().clone()),
+        }
+    }
+
+    for expected_field in expected.fields() {
+        if find(current, expected_field.name()).is_none() {
+            diff.added.push(expected_field.as_ref().clone());
+        }
+    }
+
+    diff
+}
+
+/// Estimates the compact serialized length of a JSON value in bytes.
+///
+/// String escapes are not counted and numbers are assumed to take 8 bytes,
+/// which is close enough for metrics.
+fn estimate_json_size(value: &Value) -> usize {
+    match value {
+        Value::Null | Value::Bool(true) => 4,
+        Value::Bool(false) => 5,
+        Value::Number(_) => 8,
+        Value::String(s) => s.len() + 2,
+        Value::Array(items) => {
+            2 + items.len().saturating_sub(1) + items.iter().map(estimate_json_size).sum::<usize>()
+        }
+        Value::Object(map) => {
+            2 + map.len().saturating_sub(1)
+                + map
+                    .iter()
+                    .map(|(key, value)| key.len() + 3 + estimate_json_size(value))
+                    .sum::<usize>()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use serde_json::json;
     use std::io::Cursor;
 
+    #[test]
+    fn test_data_size_json_estimate() {
+        let value = json!({"id": 1, "name": "abc", "tags": ["x", "y"], "ok": true});
+        let event = EventBuilder::new()
+            .data(EventData::Json(value.clone()))
+            .subject("test".to_string())
+            .task_type("test")
+            .build()
+            .unwrap();
+
+        // `1` is counted as 8 bytes instead of 1, everything else is exact.
+        let serialized = serde_json::to_vec(&value).unwrap().len();
+        assert_eq!(event.data_size(), serialized + 7);
+    }
+
     #[test]
     fn test_event_builder_success() {
         let event = EventBuilder::new()
@@ -718,6 +1436,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_event_builder_stamps_source_meta() {
+        let mut event = EventBuilder::new()
+            .data(EventData::Json(json!({"test": "value"})))
+            .subject("test.subject".to_string())
+            .task_id(0)
+            .task_type("nats_jetstream_subscriber")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            event.get_meta(META_SOURCE),
+            Some(&json!("nats_jetstream_subscriber"))
+        );
+        assert!(event
+            .get_meta(META_RECEIVED_AT)
+            .and_then(Value::as_str)
+            .is_some_and(|received_at| chrono::DateTime::parse_from_rfc3339(received_at).is_ok()));
+
+        event.set_meta(META_SCHEMA_ID, json!("schema-1"));
+        assert_eq!(event.get_meta(META_SCHEMA_ID), Some(&json!("schema-1")));
+    }
+
+    #[tokio::test]
+    async fn test_event_builder_keeps_inherited_source_meta() {
+        let source = Arc::new(
+            EventBuilder::new()
+                .data(EventData::Json(json!({"test": "value"})))
+                .subject("test.subject".to_string())
+                .task_id(0)
+                .task_type("source")
+                .build()
+                .unwrap(),
+        );
+
+        let derived = with_event_context(&source, async {
+            EventBuilder::new()
+                .data(EventData::Json(json!({"test": "derived"})))
+                .subject("test.derived".to_string())
+                .task_id(1)
+                .task_type("processor")
+                .build()
+                .unwrap()
+        })
+        .await;
+
+        assert_eq!(derived.get_meta(META_SOURCE), Some(&json!("source")));
+        assert_eq!(
+            derived.get_meta(META_RECEIVED_AT),
+            source.get_meta(META_RECEIVED_AT)
+        );
+    }
+
     #[test]
     fn test_event_builder_missing_data() {
         let result = EventBuilder::new()
@@ -742,6 +1513,213 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_event_builder_rejects_empty_batch() {
+        let empty_batch =
+            arrow::array::RecordBatch::new_empty(Arc::new(arrow::datatypes::Schema::empty()));
+        let builder = || {
+            EventBuilder::new()
+                .data(EventData::ArrowRecordBatch(empty_batch.clone()))
+                .subject("test.subject".to_string())
+                .task_id(1)
+                .task_type("test")
+        };
+
+        assert!(matches!(builder().build(), Err(Error::EmptyBatch)));
+
+        let event = builder().allow_empty(true).build().unwrap();
+        assert!(matches!(event.data, EventData::ArrowRecordBatch(batch) if batch.num_rows() == 0));
+    }
+
+    #[tokio::test]
+    async fn test_derived_events_share_source_span() {
+        let _guard = tracing::subscriber::set_default(tracing_subscriber::registry());
+
+        let source = Arc::new(
+            EventBuilder::new()
+                .data(EventData::Json(json!({"id": 1})))
+                .subject("source".to_string())
+                .task_id(0)
+                .task_type("test")
+                .build()
+                .unwrap(),
+        );
+        let source_span_id = source.span.as_ref().and_then(|s| s.id());
+        assert!(source_span_id.is_some());
+
+        let derived = with_event_context(&Arc::clone(&source), async {
+            EventBuilder::new()
+                .data(EventData::Json(json!({"id": 2})))
+                .subject("derived".to_string())
+                .task_id(1)
+                .task_type("test")
+                .build()
+                .unwrap()
+        })
+        .await;
+        assert_eq!(derived.span.as_ref().and_then(|s| s.id()), source_span_id);
+    }
+
+    fn batch_event(ids: Vec<i64>) -> Event {
+        let schema = Arc::new(arrow::datatypes::Schema::new(vec![
+            arrow::datatypes::Field::new("id", arrow::datatypes::DataType::Int64, false),
+        ]));
+        let batch = arrow::array::RecordBatch::try_new(
+            schema,
+            vec![Arc::new(arrow::array::Int64Array::from(ids))],
+        )
+        .unwrap();
+        EventBuilder::new()
+            .data(EventData::ArrowRecordBatch(batch))
+            .subject("test".to_string())
+            .task_id(0)
+            .task_type("test")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_event_batch_is_full_on_rows() {
+        let mut batch = EventBatch::new(3, 0);
+        batch.push(batch_event(vec![1, 2]));
+        assert!(!batch.is_full());
+
+        batch.push(batch_event(vec![3]));
+        assert!(batch.is_full());
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch.num_rows(), 3);
+
+        let drained = batch.drain();
+        assert_eq!(drained.len(), 2);
+        assert!(batch.is_empty());
+        assert_eq!(batch.num_rows(), 0);
+        assert_eq!(batch.size_bytes(), 0);
+    }
+
+    #[test]
+    fn test_event_batch_is_full_on_bytes() {
+        let mut batch = EventBatch::new(0, 1);
+        assert!(!batch.is_full());
+        batch.push(batch_event(vec![1]));
+        assert!(batch.is_full());
+    }
+
+    #[test]
+    fn test_event_batch_merge_to_record_batch() {
+        let mut batch = EventBatch::new(100, 0);
+        assert!(batch.merge_to_record_batch().is_err());
+
+        batch.push(batch_event(vec![1, 2]));
+        batch.push(batch_event(vec![3]));
+        let merged = batch.merge_to_record_batch().unwrap();
+        assert_eq!(merged.num_rows(), 3);
+
+        batch.push(
+            EventBuilder::new()
+                .data(EventData::Json(json!({"id": 4})))
+                .subject("test".to_string())
+                .task_id(0)
+                .task_type("test")
+                .build()
+                .unwrap(),
+        );
+        assert!(batch.merge_to_record_batch().is_err());
+    }
+
+    fn named_batch_event(columns: Vec<(&str, Vec<i64>)>) -> Event {
+        let fields = columns
+            .iter()
+            .map(|(name, _)| {
+                arrow::datatypes::Field::new(*name, arrow::datatypes::DataType::Int64, false)
+            })
+            .collect::<Vec<_>>();
+        let arrays = columns
+            .into_iter()
+            .map(|(_, values)| {
+                Arc::new(arrow::array::Int64Array::from(values)) as arrow::array::ArrayRef
+            })
+            .collect();
+        let batch = arrow::array::RecordBatch::try_new(
+            Arc::new(arrow::datatypes::Schema::new(fields)),
+            arrays,
+        )
+        .unwrap();
+        EventBuilder::new()
+            .data(EventData::ArrowRecordBatch(batch))
+            .subject("left".to_string())
+            .id("left-id".to_string())
+            .task_id(3)
+            .task_type("test")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_merge_columns() {
+        let mut left = named_batch_event(vec![("id", vec![1, 2]), ("amount", vec![10, 20])]);
+        left.meta = Some(Map::from_iter([
+            ("source".to_string(), json!("left")),
+            ("region".to_string(), json!("eu")),
+        ]));
+        let mut right = named_batch_event(vec![("id", vec![1, 2]), ("score", vec![7, 8])]);
+        right.subject = "right".to_string();
+        right.task_id = 5;
+        right.meta = Some(Map::from_iter([
+            ("source".to_string(), json!("right")),
+            ("lookup".to_string(), json!(true)),
+        ]));
+
+        let merged = left.merge_columns(&right).unwrap();
+
+        assert_eq!(merged.subject, "left");
+        assert_eq!(merged.id, Some("left-id".to_string()));
+        assert_eq!(merged.task_id, 3);
+        let meta = merged.meta.as_ref().unwrap();
+        assert_eq!(meta["source"], json!("left"));
+        assert_eq!(meta["region"], json!("eu"));
+        assert_eq!(meta["lookup"], json!(true));
+
+        let EventData::ArrowRecordBatch(batch) = &merged.data else {
+            panic!("expected record batch");
+        };
+        let names: Vec<&str> = batch
+            .schema_ref()
+            .fields()
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect();
+        assert_eq!(names, vec!["id_1", "amount", "id_2", "score"]);
+        assert_eq!(batch.num_rows(), 2);
+    }
+
+    #[test]
+    fn test_merge_columns_row_mismatch() {
+        let left = named_batch_event(vec![("id", vec![1, 2])]);
+        let right = named_batch_event(vec![("score", vec![7])]);
+
+        assert!(matches!(
+            left.merge_columns(&right),
+            Err(Error::MergeColumnsRowMismatch { left: 2, right: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_merge_columns_requires_record_batches() {
+        let left = named_batch_event(vec![("id", vec![1])]);
+        let right = EventBuilder::new()
+            .data(EventData::Json(json!({"id": 1})))
+            .subject("right".to_string())
+            .task_id(0)
+            .task_type("test")
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            left.merge_columns(&right),
+            Err(Error::MergeColumnsUnsupported)
+        ));
+    }
+
     #[test]
     fn test_avro_data_serialization() {
         let avro_data = AvroData {
@@ -756,6 +1734,131 @@ mod tests {
         assert_eq!(avro_data.raw_bytes, deserialized.raw_bytes);
     }
 
+    #[test]
+    fn test_avro_data_to_record_batch() {
+        use arrow::array::{Array, AsArray};
+        use arrow::datatypes::{DataType, Float64Type, Int64Type};
+
+        let schema_json = r#"{
+            "type": "record",
+            "name": "Order__e",
+            "fields": [
+                {"name": "Id", "type": "string"},
+                {"name": "CreatedDate", "type": "long"},
+                {"name": "Amount__c", "type": ["null", "double"], "default": null},
+                {"name": "Tags__c", "type": {"type": "array", "items": "string"}}
+            ]
+        }"#;
+        let schema = apache_avro::Schema::parse_str(schema_json).unwrap();
+        let value = apache_avro::types::Value::Record(vec![
+            ("Id".into(), apache_avro::types::Value::String("a01".into())),
+            (
+                "CreatedDate".into(),
+                apache_avro::types::Value::Long(1_700_000_000_000),
+            ),
+            (
+                "Amount__c".into(),
+                apache_avro::types::Value::Union(0, Box::new(apache_avro::types::Value::Null)),
+            ),
+            (
+                "Tags__c".into(),
+                apache_avro::types::Value::Array(vec![apache_avro::types::Value::String(
+                    "vip".into(),
+                )]),
+            ),
+        ]);
+        let avro_data = AvroData {
+            schema: schema_json.to_string(),
+            raw_bytes: apache_avro::to_avro_datum(&schema, value).unwrap(),
+        };
+
+        let batch = avro_data.to_record_batch().unwrap();
+
+        assert_eq!(batch.num_rows(), 1);
+        let arrow_schema = batch.schema();
+        assert_eq!(arrow_schema.field(0).data_type(), &DataType::Utf8);
+        assert!(!arrow_schema.field(0).is_nullable());
+        assert!(arrow_schema.field(2).is_nullable());
+        assert_eq!(batch.column(0).as_string::<i32>().value(0), "a01");
+        assert_eq!(
+            batch.column(1).as_primitive::<Int64Type>().value(0),
+            1_700_000_000_000
+        );
+        assert!(batch.column(2).as_primitive::<Float64Type>().is_null(0));
+        assert_eq!(
+            batch
+                .column(3)
+                .as_list::<i32>()
+                .value(0)
+                .as_string::<i32>()
+                .value(0),
+            "vip"
+        );
+    }
+
+    #[test]
+    fn test_avro_data_to_record_batch_requires_record() {
+        let avro_data = AvroData {
+            schema: r#"{"type": "string"}"#.to_string(),
+            raw_bytes: vec![],
+        };
+
+        assert!(matches!(
+            avro_data.to_record_batch(),
+            Err(Error::UnsupportedAvroSchema(_))
+        ));
+    }
+
+    #[test]
+    fn test_record_batch_display() {
+        use arrow::array::{Int64Array, StringArray};
+        use arrow::datatypes::{DataType, Field, Schema};
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, false),
+        ]));
+        let batch = arrow::array::RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec!["a", "b", "c"])),
+            ],
+        )
+        .unwrap();
+
+        let output = RecordBatchDisplay::new(&batch, 2).to_string();
+        assert_eq!(
+            output,
+            "+----+------+\n\
+             | id | name |\n\
+             +----+------+\n\
+             | 1  | a    |\n\
+             | 2  | b    |\n\
+             +----+------+\n\
+             ... 1 more rows"
+        );
+        assert!(!RecordBatchDisplay::new(&batch, 5)
+            .to_string()
+            .contains("more rows"));
+    }
+
+    #[test]
+    fn test_display_sample_json() {
+        let event = EventBuilder::new()
+            .data(EventData::Json(json!([{"id": 1}, {"id": 2}, {"id": 3}])))
+            .subject("test".to_string())
+            .task_id(0)
+            .task_type("test")
+            .build()
+            .unwrap();
+
+        let sample = event.display_sample(1);
+        assert!(sample.contains("\"id\": 1"));
+        assert!(!sample.contains("\"id\": 2"));
+        assert!(sample.ends_with("... 2 more rows"));
+    }
+
     #[test]
     fn test_event_data_json_conversion() {
         let json_data = json!({"field": "value", "number": 42});
@@ -798,6 +1901,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_event_data_from_gzip_reader() {
+        use std::io::Write as _;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(br#"{"key": "value"}"#).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let content_type = ContentType::Compressed {
+            inner: Box::new(ContentType::Json),
+            codec: CompressionCodec::Gzip,
+        };
+        let events: Vec<EventData> = EventData::from_reader(Cursor::new(compressed), content_type)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(&events[0], EventData::Json(v) if v == &json!({"key": "value"})));
+    }
+
     #[test]
     fn test_event_data_parquet_roundtrip() {
         use arrow::array::{Int32Array, StringArray};
@@ -843,4 +1967,55 @@ mod tests {
             _ => panic!("Expected Arrow RecordBatch"),
         }
     }
+
+    #[test]
+    fn test_diff_schemas_identical() {
+        use arrow::datatypes::{DataType, Field, Schema};
+
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+
+        let diff = diff_schemas(&schema, &schema);
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "schemas are identical");
+    }
+
+    #[test]
+    fn test_diff_schemas_added_removed_and_type_changed() {
+        use arrow::datatypes::{DataType, Field, Schema};
+
+        let current = Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("legacy", DataType::Utf8, true),
+        ]);
+        let expected = Schema::new(vec![
+            Field::new("ID", DataType::Int64, false),
+            Field::new("email", DataType::Utf8, true),
+        ]);
+
+        let diff = diff_schemas(&current, &expected);
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].name(), "email");
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].name(), "legacy");
+        assert_eq!(diff.type_changed.len(), 1);
+        assert_eq!(diff.type_changed[0].0.data_type(), &DataType::Int32);
+        assert_eq!(diff.type_changed[0].1.data_type(), &DataType::Int64);
+        assert_eq!(
+            diff.to_string(),
+            "added: [email (Utf8)]; removed: [legacy (Utf8)]; type changed: [id (Int32 -> Int64)]"
+        );
+    }
+
+    #[test]
+    fn test_diff_schemas_case_insensitive_match() {
+        use arrow::datatypes::{DataType, Field, Schema};
+
+        let current = Schema::new(vec![Field::new("Name", DataType::Utf8, true)]);
+        let expected = Schema::new(vec![Field::new("name", DataType::Utf8, false)]);
+
+        assert!(diff_schemas(&current, &expected).is_empty());
+    }
 }
@@ -0,0 +1,139 @@
+//! Typed event filters for receiving only matching events from a channel.
+//!
+//! An [`EventFilter`] is a plain Rust predicate over an [`Event`]. Wrapping a
+//! task's receiver in a [`FilteredReceiver`] skips non-matching events
+//! without an extra processor task in the flow.
+
+use super::Event;
+use tokio::sync::mpsc::Receiver;
+
+/// Predicate deciding whether an event is passed on.
+pub trait EventFilter: Send + Sync {
+    /// Returns true if the event should be received.
+    fn matches(&self, event: &Event) -> bool;
+}
+
+/// Passes every event.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlwaysPass;
+
+impl EventFilter for AlwaysPass {
+    fn matches(&self, _event: &Event) -> bool {
+        true
+    }
+}
+
+/// Passes events whose subject starts with the given prefix.
+#[derive(Debug, Clone)]
+pub struct SubjectFilter(pub String);
+
+impl EventFilter for SubjectFilter {
+    fn matches(&self, event: &Event) -> bool {
+        event.subject.starts_with(&self.0)
+    }
+}
+
+/// Passes events emitted by the task with the given ID.
+#[derive(Debug, Clone, Copy)]
+pub struct TaskIdFilter(pub usize);
+
+impl EventFilter for TaskIdFilter {
+    fn matches(&self, event: &Event) -> bool {
+        event.task_id == self.0
+    }
+}
+
+/// Event receiver that skips events rejected by a filter.
+///
+/// A skipped event ends its pipeline at this receiver, so its completion is
+/// signalled once per leaf reachable from the receiving task, the same way a
+/// task signals for an event it drops.
+#[derive(Debug)]
+pub struct FilteredReceiver<F: EventFilter> {
+    /// Underlying channel receiver.
+    rx: Receiver<Event>,
+    /// Filter applied to every received event.
+    filter: F,
+    /// Number of leaves reachable from the receiving task.
+    leaf_count: usize,
+}
+
+impl<F: EventFilter> FilteredReceiver<F> {
+    /// Wraps `rx`, passing only events accepted by `filter`.
+    ///
+    /// `leaf_count` is the receiving task's `TaskContext::leaf_count`.
+    pub fn new(rx: Receiver<Event>, filter: F, leaf_count: usize) -> Self {
+        Self {
+            rx,
+            filter,
+            leaf_count,
+        }
+    }
+
+    /// Receives the next matching event, or `None` once the channel is closed.
+    pub async fn recv(&mut self) -> Option<Event> {
+        loop {
+            let event = self.rx.recv().await?;
+            if self.filter.matches(&event) {
+                return Some(event);
+            }
+            if let Some(arc) = event.completion_tx.as_ref() {
+                for _ in 0..self.leaf_count.max(1) {
+                    arc.signal_completion(None);
+                }
+            }
+        }
+    }
+
+    /// Returns the underlying receiver.
+    pub fn into_inner(self) -> Receiver<Event> {
+        self.rx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{new_completion_channel, EventBuilder, EventData};
+    use serde_json::json;
+    use tokio::sync::mpsc;
+
+    fn create_event(subject: &str, task_id: usize) -> Event {
+        EventBuilder::new()
+            .data(EventData::Json(json!({})))
+            .subject(subject.to_string())
+            .task_id(task_id)
+            .task_type("test")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_builtin_filters() {
+        let event = create_event("orders.created", 2);
+
+        assert!(AlwaysPass.matches(&event));
+        assert!(SubjectFilter("orders.".to_string()).matches(&event));
+        assert!(!SubjectFilter("customers.".to_string()).matches(&event));
+        assert!(TaskIdFilter(2).matches(&event));
+        assert!(!TaskIdFilter(3).matches(&event));
+    }
+
+    #[tokio::test]
+    async fn test_filtered_receiver_skips_and_completes_rejected_events() {
+        let (tx, rx) = mpsc::channel(10);
+        let mut receiver = FilteredReceiver::new(rx, SubjectFilter("orders.".to_string()), 1);
+
+        let (completion, completion_rx) = new_completion_channel(1);
+        let mut skipped = create_event("customers.created", 0);
+        skipped.completion_tx = Some(completion);
+        tx.send(skipped).await.unwrap();
+        tx.send(create_event("orders.created", 0)).await.unwrap();
+        drop(tx);
+
+        let received = receiver.recv().await.unwrap();
+        assert_eq!(received.subject, "orders.created");
+        assert!(receiver.recv().await.is_none());
+        assert!(completion_rx.await.unwrap().is_ok());
+    }
+}
@@ -0,0 +1,167 @@
+//! Component health checks for readiness probes.
+//!
+//! Tasks that hold a connection to an external system register a
+//! [`HealthCheck`] with the HTTP server, which runs every registered check on
+//! its readiness endpoint and reports the worst status.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Health of a single component.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    /// The component works normally.
+    #[default]
+    Ok,
+    /// The component works but is recovering, e.g. reconnecting.
+    Degraded,
+    /// The component cannot do its work.
+    Down,
+}
+
+/// Result of a health check.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct HealthResult {
+    /// Overall status of the component.
+    pub status: HealthStatus,
+    /// Free-form details, e.g. the connection state or the last error.
+    pub details: HashMap<String, String>,
+}
+
+impl HealthResult {
+    /// Creates a result with the given status and no details.
+    pub fn new(status: HealthStatus) -> Self {
+        Self {
+            status,
+            details: HashMap::new(),
+        }
+    }
+
+    /// Adds a detail entry.
+    pub fn detail(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.details.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Health check of a component, run on every readiness probe.
+///
+/// Implementations should be cheap and bounded in time, since probes are
+/// frequent and run all checks concurrently.
+#[async_trait::async_trait]
+pub trait HealthCheck: Send + Sync + 'static {
+    /// Returns the current health of the component.
+    async fn check(&self) -> HealthResult;
+}
+
+/// Health reported by the component itself.
+///
+/// Useful for long-running tasks that already know their state (subscribed,
+/// reconnecting, failed) and would otherwise need an extra request to probe
+/// it. Cloning is cheap and every clone shares the same state.
+#[derive(Debug, Clone, Default)]
+pub struct HealthState {
+    /// Last reported result.
+    result: Arc<RwLock<HealthResult>>,
+}
+
+impl HealthState {
+    /// Creates a state that reports `status` until updated.
+    pub fn new(status: HealthStatus) -> Self {
+        Self {
+            result: Arc::new(RwLock::new(HealthResult::new(status))),
+        }
+    }
+
+    /// Replaces the reported result.
+    pub fn set(&self, result: HealthResult) {
+        if let Ok(mut current) = self.result.write() {
+            *current = result;
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheck for HealthState {
+    async fn check(&self) -> HealthResult {
+        self.result
+            .read()
+            .map(|result| result.clone())
+            .unwrap_or_else(|_| HealthResult::new(HealthStatus::Down))
+    }
+}
+
+/// Keeps a registered health check in the readiness report.
+///
+/// The HTTP server only holds a weak reference to a registered check, so the
+/// check is dropped from the report once its handle is dropped, e.g. when the
+/// task that registered it ends.
+#[must_use = "the health check is deregistered when the handle is dropped"]
+pub struct HealthCheckHandle {
+    /// Registered check, kept alive by this handle.
+    _check: Arc<dyn HealthCheck>,
+}
+
+impl HealthCheckHandle {
+    /// Creates a handle keeping `check` alive.
+    pub fn new(check: Arc<dyn HealthCheck>) -> Self {
+        Self { _check: check }
+    }
+}
+
+impl std::fmt::Debug for HealthCheckHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HealthCheckHandle").finish_non_exhaustive()
+    }
+}
+
+/// Combines check results into one status: the worst of all, or `Ok` when
+/// there are none.
+pub fn overall_status<'a>(results: impl IntoIterator<Item = &'a HealthResult>) -> HealthStatus {
+    results
+        .into_iter()
+        .map(|result| result.status)
+        .max()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overall_status() {
+        let ok = HealthResult::new(HealthStatus::Ok);
+        let degraded = HealthResult::new(HealthStatus::Degraded);
+        let down = HealthResult::new(HealthStatus::Down);
+
+        assert_eq!(overall_status([]), HealthStatus::Ok);
+        assert_eq!(overall_status([&ok, &degraded]), HealthStatus::Degraded);
+        assert_eq!(overall_status([&degraded, &down, &ok]), HealthStatus::Down);
+    }
+
+    #[tokio::test]
+    async fn test_health_state() {
+        let state = HealthState::new(HealthStatus::Down);
+        let shared = state.clone();
+        assert_eq!(state.check().await.status, HealthStatus::Down);
+
+        shared.set(HealthResult::new(HealthStatus::Ok).detail("topic", "/event/Order__e"));
+
+        let result = state.check().await;
+        assert_eq!(result.status, HealthStatus::Ok);
+        assert_eq!(result.details.get("topic").unwrap(), "/event/Order__e");
+    }
+
+    #[test]
+    fn test_health_result_serialization() {
+        let result = HealthResult::new(HealthStatus::Degraded).detail("state", "pending");
+
+        assert_eq!(
+            serde_json::to_value(&result).unwrap(),
+            serde_json::json!({"status": "degraded", "details": {"state": "pending"}})
+        );
+    }
+}
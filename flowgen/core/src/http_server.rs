@@ -34,4 +34,19 @@ pub trait HttpServer: Debug + Send + Sync + 'static {
     /// Tasks that require user authentication call this to validate bearer
     /// tokens and extract `UserContext` from incoming requests.
     fn auth_provider(&self) -> Option<Arc<dyn AuthProvider>>;
+
+    /// Registers a flow's throughput counters for the `/metrics` endpoint.
+    ///
+    /// Registering the same flow name again replaces its counters.
+    async fn register_metrics(&self, flow_name: String, metrics: crate::metrics::FlowMetrics);
+
+    /// Registers a component health check for the readiness endpoint.
+    ///
+    /// The check stays registered until the returned handle is dropped.
+    /// Registering the same name again replaces the previous check.
+    async fn register_health_check(
+        &self,
+        name: String,
+        check: Arc<dyn crate::health::HealthCheck>,
+    ) -> crate::health::HealthCheckHandle;
 }
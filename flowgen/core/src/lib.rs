@@ -15,10 +15,14 @@ pub mod client;
 pub mod config;
 /// Shared credential types for authenticating with external services.
 pub mod credentials;
+/// Error recovery policies for supervising failed tasks.
+pub mod error_policy;
 /// Event system with data formats, subject generation, and logging.
 pub mod event;
 /// Executor for distributed coordination via cache-based leases.
 pub mod executor;
+/// Component health checks for readiness probes.
+pub mod health;
 /// HTTP server trait for task context integration.
 pub mod http_server;
 /// MCP (Model Context Protocol) core types and backward-compatible re-exports.
@@ -28,6 +32,8 @@ pub mod mcp {
 }
 /// MCP server trait for task context integration.
 pub mod mcp_server;
+/// Flow-level throughput metrics backed by atomic counters.
+pub mod metrics;
 /// NsJail sandbox for secure script execution.
 pub mod nsjail {
     /// Sandbox executor using nsjail for process isolation.
@@ -41,12 +47,17 @@ pub mod registry;
 pub mod resource;
 /// Retry configuration and utilities for task execution.
 pub mod retry;
+/// Process-wide registry of named Arrow schemas.
+pub mod schema;
 /// Custom serialization and deserialization utilities.
 pub mod serde;
 /// Service discovery and connection management.
 pub mod service;
 /// OpenTelemetry integration for metrics and distributed tracing.
 pub mod telemetry;
+/// Test helpers for record batch assertions and mock source and sink tasks.
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 /// Validation helpers for config-supplied identifiers and paths.
 pub mod validate;
 /// Task execution framework with runner trait, context, and manager.
@@ -85,6 +96,11 @@ pub mod task {
         /// Processor implementation for script execution.
         pub mod processor;
     }
+    /// Dry-run publisher that logs events in place of side-effecting tasks.
+    pub mod dry_run {
+        /// Publisher implementation that logs and counts received events.
+        pub mod publisher;
+    }
     /// Log processor for outputting event data to logs.
     pub mod log {
         /// Configuration for log processor.
@@ -99,4 +115,90 @@ pub mod task {
         /// Processor implementation for event buffering.
         pub mod processor;
     }
+    /// Aggregate processor for grouped statistics over time windows.
+    pub mod aggregate {
+        /// Configuration for aggregate processor.
+        pub mod config;
+        /// Processor implementation for windowed aggregation.
+        pub mod processor;
+    }
+    /// Window processor for concatenating batches over time windows.
+    pub mod window {
+        /// Configuration for window processor.
+        pub mod config;
+        /// Processor implementation for tumbling windows.
+        pub mod processor;
+    }
+    /// Split processor for breaking record batches into smaller chunks.
+    pub mod split {
+        /// Configuration for split processor.
+        pub mod config;
+        /// Processor implementation for batch splitting.
+        pub mod processor;
+    }
+    /// Mask processor for redacting PII columns.
+    pub mod mask {
+        /// Configuration for mask processor.
+        pub mod config;
+        /// Processor implementation for column masking.
+        pub mod processor;
+    }
+    /// Cast processor for changing column data types.
+    pub mod cast {
+        /// Configuration for cast processor.
+        pub mod config;
+        /// Processor implementation for column type casts.
+        pub mod processor;
+    }
+    /// Project processor for keeping a subset of columns.
+    pub mod project {
+        /// Configuration for project processor.
+        pub mod config;
+        /// Processor implementation for column projection.
+        pub mod processor;
+    }
+    /// Rename processor for changing column names.
+    pub mod rename {
+        /// Configuration for rename processor.
+        pub mod config;
+        /// Processor implementation for column renames.
+        pub mod processor;
+    }
+    /// Timestamp processor for parsing string columns into UTC timestamps.
+    pub mod timestamp {
+        /// Configuration for timestamp processor.
+        pub mod config;
+        /// Processor implementation for timestamp normalization.
+        pub mod processor;
+    }
+    /// Dedup processor for dropping records seen within a window.
+    pub mod dedup {
+        /// Configuration for dedup processor.
+        pub mod config;
+        /// Processor implementation for deduplication.
+        pub mod processor;
+    }
+    /// Encrypt processor for column-level encryption.
+    pub mod encrypt {
+        /// Key loading and value encryption shared with the decrypt processor.
+        pub mod cipher;
+        /// Configuration for encrypt processor.
+        pub mod config;
+        /// Processor implementation for column encryption.
+        pub mod processor;
+    }
+    /// Decrypt processor for columns written by the encrypt processor.
+    pub mod decrypt {
+        /// Configuration for decrypt processor.
+        pub mod config;
+        /// Processor implementation for column decryption.
+        pub mod processor;
+    }
+    /// Router processor for fanning events out to subjects by condition.
+    pub mod router {
+        /// Configuration and route conditions for router processor.
+        pub mod config;
+        /// Processor implementation for conditional routing.
+        pub mod processor;
+    }
 }
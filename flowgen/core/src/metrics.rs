@@ -0,0 +1,160 @@
+//! Flow-level throughput metrics backed by atomic counters.
+//!
+//! Counters are shared with every task through the task context. Sources count
+//! received events, publishers count processed events and bytes, and the HTTP
+//! server renders all registered flows on its `/metrics` endpoint.
+
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// Point-in-time copy of a flow's counters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct MetricsSnapshot {
+    /// Events emitted by source tasks.
+    pub events_received: u64,
+    /// Events successfully written by publisher tasks.
+    pub events_processed: u64,
+    /// Events that failed after all retry attempts.
+    pub events_failed: u64,
+    /// Payload bytes successfully written by publisher tasks.
+    pub bytes_processed: u64,
+}
+
+/// Shared throughput counters for a single flow.
+///
+/// Cloning is cheap and every clone updates the same counters.
+#[derive(Debug, Clone)]
+pub struct FlowMetrics {
+    /// Events emitted by source tasks.
+    pub events_received: Arc<AtomicU64>,
+    /// Events successfully written by publisher tasks.
+    pub events_processed: Arc<AtomicU64>,
+    /// Events that failed after all retry attempts.
+    pub events_failed: Arc<AtomicU64>,
+    /// Payload bytes successfully written by publisher tasks.
+    pub bytes_processed: Arc<AtomicU64>,
+    /// Time and processed count of the last snapshot, used by `rate`.
+    last_snapshot: Arc<Mutex<(Instant, u64)>>,
+}
+
+impl Default for FlowMetrics {
+    fn default() -> Self {
+        Self {
+            events_received: Arc::new(AtomicU64::new(0)),
+            events_processed: Arc::new(AtomicU64::new(0)),
+            events_failed: Arc::new(AtomicU64::new(0)),
+            bytes_processed: Arc::new(AtomicU64::new(0)),
+            last_snapshot: Arc::new(Mutex::new((Instant::now(), 0))),
+        }
+    }
+}
+
+impl FlowMetrics {
+    /// Creates a new set of zeroed counters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records an event emitted by a source task.
+    pub fn record_received(&self) {
+        self.events_received.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records an event written by a publisher task along with its payload size.
+    pub fn record_processed(&self, bytes: u64) {
+        self.events_processed.fetch_add(1, Ordering::Relaxed);
+        self.bytes_processed.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    /// Records an event that failed after all retry attempts.
+    pub fn record_failed(&self) {
+        self.events_failed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Reads all counters and resets the baseline used by `rate`.
+    ///
+    /// The snapshot lock serializes concurrent snapshots so each one sees a
+    /// consistent baseline.
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let mut last = self
+            .last_snapshot
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let snapshot = MetricsSnapshot {
+            events_received: self.events_received.load(Ordering::Acquire),
+            events_processed: self.events_processed.load(Ordering::Acquire),
+            events_failed: self.events_failed.load(Ordering::Acquire),
+            bytes_processed: self.bytes_processed.load(Ordering::Acquire),
+        };
+
+        *last = (Instant::now(), snapshot.events_processed);
+        snapshot
+    }
+
+    /// Returns processed events per second since the last snapshot.
+    pub fn rate(&self) -> f64 {
+        let last = self
+            .last_snapshot
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let elapsed = last.0.elapsed().as_secs_f64();
+        if elapsed <= 0.0 {
+            return 0.0;
+        }
+
+        let processed = self.events_processed.load(Ordering::Acquire);
+        processed.saturating_sub(last.1) as f64 / elapsed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flow_metrics_snapshot() {
+        let metrics = FlowMetrics::new();
+        metrics.record_received();
+        metrics.record_received();
+        metrics.record_processed(128);
+        metrics.record_failed();
+
+        assert_eq!(
+            metrics.snapshot(),
+            MetricsSnapshot {
+                events_received: 2,
+                events_processed: 1,
+                events_failed: 1,
+                bytes_processed: 128,
+            }
+        );
+    }
+
+    #[test]
+    fn test_flow_metrics_clones_share_counters() {
+        let metrics = FlowMetrics::new();
+        let cloned = metrics.clone();
+        cloned.record_processed(10);
+
+        assert_eq!(metrics.snapshot().events_processed, 1);
+        assert_eq!(metrics.snapshot().bytes_processed, 10);
+    }
+
+    #[test]
+    fn test_flow_metrics_rate_since_last_snapshot() {
+        let metrics = FlowMetrics::new();
+        metrics.record_processed(0);
+        metrics.snapshot();
+
+        // Nothing processed since the snapshot.
+        assert_eq!(metrics.rate(), 0.0);
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        metrics.record_processed(0);
+        assert!(metrics.rate() > 0.0);
+    }
+}
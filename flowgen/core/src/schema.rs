@@ -0,0 +1,106 @@
+//! Process-wide registry of named Arrow schemas.
+//!
+//! Writers register the schema of the data they produce under a name (for
+//! example a table name), and other tasks look it up by that name instead of
+//! repeating the schema definition in their own configuration.
+
+use arrow::datatypes::Schema;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Global registry instance shared by all flows in the process.
+static GLOBAL: OnceLock<SchemaRegistry> = OnceLock::new();
+
+/// Thread-safe map of schema names to Arrow schemas.
+///
+/// Cloning the registry is cheap and all clones share the same entries.
+#[derive(Debug, Clone, Default)]
+pub struct SchemaRegistry {
+    schemas: Arc<RwLock<HashMap<String, Arc<Schema>>>>,
+}
+
+impl SchemaRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the process-wide registry.
+    pub fn global() -> &'static SchemaRegistry {
+        GLOBAL.get_or_init(SchemaRegistry::new)
+    }
+
+    /// Registers a schema under `name`, replacing any existing entry.
+    pub fn register(&self, name: &str, schema: Arc<Schema>) {
+        self.schemas
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(name.to_string(), schema);
+    }
+
+    /// Registers a schema under `name` unless one is already present.
+    ///
+    /// Returns the schema stored in the registry after the call.
+    pub fn register_if_absent(&self, name: &str, schema: Arc<Schema>) -> Arc<Schema> {
+        if let Some(existing) = self.get(name) {
+            return existing;
+        }
+        let mut schemas = self.schemas.write().unwrap_or_else(|e| e.into_inner());
+        Arc::clone(schemas.entry(name.to_string()).or_insert(schema))
+    }
+
+    /// Returns the schema registered under `name`, if any.
+    pub fn get(&self, name: &str) -> Option<Arc<Schema>> {
+        self.schemas
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(name)
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::datatypes::{DataType, Field};
+
+    fn schema(column: &str) -> Arc<Schema> {
+        Arc::new(Schema::new(vec![Field::new(column, DataType::Utf8, true)]))
+    }
+
+    #[test]
+    fn test_register_and_get() {
+        let registry = SchemaRegistry::new();
+        assert!(registry.get("orders").is_none());
+
+        registry.register("orders", schema("id"));
+        assert_eq!(registry.get("orders"), Some(schema("id")));
+
+        registry.register("orders", schema("order_id"));
+        assert_eq!(registry.get("orders"), Some(schema("order_id")));
+    }
+
+    #[test]
+    fn test_register_if_absent_keeps_first() {
+        let registry = SchemaRegistry::new();
+
+        assert_eq!(
+            registry.register_if_absent("orders", schema("id")),
+            schema("id")
+        );
+        assert_eq!(
+            registry.register_if_absent("orders", schema("order_id")),
+            schema("id")
+        );
+    }
+
+    #[test]
+    fn test_clones_share_entries() {
+        let registry = SchemaRegistry::new();
+        registry.clone().register("orders", schema("id"));
+        assert!(registry.get("orders").is_some());
+
+        SchemaRegistry::global().register("test_global_orders", schema("id"));
+        assert!(SchemaRegistry::global().get("test_global_orders").is_some());
+    }
+}
@@ -35,18 +35,20 @@ pub enum Error {
 /// gRPC service connection manager with TLS support.
 #[derive(Debug, Clone)]
 pub struct Service {
-    /// Service endpoint URL for connection.
-    endpoint: Option<String>,
+    /// Service endpoint URL for connection, kept for reconnects.
+    pub endpoint: Option<String>,
     /// Established gRPC channel for service communication.
     pub channel: Option<tonic::transport::Channel>,
     /// HTTP/2 keep-alive interval in seconds.
     keep_alive_interval_secs: u64,
     /// Keep-alive timeout in seconds.
     keep_alive_timeout_secs: u64,
-    /// Connection timeout in seconds.
-    connect_timeout_secs: u64,
+    /// Connection timeout.
+    connect_timeout: Duration,
     /// Enable keep-alive while connection is idle.
     keep_alive_while_idle: bool,
+    /// Optional per-request timeout applied to every RPC on the channel.
+    request_timeout: Option<Duration>,
 }
 
 impl Default for Service {
@@ -56,33 +58,59 @@ impl Default for Service {
             channel: None,
             keep_alive_interval_secs: DEFAULT_KEEP_ALIVE_INTERVAL_SECS,
             keep_alive_timeout_secs: DEFAULT_KEEP_ALIVE_TIMEOUT_SECS,
-            connect_timeout_secs: DEFAULT_CONNECT_TIMEOUT_SECS,
+            connect_timeout: Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS),
             keep_alive_while_idle: true,
+            request_timeout: None,
+        }
+    }
+}
+
+impl Service {
+    /// Re-creates the gRPC channel to the same endpoint with the same settings.
+    ///
+    /// The previous channel is dropped first, so [`Service::is_connected`] reports
+    /// `false` if the new connection cannot be established.
+    pub async fn reconnect(&mut self) -> Result<(), Error> {
+        self.channel = None;
+        self.channel = Some(self.create_channel().await?);
+        Ok(())
+    }
+
+    /// Returns whether a channel has been established.
+    ///
+    /// Tonic channels re-dial dropped connections lazily, so this does not probe
+    /// the remote endpoint.
+    pub fn is_connected(&self) -> bool {
+        self.channel.is_some()
+    }
+
+    /// Creates a TLS channel to the configured endpoint.
+    async fn create_channel(&self) -> Result<tonic::transport::Channel, Error> {
+        let endpoint = self.endpoint.clone().ok_or_else(Error::MissingEndpoint)?;
+        let tls_config = tonic::transport::ClientTlsConfig::new().with_native_roots();
+        let mut endpoint = tonic::transport::Channel::from_shared(endpoint)
+            .map_err(|e| Error::InvalidUri { source: e })?
+            .tls_config(tls_config)
+            .map_err(|e| Error::TransportError { source: e })?
+            .http2_keep_alive_interval(Duration::from_secs(self.keep_alive_interval_secs))
+            .keep_alive_timeout(Duration::from_secs(self.keep_alive_timeout_secs))
+            .keep_alive_while_idle(self.keep_alive_while_idle)
+            .connect_timeout(self.connect_timeout);
+        if let Some(timeout) = self.request_timeout {
+            endpoint = endpoint.timeout(timeout);
         }
+        endpoint
+            .connect()
+            .await
+            .map_err(|e| Error::TransportError { source: e })
     }
 }
 
 impl super::client::Client for Service {
     type Error = Error;
     async fn connect(mut self) -> Result<Self, Self::Error> {
-        if let Some(endpoint) = self.endpoint.take() {
-            let tls_config = tonic::transport::ClientTlsConfig::new().with_native_roots();
-            let channel = tonic::transport::Channel::from_shared(endpoint)
-                .map_err(|e| Error::InvalidUri { source: e })?
-                .tls_config(tls_config)
-                .map_err(|e| Error::TransportError { source: e })?
-                .http2_keep_alive_interval(Duration::from_secs(self.keep_alive_interval_secs))
-                .keep_alive_timeout(Duration::from_secs(self.keep_alive_timeout_secs))
-                .keep_alive_while_idle(self.keep_alive_while_idle)
-                .connect_timeout(Duration::from_secs(self.connect_timeout_secs))
-                .connect()
-                .await
-                .map_err(|e| Error::TransportError { source: e })?;
-            self.channel = Some(channel);
-            Ok(self)
-        } else {
-            Err(Error::MissingEndpoint())
-        }
+        self.channel = Some(self.create_channel().await?);
+        Ok(self)
     }
 }
 
@@ -95,10 +123,12 @@ pub struct ServiceBuilder {
     keep_alive_interval_secs: Option<u64>,
     /// Keep-alive timeout in seconds.
     keep_alive_timeout_secs: Option<u64>,
-    /// Connection timeout in seconds.
-    connect_timeout_secs: Option<u64>,
+    /// Connection timeout.
+    connect_timeout: Option<Duration>,
     /// Enable keep-alive while connection is idle.
     keep_alive_while_idle: Option<bool>,
+    /// Per-request timeout.
+    request_timeout: Option<Duration>,
 }
 
 impl ServiceBuilder {
@@ -139,9 +169,9 @@ impl ServiceBuilder {
     /// Sets the connection timeout.
     ///
     /// # Arguments
-    /// * `secs` - Timeout in seconds for establishing connection
-    pub fn connect_timeout(&mut self, secs: u64) -> &mut Self {
-        self.connect_timeout_secs = Some(secs);
+    /// * `timeout` - Timeout for establishing the connection
+    pub fn connect_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.connect_timeout = Some(timeout);
         self
     }
 
@@ -154,6 +184,18 @@ impl ServiceBuilder {
         self
     }
 
+    /// Sets the per-request timeout applied to every RPC on the channel.
+    ///
+    /// For streaming RPCs this bounds the time until the response starts,
+    /// not the lifetime of the stream.
+    ///
+    /// # Arguments
+    /// * `timeout` - Timeout for each request
+    pub fn request_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
     /// Builds the Service instance with the configured endpoint.
     ///
     /// # Returns
@@ -170,11 +212,12 @@ impl ServiceBuilder {
                 .keep_alive_timeout_secs
                 .take()
                 .unwrap_or(DEFAULT_KEEP_ALIVE_TIMEOUT_SECS),
-            connect_timeout_secs: self
-                .connect_timeout_secs
+            connect_timeout: self
+                .connect_timeout
                 .take()
-                .unwrap_or(DEFAULT_CONNECT_TIMEOUT_SECS),
+                .unwrap_or(Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS)),
             keep_alive_while_idle: self.keep_alive_while_idle.take().unwrap_or(true),
+            request_timeout: self.request_timeout.take(),
         })
     }
 }
@@ -231,8 +274,34 @@ mod tests {
             service.keep_alive_timeout_secs,
             DEFAULT_KEEP_ALIVE_TIMEOUT_SECS
         );
-        assert_eq!(service.connect_timeout_secs, DEFAULT_CONNECT_TIMEOUT_SECS);
+        assert_eq!(
+            service.connect_timeout,
+            Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS)
+        );
         assert!(service.keep_alive_while_idle);
+        assert!(service.request_timeout.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_service_reconnect_without_endpoint() {
+        let mut service = ServiceBuilder::new().build().unwrap();
+        assert!(!service.is_connected());
+
+        let result = service.reconnect().await;
+        assert!(matches!(result, Err(Error::MissingEndpoint())));
+        assert!(!service.is_connected());
+    }
+
+    #[tokio::test]
+    async fn test_service_reconnect_invalid_uri() {
+        let mut service = ServiceBuilder::new()
+            .endpoint("not a uri".to_string())
+            .build()
+            .unwrap();
+
+        let result = service.reconnect().await;
+        assert!(matches!(result, Err(Error::InvalidUri { .. })));
+        assert_eq!(service.endpoint, Some("not a uri".to_string()));
     }
 
     #[test]
@@ -242,15 +311,17 @@ mod tests {
             .endpoint("https://example.com".to_string())
             .keep_alive_interval(15)
             .keep_alive_timeout(5)
-            .connect_timeout(60)
+            .connect_timeout(Duration::from_secs(60))
             .keep_alive_while_idle(false)
+            .request_timeout(Duration::from_millis(1500))
             .build()
             .unwrap();
 
         assert_eq!(service.endpoint, Some("https://example.com".to_string()));
         assert_eq!(service.keep_alive_interval_secs, 15);
         assert_eq!(service.keep_alive_timeout_secs, 5);
-        assert_eq!(service.connect_timeout_secs, 60);
+        assert_eq!(service.connect_timeout, Duration::from_secs(60));
         assert!(!service.keep_alive_while_idle);
+        assert_eq!(service.request_timeout, Some(Duration::from_millis(1500)));
     }
 }
@@ -0,0 +1,131 @@
+//! Configuration for the aggregate task processor.
+//!
+//! The aggregate task groups Arrow RecordBatch rows by a set of columns and
+//! computes count, sum, average, minimum and maximum over fixed time windows.
+
+use crate::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Configuration for aggregate processor task.
+///
+/// Accumulates rows from incoming RecordBatch events into tumbling windows and
+/// emits one single-row RecordBatch per group when each window closes.
+///
+/// # Examples
+///
+/// ```yaml
+/// aggregate:
+///   name: "orders_per_region"
+///   window: "1m"
+///   group_by:
+///     - region
+///   aggregations:
+///     - column: order_id
+///       function: count
+///       output_name: orders
+///     - column: amount
+///       function: avg
+///       output_name: avg_amount
+/// ```
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct Processor {
+    /// Unique name identifier for this aggregate task.
+    pub name: String,
+    /// Length of each tumbling window. Accepts duration strings: "500ms", "30s", "5m", etc.
+    #[serde(with = "humantime_serde")]
+    pub window: Duration,
+    /// Columns whose values identify a group. When empty, all rows form a single group.
+    #[serde(default)]
+    pub group_by: Vec<String>,
+    /// Aggregations computed for each group.
+    pub aggregations: Vec<AggregationSpec>,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    pub retry: Option<crate::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    pub error_policy: Option<crate::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Processor {}
+
+/// A single aggregation over one input column.
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct AggregationSpec {
+    /// Input column to aggregate.
+    pub column: String,
+    /// Aggregate function to apply.
+    pub function: AggFunc,
+    /// Name of the output column.
+    pub output_name: String,
+}
+
+/// Supported aggregate functions.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AggFunc {
+    /// Number of non-null values.
+    Count,
+    /// Sum of numeric values.
+    Sum,
+    /// Arithmetic mean of numeric values.
+    Avg,
+    /// Smallest numeric value.
+    Min,
+    /// Largest numeric value.
+    Max,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_processor_deserialization() {
+        let yaml = r#"
+name: orders_per_region
+window: 1m
+group_by: [region]
+aggregations:
+  - column: order_id
+    function: count
+    output_name: orders
+  - column: amount
+    function: avg
+    output_name: avg_amount
+"#;
+        let processor: Processor = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(processor.window, Duration::from_secs(60));
+        assert_eq!(processor.group_by, vec!["region".to_string()]);
+        assert_eq!(processor.aggregations.len(), 2);
+        assert_eq!(processor.aggregations[0].function, AggFunc::Count);
+        assert_eq!(processor.aggregations[1].function, AggFunc::Avg);
+        assert_eq!(processor.aggregations[1].output_name, "avg_amount");
+    }
+
+    #[test]
+    fn test_processor_serialization() {
+        let processor = Processor {
+            name: "totals".to_string(),
+            window: Duration::from_secs(10),
+            group_by: vec![],
+            aggregations: vec![AggregationSpec {
+                column: "amount".to_string(),
+                function: AggFunc::Sum,
+                output_name: "total".to_string(),
+            }],
+            depends_on: None,
+            retry: None,
+            error_policy: None,
+        };
+
+        let json = serde_json::to_string(&processor).unwrap();
+        let deserialized: Processor = serde_json::from_str(&json).unwrap();
+        assert_eq!(processor, deserialized);
+    }
+}
@@ -0,0 +1,712 @@
+//! Aggregate processor for computing grouped statistics over time windows.
+//!
+//! Accumulates rows from incoming Arrow RecordBatch events into tumbling windows,
+//! keeping running partial aggregates per group. When a window closes, one
+//! single-row RecordBatch is emitted per group containing the group columns and
+//! the configured aggregations.
+
+use super::config::{AggFunc, AggregationSpec};
+use crate::event::{
+    new_completion_channel, spawn_merged_completion, Event, EventBuilder, EventData, EventExt,
+    SharedCompletionTx,
+};
+use arrow::array::{Array, ArrayRef, AsArray, Float64Array, RecordBatch, StringArray, UInt32Array};
+use arrow::compute::kernels::aggregate;
+use arrow::datatypes::{DataType, Field, Float64Type, Schema};
+use arrow::util::display::{ArrayFormatter, FormatOptions};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tokio::time::{interval_at, Instant};
+use tracing::{error, warn};
+
+/// Errors that can occur during aggregate processing operations.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Expected ArrowRecordBatch event data, got JSON")]
+    ExpectedRecordBatchGotJson,
+    #[error("Expected ArrowRecordBatch event data, got Avro")]
+    ExpectedRecordBatchGotAvro,
+    #[error("Column '{0}' not found in record batch")]
+    MissingColumn(String),
+    #[error("Arrow error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+    #[error("Aggregate window must be greater than zero")]
+    ZeroWindow,
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+}
+
+/// Values of the `group_by` columns identifying a group. `None` is a null value.
+type GroupKey = Vec<Option<String>>;
+
+/// Running partial aggregate for one aggregation of one group.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Accumulator {
+    count: u64,
+    sum: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl Accumulator {
+    /// Merges a batch of numeric values into the running aggregate.
+    fn update(&mut self, values: &Float64Array) {
+        self.count += (values.len() - values.null_count()) as u64;
+        if let Some(sum) = aggregate::sum(values) {
+            self.sum += sum;
+        }
+        if let Some(min) = aggregate::min(values) {
+            self.min = Some(self.min.map_or(min, |m| m.min(min)));
+        }
+        if let Some(max) = aggregate::max(values) {
+            self.max = Some(self.max.map_or(max, |m| m.max(max)));
+        }
+    }
+
+    /// Returns the final value of the aggregate. Empty groups yield null,
+    /// except for count which yields zero.
+    fn finish(&self, function: AggFunc) -> Option<f64> {
+        match function {
+            AggFunc::Count => Some(self.count as f64),
+            _ if self.count == 0 => None,
+            AggFunc::Sum => Some(self.sum),
+            AggFunc::Avg => Some(self.sum / self.count as f64),
+            AggFunc::Min => self.min,
+            AggFunc::Max => self.max,
+        }
+    }
+}
+
+/// Returns the batch column with the given name.
+fn column<'a>(batch: &'a RecordBatch, name: &str) -> Result<&'a ArrayRef, Error> {
+    batch
+        .column_by_name(name)
+        .ok_or_else(|| Error::MissingColumn(name.to_string()))
+}
+
+/// Computes the group key of every row in the batch.
+fn group_rows(
+    batch: &RecordBatch,
+    group_by: &[String],
+) -> Result<HashMap<GroupKey, Vec<u32>>, Error> {
+    let columns = group_by
+        .iter()
+        .map(|name| column(batch, name))
+        .collect::<Result<Vec<_>, _>>()?;
+    let options = FormatOptions::default();
+    let formatters = columns
+        .iter()
+        .map(|array| ArrayFormatter::try_new(array.as_ref(), &options))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|source| Error::Arrow { source })?;
+
+    let mut groups: HashMap<GroupKey, Vec<u32>> = HashMap::new();
+    for row in 0..batch.num_rows() {
+        let key = columns
+            .iter()
+            .zip(&formatters)
+            .map(|(array, formatter)| {
+                (!array.is_null(row)).then(|| formatter.value(row).to_string())
+            })
+            .collect();
+        groups.entry(key).or_default().push(row as u32);
+    }
+    Ok(groups)
+}
+
+/// Folds the rows of a batch into the per-group accumulators.
+///
+/// Count aggregations use the input column as-is so non-numeric columns can be
+/// counted. All other aggregations cast the column to Float64 first; values that
+/// cannot be cast are treated as nulls.
+fn accumulate(
+    groups: &mut HashMap<GroupKey, Vec<Accumulator>>,
+    batch: &RecordBatch,
+    group_by: &[String],
+    aggregations: &[AggregationSpec],
+) -> Result<(), Error> {
+    let inputs = aggregations
+        .iter()
+        .map(|spec| {
+            let input = column(batch, &spec.column)?;
+            match spec.function {
+                AggFunc::Count => Ok(Arc::clone(input)),
+                _ => arrow::compute::cast(input.as_ref(), &DataType::Float64)
+                    .map_err(|source| Error::Arrow { source }),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (key, rows) in group_rows(batch, group_by)? {
+        let indices = UInt32Array::from(rows);
+        let accumulators = groups
+            .entry(key)
+            .or_insert_with(|| vec![Accumulator::default(); aggregations.len()]);
+
+        for ((spec, input), accumulator) in aggregations.iter().zip(&inputs).zip(accumulators) {
+            let values = arrow::compute::take(input.as_ref(), &indices, None)
+                .map_err(|source| Error::Arrow { source })?;
+            match spec.function {
+                AggFunc::Count => {
+                    accumulator.count += (values.len() - values.null_count()) as u64;
+                }
+                _ => accumulator.update(values.as_primitive::<Float64Type>()),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Builds the single-row output batch for one group.
+fn group_batch(
+    key: GroupKey,
+    accumulators: &[Accumulator],
+    group_by: &[String],
+    aggregations: &[AggregationSpec],
+) -> Result<RecordBatch, Error> {
+    let mut fields = Vec::with_capacity(group_by.len() + aggregations.len());
+    let mut columns: Vec<ArrayRef> = Vec::with_capacity(group_by.len() + aggregations.len());
+
+    for (name, value) in group_by.iter().zip(key) {
+        fields.push(Field::new(name, DataType::Utf8, true));
+        columns.push(Arc::new(StringArray::from(vec![value])));
+    }
+    for (spec, accumulator) in aggregations.iter().zip(accumulators) {
+        fields.push(Field::new(&spec.output_name, DataType::Float64, true));
+        columns.push(Arc::new(Float64Array::from(vec![
+            accumulator.finish(spec.function)
+        ])));
+    }
+
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+        .map_err(|source| Error::Arrow { source })
+}
+
+/// Aggregate processor that computes grouped statistics over tumbling windows.
+///
+/// Like the buffer processor, this keeps state across events and uses
+/// `tokio::select!` to react to incoming events, window ticks and shutdown.
+#[derive(Debug)]
+pub struct Processor {
+    /// Aggregate processor configuration.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for processed events.
+    tx: Option<Sender<Event>>,
+    /// Channel receiver for incoming events.
+    rx: Receiver<Event>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+impl Processor {
+    /// Emits one event per group for the closed window.
+    ///
+    /// Groups are emitted in key order. The meta of the first event in the window
+    /// is attached to every output event. The completion channels of all events
+    /// in the window are signalled once every output event has completed, or
+    /// right away when this task is a leaf.
+    async fn flush_window(
+        &self,
+        groups: HashMap<GroupKey, Vec<Accumulator>>,
+        meta: Option<Map<String, Value>>,
+        completions: Vec<SharedCompletionTx>,
+    ) -> Result<(), Error> {
+        let mut groups: Vec<_> = groups.into_iter().collect();
+        groups.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let downstream_leaves = self.task_context.leaf_count.max(1);
+        let mut per_event_receivers = Vec::new();
+
+        for (key, accumulators) in groups {
+            let batch = group_batch(
+                key,
+                &accumulators,
+                &self.config.group_by,
+                &self.config.aggregations,
+            )?;
+
+            let mut event_builder = EventBuilder::new()
+                .data(EventData::ArrowRecordBatch(batch))
+                .subject(self.config.name.to_owned())
+                .task_id(self.task_id)
+                .task_type(self.task_type);
+            if let Some(meta) = meta.clone() {
+                event_builder = event_builder.meta(meta);
+            }
+            let mut event = event_builder
+                .build()
+                .map_err(|source| Error::EventBuilder { source })?;
+
+            if self.tx.is_some() && !completions.is_empty() {
+                let (per_state, per_rx) = new_completion_channel(downstream_leaves);
+                event.completion_tx = Some(per_state);
+                per_event_receivers.push(per_rx);
+            }
+
+            event
+                .send_with_logging(self.tx.as_ref())
+                .await
+                .map_err(|source| Error::SendMessage { source })?;
+        }
+
+        spawn_merged_completion(completions, per_event_receivers, downstream_leaves);
+        Ok(())
+    }
+
+    /// Runs the window loop until the channel closes or the task is cancelled.
+    async fn process_events(&mut self) -> Result<(), Error> {
+        let window = self.config.window;
+        let mut ticker = interval_at(Instant::now() + window, window);
+        let mut groups: HashMap<GroupKey, Vec<Accumulator>> = HashMap::new();
+        let mut window_meta: Option<Map<String, Value>> = None;
+        let mut window_completions: Vec<SharedCompletionTx> = Vec::new();
+
+        loop {
+            if self.task_context.cancellation_token.is_cancelled() {
+                return Ok(());
+            }
+
+            tokio::select! {
+                result = self.rx.recv() => {
+                    match result {
+                        Some(event) => {
+                            let batch = match &event.data {
+                                EventData::ArrowRecordBatch(batch) => batch,
+                                EventData::Json(_) => {
+                                    warn!(error = %Error::ExpectedRecordBatchGotJson, "Skipping event");
+                                    continue;
+                                }
+                                EventData::Avro(_) => {
+                                    warn!(error = %Error::ExpectedRecordBatchGotAvro, "Skipping event");
+                                    continue;
+                                }
+                            };
+
+                            // A malformed batch should not discard the rest of the window.
+                            if let Err(e) = accumulate(
+                                &mut groups,
+                                batch,
+                                &self.config.group_by,
+                                &self.config.aggregations,
+                            ) {
+                                warn!(error = %e, "Failed to aggregate record batch, skipping");
+                                continue;
+                            }
+
+                            if window_meta.is_none() {
+                                window_meta = event.meta.clone();
+                            }
+                            window_completions.extend(event.completion_tx.clone());
+                        }
+                        None => {
+                            // Channel closed, flush the partial window and exit.
+                            if !groups.is_empty() {
+                                self.flush_window(groups, window_meta, window_completions).await?;
+                            }
+                            return Ok(());
+                        }
+                    }
+                }
+
+                _ = ticker.tick() => {
+                    if !groups.is_empty() {
+                        let closed = std::mem::take(&mut groups);
+                        let completions = std::mem::take(&mut window_completions);
+                        self.flush_window(closed, window_meta.take(), completions).await?;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::task::runner::Runner for Processor {
+    type Error = Error;
+    type EventHandler = ();
+
+    /// Initializes the aggregate processor.
+    ///
+    /// Aggregate processor keeps window state across events, so there is no
+    /// separate EventHandler and this returns unit type.
+    async fn init(&self) -> Result<Self::EventHandler, Self::Error> {
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(mut self) -> Result<(), Error> {
+        if let Err(e) = self.process_events().await {
+            error!(error = %e, "Failed to process events");
+            return Err(e);
+        }
+
+        Ok(())
+    }
+}
+
+/// Builder for constructing Processor instances with validation.
+#[derive(Debug, Default)]
+pub struct ProcessorBuilder {
+    /// Aggregate processor configuration (required for build).
+    config: Option<Arc<super::config::Processor>>,
+    /// Event sender for passing events to next task (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Event receiver for incoming events (required for build).
+    rx: Option<Receiver<Event>>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Option<Arc<crate::task::context::TaskContext>>,
+    /// Task type for event categorization and logging.
+    task_type: Option<&'static str>,
+}
+
+impl ProcessorBuilder {
+    pub fn new() -> ProcessorBuilder {
+        ProcessorBuilder {
+            ..Default::default()
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Processor>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn receiver(mut self, receiver: Receiver<Event>) -> Self {
+        self.rx = Some(receiver);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = task_id;
+        self
+    }
+
+    pub fn task_context(mut self, task_context: Arc<crate::task::context::TaskContext>) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Processor, Error> {
+        let config = self
+            .config
+            .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?;
+        if config.window.is_zero() {
+            return Err(Error::ZeroWindow);
+        }
+
+        Ok(Processor {
+            config,
+            rx: self
+                .rx
+                .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
+            tx: self.tx,
+            task_id: self.task_id,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::Int64Array;
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+
+    fn create_mock_task_context() -> Arc<crate::task::context::TaskContext> {
+        let mut labels = Map::new();
+        labels.insert(
+            "description".to_string(),
+            Value::String("Aggregate Test".to_string()),
+        );
+        let task_manager = Arc::new(
+            crate::task::manager::TaskManagerBuilder::new()
+                .build()
+                .unwrap(),
+        );
+        let cache =
+            Arc::new(crate::cache::memory::MemoryCache::new()) as Arc<dyn crate::cache::Cache>;
+        Arc::new(
+            crate::task::context::TaskContextBuilder::new()
+                .flow_name("test-flow".to_string())
+                .flow_labels(Some(labels))
+                .task_manager(task_manager)
+                .cache(cache)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn spec(column: &str, function: AggFunc, output_name: &str) -> AggregationSpec {
+        AggregationSpec {
+            column: column.to_string(),
+            function,
+            output_name: output_name.to_string(),
+        }
+    }
+
+    fn create_config(window: Duration) -> Arc<super::super::config::Processor> {
+        Arc::new(super::super::config::Processor {
+            name: "aggregate".to_string(),
+            window,
+            group_by: vec!["region".to_string()],
+            aggregations: vec![
+                spec("amount", AggFunc::Count, "count"),
+                spec("amount", AggFunc::Sum, "sum"),
+                spec("amount", AggFunc::Avg, "avg"),
+                spec("amount", AggFunc::Min, "min"),
+                spec("amount", AggFunc::Max, "max"),
+            ],
+            depends_on: None,
+            retry: None,
+            error_policy: None,
+        })
+    }
+
+    fn create_batch(regions: Vec<Option<&str>>, amounts: Vec<Option<i64>>) -> RecordBatch {
+        RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("region", DataType::Utf8, true),
+                Field::new("amount", DataType::Int64, true),
+            ])),
+            vec![
+                Arc::new(StringArray::from(regions)),
+                Arc::new(Int64Array::from(amounts)),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn float_value(batch: &RecordBatch, name: &str) -> Option<f64> {
+        let array = batch
+            .column_by_name(name)
+            .unwrap()
+            .as_primitive::<Float64Type>();
+        (!array.is_null(0)).then(|| array.value(0))
+    }
+
+    #[test]
+    fn test_accumulate_across_batches() {
+        let config = create_config(Duration::from_secs(1));
+        let mut groups = HashMap::new();
+
+        let first = create_batch(
+            vec![Some("eu"), Some("us"), Some("eu")],
+            vec![Some(10), Some(5), Some(30)],
+        );
+        let second = create_batch(vec![Some("eu"), None], vec![None, Some(7)]);
+        accumulate(&mut groups, &first, &config.group_by, &config.aggregations).unwrap();
+        accumulate(&mut groups, &second, &config.group_by, &config.aggregations).unwrap();
+
+        assert_eq!(groups.len(), 3);
+
+        let eu = &groups[&vec![Some("eu".to_string())]];
+        let batch = group_batch(
+            vec![Some("eu".to_string())],
+            eu,
+            &config.group_by,
+            &config.aggregations,
+        )
+        .unwrap();
+        assert_eq!(batch.num_rows(), 1);
+        assert_eq!(float_value(&batch, "count"), Some(2.0));
+        assert_eq!(float_value(&batch, "sum"), Some(40.0));
+        assert_eq!(float_value(&batch, "avg"), Some(20.0));
+        assert_eq!(float_value(&batch, "min"), Some(10.0));
+        assert_eq!(float_value(&batch, "max"), Some(30.0));
+
+        assert!(groups.contains_key(&vec![None]));
+    }
+
+    #[test]
+    fn test_empty_group_yields_nulls() {
+        let config = create_config(Duration::from_secs(1));
+        let mut groups = HashMap::new();
+        let batch = create_batch(vec![Some("eu")], vec![None]);
+        accumulate(&mut groups, &batch, &config.group_by, &config.aggregations).unwrap();
+
+        let key = vec![Some("eu".to_string())];
+        let batch = group_batch(
+            key.clone(),
+            &groups[&key],
+            &config.group_by,
+            &config.aggregations,
+        )
+        .unwrap();
+        assert_eq!(float_value(&batch, "count"), Some(0.0));
+        assert_eq!(float_value(&batch, "sum"), None);
+        assert_eq!(float_value(&batch, "avg"), None);
+    }
+
+    #[test]
+    fn test_accumulate_missing_column() {
+        let config = create_config(Duration::from_secs(1));
+        let mut groups = HashMap::new();
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new(
+                "region",
+                DataType::Utf8,
+                true,
+            )])),
+            vec![Arc::new(StringArray::from(vec![Some("eu")]))],
+        )
+        .unwrap();
+
+        let result = accumulate(&mut groups, &batch, &config.group_by, &config.aggregations);
+        assert!(matches!(result, Err(Error::MissingColumn(c)) if c == "amount"));
+        assert!(groups.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_processor_emits_window_per_group() {
+        let (in_tx, in_rx) = mpsc::channel(10);
+        let (out_tx, mut out_rx) = mpsc::channel(10);
+
+        let processor = ProcessorBuilder::new()
+            .config(create_config(Duration::from_secs(3600)))
+            .receiver(in_rx)
+            .sender(out_tx)
+            .task_type("test")
+            .task_context(create_mock_task_context())
+            .build()
+            .await
+            .unwrap();
+
+        let batch = create_batch(
+            vec![Some("us"), Some("eu"), Some("us")],
+            vec![Some(1), Some(2), Some(3)],
+        );
+        let event = EventBuilder::new()
+            .data(EventData::ArrowRecordBatch(batch))
+            .subject("input".to_string())
+            .task_id(0)
+            .task_type("test")
+            .build()
+            .unwrap();
+        in_tx.send(event).await.unwrap();
+        drop(in_tx);
+
+        // Closing the channel flushes the partial window.
+        crate::task::runner::Runner::run(processor).await.unwrap();
+
+        let mut results = Vec::new();
+        while let Ok(event) = out_rx.try_recv() {
+            let EventData::ArrowRecordBatch(batch) = event.data else {
+                panic!("expected record batch");
+            };
+            results.push(batch);
+        }
+        assert_eq!(results.len(), 2);
+
+        let region = |batch: &RecordBatch| {
+            batch
+                .column_by_name("region")
+                .unwrap()
+                .as_string::<i32>()
+                .value(0)
+                .to_string()
+        };
+        assert_eq!(region(&results[0]), "eu");
+        assert_eq!(region(&results[1]), "us");
+        assert_eq!(float_value(&results[1], "sum"), Some(4.0));
+    }
+
+    #[tokio::test]
+    async fn test_processor_completes_every_event_in_window() {
+        let (in_tx, in_rx) = mpsc::channel(10);
+        let (out_tx, mut out_rx) = mpsc::channel(10);
+
+        let processor = ProcessorBuilder::new()
+            .config(create_config(Duration::from_secs(3600)))
+            .receiver(in_rx)
+            .sender(out_tx)
+            .task_type("test")
+            .task_context(create_mock_task_context())
+            .build()
+            .await
+            .unwrap();
+
+        let mut source_receivers = Vec::new();
+        for region in ["us", "eu"] {
+            let (completion_tx, completion_rx) = crate::event::new_completion_channel(1);
+            let mut event = EventBuilder::new()
+                .data(EventData::ArrowRecordBatch(create_batch(
+                    vec![Some(region)],
+                    vec![Some(1)],
+                )))
+                .subject("input".to_string())
+                .task_id(0)
+                .task_type("test")
+                .build()
+                .unwrap();
+            event.completion_tx = Some(completion_tx);
+            source_receivers.push(completion_rx);
+            in_tx.send(event).await.unwrap();
+        }
+        drop(in_tx);
+
+        crate::task::runner::Runner::run(processor).await.unwrap();
+
+        // Completing the output events completes every input event.
+        while let Ok(event) = out_rx.try_recv() {
+            event.completion_tx.unwrap().signal_completion(None);
+        }
+        for rx in source_receivers {
+            tokio::time::timeout(Duration::from_secs(1), rx)
+                .await
+                .expect("input event was not completed")
+                .unwrap()
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_processor_builder_rejects_zero_window() {
+        let (_tx, rx) = mpsc::channel(1);
+        let result = ProcessorBuilder::new()
+            .config(create_config(Duration::ZERO))
+            .receiver(rx)
+            .task_type("test")
+            .task_context(create_mock_task_context())
+            .build()
+            .await;
+        assert!(matches!(result, Err(Error::ZeroWindow)));
+    }
+}
@@ -58,6 +58,8 @@ pub struct Processor {
     pub depends_on: Option<Vec<String>>,
     /// Optional retry configuration (overrides app-level retry config).
     pub retry: Option<crate::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    pub error_policy: Option<crate::error_policy::ErrorPolicy>,
 }
 
 /// Default timeout value of 30 seconds.
@@ -80,6 +82,7 @@ mod tests {
             partition_key: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         let json = serde_json::to_string(&processor).unwrap();
@@ -101,6 +104,7 @@ mod tests {
             partition_key: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         assert_eq!(processor.timeout, Some(Duration::from_secs(10)));
@@ -116,6 +120,7 @@ mod tests {
             partition_key: Some("{{event.data.program_id}}.{{event.data.country}}".to_string()),
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         assert_eq!(
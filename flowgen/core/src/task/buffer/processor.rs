@@ -584,6 +584,7 @@ mod tests {
             partition_key: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
         let (tx, rx) = mpsc::channel(100);
 
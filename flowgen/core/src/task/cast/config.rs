@@ -0,0 +1,62 @@
+//! Configuration for the cast task processor.
+//!
+//! The cast task changes the data type of selected columns, e.g. to turn the
+//! all-string columns read from CSV into the types a destination expects.
+
+use crate::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Configuration for cast processor task.
+///
+/// # Examples
+///
+/// ```yaml
+/// cast:
+///   name: "type_orders"
+///   casts:
+///     order_id: int64
+///     amount: float64
+///     paid: bool
+///     created_at: "timestamp[us, UTC]"
+/// ```
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Processor {
+    /// The unique name / identifier of the task.
+    pub name: String,
+    /// Target Arrow type per column name (e.g. `int64`, `timestamp[ms]`).
+    pub casts: HashMap<String, String>,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    #[serde(default)]
+    pub retry: Option<crate::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<crate::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Processor {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_processor_deserialization() {
+        let yaml = r#"
+name: type_orders
+casts:
+  order_id: int64
+  created_at: "timestamp[us, UTC]"
+"#;
+        let processor: Processor = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(processor.casts.len(), 2);
+        assert_eq!(processor.casts["order_id"], "int64");
+        assert_eq!(processor.casts["created_at"], "timestamp[us, UTC]");
+    }
+}
@@ -0,0 +1,488 @@
+//! Column type cast processor.
+//!
+//! Casts configured columns of Arrow RecordBatch events to new data types
+//! using Arrow compute casts. All other columns pass through unchanged.
+
+use crate::event::{Event, EventBuilder, EventData, EventExt};
+use arrow::array::{Array, ArrayRef, RecordBatch};
+use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+use arrow::util::display::{ArrayFormatter, FormatOptions};
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tracing::{error, Instrument};
+
+/// Errors that can occur during cast processing operations.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Arrow error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+    #[error("Invalid target type '{type_name}' for column '{column}'")]
+    InvalidType { column: String, type_name: String },
+    #[error("Cannot cast value '{value}' of column '{column}' to {target_type}")]
+    CastFailed {
+        column: String,
+        value: String,
+        target_type: String,
+    },
+    #[error("Cast requires Arrow record batch data, convert the event to Arrow first.")]
+    NotRecordBatch,
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+    #[error("Task failed after all retry attempts: {source}")]
+    RetryExhausted {
+        #[source]
+        source: Box<Error>,
+    },
+}
+
+/// Parses a target type name into an Arrow data type.
+///
+/// Accepts short names (`int64`, `float64`, `bool`, `string`, `date32`),
+/// timestamps as `timestamp[unit]` or `timestamp[unit, tz]` with unit one of
+/// `s`, `ms`, `us` or `ns`, and any type in Arrow's own notation
+/// (e.g. `Decimal128(10, 2)`).
+fn parse_data_type(type_name: &str) -> Option<DataType> {
+    let type_name = type_name.trim();
+    let data_type = match type_name.to_lowercase().as_str() {
+        "bool" | "boolean" => DataType::Boolean,
+        "int8" => DataType::Int8,
+        "int16" => DataType::Int16,
+        "int32" => DataType::Int32,
+        "int64" => DataType::Int64,
+        "uint8" => DataType::UInt8,
+        "uint16" => DataType::UInt16,
+        "uint32" => DataType::UInt32,
+        "uint64" => DataType::UInt64,
+        "float32" | "float" => DataType::Float32,
+        "float64" | "double" => DataType::Float64,
+        "string" | "utf8" => DataType::Utf8,
+        "large_string" | "large_utf8" => DataType::LargeUtf8,
+        "binary" => DataType::Binary,
+        "date32" | "date" => DataType::Date32,
+        "date64" => DataType::Date64,
+        lower if lower.starts_with("timestamp[") && lower.ends_with(']') => {
+            // Slice the original string so the time zone keeps its case.
+            let inner = &type_name["timestamp[".len()..type_name.len() - 1];
+            let (unit, tz) = match inner.split_once(',') {
+                Some((unit, tz)) => (unit, Some(tz.trim().into())),
+                None => (inner, None),
+            };
+            let unit = match unit.trim().to_lowercase().as_str() {
+                "s" => TimeUnit::Second,
+                "ms" => TimeUnit::Millisecond,
+                "us" => TimeUnit::Microsecond,
+                "ns" => TimeUnit::Nanosecond,
+                _ => return None,
+            };
+            DataType::Timestamp(unit, tz)
+        }
+        _ => return DataType::from_str(type_name).ok(),
+    };
+    Some(data_type)
+}
+
+/// Casts a single column, failing on the first value that cannot be converted.
+fn cast_column(column: &ArrayRef, name: &str, data_type: &DataType) -> Result<ArrayRef, Error> {
+    // Safe casts turn unconvertible values into nulls; compare validity to find them.
+    let casted = arrow::compute::cast(column.as_ref(), data_type)
+        .map_err(|source| Error::Arrow { source })?;
+    if let Some(index) = (0..column.len()).find(|&i| column.is_valid(i) && casted.is_null(i)) {
+        let value = ArrayFormatter::try_new(column.as_ref(), &FormatOptions::default())
+            .map(|formatter| formatter.value(index).to_string())
+            .unwrap_or_default();
+        return Err(Error::CastFailed {
+            column: name.to_string(),
+            value,
+            target_type: data_type.to_string(),
+        });
+    }
+    Ok(casted)
+}
+
+/// Casts the configured columns of a record batch.
+///
+/// Columns not present in the batch are ignored.
+fn cast_batch(batch: &RecordBatch, casts: &[(String, DataType)]) -> Result<RecordBatch, Error> {
+    let schema = batch.schema();
+    let mut fields: Vec<Field> = schema.fields().iter().map(|f| f.as_ref().clone()).collect();
+    let mut columns: Vec<ArrayRef> = batch.columns().to_vec();
+
+    for (column, data_type) in casts {
+        let Ok(index) = schema.index_of(column) else {
+            continue;
+        };
+        columns[index] = cast_column(&columns[index], column, data_type)?;
+        fields[index] = fields[index].clone().with_data_type(data_type.clone());
+    }
+
+    RecordBatch::try_new(
+        Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone())),
+        columns,
+    )
+    .map_err(|source| Error::Arrow { source })
+}
+
+/// Handles individual event processing by casting configured columns.
+pub struct EventHandler {
+    /// Parsed target types keyed by column name.
+    casts: Vec<(String, DataType)>,
+    /// Processor configuration settings.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for processed events (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event tracking.
+    task_id: usize,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+}
+
+impl EventHandler {
+    /// Processes an event by casting its configured columns.
+    #[tracing::instrument(skip(self, event), name = "task.handle")]
+    async fn handle(&self, event: Event) -> Result<(), Error> {
+        if self.task_context.cancellation_token.is_cancelled() {
+            return Ok(());
+        }
+
+        let event = Arc::new(event);
+        let completion_tx_arc = Arc::clone(&event).completion_tx.clone();
+        crate::event::with_event_context(&Arc::clone(&event), async move {
+            // Casting keeps the row count, so an empty input stays an empty output.
+            let event_builder = EventBuilder::new().allow_empty(true);
+            let data = match &event.data {
+                EventData::ArrowRecordBatch(batch) => {
+                    EventData::ArrowRecordBatch(cast_batch(batch, &self.casts)?)
+                }
+                EventData::Json(_) | EventData::Avro(_) => return Err(Error::NotRecordBatch),
+            };
+
+            let mut e = event_builder
+                .data(data)
+                .subject(self.config.name.to_owned())
+                .task_id(self.task_id)
+                .task_type(self.task_type)
+                .build()
+                .map_err(|source| Error::EventBuilder { source })?;
+
+            // Signal completion or pass through to next task.
+            match self.tx {
+                None => {
+                    if let Some(arc) = completion_tx_arc.as_ref() {
+                        arc.signal_completion(e.data_as_json().ok());
+                    }
+                }
+                Some(_) => {
+                    e.completion_tx = completion_tx_arc.clone();
+                }
+            }
+
+            e.send_with_logging(self.tx.as_ref())
+                .await
+                .map_err(|source| Error::SendMessage { source })?;
+            Ok(())
+        })
+        .await
+    }
+}
+
+/// Type cast processor that changes column data types.
+#[derive(Debug)]
+pub struct Processor {
+    /// Cast task configuration.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for casted events.
+    tx: Option<Sender<Event>>,
+    /// Channel receiver for incoming events to cast.
+    rx: Receiver<Event>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl crate::task::runner::Runner for Processor {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Initializes the processor by parsing the target types.
+    async fn init(&self) -> Result<Self::EventHandler, Self::Error> {
+        let mut casts = self
+            .config
+            .casts
+            .iter()
+            .map(|(column, type_name)| {
+                parse_data_type(type_name)
+                    .map(|data_type| (column.clone(), data_type))
+                    .ok_or_else(|| Error::InvalidType {
+                        column: column.clone(),
+                        type_name: type_name.clone(),
+                    })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        casts.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Ok(EventHandler {
+            casts,
+            config: Arc::clone(&self.config),
+            tx: self.tx.clone(),
+            task_id: self.task_id,
+            task_type: self.task_type,
+            task_context: Arc::clone(&self.task_context),
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(mut self) -> Result<(), Error> {
+        let retry_config =
+            crate::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        let event_handler = match tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+            match self.init().await {
+                Ok(handler) => Ok(handler),
+                Err(e @ Error::InvalidType { .. }) => {
+                    error!(error = %e, "Invalid cast configuration");
+                    Err(tokio_retry::RetryError::permanent(e))
+                }
+                Err(e) => {
+                    error!(error = %e, "Failed to initialize cast processor");
+                    Err(tokio_retry::RetryError::transient(e))
+                }
+            }
+        })
+        .await
+        {
+            Ok(handler) => Arc::new(handler),
+            Err(e) => {
+                return Err(e);
+            }
+        };
+
+        loop {
+            match self.rx.recv().await {
+                Some(event) => {
+                    let event_handler = Arc::clone(&event_handler);
+                    let retry_strategy = retry_config.strategy();
+                    tokio::spawn(
+                        async move {
+                            let result = tokio_retry::Retry::spawn(retry_strategy, || async {
+                                match event_handler.handle(event.clone()).await {
+                                    Ok(result) => Ok(result),
+                                    Err(e) => {
+                                        error!(error = %e, "Failed to cast event");
+                                        Err(tokio_retry::RetryError::transient(e))
+                                    }
+                                }
+                            })
+                            .await;
+
+                            if let Err(err) = result {
+                                error!(error = %err, "Cast failed after all retry attempts.");
+                                // Emit error event downstream for error handling.
+                                let mut error_event = event.clone();
+                                error_event.error = Some(err.to_string());
+                                if let Some(ref tx) = event_handler.tx {
+                                    tx.send(error_event).await.ok();
+                                }
+                            }
+                        }
+                        .instrument(tracing::Span::current()),
+                    );
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Builder for constructing Processor instances with validation.
+#[derive(Debug, Default)]
+pub struct ProcessorBuilder {
+    /// Processor configuration (required for build).
+    config: Option<Arc<super::config::Processor>>,
+    /// Event sender for passing events to next task (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Event receiver for incoming events (required for build).
+    rx: Option<Receiver<Event>>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Option<Arc<crate::task::context::TaskContext>>,
+    /// Task type for event categorization and logging.
+    task_type: Option<&'static str>,
+}
+
+impl ProcessorBuilder {
+    pub fn new() -> ProcessorBuilder {
+        ProcessorBuilder {
+            ..Default::default()
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Processor>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn receiver(mut self, receiver: Receiver<Event>) -> Self {
+        self.rx = Some(receiver);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = task_id;
+        self
+    }
+
+    pub fn task_context(mut self, task_context: Arc<crate::task::context::TaskContext>) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Processor, Error> {
+        Ok(Processor {
+            config: self
+                .config
+                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            rx: self
+                .rx
+                .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
+            tx: self.tx,
+            task_id: self.task_id,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{AsArray, StringArray};
+    use arrow::datatypes::{Float64Type, Int64Type, TimestampMicrosecondType};
+
+    fn casts(entries: &[(&str, &str)]) -> Vec<(String, DataType)> {
+        entries
+            .iter()
+            .map(|(column, type_name)| (column.to_string(), parse_data_type(type_name).unwrap()))
+            .collect()
+    }
+
+    fn string_batch(columns: Vec<(&str, Vec<Option<&str>>)>) -> RecordBatch {
+        let fields = columns
+            .iter()
+            .map(|(name, _)| Field::new(*name, DataType::Utf8, true))
+            .collect::<Vec<_>>();
+        let arrays = columns
+            .into_iter()
+            .map(|(_, values)| Arc::new(StringArray::from(values)) as ArrayRef)
+            .collect();
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays).unwrap()
+    }
+
+    #[test]
+    fn test_parse_data_type() {
+        assert_eq!(parse_data_type("int64"), Some(DataType::Int64));
+        assert_eq!(parse_data_type("Float64"), Some(DataType::Float64));
+        assert_eq!(parse_data_type("bool"), Some(DataType::Boolean));
+        assert_eq!(
+            parse_data_type("timestamp[us, UTC]"),
+            Some(DataType::Timestamp(
+                TimeUnit::Microsecond,
+                Some("UTC".into())
+            ))
+        );
+        assert_eq!(
+            parse_data_type("timestamp[ms]"),
+            Some(DataType::Timestamp(TimeUnit::Millisecond, None))
+        );
+        assert_eq!(
+            parse_data_type("Decimal128(10, 2)"),
+            Some(DataType::Decimal128(10, 2))
+        );
+        assert_eq!(parse_data_type("timestamp[weeks]"), None);
+        assert_eq!(parse_data_type("not_a_type"), None);
+    }
+
+    #[test]
+    fn test_cast_batch() {
+        let batch = string_batch(vec![
+            ("id", vec![Some("1"), Some("2")]),
+            ("amount", vec![Some("9.5"), None]),
+            ("created_at", vec![Some("2024-01-02T03:04:05Z"), None]),
+            ("note", vec![Some("a"), Some("b")]),
+        ]);
+        let casts = casts(&[
+            ("id", "int64"),
+            ("amount", "float64"),
+            ("created_at", "timestamp[us, UTC]"),
+            ("missing", "int64"),
+        ]);
+
+        let casted = cast_batch(&batch, &casts).unwrap();
+
+        assert_eq!(casted.num_columns(), 4);
+        assert_eq!(casted.column(0).as_primitive::<Int64Type>().value(1), 2);
+        assert_eq!(casted.column(1).as_primitive::<Float64Type>().value(0), 9.5);
+        assert!(casted.column(1).is_null(1));
+        assert_eq!(
+            casted
+                .column(2)
+                .as_primitive::<TimestampMicrosecondType>()
+                .value(0),
+            1_704_164_645_000_000
+        );
+        assert_eq!(casted.schema().field(3).data_type(), &DataType::Utf8);
+    }
+
+    #[test]
+    fn test_cast_batch_reports_first_failing_value() {
+        let batch = string_batch(vec![("id", vec![Some("1"), None, Some("abc"), Some("x")])]);
+
+        match cast_batch(&batch, &casts(&[("id", "int64")])) {
+            Err(Error::CastFailed {
+                column,
+                value,
+                target_type,
+            }) => {
+                assert_eq!(column, "id");
+                assert_eq!(value, "abc");
+                assert_eq!(target_type, "Int64");
+            }
+            other => panic!("expected CastFailed, got {other:?}"),
+        }
+    }
+}
@@ -43,6 +43,8 @@ pub struct TaskContext {
     pub retry: Option<crate::retry::RetryConfig>,
     /// Cancellation token for graceful shutdown coordination.
     pub cancellation_token: tokio_util::sync::CancellationToken,
+    /// Throughput counters shared by all tasks in the flow.
+    pub metrics: crate::metrics::FlowMetrics,
     /// Number of leaf tasks in this flow's directed acyclic graph.
     ///
     /// Source tasks use this when constructing the completion channel so the
@@ -50,6 +52,12 @@ pub struct TaskContext {
     /// Defaults to one for linear flows where a single terminal task signals
     /// completion.
     pub leaf_count: usize,
+    /// Whether the flow runs in dry-run mode.
+    ///
+    /// Publishers are replaced by pass-through tasks in dry-run mode, but
+    /// sources with side effects of their own (such as moving a file once it
+    /// was processed) check this flag to skip them.
+    pub dry_run: bool,
 }
 
 impl std::fmt::Debug for TaskContext {
@@ -76,6 +84,8 @@ impl std::fmt::Debug for TaskContext {
             .field("resource_loader", &self.resource_loader)
             .field("retry", &self.retry)
             .field("cancellation_token", &"<CancellationToken>")
+            .field("metrics", &self.metrics)
+            .field("dry_run", &self.dry_run)
             .finish()
     }
 }
@@ -103,8 +113,12 @@ pub struct TaskContextBuilder {
     retry: Option<crate::retry::RetryConfig>,
     /// Cancellation token for graceful shutdown coordination.
     cancellation_token: Option<tokio_util::sync::CancellationToken>,
+    /// Throughput counters shared by all tasks in the flow.
+    metrics: Option<crate::metrics::FlowMetrics>,
     /// Number of leaf tasks in the flow. Defaults to one when not set.
     leaf_count: Option<usize>,
+    /// Whether the flow runs in dry-run mode. Defaults to false.
+    dry_run: bool,
 }
 
 impl TaskContextBuilder {
@@ -228,6 +242,24 @@ impl TaskContextBuilder {
         self
     }
 
+    /// Sets whether the flow runs in dry-run mode.
+    ///
+    /// # Arguments
+    /// * `dry_run` - True when publishers are replaced by pass-through tasks
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Sets the flow's throughput counters.
+    ///
+    /// # Arguments
+    /// * `metrics` - Counters shared by all tasks in the flow
+    pub fn metrics(mut self, metrics: crate::metrics::FlowMetrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
     /// Builds the TaskContext instance.
     ///
     /// # Errors
@@ -252,7 +284,9 @@ impl TaskContextBuilder {
             resource_loader: self.resource_loader,
             retry: self.retry,
             cancellation_token: self.cancellation_token.unwrap_or_default(),
+            metrics: self.metrics.unwrap_or_default(),
             leaf_count: self.leaf_count.unwrap_or(1),
+            dry_run: self.dry_run,
         })
     }
 }
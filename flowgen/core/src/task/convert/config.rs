@@ -15,6 +15,11 @@ pub struct Processor {
     /// Optional schema definition for target format validation.
     /// Can be specified as inline schema or loaded from external resource file.
     pub schema: Option<crate::resource::Source>,
+    /// Optional name of a schema in the global schema registry (Arrow target only).
+    /// Used when no inline `schema` is given; events pass through unchanged until
+    /// a schema with this name has been registered, e.g. by an object store writer.
+    #[serde(default)]
+    pub schema_name: Option<String>,
     /// Optional list of upstream task names this task depends on.
     /// When set, this task only receives events from the named tasks.
     /// When not set, the task receives from the previous task in the list (linear chain).
@@ -23,6 +28,9 @@ pub struct Processor {
     /// Optional retry configuration (overrides app-level retry config).
     #[serde(default)]
     pub retry: Option<crate::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<crate::error_policy::ErrorPolicy>,
 }
 
 /// Supported target formats for event data conversion.
@@ -136,6 +136,22 @@ enum SchemaConfig {
 }
 
 impl EventHandler {
+    /// Returns the Arrow schema batches are cast to.
+    ///
+    /// A configured schema takes precedence; otherwise `schema_name` is looked up in
+    /// the global schema registry on every event, since the schema may be registered
+    /// by another task after this one started.
+    fn target_arrow_schema(&self) -> Option<Arc<Schema>> {
+        match &self.schema_config {
+            SchemaConfig::Arrow(schema) => Some(Arc::clone(schema)),
+            _ => self
+                .config
+                .schema_name
+                .as_deref()
+                .and_then(|name| crate::schema::SchemaRegistry::global().get(name)),
+        }
+    }
+
     /// Processes an event and converts to selected target format.
     #[tracing::instrument(skip(self, event), name = "task.handle")]
     async fn handle(&self, event: Event) -> Result<(), Error> {
@@ -146,7 +162,8 @@ impl EventHandler {
         let event = Arc::new(event);
         let completion_tx_arc = Arc::clone(&event).completion_tx.clone();
         crate::event::with_event_context(&Arc::clone(&event), async move {
-            let event_builder = EventBuilder::new();
+            // Conversion keeps the row count, so an empty input stays an empty output.
+            let event_builder = EventBuilder::new().allow_empty(true);
             let data = match &event.data {
                 EventData::Json(data) => match self.config.target_format {
                     crate::task::convert::config::TargetFormat::Avro => match &self.schema_config {
@@ -185,8 +202,8 @@ impl EventHandler {
                         return Err(Error::ArrowToAvroNotSupported)
                     }
                     crate::task::convert::config::TargetFormat::Arrow => {
-                        match &self.schema_config {
-                            SchemaConfig::Arrow(target_schema) => {
+                        match self.target_arrow_schema() {
+                            Some(ref target_schema) => {
                                 // Handle empty batches (e.g., API returns only headers with no data rows)
                                 // by creating an empty batch with the target schema.
                                 if batch.num_columns() == 0 || batch.num_rows() == 0 {
@@ -236,7 +253,7 @@ impl EventHandler {
                                     EventData::ArrowRecordBatch(casted_batch)
                                 }
                             }
-                            _ => EventData::ArrowRecordBatch(batch.clone()),
+                            None => EventData::ArrowRecordBatch(batch.clone()),
                         }
                     }
                 },
@@ -601,8 +618,10 @@ mod tests {
             schema: Some(crate::resource::Source::Inline(
                 r#"{"type": "string"}"#.to_string(),
             )),
+            schema_name: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
         let (tx, rx) = mpsc::channel(100);
 
@@ -638,8 +657,10 @@ mod tests {
             name: "test".to_string(),
             target_format: crate::task::convert::config::TargetFormat::Avro,
             schema: None,
+            schema_name: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
 
         let (tx, mut rx) = mpsc::channel(100);
@@ -663,6 +684,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
         };
 
         tokio::spawn(async move {
@@ -687,8 +709,10 @@ mod tests {
             name: "test".to_string(),
             target_format: crate::task::convert::config::TargetFormat::Json,
             schema: None,
+            schema_name: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
 
         let (tx, mut rx) = mpsc::channel(100);
@@ -724,6 +748,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
         };
 
         tokio::spawn(async move {
@@ -748,8 +773,10 @@ mod tests {
             name: "test".to_string(),
             target_format: crate::task::convert::config::TargetFormat::Avro,
             schema: None,
+            schema_name: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
 
         let (tx, mut rx) = mpsc::channel(100);
@@ -779,6 +806,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
         };
 
         tokio::spawn(async move {
@@ -797,4 +825,75 @@ mod tests {
         assert_eq!(output_event.subject, "test");
         assert_eq!(output_event.task_id, 1);
     }
+
+    #[tokio::test]
+    async fn test_event_handler_arrow_registry_schema() {
+        use arrow::array::{Int64Array, StringArray};
+        use arrow::datatypes::{DataType, Field};
+
+        let config = Arc::new(crate::task::convert::config::Processor {
+            name: "test".to_string(),
+            target_format: crate::task::convert::config::TargetFormat::Arrow,
+            schema: None,
+            schema_name: Some("test_convert_registry_orders".to_string()),
+            depends_on: None,
+            retry: None,
+            error_policy: None,
+        });
+
+        let (tx, mut rx) = mpsc::channel(100);
+
+        let event_handler = EventHandler {
+            config,
+            tx: Some(tx),
+            task_id: 1,
+            schema_config: SchemaConfig::None,
+            task_type: "test",
+            task_context: create_mock_task_context(),
+        };
+
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new("id", DataType::Utf8, true)])),
+            vec![Arc::new(StringArray::from(vec!["1", "2"]))],
+        )
+        .unwrap();
+        let input_event = |batch: RecordBatch| Event {
+            data: EventData::ArrowRecordBatch(batch),
+            subject: "input.subject".to_string(),
+            task_id: 0,
+            id: None,
+            timestamp: 123456789,
+            task_type: "test",
+            meta: None,
+            error: None,
+            completion_tx: None,
+            span: None,
+        };
+
+        // Not registered yet, so the batch passes through unchanged.
+        event_handler
+            .handle(input_event(batch.clone()))
+            .await
+            .unwrap();
+        match rx.recv().await.unwrap().data {
+            EventData::ArrowRecordBatch(output) => assert_eq!(output, batch),
+            _ => panic!("Expected Arrow passthrough"),
+        }
+
+        crate::schema::SchemaRegistry::global().register(
+            "test_convert_registry_orders",
+            Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, true)])),
+        );
+        event_handler.handle(input_event(batch)).await.unwrap();
+        match rx.recv().await.unwrap().data {
+            EventData::ArrowRecordBatch(output) => {
+                assert_eq!(output.schema().field(0).data_type(), &DataType::Int64);
+                assert_eq!(
+                    output.column(0).as_ref(),
+                    &Int64Array::from(vec![1, 2]) as &dyn arrow::array::Array
+                );
+            }
+            _ => panic!("Expected Arrow batch"),
+        }
+    }
 }
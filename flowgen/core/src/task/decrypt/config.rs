@@ -0,0 +1,76 @@
+//! Configuration for the decrypt task processor.
+//!
+//! The decrypt task restores columns encrypted by the encrypt task. It must be
+//! configured with the same algorithm and key reference.
+
+use crate::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+
+use crate::task::encrypt::config::EncryptAlgorithm;
+
+/// Configuration for decrypt processor task.
+///
+/// # Examples
+///
+/// Key read from a file:
+/// ```yaml
+/// decrypt:
+///   name: "decrypt_pii"
+///   decrypt_columns:
+///     - ssn
+///     - card_number
+///   key_ref: "/etc/flowgen/keys/pii.key"
+/// ```
+///
+/// Key read from the flow cache (NATS KV):
+/// ```yaml
+/// decrypt:
+///   name: "decrypt_pii"
+///   decrypt_columns:
+///     - ssn
+///   algorithm: chacha20_poly1305
+///   key_ref: "cache:keys.pii"
+/// ```
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct Processor {
+    /// Unique name identifier for this decrypt task.
+    pub name: String,
+    /// Columns (or top-level JSON fields) to decrypt.
+    pub decrypt_columns: Vec<String>,
+    /// Cipher the values were encrypted with (default: aes_256_gcm).
+    #[serde(default)]
+    pub algorithm: EncryptAlgorithm,
+    /// Key material reference, identical to the one used by the encrypt task.
+    pub key_ref: String,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    pub retry: Option<crate::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    pub error_policy: Option<crate::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Processor {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_processor_deserialization() {
+        let yaml = r#"
+name: decrypt_pii
+decrypt_columns: [ssn]
+algorithm: chacha20_poly1305
+key_ref: /etc/flowgen/keys/pii.key
+"#;
+        let processor: Processor = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(processor.decrypt_columns, vec!["ssn"]);
+        assert_eq!(processor.algorithm, EncryptAlgorithm::ChaCha20Poly1305);
+        assert_eq!(processor.key_ref, "/etc/flowgen/keys/pii.key");
+    }
+}
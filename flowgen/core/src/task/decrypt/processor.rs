@@ -0,0 +1,383 @@
+//! Column decryption processor.
+//!
+//! Reverses the encrypt task: decrypts the configured columns of Arrow
+//! RecordBatch events (or top-level fields of JSON events) back to plaintext
+//! strings using the same key derivation.
+
+use crate::event::{Event, EventBuilder, EventData, EventExt};
+use crate::task::encrypt::cipher::{self, Cipher};
+use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tracing::{error, Instrument};
+
+/// Errors that can occur during decrypt processing operations.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error(transparent)]
+    Cipher(#[from] cipher::Error),
+    #[error("Avro event data cannot be decrypted, convert it to JSON or Arrow first.")]
+    AvroNotSupported,
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+    #[error("Task failed after all retry attempts: {source}")]
+    RetryExhausted {
+        #[source]
+        source: Box<Error>,
+    },
+}
+
+/// Handles individual event processing by decrypting configured columns.
+pub struct EventHandler {
+    /// Cipher derived from the configured key.
+    cipher: Cipher,
+    /// Processor configuration settings.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for processed events (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event tracking.
+    task_id: usize,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+}
+
+impl EventHandler {
+    /// Processes an event by decrypting its configured columns.
+    #[tracing::instrument(skip(self, event), name = "task.handle")]
+    async fn handle(&self, event: Event) -> Result<(), Error> {
+        if self.task_context.cancellation_token.is_cancelled() {
+            return Ok(());
+        }
+
+        let event = Arc::new(event);
+        let completion_tx_arc = Arc::clone(&event).completion_tx.clone();
+        crate::event::with_event_context(&Arc::clone(&event), async move {
+            let columns = &self.config.decrypt_columns;
+            let decrypt = |value: &str| self.cipher.decrypt(value);
+            let data = match &event.data {
+                EventData::ArrowRecordBatch(batch) => {
+                    EventData::ArrowRecordBatch(cipher::transform_batch(batch, columns, decrypt)?)
+                }
+                EventData::Json(data) => {
+                    let mut data = data.clone();
+                    cipher::transform_json(&mut data, columns, &decrypt)?;
+                    EventData::Json(data)
+                }
+                EventData::Avro(_) => return Err(Error::AvroNotSupported),
+            };
+
+            // Decryption keeps the row count, so an empty input stays an empty output.
+            let mut e = EventBuilder::new()
+                .data(data)
+                .allow_empty(true)
+                .subject(self.config.name.to_owned())
+                .task_id(self.task_id)
+                .task_type(self.task_type)
+                .build()
+                .map_err(|source| Error::EventBuilder { source })?;
+
+            // Signal completion or pass through to next task.
+            match self.tx {
+                None => {
+                    if let Some(arc) = completion_tx_arc.as_ref() {
+                        arc.signal_completion(e.data_as_json().ok());
+                    }
+                }
+                Some(_) => {
+                    e.completion_tx = completion_tx_arc.clone();
+                }
+            }
+
+            e.send_with_logging(self.tx.as_ref())
+                .await
+                .map_err(|source| Error::SendMessage { source })?;
+            Ok(())
+        })
+        .await
+    }
+}
+
+/// Column decryption processor.
+#[derive(Debug)]
+pub struct Processor {
+    /// Decrypt task configuration.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for decrypted events.
+    tx: Option<Sender<Event>>,
+    /// Channel receiver for incoming events to decrypt.
+    rx: Receiver<Event>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl crate::task::runner::Runner for Processor {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Initializes the processor by loading the key and deriving the cipher.
+    async fn init(&self) -> Result<Self::EventHandler, Self::Error> {
+        let cipher = Cipher::load(
+            &self.config.key_ref,
+            self.config.algorithm,
+            self.task_context.cache.as_ref(),
+        )
+        .await?;
+
+        Ok(EventHandler {
+            cipher,
+            config: Arc::clone(&self.config),
+            tx: self.tx.clone(),
+            task_id: self.task_id,
+            task_type: self.task_type,
+            task_context: Arc::clone(&self.task_context),
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(mut self) -> Result<(), Error> {
+        let retry_config =
+            crate::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        let event_handler = match tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+            match self.init().await {
+                Ok(handler) => Ok(handler),
+                Err(e @ Error::Cipher(cipher::Error::KeyTooShort(_))) => {
+                    error!(error = %e, "Invalid encryption key");
+                    Err(tokio_retry::RetryError::permanent(e))
+                }
+                Err(e) => {
+                    error!(error = %e, "Failed to initialize decrypt processor");
+                    Err(tokio_retry::RetryError::transient(e))
+                }
+            }
+        })
+        .await
+        {
+            Ok(handler) => Arc::new(handler),
+            Err(e) => {
+                return Err(e);
+            }
+        };
+
+        loop {
+            match self.rx.recv().await {
+                Some(event) => {
+                    let event_handler = Arc::clone(&event_handler);
+                    let retry_strategy = retry_config.strategy();
+                    tokio::spawn(
+                        async move {
+                            let result = tokio_retry::Retry::spawn(retry_strategy, || async {
+                                match event_handler.handle(event.clone()).await {
+                                    Ok(result) => Ok(result),
+                                    Err(e) => {
+                                        error!(error = %e, "Failed to decrypt event");
+                                        Err(tokio_retry::RetryError::transient(e))
+                                    }
+                                }
+                            })
+                            .await;
+
+                            if let Err(err) = result {
+                                error!(error = %err, "Decrypt failed after all retry attempts.");
+                                // Emit error event downstream for error handling.
+                                let mut error_event = event.clone();
+                                error_event.error = Some(err.to_string());
+                                if let Some(ref tx) = event_handler.tx {
+                                    tx.send(error_event).await.ok();
+                                }
+                            }
+                        }
+                        .instrument(tracing::Span::current()),
+                    );
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Builder for constructing Processor instances with validation.
+#[derive(Debug, Default)]
+pub struct ProcessorBuilder {
+    /// Processor configuration (required for build).
+    config: Option<Arc<super::config::Processor>>,
+    /// Event sender for passing events to next task (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Event receiver for incoming events (required for build).
+    rx: Option<Receiver<Event>>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Option<Arc<crate::task::context::TaskContext>>,
+    /// Task type for event categorization and logging.
+    task_type: Option<&'static str>,
+}
+
+impl ProcessorBuilder {
+    pub fn new() -> ProcessorBuilder {
+        ProcessorBuilder {
+            ..Default::default()
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Processor>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn receiver(mut self, receiver: Receiver<Event>) -> Self {
+        self.rx = Some(receiver);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = task_id;
+        self
+    }
+
+    pub fn task_context(mut self, task_context: Arc<crate::task::context::TaskContext>) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Processor, Error> {
+        Ok(Processor {
+            config: self
+                .config
+                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            rx: self
+                .rx
+                .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
+            tx: self.tx,
+            task_id: self.task_id,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::encrypt::config::EncryptAlgorithm;
+    use crate::task::runner::Runner;
+    use arrow::array::{Array, AsArray, RecordBatch, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use serde_json::{Map, Value};
+    use tokio::sync::mpsc;
+
+    const MATERIAL: &[u8] = b"0123456789abcdef0123456789abcdef";
+
+    fn create_mock_task_context() -> Arc<crate::task::context::TaskContext> {
+        let mut labels = Map::new();
+        labels.insert(
+            "description".to_string(),
+            Value::String("Decrypt Test".to_string()),
+        );
+        let task_manager = Arc::new(
+            crate::task::manager::TaskManagerBuilder::new()
+                .build()
+                .unwrap(),
+        );
+        let cache =
+            Arc::new(crate::cache::memory::MemoryCache::new()) as Arc<dyn crate::cache::Cache>;
+        Arc::new(
+            crate::task::context::TaskContextBuilder::new()
+                .flow_name("test-flow".to_string())
+                .flow_labels(Some(labels))
+                .task_manager(task_manager)
+                .cache(cache)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_decrypt_batch() {
+        let task_context = create_mock_task_context();
+        task_context
+            .cache
+            .put("keys.pii", bytes::Bytes::from_static(MATERIAL), None)
+            .await
+            .unwrap();
+        let cipher = Cipher::from_material(MATERIAL, EncryptAlgorithm::ChaCha20Poly1305).unwrap();
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new("ssn", DataType::Utf8, true)])),
+            vec![Arc::new(StringArray::from(vec![
+                Some(cipher.encrypt("123-45-6789").unwrap()),
+                None,
+            ]))],
+        )
+        .unwrap();
+
+        let (_in_tx, in_rx) = mpsc::channel(1);
+        let (out_tx, mut out_rx) = mpsc::channel(1);
+        let handler = ProcessorBuilder::new()
+            .config(Arc::new(super::super::config::Processor {
+                name: "decrypt_pii".to_string(),
+                decrypt_columns: vec!["ssn".to_string()],
+                algorithm: EncryptAlgorithm::ChaCha20Poly1305,
+                key_ref: "cache:keys.pii".to_string(),
+                depends_on: None,
+                retry: None,
+                error_policy: None,
+            }))
+            .receiver(in_rx)
+            .sender(out_tx)
+            .task_type("decrypt")
+            .task_context(task_context)
+            .build()
+            .await
+            .unwrap()
+            .init()
+            .await
+            .unwrap();
+
+        let event = EventBuilder::new()
+            .data(EventData::ArrowRecordBatch(batch))
+            .subject("test".to_string())
+            .task_id(0)
+            .task_type("test")
+            .build()
+            .unwrap();
+        handler.handle(event).await.unwrap();
+
+        let EventData::ArrowRecordBatch(decrypted) = out_rx.recv().await.unwrap().data else {
+            panic!("expected arrow data");
+        };
+        let ssn = decrypted.column(0).as_string::<i32>();
+        assert_eq!(ssn.value(0), "123-45-6789");
+        assert!(ssn.is_null(1));
+    }
+}
@@ -0,0 +1,99 @@
+//! Configuration for the dedup task processor.
+//!
+//! The dedup task drops rows whose key columns were already seen within a
+//! configurable window, so retried or replayed upstream data is forwarded once.
+
+use crate::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Configuration for dedup processor task.
+///
+/// # Examples
+///
+/// In-process deduplication:
+/// ```yaml
+/// dedup:
+///   name: "dedup_orders"
+///   key_columns:
+///     - order_id
+///   window: "10m"
+/// ```
+///
+/// Deduplication shared by all workers through the flow cache:
+/// ```yaml
+/// dedup:
+///   name: "dedup_orders"
+///   key_columns:
+///     - account_id
+///     - order_id
+///   window: "1h"
+///   backend: cache
+/// ```
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct Processor {
+    /// Unique name identifier for this dedup task.
+    pub name: String,
+    /// Columns (or top-level JSON fields) that together identify a record.
+    pub key_columns: Vec<String>,
+    /// How long a seen key is remembered. Accepts duration strings: "30s", "10m", "1h", etc.
+    #[serde(with = "humantime_serde")]
+    pub window: Duration,
+    /// Where seen keys are stored (default: memory).
+    #[serde(default)]
+    pub backend: DedupBackend,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    pub retry: Option<crate::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    pub error_policy: Option<crate::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Processor {}
+
+/// Storage for seen keys.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DedupBackend {
+    /// Keys are kept in process memory and are not shared between workers.
+    #[default]
+    Memory,
+    /// Keys are stored in the flow cache (NATS KV when the app cache is enabled)
+    /// and expire through the per-key TTL.
+    Cache,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_processor_deserialization() {
+        let yaml = r#"
+name: dedup_orders
+key_columns: [account_id, order_id]
+window: 1h
+backend: cache
+"#;
+        let processor: Processor = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(processor.key_columns, vec!["account_id", "order_id"]);
+        assert_eq!(processor.window, Duration::from_secs(3600));
+        assert_eq!(processor.backend, DedupBackend::Cache);
+    }
+
+    #[test]
+    fn test_processor_default_backend() {
+        let yaml = r#"
+name: dedup_orders
+key_columns: [order_id]
+window: 10m
+"#;
+        let processor: Processor = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(processor.backend, DedupBackend::Memory);
+    }
+}
@@ -0,0 +1,824 @@
+//! Deduplication processor for dropping repeated records.
+//!
+//! Computes a composite key from the configured columns of each row (or JSON
+//! object), remembers keys for the configured window and forwards only rows
+//! whose key has not been seen. Events left with no rows are dropped.
+
+use super::config::DedupBackend;
+use crate::event::{Event, EventBuilder, EventData, EventExt, SharedCompletionTx};
+use arrow::array::{Array, AsArray, BooleanArray, RecordBatch};
+use arrow::datatypes::DataType;
+use serde_json::Value;
+use sha2::Digest;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{Receiver, Sender};
+use tracing::{debug, error, warn, Instrument};
+
+/// Errors that can occur during dedup processing operations.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Key column '{0}' not found in record batch")]
+    MissingKeyColumn(String),
+    #[error("Arrow error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+    #[error("Dedup cache error: {source}")]
+    Cache {
+        #[source]
+        source: crate::cache::Error,
+    },
+    #[error("Avro event data cannot be deduplicated, convert it to JSON or Arrow first.")]
+    AvroNotSupported,
+    #[error("Dedup window must be greater than zero")]
+    ZeroWindow,
+    #[error("At least one key column must be configured")]
+    EmptyKeyColumns,
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+}
+
+/// Hashes the key column values of one record into a fixed-length key.
+///
+/// Values are encoded as a JSON array before hashing so `["a,b"]` and
+/// `["a", "b"]` cannot collide, and nulls stay distinct from the string `"null"`.
+fn hash_key(parts: &[Option<&str>]) -> String {
+    let encoded = serde_json::to_vec(parts).unwrap_or_default();
+    format!("{:x}", sha2::Sha256::digest(&encoded))
+}
+
+/// Computes one key per row of a record batch.
+fn batch_keys(batch: &RecordBatch, key_columns: &[String]) -> Result<Vec<String>, Error> {
+    let columns = key_columns
+        .iter()
+        .map(|name| {
+            let column = batch
+                .column_by_name(name)
+                .ok_or_else(|| Error::MissingKeyColumn(name.clone()))?;
+            arrow::compute::cast(column.as_ref(), &DataType::Utf8)
+                .map_err(|source| Error::Arrow { source })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    Ok((0..batch.num_rows())
+        .map(|row| {
+            let parts: Vec<Option<&str>> = columns
+                .iter()
+                .map(|column| {
+                    let strings = column.as_string::<i32>();
+                    (!strings.is_null(row)).then(|| strings.value(row))
+                })
+                .collect();
+            hash_key(&parts)
+        })
+        .collect())
+}
+
+/// Computes the key of a JSON object. Missing fields count as null.
+fn json_key(value: &Value, key_columns: &[String]) -> String {
+    let parts: Vec<Option<String>> = key_columns
+        .iter()
+        .map(|name| match value.get(name) {
+            None | Some(Value::Null) => None,
+            Some(Value::String(s)) => Some(s.clone()),
+            Some(other) => Some(other.to_string()),
+        })
+        .collect();
+    let parts: Vec<Option<&str>> = parts.iter().map(|p| p.as_deref()).collect();
+    hash_key(&parts)
+}
+
+/// Seen keys held in process memory with their expiry instants.
+#[derive(Debug)]
+struct MemoryStore {
+    seen: HashMap<String, Instant>,
+    window: Duration,
+    last_pruned: Instant,
+}
+
+impl MemoryStore {
+    fn new(window: Duration, now: Instant) -> Self {
+        Self {
+            seen: HashMap::new(),
+            window,
+            last_pruned: now,
+        }
+    }
+
+    /// Records the key and returns whether it was unseen within the window.
+    fn insert(&mut self, key: String, now: Instant) -> bool {
+        // Expired keys are swept at most once per window to keep inserts cheap.
+        if now.duration_since(self.last_pruned) >= self.window {
+            self.seen.retain(|_, expires_at| *expires_at > now);
+            self.last_pruned = now;
+        }
+        match self.seen.get(&key) {
+            Some(expires_at) if *expires_at > now => false,
+            _ => {
+                self.seen.insert(key, now + self.window);
+                true
+            }
+        }
+    }
+}
+
+/// Backend holding the keys seen by this task.
+#[derive(Debug)]
+enum Store {
+    Memory(Mutex<MemoryStore>),
+    Cache {
+        cache: Arc<dyn crate::cache::Cache>,
+        /// Key prefix scoping entries to this flow and task.
+        prefix: String,
+        /// Per-key TTL derived from the window, rounded up to whole seconds.
+        ttl_secs: u64,
+    },
+}
+
+impl Store {
+    /// Records the keys and returns, for each one, whether it was unseen.
+    ///
+    /// On error the keys recorded by this call are removed again.
+    async fn insert_all(&self, keys: &[String]) -> Result<Vec<bool>, Error> {
+        match self {
+            Store::Memory(store) => {
+                let now = Instant::now();
+                let mut store = store.lock().unwrap_or_else(|e| e.into_inner());
+                Ok(keys
+                    .iter()
+                    .map(|key| store.insert(key.clone(), now))
+                    .collect())
+            }
+            Store::Cache {
+                cache,
+                prefix,
+                ttl_secs,
+            } => {
+                let mut unseen = Vec::with_capacity(keys.len());
+                for key in keys {
+                    // `create` is atomic, so workers sharing the cache agree on
+                    // which of them saw a key first.
+                    let result = cache
+                        .create(
+                            &format!("{prefix}.{key}"),
+                            bytes::Bytes::from_static(b"1"),
+                            Some(*ttl_secs),
+                        )
+                        .await;
+                    match result {
+                        Ok(_) => unseen.push(true),
+                        Err(crate::cache::CacheError::AlreadyExists) => unseen.push(false),
+                        Err(source) => {
+                            self.remove_all(&recorded_keys(keys, &unseen)).await;
+                            return Err(Error::Cache { source });
+                        }
+                    }
+                }
+                Ok(unseen)
+            }
+        }
+    }
+
+    /// Forgets keys recorded for records that were not delivered, so a retry
+    /// does not drop them as duplicates.
+    ///
+    /// Failures are logged; a key that cannot be removed expires with the window.
+    async fn remove_all(&self, keys: &[String]) {
+        match self {
+            Store::Memory(store) => {
+                let mut store = store.lock().unwrap_or_else(|e| e.into_inner());
+                for key in keys {
+                    store.seen.remove(key);
+                }
+            }
+            Store::Cache { cache, prefix, .. } => {
+                for key in keys {
+                    if let Err(e) = cache.delete(&format!("{prefix}.{key}")).await {
+                        warn!(error = %e, "Failed to remove dedup key after a failed attempt");
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the keys `insert_all` recorded as unseen.
+fn recorded_keys(keys: &[String], unseen: &[bool]) -> Vec<String> {
+    keys.iter()
+        .zip(unseen)
+        .filter(|(_, unseen)| **unseen)
+        .map(|(key, _)| key.clone())
+        .collect()
+}
+
+/// Handles individual event processing by dropping already seen records.
+pub struct EventHandler {
+    /// Storage for seen keys.
+    store: Store,
+    /// Processor configuration settings.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for processed events (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event tracking.
+    task_id: usize,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+}
+
+impl EventHandler {
+    /// Removes records already seen from the event data.
+    ///
+    /// Returns the remaining data, or `None` when every record was a duplicate,
+    /// together with the keys recorded for the remaining records.
+    async fn dedup(&self, data: &EventData) -> Result<(Option<EventData>, Vec<String>), Error> {
+        let key_columns = &self.config.key_columns;
+        let keys = match data {
+            EventData::ArrowRecordBatch(batch) => batch_keys(batch, key_columns)?,
+            EventData::Json(Value::Array(items)) => items
+                .iter()
+                .map(|item| json_key(item, key_columns))
+                .collect(),
+            EventData::Json(value) => vec![json_key(value, key_columns)],
+            EventData::Avro(_) => return Err(Error::AvroNotSupported),
+        };
+        let unseen = self.store.insert_all(&keys).await?;
+        let recorded = recorded_keys(&keys, &unseen);
+
+        match self.filter_unseen(data, unseen) {
+            Ok(data) => Ok((data, recorded)),
+            Err(e) => {
+                self.store.remove_all(&recorded).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// Keeps the records of `data` whose entry in `unseen` is true.
+    fn filter_unseen(
+        &self,
+        data: &EventData,
+        unseen: Vec<bool>,
+    ) -> Result<Option<EventData>, Error> {
+        match data {
+            EventData::ArrowRecordBatch(batch) => {
+                let kept = unseen.iter().filter(|keep| **keep).count();
+                if kept == 0 {
+                    return Ok(None);
+                }
+                if kept < batch.num_rows() {
+                    debug!(
+                        duplicates = batch.num_rows() - kept,
+                        "Dropped duplicate rows"
+                    );
+                }
+                let batch = arrow::compute::filter_record_batch(batch, &BooleanArray::from(unseen))
+                    .map_err(|source| Error::Arrow { source })?;
+                Ok(Some(EventData::ArrowRecordBatch(batch)))
+            }
+            EventData::Json(Value::Array(items)) => {
+                let kept: Vec<Value> = items
+                    .iter()
+                    .zip(unseen)
+                    .filter_map(|(item, keep)| keep.then(|| item.clone()))
+                    .collect();
+                if kept.is_empty() {
+                    return Ok(None);
+                }
+                if kept.len() < items.len() {
+                    debug!(
+                        duplicates = items.len() - kept.len(),
+                        "Dropped duplicate items"
+                    );
+                }
+                Ok(Some(EventData::Json(Value::Array(kept))))
+            }
+            EventData::Json(_) => Ok(unseen[0].then(|| data.clone())),
+            EventData::Avro(_) => Err(Error::AvroNotSupported),
+        }
+    }
+
+    /// Processes an event by forwarding only records with unseen keys.
+    #[tracing::instrument(skip(self, event), name = "task.handle")]
+    async fn handle(&self, event: Event) -> Result<(), Error> {
+        if self.task_context.cancellation_token.is_cancelled() {
+            return Ok(());
+        }
+
+        let event = Arc::new(event);
+        let completion_tx_arc = Arc::clone(&event).completion_tx.clone();
+        crate::event::with_event_context(&Arc::clone(&event), async move {
+            let (data, recorded) = self.dedup(&event.data).await?;
+            let Some(data) = data else {
+                debug!("Dropped duplicate event");
+                // The pipeline ends here for this event. Signal once per leaf
+                // reachable from this task so the source is not left waiting
+                // for completions that will never arrive.
+                if let Some(arc) = completion_tx_arc.as_ref() {
+                    let upstream_leaf_share = self.task_context.leaf_count.max(1);
+                    for _ in 0..upstream_leaf_share {
+                        arc.signal_completion(None);
+                    }
+                }
+                return Ok(());
+            };
+
+            let result = self.forward(data, completion_tx_arc).await;
+            if result.is_err() {
+                // The records were not delivered; forget their keys so the
+                // retry does not drop them as duplicates.
+                self.store.remove_all(&recorded).await;
+            }
+            result
+        })
+        .await
+    }
+
+    /// Sends the deduplicated data to the next task, or signals completion
+    /// when this task is a leaf.
+    async fn forward(
+        &self,
+        data: EventData,
+        completion_tx: Option<SharedCompletionTx>,
+    ) -> Result<(), Error> {
+        let mut e = EventBuilder::new()
+            .data(data)
+            .subject(self.config.name.to_owned())
+            .task_id(self.task_id)
+            .task_type(self.task_type)
+            .build()
+            .map_err(|source| Error::EventBuilder { source })?;
+
+        // Signal completion or pass through to next task.
+        match self.tx {
+            None => {
+                if let Some(arc) = completion_tx.as_ref() {
+                    arc.signal_completion(e.data_as_json().ok());
+                }
+            }
+            Some(_) => {
+                e.completion_tx = completion_tx;
+            }
+        }
+
+        e.send_with_logging(self.tx.as_ref())
+            .await
+            .map_err(|source| Error::SendMessage { source })
+    }
+}
+
+/// Deduplication processor that drops records seen within a window.
+#[derive(Debug)]
+pub struct Processor {
+    /// Dedup task configuration.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for deduplicated events.
+    tx: Option<Sender<Event>>,
+    /// Channel receiver for incoming events.
+    rx: Receiver<Event>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl crate::task::runner::Runner for Processor {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Initializes the processor by setting up the configured key store.
+    async fn init(&self) -> Result<Self::EventHandler, Self::Error> {
+        let window = self.config.window;
+        let store = match self.config.backend {
+            DedupBackend::Memory => {
+                Store::Memory(Mutex::new(MemoryStore::new(window, Instant::now())))
+            }
+            DedupBackend::Cache => Store::Cache {
+                cache: Arc::clone(&self.task_context.cache),
+                prefix: format!("{}.{}", self.task_context.flow.name, self.config.name),
+                ttl_secs: (window.as_secs() + u64::from(window.subsec_nanos() > 0)).max(1),
+            },
+        };
+
+        Ok(EventHandler {
+            store,
+            config: Arc::clone(&self.config),
+            tx: self.tx.clone(),
+            task_id: self.task_id,
+            task_type: self.task_type,
+            task_context: Arc::clone(&self.task_context),
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(mut self) -> Result<(), Error> {
+        let retry_config =
+            crate::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        let event_handler = match tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+            match self.init().await {
+                Ok(handler) => Ok(handler),
+                Err(e) => {
+                    error!(error = %e, "Failed to initialize dedup processor");
+                    Err(tokio_retry::RetryError::transient(e))
+                }
+            }
+        })
+        .await
+        {
+            Ok(handler) => Arc::new(handler),
+            Err(e) => {
+                return Err(e);
+            }
+        };
+
+        loop {
+            match self.rx.recv().await {
+                Some(event) => {
+                    let event_handler = Arc::clone(&event_handler);
+                    let retry_strategy = retry_config.strategy();
+                    tokio::spawn(
+                        async move {
+                            let result = tokio_retry::Retry::spawn(retry_strategy, || async {
+                                match event_handler.handle(event.clone()).await {
+                                    Ok(result) => Ok(result),
+                                    Err(e) => {
+                                        error!(error = %e, "Failed to deduplicate event");
+                                        Err(tokio_retry::RetryError::transient(e))
+                                    }
+                                }
+                            })
+                            .await;
+
+                            if let Err(err) = result {
+                                error!(error = %err, "Dedup failed after all retry attempts.");
+                                // Emit error event downstream for error handling.
+                                let mut error_event = event.clone();
+                                error_event.error = Some(err.to_string());
+                                if let Some(ref tx) = event_handler.tx {
+                                    tx.send(error_event).await.ok();
+                                }
+                            }
+                        }
+                        .instrument(tracing::Span::current()),
+                    );
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Builder for constructing Processor instances with validation.
+#[derive(Debug, Default)]
+pub struct ProcessorBuilder {
+    /// Processor configuration (required for build).
+    config: Option<Arc<super::config::Processor>>,
+    /// Event sender for passing events to next task (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Event receiver for incoming events (required for build).
+    rx: Option<Receiver<Event>>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Option<Arc<crate::task::context::TaskContext>>,
+    /// Task type for event categorization and logging.
+    task_type: Option<&'static str>,
+}
+
+impl ProcessorBuilder {
+    pub fn new() -> ProcessorBuilder {
+        ProcessorBuilder {
+            ..Default::default()
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Processor>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn receiver(mut self, receiver: Receiver<Event>) -> Self {
+        self.rx = Some(receiver);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = task_id;
+        self
+    }
+
+    pub fn task_context(mut self, task_context: Arc<crate::task::context::TaskContext>) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Processor, Error> {
+        let config = self
+            .config
+            .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?;
+        if config.window.is_zero() {
+            return Err(Error::ZeroWindow);
+        }
+        if config.key_columns.is_empty() {
+            return Err(Error::EmptyKeyColumns);
+        }
+
+        Ok(Processor {
+            config,
+            rx: self
+                .rx
+                .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
+            tx: self.tx,
+            task_id: self.task_id,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::runner::Runner;
+    use arrow::array::{Int64Array, StringArray};
+    use arrow::datatypes::{Field, Schema};
+    use serde_json::{json, Map};
+    use tokio::sync::mpsc;
+
+    fn create_mock_task_context() -> Arc<crate::task::context::TaskContext> {
+        let mut labels = Map::new();
+        labels.insert(
+            "description".to_string(),
+            Value::String("Dedup Test".to_string()),
+        );
+        let task_manager = Arc::new(
+            crate::task::manager::TaskManagerBuilder::new()
+                .build()
+                .unwrap(),
+        );
+        let cache =
+            Arc::new(crate::cache::memory::MemoryCache::new()) as Arc<dyn crate::cache::Cache>;
+        Arc::new(
+            crate::task::context::TaskContextBuilder::new()
+                .flow_name("test-flow".to_string())
+                .flow_labels(Some(labels))
+                .task_manager(task_manager)
+                .cache(cache)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn config(backend: DedupBackend) -> super::super::config::Processor {
+        super::super::config::Processor {
+            name: "dedup_orders".to_string(),
+            key_columns: vec!["account".to_string(), "order_id".to_string()],
+            window: Duration::from_secs(60),
+            backend,
+            depends_on: None,
+            retry: None,
+            error_policy: None,
+        }
+    }
+
+    async fn handler(backend: DedupBackend, tx: Sender<Event>) -> EventHandler {
+        let (_in_tx, rx) = mpsc::channel(1);
+        ProcessorBuilder::new()
+            .config(Arc::new(config(backend)))
+            .receiver(rx)
+            .sender(tx)
+            .task_type("dedup")
+            .task_context(create_mock_task_context())
+            .build()
+            .await
+            .unwrap()
+            .init()
+            .await
+            .unwrap()
+    }
+
+    fn batch(accounts: Vec<Option<&str>>, order_ids: Vec<i64>) -> RecordBatch {
+        RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("account", DataType::Utf8, true),
+                Field::new("order_id", DataType::Int64, false),
+            ])),
+            vec![
+                Arc::new(StringArray::from(accounts)),
+                Arc::new(Int64Array::from(order_ids)),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn event(data: EventData) -> Event {
+        EventBuilder::new()
+            .data(data)
+            .subject("test".to_string())
+            .task_id(0)
+            .task_type("test")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_hash_key_distinguishes_separators_and_nulls() {
+        assert_ne!(hash_key(&[Some("a,b")]), hash_key(&[Some("a"), Some("b")]));
+        assert_ne!(hash_key(&[None]), hash_key(&[Some("null")]));
+        assert_eq!(hash_key(&[Some("a")]), hash_key(&[Some("a")]));
+    }
+
+    #[test]
+    fn test_json_key_matches_batch_key() {
+        let rows = batch(vec![Some("acme")], vec![42]);
+        let columns = vec!["account".to_string(), "order_id".to_string()];
+        assert_eq!(
+            batch_keys(&rows, &columns).unwrap()[0],
+            json_key(&json!({"account": "acme", "order_id": 42}), &columns)
+        );
+    }
+
+    #[test]
+    fn test_batch_keys_missing_column() {
+        let rows = batch(vec![Some("acme")], vec![42]);
+        assert!(matches!(
+            batch_keys(&rows, &["customer".to_string()]),
+            Err(Error::MissingKeyColumn(column)) if column == "customer"
+        ));
+    }
+
+    #[test]
+    fn test_memory_store_expires_keys() {
+        let now = Instant::now();
+        let mut store = MemoryStore::new(Duration::from_secs(10), now);
+
+        assert!(store.insert("a".to_string(), now));
+        assert!(!store.insert("a".to_string(), now + Duration::from_secs(5)));
+        assert!(store.insert("a".to_string(), now + Duration::from_secs(11)));
+        assert!(store.insert("b".to_string(), now + Duration::from_secs(30)));
+        assert_eq!(store.seen.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dedup_batch_rows() {
+        let (tx, mut rx) = mpsc::channel(10);
+        let handler = handler(DedupBackend::Memory, tx).await;
+
+        handler
+            .handle(event(EventData::ArrowRecordBatch(batch(
+                vec![Some("acme"), Some("acme"), None],
+                vec![1, 1, 1],
+            ))))
+            .await
+            .unwrap();
+        handler
+            .handle(event(EventData::ArrowRecordBatch(batch(
+                vec![Some("acme"), Some("globex")],
+                vec![1, 2],
+            ))))
+            .await
+            .unwrap();
+
+        let EventData::ArrowRecordBatch(first) = rx.recv().await.unwrap().data else {
+            panic!("expected arrow data");
+        };
+        assert_eq!(first.num_rows(), 2);
+        let EventData::ArrowRecordBatch(second) = rx.recv().await.unwrap().data else {
+            panic!("expected arrow data");
+        };
+        assert_eq!(second.num_rows(), 1);
+        assert_eq!(second.column(0).as_string::<i32>().value(0), "globex");
+    }
+
+    #[tokio::test]
+    async fn test_dedup_json_with_cache_backend() {
+        let (tx, mut rx) = mpsc::channel(10);
+        let handler = handler(DedupBackend::Cache, tx).await;
+        let order = json!({"account": "acme", "order_id": 1, "amount": 10});
+
+        handler
+            .handle(event(EventData::Json(order.clone())))
+            .await
+            .unwrap();
+        handler
+            .handle(event(EventData::Json(
+                json!([order, {"account": "acme", "order_id": 2}]),
+            )))
+            .await
+            .unwrap();
+
+        let EventData::Json(first) = rx.recv().await.unwrap().data else {
+            panic!("expected json data");
+        };
+        assert_eq!(first, order);
+        let EventData::Json(second) = rx.recv().await.unwrap().data else {
+            panic!("expected json data");
+        };
+        assert_eq!(second, json!([{"account": "acme", "order_id": 2}]));
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_event_is_dropped() {
+        let (tx, mut rx) = mpsc::channel(10);
+        let handler = handler(DedupBackend::Memory, tx).await;
+        let order = json!({"account": "acme", "order_id": 1});
+
+        handler
+            .handle(event(EventData::Json(order.clone())))
+            .await
+            .unwrap();
+        handler.handle(event(EventData::Json(order))).await.unwrap();
+        drop(handler);
+
+        assert!(rx.recv().await.is_some());
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_failed_send_does_not_record_keys() {
+        let (closed_tx, closed_rx) = mpsc::channel(1);
+        drop(closed_rx);
+        let mut handler = handler(DedupBackend::Cache, closed_tx).await;
+        let rows = || {
+            event(EventData::ArrowRecordBatch(batch(
+                vec![Some("acme"), Some("globex")],
+                vec![1, 2],
+            )))
+        };
+
+        assert!(matches!(
+            handler.handle(rows()).await,
+            Err(Error::SendMessage { .. })
+        ));
+
+        // The retry must deliver the rows the failed attempt did not.
+        let (tx, mut rx) = mpsc::channel(10);
+        handler.tx = Some(tx);
+        handler.handle(rows()).await.unwrap();
+
+        let EventData::ArrowRecordBatch(delivered) = rx.recv().await.unwrap().data else {
+            panic!("expected arrow data");
+        };
+        assert_eq!(delivered.num_rows(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_builder_rejects_invalid_config() {
+        let (_tx, rx) = mpsc::channel(1);
+        let mut zero_window = config(DedupBackend::Memory);
+        zero_window.window = Duration::ZERO;
+        let result = ProcessorBuilder::new()
+            .config(Arc::new(zero_window))
+            .receiver(rx)
+            .task_type("dedup")
+            .task_context(create_mock_task_context())
+            .build()
+            .await;
+        assert!(matches!(result, Err(Error::ZeroWindow)));
+
+        let (_tx, rx) = mpsc::channel(1);
+        let mut no_keys = config(DedupBackend::Memory);
+        no_keys.key_columns.clear();
+        let result = ProcessorBuilder::new()
+            .config(Arc::new(no_keys))
+            .receiver(rx)
+            .task_type("dedup")
+            .task_context(create_mock_task_context())
+            .build()
+            .await;
+        assert!(matches!(result, Err(Error::EmptyKeyColumns)));
+    }
+}
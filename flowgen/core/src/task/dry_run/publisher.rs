@@ -0,0 +1,387 @@
+//! Dry-run publisher that logs events in place of a side-effecting task.
+//!
+//! Used by flows running in dry-run mode: sources and processors run normally
+//! while every publisher is swapped for this task, which logs each received
+//! event at `INFO` level and counts it instead of writing to the target system.
+
+use crate::event::{Event, EventData};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tracing::{info, Instrument};
+
+/// Number of record batch rows logged per event.
+const DEFAULT_PREVIEW_ROWS: usize = 10;
+
+/// Errors that can occur during dry-run publishing.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Arrow error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+}
+
+/// Formats the first `max_rows` rows of a record batch, one `column=value` line per row.
+fn format_rows(
+    batch: &arrow::array::RecordBatch,
+    max_rows: usize,
+) -> Result<String, arrow::error::ArrowError> {
+    use arrow::util::display::{ArrayFormatter, FormatOptions};
+
+    let options = FormatOptions::default().with_null("null");
+    let formatters = batch
+        .columns()
+        .iter()
+        .map(|column| ArrayFormatter::try_new(column.as_ref(), &options))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let schema = batch.schema();
+    let rows: Vec<String> = (0..batch.num_rows().min(max_rows))
+        .map(|row| {
+            schema
+                .fields()
+                .iter()
+                .zip(&formatters)
+                .map(|(field, formatter)| format!("{}={}", field.name(), formatter.value(row)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .collect();
+
+    Ok(rows.join("\n"))
+}
+
+/// Handles individual dry-run events.
+pub struct EventHandler {
+    /// Name of the task being replaced.
+    name: String,
+    /// Current task identifier.
+    task_id: usize,
+    /// Task type of the replaced task, for logging.
+    task_type: &'static str,
+    /// Event sender for passing events through to downstream tasks.
+    tx: Option<Sender<Event>>,
+    /// Number of events received across all dry-run publishers in the flow.
+    received: Arc<AtomicU64>,
+    /// Number of events after which the flow is cancelled.
+    sample_count: Option<u64>,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+}
+
+impl EventHandler {
+    /// Logs and counts an event, then passes it through or signals completion.
+    #[tracing::instrument(skip(self, event), name = "task.handle")]
+    async fn handle(&self, event: Event) -> Result<(), Error> {
+        crate::event::continue_trace(&event);
+
+        let count = self.received.fetch_add(1, Ordering::Relaxed) + 1;
+
+        match &event.data {
+            EventData::ArrowRecordBatch(batch) => {
+                let rows = format_rows(batch, DEFAULT_PREVIEW_ROWS)
+                    .map_err(|source| Error::Arrow { source })?;
+                info!(
+                    task = %self.name,
+                    replaced_task_type = %self.task_type,
+                    count,
+                    subject = %event.subject,
+                    num_rows = batch.num_rows(),
+                    "Dry run: received record batch\nschema: {}\n{}",
+                    batch.schema(),
+                    rows
+                );
+            }
+            EventData::Json(_) | EventData::Avro(_) => {
+                info!(
+                    task = %self.name,
+                    replaced_task_type = %self.task_type,
+                    count,
+                    "Dry run: received event\n{}",
+                    event
+                );
+            }
+        }
+
+        match self.tx {
+            Some(ref tx) => {
+                let mut event = event;
+                event.task_id = self.task_id;
+                tx.send(event).await.map_err(|_| Error::SendMessage {
+                    source: crate::event::Error::SendMessage,
+                })?;
+            }
+            None => {
+                // Leaf task: signal completion so sources acknowledge as usual.
+                if let Some(arc) = event.completion_tx.as_ref() {
+                    arc.signal_completion(event.data_as_json().ok());
+                }
+            }
+        }
+
+        if let Some(sample_count) = self.sample_count {
+            if count >= sample_count && !self.task_context.cancellation_token.is_cancelled() {
+                info!(sample_count, "Dry run sample count reached, stopping flow");
+                self.task_context.cancellation_token.cancel();
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Publisher that logs and counts events instead of writing them.
+#[derive(Debug)]
+pub struct Publisher {
+    /// Name of the task being replaced.
+    name: String,
+    /// Channel receiver for incoming events.
+    rx: Receiver<Event>,
+    /// Channel sender for passing events through.
+    tx: Option<Sender<Event>>,
+    /// Current task identifier.
+    task_id: usize,
+    /// Task type of the replaced task.
+    task_type: &'static str,
+    /// Shared received-event counter.
+    received: Arc<AtomicU64>,
+    /// Number of events after which the flow is cancelled.
+    sample_count: Option<u64>,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+}
+
+#[async_trait::async_trait]
+impl crate::task::runner::Runner for Publisher {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Initializes the publisher. Nothing is connected in dry-run mode.
+    async fn init(&self) -> Result<Self::EventHandler, Self::Error> {
+        Ok(EventHandler {
+            name: self.name.clone(),
+            task_id: self.task_id,
+            task_type: self.task_type,
+            tx: self.tx.clone(),
+            received: Arc::clone(&self.received),
+            sample_count: self.sample_count,
+            task_context: Arc::clone(&self.task_context),
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(mut self) -> Result<(), Error> {
+        let event_handler = self.init().await?;
+        let cancellation_token = self.task_context.cancellation_token.clone();
+
+        async move {
+            loop {
+                tokio::select! {
+                    _ = cancellation_token.cancelled() => return Ok(()),
+                    event = self.rx.recv() => match event {
+                        Some(event) => event_handler.handle(event).await?,
+                        None => return Ok(()),
+                    },
+                }
+            }
+        }
+        .instrument(tracing::Span::current())
+        .await
+    }
+}
+
+/// Builder for constructing dry-run Publisher instances.
+#[derive(Debug, Default)]
+pub struct PublisherBuilder {
+    /// Name of the task being replaced (required for build).
+    name: Option<String>,
+    /// Event receiver for incoming events (required for build).
+    rx: Option<Receiver<Event>>,
+    /// Event sender for passing events through (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Current task identifier.
+    task_id: usize,
+    /// Task type of the replaced task.
+    task_type: Option<&'static str>,
+    /// Shared received-event counter. A new counter is created if unset.
+    received: Option<Arc<AtomicU64>>,
+    /// Number of events after which the flow is cancelled.
+    sample_count: Option<u64>,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Option<Arc<crate::task::context::TaskContext>>,
+}
+
+impl PublisherBuilder {
+    pub fn new() -> PublisherBuilder {
+        PublisherBuilder {
+            ..Default::default()
+        }
+    }
+
+    pub fn name(mut self, name: String) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn receiver(mut self, receiver: Receiver<Event>) -> Self {
+        self.rx = Some(receiver);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = task_id;
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    /// Sets the counter shared by all dry-run publishers in a flow.
+    pub fn received(mut self, received: Arc<AtomicU64>) -> Self {
+        self.received = Some(received);
+        self
+    }
+
+    /// Sets the number of events after which the flow is stopped.
+    pub fn sample_count(mut self, sample_count: Option<u64>) -> Self {
+        self.sample_count = sample_count;
+        self
+    }
+
+    pub fn task_context(mut self, task_context: Arc<crate::task::context::TaskContext>) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub async fn build(self) -> Result<Publisher, Error> {
+        Ok(Publisher {
+            name: self
+                .name
+                .ok_or_else(|| Error::MissingBuilderAttribute("name".to_string()))?,
+            rx: self
+                .rx
+                .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
+            tx: self.tx,
+            task_id: self.task_id,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+            received: self.received.unwrap_or_default(),
+            sample_count: self.sample_count,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::EventBuilder;
+    use crate::task::runner::Runner;
+    use arrow::array::{Int64Array, RecordBatch, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use serde_json::json;
+    use tokio::sync::mpsc;
+
+    fn create_mock_task_context() -> Arc<crate::task::context::TaskContext> {
+        let task_manager = Arc::new(
+            crate::task::manager::TaskManagerBuilder::new()
+                .build()
+                .unwrap(),
+        );
+        let cache =
+            Arc::new(crate::cache::memory::MemoryCache::new()) as Arc<dyn crate::cache::Cache>;
+        Arc::new(
+            crate::task::context::TaskContextBuilder::new()
+                .flow_name("test-flow".to_string())
+                .task_manager(task_manager)
+                .cache(cache)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_format_rows_limits_output() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec![Some("a"), None, Some("c")])),
+            ],
+        )
+        .unwrap();
+
+        let output = format_rows(&batch, 2).unwrap();
+        assert_eq!(output, "id=1, name=a\nid=2, name=null");
+    }
+
+    #[tokio::test]
+    async fn test_publisher_builder_missing_receiver() {
+        let result = PublisherBuilder::new()
+            .name("test".to_string())
+            .task_type("nats_jetstream_publisher")
+            .task_context(create_mock_task_context())
+            .build()
+            .await;
+
+        assert!(
+            matches!(result.unwrap_err(), Error::MissingBuilderAttribute(attr) if attr == "receiver")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_publisher_stops_after_sample_count() {
+        let task_context = create_mock_task_context();
+        let (tx, rx) = mpsc::channel(10);
+        let received = Arc::new(AtomicU64::new(0));
+
+        let publisher = PublisherBuilder::new()
+            .name("test".to_string())
+            .receiver(rx)
+            .task_type("nats_jetstream_publisher")
+            .received(Arc::clone(&received))
+            .sample_count(Some(2))
+            .task_context(Arc::clone(&task_context))
+            .build()
+            .await
+            .unwrap();
+
+        for i in 0..2 {
+            let event = EventBuilder::new()
+                .data(EventData::Json(json!({"i": i})))
+                .subject("test".to_string())
+                .task_type("test")
+                .build()
+                .unwrap();
+            tx.send(event).await.unwrap();
+        }
+
+        publisher.run().await.unwrap();
+        assert_eq!(received.load(Ordering::Relaxed), 2);
+        assert!(task_context.cancellation_token.is_cancelled());
+    }
+}
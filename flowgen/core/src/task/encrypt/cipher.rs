@@ -0,0 +1,357 @@
+//! Column cipher shared by the encrypt and decrypt tasks.
+//!
+//! Both tasks load key material from the same `key_ref` and derive the AEAD key
+//! the same way, so values encrypted by one can always be opened by the other.
+//! Encrypted values are base64 (standard alphabet) of `nonce || ciphertext || tag`.
+
+use super::config::EncryptAlgorithm;
+use arrow::array::{Array, ArrayRef, AsArray, RecordBatch, StringArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use base64::Engine;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde_json::Value;
+use std::sync::Arc;
+
+/// Prefix marking a `key_ref` that is read from the flow cache.
+const CACHE_KEY_PREFIX: &str = "cache:";
+
+/// Minimum key material length, matching the 256-bit keys of both algorithms.
+const MIN_KEY_MATERIAL_LEN: usize = 32;
+
+/// HKDF salt shared by all flowgen column ciphers.
+const KEY_DERIVATION_SALT: &[u8] = b"flowgen.task.encrypt";
+
+/// Errors that can occur while loading keys or encrypting values.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Failed to read key file {path}: {source}")]
+    ReadKeyFile {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Failed to read key '{key}' from cache: {source}")]
+    ReadCacheKey {
+        key: String,
+        #[source]
+        source: crate::cache::Error,
+    },
+    #[error("Key '{0}' not found in cache")]
+    CacheKeyNotFound(String),
+    #[error("Key material must be at least {MIN_KEY_MATERIAL_LEN} bytes, got {0}")]
+    KeyTooShort(usize),
+    #[error("Failed to derive encryption key")]
+    KeyDerivation,
+    #[error("Failed to generate nonce")]
+    Nonce,
+    #[error("Failed to encrypt value")]
+    Seal,
+    #[error("Failed to decrypt value, the key differs or the ciphertext is corrupted")]
+    Open,
+    #[error("Encrypted value is not valid base64: {source}")]
+    Base64 {
+        #[source]
+        source: base64::DecodeError,
+    },
+    #[error("Decrypted value is not valid UTF-8")]
+    Utf8,
+    #[error("Arrow error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+}
+
+/// Authenticated cipher for individual column values.
+pub struct Cipher {
+    key: LessSafeKey,
+    rng: SystemRandom,
+}
+
+impl std::fmt::Debug for Cipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cipher")
+            .field("algorithm", self.key.algorithm())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Cipher {
+    /// Loads key material from `key_ref` and derives the cipher key.
+    ///
+    /// # Arguments
+    /// * `key_ref` - Path to a key file, or `cache:<key>` to read from the flow cache
+    /// * `algorithm` - Cipher algorithm
+    /// * `cache` - Flow cache used for `cache:` references
+    pub async fn load(
+        key_ref: &str,
+        algorithm: EncryptAlgorithm,
+        cache: &dyn crate::cache::Cache,
+    ) -> Result<Self, Error> {
+        let material = match key_ref.strip_prefix(CACHE_KEY_PREFIX) {
+            Some(key) => cache
+                .get(key)
+                .await
+                .map_err(|source| Error::ReadCacheKey {
+                    key: key.to_string(),
+                    source,
+                })?
+                .ok_or_else(|| Error::CacheKeyNotFound(key.to_string()))?
+                .to_vec(),
+            None => tokio::fs::read(key_ref)
+                .await
+                .map_err(|source| Error::ReadKeyFile {
+                    path: key_ref.to_string(),
+                    source,
+                })?,
+        };
+        Self::from_material(&material, algorithm)
+    }
+
+    /// Derives the cipher key from raw key material with HKDF-SHA256.
+    ///
+    /// Trailing whitespace is ignored so key files may end with a newline. The
+    /// algorithm name is bound into the derivation, so the same material yields
+    /// unrelated keys for different algorithms.
+    pub fn from_material(material: &[u8], algorithm: EncryptAlgorithm) -> Result<Self, Error> {
+        let material = material.trim_ascii_end();
+        if material.len() < MIN_KEY_MATERIAL_LEN {
+            return Err(Error::KeyTooShort(material.len()));
+        }
+
+        let aead_algorithm = match algorithm {
+            EncryptAlgorithm::Aes256Gcm => &ring::aead::AES_256_GCM,
+            EncryptAlgorithm::ChaCha20Poly1305 => &ring::aead::CHACHA20_POLY1305,
+        };
+        let info = [algorithm.as_str().as_bytes()];
+        let okm = ring::hkdf::Salt::new(ring::hkdf::HKDF_SHA256, KEY_DERIVATION_SALT)
+            .extract(material)
+            .expand(&info, aead_algorithm)
+            .map_err(|_| Error::KeyDerivation)?;
+
+        Ok(Self {
+            key: LessSafeKey::new(UnboundKey::from(okm)),
+            rng: SystemRandom::new(),
+        })
+    }
+
+    /// Encrypts a value with a fresh random nonce.
+    pub fn encrypt(&self, plaintext: &str) -> Result<String, Error> {
+        let mut nonce = [0u8; NONCE_LEN];
+        self.rng.fill(&mut nonce).map_err(|_| Error::Nonce)?;
+
+        let mut in_out = plaintext.as_bytes().to_vec();
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut in_out,
+            )
+            .map_err(|_| Error::Seal)?;
+
+        let mut sealed = Vec::with_capacity(NONCE_LEN + in_out.len());
+        sealed.extend_from_slice(&nonce);
+        sealed.extend_from_slice(&in_out);
+        Ok(base64::engine::general_purpose::STANDARD.encode(sealed))
+    }
+
+    /// Decrypts a value produced by [`Cipher::encrypt`].
+    pub fn decrypt(&self, encoded: &str) -> Result<String, Error> {
+        let sealed = base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|source| Error::Base64 { source })?;
+        if sealed.len() < NONCE_LEN {
+            return Err(Error::Open);
+        }
+
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| Error::Open)?;
+        let mut in_out = ciphertext.to_vec();
+        let plaintext = self
+            .key
+            .open_in_place(nonce, Aad::empty(), &mut in_out)
+            .map_err(|_| Error::Open)?;
+        String::from_utf8(plaintext.to_vec()).map_err(|_| Error::Utf8)
+    }
+}
+
+/// Applies `f` to every non-null value of the given columns.
+///
+/// Target columns are cast to strings and become `Utf8`. Columns not present
+/// in the batch are ignored.
+pub fn transform_batch<F>(
+    batch: &RecordBatch,
+    columns: &[String],
+    f: F,
+) -> Result<RecordBatch, Error>
+where
+    F: Fn(&str) -> Result<String, Error>,
+{
+    let schema = batch.schema();
+    let mut fields: Vec<Field> = schema.fields().iter().map(|f| f.as_ref().clone()).collect();
+    let mut arrays: Vec<ArrayRef> = batch.columns().to_vec();
+
+    for column in columns {
+        let Ok(index) = schema.index_of(column) else {
+            continue;
+        };
+        let strings = arrow::compute::cast(arrays[index].as_ref(), &DataType::Utf8)
+            .map_err(|source| Error::Arrow { source })?;
+        let transformed = strings
+            .as_string::<i32>()
+            .iter()
+            .map(|value| value.map(&f).transpose())
+            .collect::<Result<StringArray, Error>>()?;
+
+        fields[index] = Field::new(column, DataType::Utf8, fields[index].is_nullable())
+            .with_metadata(fields[index].metadata().clone());
+        arrays[index] = Arc::new(transformed);
+    }
+
+    RecordBatch::try_new(
+        Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone())),
+        arrays,
+    )
+    .map_err(|source| Error::Arrow { source })
+}
+
+/// Applies `f` to the given top-level fields of a JSON object or array of objects.
+///
+/// Non-string values are passed as their JSON text; nulls are left untouched.
+pub fn transform_json<F>(value: &mut Value, columns: &[String], f: &F) -> Result<(), Error>
+where
+    F: Fn(&str) -> Result<String, Error>,
+{
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                transform_json(item, columns, f)?;
+            }
+        }
+        Value::Object(object) => {
+            for column in columns {
+                if let Some(field) = object.get_mut(column) {
+                    let transformed = match field {
+                        Value::Null => continue,
+                        Value::String(s) => f(s)?,
+                        other => f(&other.to_string())?,
+                    };
+                    *field = Value::String(transformed);
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::Int64Array;
+    use serde_json::json;
+
+    const MATERIAL: &[u8] = b"0123456789abcdef0123456789abcdef\n";
+
+    #[test]
+    fn test_roundtrip_all_algorithms() {
+        for algorithm in [
+            EncryptAlgorithm::Aes256Gcm,
+            EncryptAlgorithm::ChaCha20Poly1305,
+        ] {
+            let cipher = Cipher::from_material(MATERIAL, algorithm).unwrap();
+            let encrypted = cipher.encrypt("123-45-6789").unwrap();
+
+            assert_ne!(encrypted, "123-45-6789");
+            assert_ne!(encrypted, cipher.encrypt("123-45-6789").unwrap());
+            assert_eq!(cipher.decrypt(&encrypted).unwrap(), "123-45-6789");
+        }
+    }
+
+    #[test]
+    fn test_decrypt_with_other_algorithm_fails() {
+        let aes = Cipher::from_material(MATERIAL, EncryptAlgorithm::Aes256Gcm).unwrap();
+        let chacha = Cipher::from_material(MATERIAL, EncryptAlgorithm::ChaCha20Poly1305).unwrap();
+        let encrypted = aes.encrypt("secret").unwrap();
+
+        assert!(matches!(chacha.decrypt(&encrypted), Err(Error::Open)));
+        assert!(matches!(
+            aes.decrypt("not base64!"),
+            Err(Error::Base64 { .. })
+        ));
+        assert!(matches!(aes.decrypt("AAAA"), Err(Error::Open)));
+    }
+
+    #[test]
+    fn test_key_too_short() {
+        assert!(matches!(
+            Cipher::from_material(b"short\n", EncryptAlgorithm::Aes256Gcm),
+            Err(Error::KeyTooShort(5))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_load_from_cache() {
+        let cache = crate::cache::memory::MemoryCache::new();
+        crate::cache::Cache::put(&cache, "keys.pii", bytes::Bytes::from(MATERIAL), None)
+            .await
+            .unwrap();
+
+        let cipher = Cipher::load("cache:keys.pii", EncryptAlgorithm::Aes256Gcm, &cache)
+            .await
+            .unwrap();
+        let expected = Cipher::from_material(MATERIAL, EncryptAlgorithm::Aes256Gcm).unwrap();
+        assert_eq!(
+            expected.decrypt(&cipher.encrypt("value").unwrap()).unwrap(),
+            "value"
+        );
+
+        assert!(matches!(
+            Cipher::load("cache:missing", EncryptAlgorithm::Aes256Gcm, &cache).await,
+            Err(Error::CacheKeyNotFound(key)) if key == "missing"
+        ));
+    }
+
+    #[test]
+    fn test_transform_batch() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("id", DataType::Int64, false),
+                Field::new("ssn", DataType::Int64, true),
+            ])),
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(Int64Array::from(vec![Some(123456789), None])),
+            ],
+        )
+        .unwrap();
+
+        let transformed =
+            transform_batch(&batch, &["ssn".to_string()], |v| Ok(format!("<{v}>"))).unwrap();
+
+        assert_eq!(transformed.column(0).as_ref(), batch.column(0).as_ref());
+        assert_eq!(transformed.schema().field(1).data_type(), &DataType::Utf8);
+        let ssn = transformed.column(1).as_string::<i32>();
+        assert_eq!(ssn.value(0), "<123456789>");
+        assert!(ssn.is_null(1));
+    }
+
+    #[test]
+    fn test_transform_json() {
+        let mut value = json!([{"ssn": "123", "card": 4111, "name": "a"}, {"ssn": null}]);
+
+        transform_json(
+            &mut value,
+            &["ssn".to_string(), "card".to_string()],
+            &|v: &str| Ok(format!("<{v}>")),
+        )
+        .unwrap();
+
+        assert_eq!(
+            value,
+            json!([{"ssn": "<123>", "card": "<4111>", "name": "a"}, {"ssn": null}])
+        );
+    }
+}
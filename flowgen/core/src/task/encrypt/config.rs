@@ -0,0 +1,108 @@
+//! Configuration for the encrypt task processor.
+//!
+//! The encrypt task replaces sensitive column values with authenticated
+//! ciphertext so they can be stored at rest and restored by the decrypt task.
+
+use crate::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for encrypt processor task.
+///
+/// # Examples
+///
+/// Key read from a file:
+/// ```yaml
+/// encrypt:
+///   name: "encrypt_pii"
+///   encrypt_columns:
+///     - ssn
+///     - card_number
+///   key_ref: "/etc/flowgen/keys/pii.key"
+/// ```
+///
+/// Key read from the flow cache (NATS KV):
+/// ```yaml
+/// encrypt:
+///   name: "encrypt_pii"
+///   encrypt_columns:
+///     - ssn
+///   algorithm: chacha20_poly1305
+///   key_ref: "cache:keys.pii"
+/// ```
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct Processor {
+    /// Unique name identifier for this encrypt task.
+    pub name: String,
+    /// Columns (or top-level JSON fields) to encrypt.
+    pub encrypt_columns: Vec<String>,
+    /// Cipher used to encrypt values (default: aes_256_gcm).
+    #[serde(default)]
+    pub algorithm: EncryptAlgorithm,
+    /// Key material reference. A path to a key file, or `cache:<key>` to read
+    /// the key from the flow cache.
+    pub key_ref: String,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    pub retry: Option<crate::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    pub error_policy: Option<crate::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Processor {}
+
+/// Supported authenticated encryption algorithms.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub enum EncryptAlgorithm {
+    /// AES-256 in Galois/Counter Mode.
+    #[default]
+    #[serde(rename = "aes_256_gcm")]
+    Aes256Gcm,
+    /// ChaCha20 stream cipher with Poly1305 authenticator.
+    #[serde(rename = "chacha20_poly1305")]
+    ChaCha20Poly1305,
+}
+
+impl EncryptAlgorithm {
+    /// Returns the configuration name of the algorithm.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EncryptAlgorithm::Aes256Gcm => "aes_256_gcm",
+            EncryptAlgorithm::ChaCha20Poly1305 => "chacha20_poly1305",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_processor_deserialization() {
+        let yaml = r#"
+name: encrypt_pii
+encrypt_columns: [ssn, card_number]
+algorithm: chacha20_poly1305
+key_ref: "cache:keys.pii"
+"#;
+        let processor: Processor = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(processor.encrypt_columns, vec!["ssn", "card_number"]);
+        assert_eq!(processor.algorithm, EncryptAlgorithm::ChaCha20Poly1305);
+        assert_eq!(processor.key_ref, "cache:keys.pii");
+    }
+
+    #[test]
+    fn test_processor_default_algorithm() {
+        let yaml = r#"
+name: encrypt_pii
+encrypt_columns: [ssn]
+key_ref: /etc/flowgen/keys/pii.key
+"#;
+        let processor: Processor = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(processor.algorithm, EncryptAlgorithm::Aes256Gcm);
+    }
+}
@@ -14,6 +14,16 @@ pub enum ConfigError {
     MissingSchedule,
     #[error("Cannot specify both 'interval' and 'cron' params")]
     BothSchedulesSpecified,
+    #[error("Cannot specify both 'payload' and 'schema' params")]
+    BothPayloadAndSchema,
+    #[error("'batch_size' must be greater than zero")]
+    ZeroBatchSize,
+    #[error("Cannot specify 'rate_mode' together with 'interval' or 'cron' params")]
+    RateModeWithSchedule,
+    #[error("'events_per_burst' must be greater than zero")]
+    ZeroBurstSize,
+    #[error("'start_rps' and 'end_rps' must be greater than zero")]
+    InvalidRampRate,
 }
 
 /// Configuration for generate subscriber tasks that produce scheduled events.
@@ -24,6 +34,12 @@ pub struct Subscriber {
     /// Optional structured payload for generated events.
     /// Accepts arbitrary JSON that will be included in the event alongside system_info.
     pub payload: Option<serde_json::Value>,
+    /// Optional list of typed fields. When set, each run emits an Arrow RecordBatch
+    /// filled with random values instead of a JSON payload.
+    /// Mutually exclusive with `payload`.
+    pub schema: Option<Vec<GeneratedField>>,
+    /// Number of rows per generated RecordBatch (defaults to 1). Only used with `schema`.
+    pub batch_size: Option<usize>,
     /// Interval - runs IMMEDIATELY then repeats every duration.
     /// Accepts duration strings: "100ms", "30s", "5m", etc.
     /// Mutually exclusive with `cron`.
@@ -39,6 +55,10 @@ pub struct Subscriber {
     ///
     /// Mutually exclusive with `interval`.
     pub cron: Option<String>,
+    /// Optional load-testing rate mode (fixed, burst or ramp).
+    /// Events are emitted at the configured pace without waiting for flow completion.
+    /// Mutually exclusive with `interval` and `cron`.
+    pub rate_mode: Option<RateMode>,
     /// Optional timezone for cron scheduling (defaults to UTC).
     /// Uses IANA timezone names (e.g., "US/Eastern", "Europe/London", "Asia/Tokyo").
     #[serde(default)]
@@ -64,12 +84,173 @@ pub struct Subscriber {
     /// Optional retry configuration (overrides app-level retry config).
     #[serde(default)]
     pub retry: Option<crate::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<crate::error_policy::ErrorPolicy>,
+}
+
+/// A named field filled with random data by the generate task.
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize, Hash)]
+pub struct GeneratedField {
+    /// Column name.
+    pub name: String,
+    /// Column type, which determines the generated values.
+    #[serde(flatten)]
+    pub field_type: GeneratedType,
+}
+
+/// Default increment for sequence fields.
+fn default_sequence_step() -> i64 {
+    1
+}
+
+/// Column types supported for generated data.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Deserialize, Serialize, Hash)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum GeneratedType {
+    /// Random UUID string.
+    Utf8,
+    /// Random 64-bit integer.
+    Int64,
+    /// Random float in `[0, 1)`.
+    Float64,
+    /// Current time as a UTC timestamp with microsecond precision.
+    Timestamp,
+    /// 64-bit integer counting up from `start` by `step` across rows and runs.
+    /// Restarts from `start` once `count` is reached.
+    Sequence {
+        #[serde(default)]
+        start: i64,
+        #[serde(default = "default_sequence_step")]
+        step: i64,
+    },
+}
+
+/// Pacing of generated events for load testing.
+///
+/// # Examples
+///
+/// ```yaml
+/// rate_mode:
+///   burst:
+///     events_per_burst: 500
+///     burst_interval: 10s
+/// ```
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RateMode {
+    /// One event every `interval`.
+    Fixed {
+        #[serde(with = "humantime_serde")]
+        interval: Duration,
+    },
+    /// `events_per_burst` events at once, then a pause of `burst_interval`.
+    Burst {
+        events_per_burst: u64,
+        #[serde(with = "humantime_serde")]
+        burst_interval: Duration,
+    },
+    /// One event per tick, with the delay between ticks moving linearly from
+    /// `1 / start_rps` to `1 / end_rps` over `ramp_duration`, then holding at `end_rps`.
+    Ramp {
+        start_rps: f64,
+        end_rps: f64,
+        #[serde(with = "humantime_serde")]
+        ramp_duration: Duration,
+    },
+}
+
+impl std::hash::Hash for RateMode {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            RateMode::Fixed { interval } => interval.hash(state),
+            RateMode::Burst {
+                events_per_burst,
+                burst_interval,
+            } => {
+                events_per_burst.hash(state);
+                burst_interval.hash(state);
+            }
+            RateMode::Ramp {
+                start_rps,
+                end_rps,
+                ramp_duration,
+            } => {
+                start_rps.to_bits().hash(state);
+                end_rps.to_bits().hash(state);
+                ramp_duration.hash(state);
+            }
+        }
+    }
+}
+
+impl RateMode {
+    /// Number of events emitted on each tick.
+    pub fn events_per_tick(&self) -> u64 {
+        match self {
+            RateMode::Burst {
+                events_per_burst, ..
+            } => *events_per_burst,
+            RateMode::Fixed { .. } | RateMode::Ramp { .. } => 1,
+        }
+    }
+
+    /// Delay before the next tick, given the time elapsed since the first tick.
+    pub fn delay(&self, elapsed: Duration) -> Duration {
+        match self {
+            RateMode::Fixed { interval } => *interval,
+            RateMode::Burst { burst_interval, .. } => *burst_interval,
+            RateMode::Ramp {
+                start_rps,
+                end_rps,
+                ramp_duration,
+            } => {
+                let progress = match ramp_duration.is_zero() {
+                    true => 1.0,
+                    false => (elapsed.as_secs_f64() / ramp_duration.as_secs_f64()).min(1.0),
+                };
+                let start_interval = 1.0 / start_rps;
+                let end_interval = 1.0 / end_rps;
+                Duration::from_secs_f64(start_interval + (end_interval - start_interval) * progress)
+            }
+        }
+    }
+
+    /// Validates burst size and ramp rates.
+    fn validate(&self) -> Result<(), ConfigError> {
+        match self {
+            RateMode::Burst {
+                events_per_burst: 0,
+                ..
+            } => Err(ConfigError::ZeroBurstSize),
+            RateMode::Ramp {
+                start_rps, end_rps, ..
+            } if !(*start_rps > 0.0 && *end_rps > 0.0) => Err(ConfigError::InvalidRampRate),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl Subscriber {
     /// Validates that exactly one scheduling method is specified.
     /// Allows neither interval nor cron when count is specified (run-once mode).
+    /// Also rejects combining `payload` with `schema` and a zero `batch_size`.
+    /// A `rate_mode` replaces `interval` and `cron`.
     pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.payload.is_some() && self.schema.is_some() {
+            return Err(ConfigError::BothPayloadAndSchema);
+        }
+        if self.batch_size == Some(0) {
+            return Err(ConfigError::ZeroBatchSize);
+        }
+        if let Some(rate_mode) = &self.rate_mode {
+            if self.interval.is_some() || self.cron.is_some() {
+                return Err(ConfigError::RateModeWithSchedule);
+            }
+            return rate_mode.validate();
+        }
+
         match (&self.interval, &self.cron, &self.count) {
             // Both interval and cron specified - error
             (Some(_), Some(_), _) => Err(ConfigError::BothSchedulesSpecified),
@@ -110,6 +291,7 @@ mod tests {
             count: Some(10),
             ack_timeout: None,
             retry: None,
+            error_policy: None,
             ..Default::default()
         };
 
@@ -131,6 +313,7 @@ mod tests {
             count: None,
             ack_timeout: None,
             retry: None,
+            error_policy: None,
             ..Default::default()
         };
 
@@ -149,6 +332,7 @@ mod tests {
             count: None,
             ack_timeout: None,
             retry: None,
+            error_policy: None,
             ..Default::default()
         };
 
@@ -168,6 +352,7 @@ mod tests {
             count: None,
             ack_timeout: None,
             retry: None,
+            error_policy: None,
             ..Default::default()
         };
 
@@ -187,6 +372,7 @@ mod tests {
             count: Some(1),
             ack_timeout: None,
             retry: None,
+            error_policy: None,
             ..Default::default()
         };
 
@@ -194,6 +380,185 @@ mod tests {
         assert!(config.validate().is_ok());
     }
 
+    #[test]
+    fn test_subscriber_config_with_schema() {
+        let yaml = r#"
+name: load_test
+interval: 1s
+batch_size: 100
+schema:
+  - name: id
+    type: utf8
+  - name: amount
+    type: float64
+  - name: created_at
+    type: timestamp
+  - name: seq
+    type: sequence
+  - name: order_id
+    type: sequence
+    start: 1000
+    step: 10
+"#;
+        let config: Subscriber = serde_yaml::from_str(yaml).unwrap();
+
+        let schema = config.schema.as_ref().unwrap();
+        assert_eq!(schema.len(), 5);
+        assert_eq!(
+            schema[3].field_type,
+            GeneratedType::Sequence { start: 0, step: 1 }
+        );
+        assert_eq!(
+            schema[4].field_type,
+            GeneratedType::Sequence {
+                start: 1000,
+                step: 10
+            }
+        );
+        assert_eq!(schema[0].name, "id");
+        assert_eq!(schema[1].field_type, GeneratedType::Float64);
+        assert_eq!(schema[2].field_type, GeneratedType::Timestamp);
+        assert_eq!(config.batch_size, Some(100));
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validation_payload_and_schema() {
+        let config = Subscriber {
+            name: "test".to_string(),
+            payload: Some(json!({"test": "data"})),
+            schema: Some(vec![GeneratedField {
+                name: "id".to_string(),
+                field_type: GeneratedType::Utf8,
+            }]),
+            count: Some(1),
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::BothPayloadAndSchema)
+        ));
+
+        let config = Subscriber {
+            payload: None,
+            batch_size: Some(0),
+            ..config
+        };
+        assert!(matches!(config.validate(), Err(ConfigError::ZeroBatchSize)));
+    }
+
+    #[test]
+    fn test_rate_mode_deserialization() {
+        let yaml = r#"
+name: load_test
+rate_mode:
+  burst:
+    events_per_burst: 500
+    burst_interval: 10s
+"#;
+        let config: Subscriber = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            config.rate_mode,
+            Some(RateMode::Burst {
+                events_per_burst: 500,
+                burst_interval: Duration::from_secs(10),
+            })
+        );
+        assert!(config.validate().is_ok());
+
+        let yaml = r#"
+name: load_test
+rate_mode:
+  ramp:
+    start_rps: 10
+    end_rps: 100.5
+    ramp_duration: 1m
+"#;
+        let config: Subscriber = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            config.rate_mode,
+            Some(RateMode::Ramp {
+                start_rps: 10.0,
+                end_rps: 100.5,
+                ramp_duration: Duration::from_secs(60),
+            })
+        );
+    }
+
+    #[test]
+    fn test_rate_mode_delay() {
+        let fixed = RateMode::Fixed {
+            interval: Duration::from_millis(250),
+        };
+        assert_eq!(
+            fixed.delay(Duration::from_secs(10)),
+            Duration::from_millis(250)
+        );
+        assert_eq!(fixed.events_per_tick(), 1);
+
+        let burst = RateMode::Burst {
+            events_per_burst: 50,
+            burst_interval: Duration::from_secs(1),
+        };
+        assert_eq!(burst.delay(Duration::ZERO), Duration::from_secs(1));
+        assert_eq!(burst.events_per_tick(), 50);
+
+        let ramp = RateMode::Ramp {
+            start_rps: 10.0,
+            end_rps: 100.0,
+            ramp_duration: Duration::from_secs(10),
+        };
+        let delay_secs = |elapsed| ramp.delay(Duration::from_secs(elapsed)).as_secs_f64();
+        assert!((delay_secs(0) - 0.1).abs() < 1e-6);
+        assert!((delay_secs(5) - 0.055).abs() < 1e-6);
+        assert!((delay_secs(10) - 0.01).abs() < 1e-6);
+        assert!((delay_secs(60) - 0.01).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_validation_rate_mode() {
+        let config = Subscriber {
+            name: "test".to_string(),
+            rate_mode: Some(RateMode::Fixed {
+                interval: Duration::from_millis(10),
+            }),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        let config = Subscriber {
+            interval: Some(Duration::from_secs(1)),
+            ..config
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::RateModeWithSchedule)
+        ));
+
+        let config = Subscriber {
+            interval: None,
+            rate_mode: Some(RateMode::Burst {
+                events_per_burst: 0,
+                burst_interval: Duration::from_secs(1),
+            }),
+            ..config
+        };
+        assert!(matches!(config.validate(), Err(ConfigError::ZeroBurstSize)));
+
+        let config = Subscriber {
+            rate_mode: Some(RateMode::Ramp {
+                start_rps: 0.0,
+                end_rps: 10.0,
+                ramp_duration: Duration::from_secs(1),
+            }),
+            ..config
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::InvalidRampRate)
+        ));
+    }
+
     #[test]
     fn test_subscriber_config_serialization() {
         let config = Subscriber {
@@ -204,6 +569,7 @@ mod tests {
             count: Some(5),
             ack_timeout: None,
             retry: None,
+            error_policy: None,
             ..Default::default()
         };
 
@@ -223,6 +589,7 @@ mod tests {
             count: None,
             ack_timeout: None,
             retry: None,
+            error_policy: None,
             ..Default::default()
         };
 
@@ -2,15 +2,27 @@
 //!
 //! Implements a timer-based event generator that creates events at regular intervals
 //! with optional structured data payloads and count limits for testing and simulation workflows.
+//! With a typed `schema`, each run emits an Arrow RecordBatch of random rows instead,
+//! which is useful for load testing flows without real upstream systems.
 
 use crate::event::{new_completion_channel, Event, EventBuilder, EventData, EventExt};
+use crate::task::generate::config::{GeneratedField, GeneratedType, RateMode};
+use arrow::{
+    array::{ArrayRef, Float64Array, Int64Array, StringArray, TimestampMicrosecondArray},
+    datatypes::{DataType, Field, Schema, TimeUnit},
+    record_batch::RecordBatch,
+};
 use chrono::DateTime;
 use croner::Cron;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::{
     str::FromStr,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
 use tokio::{sync::mpsc::Sender, time};
@@ -72,6 +84,69 @@ pub enum Error {
         #[source]
         source: crate::task::generate::config::ConfigError,
     },
+    #[error("Error generating record batch: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+}
+
+/// Generates a RecordBatch with `rows` rows of random data for the given fields.
+///
+/// `sequence_offset` is the number of rows generated before this batch, so sequence
+/// fields continue where the previous batch stopped.
+fn generate_batch(
+    fields: &[GeneratedField],
+    rows: usize,
+    sequence_offset: u64,
+) -> Result<RecordBatch, Error> {
+    let mut rng = rand::thread_rng();
+    let now = chrono::Utc::now().timestamp_micros();
+
+    let (schema_fields, columns): (Vec<Field>, Vec<ArrayRef>) = fields
+        .iter()
+        .map(|field| {
+            let (data_type, column): (DataType, ArrayRef) = match field.field_type {
+                GeneratedType::Utf8 => (
+                    DataType::Utf8,
+                    Arc::new(StringArray::from_iter_values((0..rows).map(|_| {
+                        uuid::Builder::from_random_bytes(rng.gen())
+                            .into_uuid()
+                            .to_string()
+                    }))),
+                ),
+                GeneratedType::Int64 => (
+                    DataType::Int64,
+                    Arc::new(Int64Array::from_iter_values(
+                        (0..rows).map(|_| rng.gen::<i64>()),
+                    )),
+                ),
+                GeneratedType::Float64 => (
+                    DataType::Float64,
+                    Arc::new(Float64Array::from_iter_values(
+                        (0..rows).map(|_| rng.gen::<f64>()),
+                    )),
+                ),
+                GeneratedType::Sequence { start, step } => (
+                    DataType::Int64,
+                    Arc::new(Int64Array::from_iter_values((0..rows as u64).map(|row| {
+                        start.wrapping_add(step.wrapping_mul((sequence_offset + row) as i64))
+                    }))),
+                ),
+                GeneratedType::Timestamp => (
+                    DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+                    Arc::new(
+                        TimestampMicrosecondArray::from_iter_values((0..rows).map(|_| now))
+                            .with_timezone("UTC"),
+                    ),
+                ),
+            };
+            (Field::new(&field.name, data_type, false), column)
+        })
+        .unzip();
+
+    RecordBatch::try_new(Arc::new(Schema::new(schema_fields)), columns)
+        .map_err(|source| Error::Arrow { source })
 }
 /// Event handler for generating scheduled events.
 pub struct EventHandler {
@@ -80,6 +155,8 @@ pub struct EventHandler {
     task_id: usize,
     task_context: Arc<crate::task::context::TaskContext>,
     task_type: &'static str,
+    /// Rows generated so far, used as the position of `sequence` fields.
+    sequence_offset: AtomicU64,
 }
 
 impl EventHandler {
@@ -131,6 +208,101 @@ impl EventHandler {
         }
     }
 
+    /// Prepares random Arrow rows for a typed schema, otherwise JSON data with
+    /// system information and optional user-defined payload.
+    fn event_data(&self, system_info: &SystemInfo) -> Result<EventData, Error> {
+        match &self.config.schema {
+            Some(fields) => {
+                let rows = self.config.batch_size.unwrap_or(1);
+                let sequence_offset = self
+                    .sequence_offset
+                    .fetch_add(rows as u64, Ordering::Relaxed);
+                Ok(EventData::ArrowRecordBatch(generate_batch(
+                    fields,
+                    rows,
+                    sequence_offset,
+                )?))
+            }
+            None => {
+                let mut data = match &self.config.payload {
+                    Some(user_data) => user_data.clone(),
+                    None => json!({}),
+                };
+                if let Some(obj) = data.as_object_mut() {
+                    obj.insert(
+                        "system_info".to_string(),
+                        serde_json::to_value(system_info).unwrap_or(serde_json::Value::Null),
+                    );
+                }
+                Ok(EventData::Json(data))
+            }
+        }
+    }
+
+    /// Generates events at the pace set by `rate_mode`.
+    ///
+    /// Unlike scheduled runs, events are sent without waiting for flow completion
+    /// so the configured rate is not capped by downstream latency. The counter is
+    /// only persisted once `count` is reached.
+    async fn handle_rate(
+        &self,
+        rate_mode: &RateMode,
+        mut counter: u64,
+        counter_cache_key: &str,
+    ) -> Result<(), Error> {
+        let started = time::Instant::now();
+
+        loop {
+            if self.task_context.cancellation_token.is_cancelled() {
+                return Ok(());
+            }
+
+            let current_time = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_err(|e| Error::SystemTime { source: e })?
+                .as_secs();
+            let delay = rate_mode.delay(started.elapsed());
+            let system_info = SystemInfo {
+                last_run_time: Some(current_time),
+                next_run_time: Some(current_time + delay.as_secs()),
+            };
+
+            for _ in 0..rate_mode.events_per_tick() {
+                if self.config.count.is_some_and(|count| counter >= count) {
+                    break;
+                }
+
+                let e = EventBuilder::new()
+                    .data(self.event_data(&system_info)?)
+                    .subject(self.config.name.to_owned())
+                    .task_id(self.task_id)
+                    .task_type(self.task_type)
+                    .build()
+                    .map_err(|source| Error::EventBuilder { source })?;
+                e.send_with_logging(self.tx.as_ref())
+                    .await
+                    .map_err(|source| Error::SendMessage { source })?;
+                self.task_context.metrics.record_received();
+                counter += 1;
+            }
+
+            if self.config.count.is_some_and(|count| counter >= count) {
+                if let Err(cache_err) = self
+                    .task_context
+                    .cache
+                    .put(counter_cache_key, counter.to_string().into(), None)
+                    .await
+                {
+                    warn!("Failed to update counter cache: {:?}", cache_err);
+                }
+                self.sequence_offset.store(0, Ordering::Relaxed);
+                return Ok(());
+            }
+
+            time::sleep(delay).await;
+        }
+    }
+
     /// Generates events at scheduled intervals.
     async fn handle(&self) -> Result<(), Error> {
         // Note: This generator creates events from scratch (no incoming events),
@@ -166,6 +338,12 @@ impl EventHandler {
             }
         }
 
+        if let Some(rate_mode) = &self.config.rate_mode {
+            return self
+                .handle_rate(rate_mode, counter, &counter_cache_key)
+                .await;
+        }
+
         loop {
             if self.task_context.cancellation_token.is_cancelled() {
                 return Ok(());
@@ -214,17 +392,7 @@ impl EventHandler {
                 next_run_time: next_run_time_val,
             };
 
-            // Prepare event data with system information and optional user-defined payload.
-            let mut data = match &self.config.payload {
-                Some(user_data) => user_data.clone(),
-                None => json!({}),
-            };
-            if let Some(obj) = data.as_object_mut() {
-                obj.insert(
-                    "system_info".to_string(),
-                    serde_json::to_value(&system_info).unwrap_or(serde_json::Value::Null),
-                );
-            }
+            let data = self.event_data(&system_info)?;
 
             // Create a completion channel sized to the number of leaves in
             // this flow's directed acyclic graph. The source acks the
@@ -234,7 +402,7 @@ impl EventHandler {
 
             // Build and send event with completion channel.
             let e = EventBuilder::new()
-                .data(EventData::Json(data))
+                .data(data)
                 .subject(self.config.name.to_owned())
                 .task_id(self.task_id)
                 .task_type(self.task_type)
@@ -244,6 +412,7 @@ impl EventHandler {
             e.send_with_logging(self.tx.as_ref())
                 .await
                 .map_err(|source| Error::SendMessage { source })?;
+            self.task_context.metrics.record_received();
 
             // Wait for flow completion before updating cache.
             let success = match self.config.ack_timeout {
@@ -279,7 +448,11 @@ impl EventHandler {
             }
 
             match self.config.count {
-                Some(count) if count == counter => return Ok(()),
+                Some(count) if count == counter => {
+                    // Sequence fields restart from `start` on the next run.
+                    self.sequence_offset.store(0, Ordering::Relaxed);
+                    return Ok(());
+                }
                 Some(_) | None => {}
             }
         }
@@ -317,6 +490,7 @@ impl crate::task::runner::Runner for Subscriber {
             task_id: self.task_id,
             task_context: Arc::clone(&self.task_context),
             task_type: self.task_type,
+            sequence_offset: AtomicU64::new(0),
         })
     }
 
@@ -473,6 +647,7 @@ mod tests {
             count: Some(1),
             ack_timeout: None,
             retry: None,
+            error_policy: None,
             ..Default::default()
         });
         let (tx, _rx) = mpsc::channel(100);
@@ -511,6 +686,7 @@ mod tests {
             count: Some(2),
             ack_timeout: None,
             retry: None,
+            error_policy: None,
             ..Default::default()
         });
 
@@ -559,6 +735,7 @@ mod tests {
             count: Some(1),
             ack_timeout: None,
             retry: None,
+            error_policy: None,
             ..Default::default()
         });
 
@@ -591,6 +768,145 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_generate_batch() {
+        use arrow::array::{Array, AsArray};
+        use arrow::datatypes::{Float64Type, TimestampMicrosecondType};
+
+        let fields = vec![
+            GeneratedField {
+                name: "id".to_string(),
+                field_type: GeneratedType::Utf8,
+            },
+            GeneratedField {
+                name: "quantity".to_string(),
+                field_type: GeneratedType::Int64,
+            },
+            GeneratedField {
+                name: "score".to_string(),
+                field_type: GeneratedType::Float64,
+            },
+            GeneratedField {
+                name: "created_at".to_string(),
+                field_type: GeneratedType::Timestamp,
+            },
+        ];
+        let batch = generate_batch(&fields, 5, 0).unwrap();
+
+        assert_eq!(batch.num_rows(), 5);
+        assert_eq!(batch.num_columns(), 4);
+        assert_eq!(batch.schema().field(1).data_type(), &DataType::Int64);
+
+        let ids = batch.column(0).as_string::<i32>();
+        assert!(ids
+            .iter()
+            .all(|id| uuid::Uuid::parse_str(id.unwrap()).is_ok()));
+        assert_ne!(ids.value(0), ids.value(1));
+
+        let scores = batch.column(2).as_primitive::<Float64Type>();
+        assert!(scores.values().iter().all(|v| (0.0..1.0).contains(v)));
+
+        let created_at = batch.column(3).as_primitive::<TimestampMicrosecondType>();
+        assert_eq!(created_at.timezone(), Some("UTC"));
+        assert_eq!(created_at.null_count(), 0);
+    }
+
+    #[test]
+    fn test_generate_batch_sequence() {
+        use arrow::array::AsArray;
+        use arrow::datatypes::Int64Type;
+
+        let fields = vec![
+            GeneratedField {
+                name: "seq".to_string(),
+                field_type: GeneratedType::Sequence { start: 0, step: 1 },
+            },
+            GeneratedField {
+                name: "order_id".to_string(),
+                field_type: GeneratedType::Sequence {
+                    start: 1000,
+                    step: 10,
+                },
+            },
+        ];
+
+        let batch = generate_batch(&fields, 3, 0).unwrap();
+        let seq = batch.column(0).as_primitive::<Int64Type>();
+        assert_eq!(seq.values().to_vec(), vec![0, 1, 2]);
+
+        let batch = generate_batch(&fields, 3, 3).unwrap();
+        let seq = batch.column(0).as_primitive::<Int64Type>();
+        let order_ids = batch.column(1).as_primitive::<Int64Type>();
+        assert_eq!(seq.values().to_vec(), vec![3, 4, 5]);
+        assert_eq!(order_ids.values().to_vec(), vec![1030, 1040, 1050]);
+    }
+
+    #[tokio::test]
+    async fn test_subscriber_event_arrow_schema() {
+        let config = Arc::new(crate::task::generate::config::Subscriber {
+            name: "test".to_string(),
+            schema: Some(vec![GeneratedField {
+                name: "id".to_string(),
+                field_type: GeneratedType::Utf8,
+            }]),
+            batch_size: Some(3),
+            count: Some(1),
+            ..Default::default()
+        });
+
+        let (tx, mut rx) = mpsc::channel(100);
+        let subscriber = Subscriber {
+            config,
+            tx: Some(tx),
+            task_id: 0,
+            task_type: "test",
+            task_context: create_mock_task_context(),
+        };
+
+        tokio::spawn(async move {
+            let _ = subscriber.run().await;
+        });
+
+        let event = rx.recv().await.unwrap();
+        match event.data {
+            EventData::ArrowRecordBatch(batch) => assert_eq!(batch.num_rows(), 3),
+            _ => panic!("Expected Arrow event data"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscriber_burst_rate_mode() {
+        let config = Arc::new(crate::task::generate::config::Subscriber {
+            name: "test".to_string(),
+            rate_mode: Some(RateMode::Burst {
+                events_per_burst: 3,
+                burst_interval: Duration::from_millis(10),
+            }),
+            count: Some(5),
+            ..Default::default()
+        });
+
+        let (tx, mut rx) = mpsc::channel(100);
+        let subscriber = Subscriber {
+            config,
+            tx: Some(tx),
+            task_id: 0,
+            task_type: "test",
+            task_context: create_mock_task_context(),
+        };
+
+        tokio::spawn(async move {
+            let _ = subscriber.run().await;
+        });
+
+        // Events are not acknowledged; rate modes do not wait for flow completion.
+        for _ in 0..5 {
+            let event = rx.recv().await.unwrap();
+            assert!(event.completion_tx.is_none());
+        }
+        assert!(rx.recv().await.is_none());
+    }
+
     #[tokio::test]
     async fn test_cache_key_generation() {
         let config = Arc::new(crate::task::generate::config::Subscriber {
@@ -601,6 +917,7 @@ mod tests {
             count: Some(1),
             ack_timeout: None,
             retry: None,
+            error_policy: None,
             ..Default::default()
         });
 
@@ -672,6 +989,7 @@ mod tests {
             count: Some(2),
             ack_timeout: None,
             retry: None,
+            error_policy: None,
             ..Default::default()
         });
 
@@ -749,6 +1067,7 @@ mod tests {
             count: Some(3),
             ack_timeout: None,
             retry: None,
+            error_policy: None,
             ..Default::default()
         });
 
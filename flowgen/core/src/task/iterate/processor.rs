@@ -4,14 +4,11 @@
 //! for each array element, enabling fan-out processing patterns.
 
 use crate::event::{
-    new_completion_channel, CompletionRx, Event, EventBuilder, EventData, EventExt,
-    SharedCompletionTx,
+    new_completion_channel, spawn_fan_in_completion, Event, EventBuilder, EventData, EventExt,
 };
 use serde_json::Value;
-use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use tokio::sync::mpsc::{Receiver, Sender};
-use tokio::sync::oneshot;
 use tracing::{error, Instrument};
 
 /// Errors that can occur during loop processing operations.
@@ -46,68 +43,6 @@ pub enum Error {
     },
 }
 
-/// Coordinates completion signalling across all events emitted for a single
-/// iterated array.
-///
-/// Each emitted event carries its own per-element completion channel. The
-/// associated receivers are joined into a fan-in task which awaits all of
-/// them and only then signals the upstream source. This is required because
-/// downstream processors spawn a `tokio::task` per incoming event —
-/// attaching the upstream completion to only the last emitted event would
-/// signal completion while other elements are still in flight, leading to
-/// premature acknowledgements and silent data loss.
-///
-/// The upstream `CompletionState` was sized at flow build time to expect one
-/// signal per leaf reachable from iterate's subtree. Once every element
-/// finishes, the fan-in emits exactly that many upstream signals so the
-/// original source's leaf-count contract is satisfied.
-///
-/// Per-element errors are not propagated upstream: failed downstream tasks
-/// emit error events of their own, and the source falls back to its
-/// acknowledgement timeout when no completion arrives. Missing per-element
-/// signals are treated as success to avoid deadlocking the source on buggy
-/// downstream handlers.
-fn spawn_fan_in_completion(
-    upstream: SharedCompletionTx,
-    per_event_receivers: Vec<CompletionRx>,
-    upstream_leaf_share: usize,
-) {
-    tokio::spawn(async move {
-        let total = per_event_receivers.len();
-        let remaining = Arc::new(AtomicUsize::new(total));
-        let (done_tx, done_rx) = oneshot::channel::<()>();
-        let done_tx = Arc::new(std::sync::Mutex::new(Some(done_tx)));
-
-        for rx in per_event_receivers {
-            let remaining = Arc::clone(&remaining);
-            let done_tx = Arc::clone(&done_tx);
-            tokio::spawn(async move {
-                // Drop the result regardless of outcome; errors flow as
-                // downstream events, not through completion channels.
-                let _ = rx.await;
-                if remaining.fetch_sub(1, Ordering::SeqCst) == 1 {
-                    if let Ok(mut guard) = done_tx.lock() {
-                        if let Some(tx) = guard.take() {
-                            tx.send(()).ok();
-                        }
-                    }
-                }
-            });
-        }
-
-        // Wait for all per-event completions to report in.
-        done_rx.await.ok();
-
-        // Emit one upstream signal per leaf in iterate's subtree. The source
-        // sized its completion channel for the full leaf set; collapsing the
-        // entire subtree into a single signal would leave the source waiting
-        // for the rest forever.
-        for _ in 0..upstream_leaf_share.max(1) {
-            upstream.signal_completion(None);
-        }
-    });
-}
-
 /// Handles individual event processing by iterating over JSON arrays.
 pub struct EventHandler {
     /// Loop processor configuration settings.
@@ -449,6 +384,7 @@ mod tests {
             iterate_key: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
         let (tx, rx) = mpsc::channel(100);
 
@@ -485,6 +421,7 @@ mod tests {
             iterate_key: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
 
         let (tx, mut rx) = mpsc::channel(100);
@@ -507,6 +444,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
         };
 
         tokio::spawn(async move {
@@ -537,6 +475,7 @@ mod tests {
             iterate_key: Some("items".to_string()),
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
 
         let (tx, mut rx) = mpsc::channel(100);
@@ -562,6 +501,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
         };
 
         tokio::spawn(async move {
@@ -592,6 +532,7 @@ mod tests {
             iterate_key: Some("missing".to_string()),
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
 
         let (tx, _rx) = mpsc::channel(100);
@@ -614,6 +555,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
         };
 
         let result = event_handler.handle(input_event).await;
@@ -632,6 +574,7 @@ mod tests {
             iterate_key: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
 
         let (tx, mut rx) = mpsc::channel(100);
@@ -656,6 +599,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: Some(Arc::clone(&upstream_state)),
+            span: None,
         };
 
         tokio::spawn(async move {
@@ -714,6 +658,7 @@ mod tests {
             iterate_key: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
 
         let event_handler = EventHandler {
@@ -736,6 +681,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: Some(upstream_state),
+            span: None,
         };
 
         event_handler.handle(input_event).await.unwrap();
@@ -754,6 +700,7 @@ mod tests {
             iterate_key: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
 
         let (tx, _rx) = mpsc::channel(100);
@@ -776,6 +723,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
         };
 
         let result = event_handler.handle(input_event).await;
@@ -798,6 +746,7 @@ mod tests {
             iterate_key: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
 
         let (tx, mut rx) = mpsc::channel(100);
@@ -824,6 +773,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
         };
 
         tokio::spawn(async move {
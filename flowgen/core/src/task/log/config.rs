@@ -15,6 +15,10 @@ pub struct Processor {
     /// When false, logs pretty-printed JSON strings for console readability.
     #[serde(default)]
     pub structured: bool,
+    /// Logs a sample of the first rows of the event data instead of the whole
+    /// event. Arrow and Avro data are shown as a table. Ignored in structured mode.
+    #[serde(default)]
+    pub sample_rows: Option<usize>,
     /// Optional list of upstream task names this task depends on.
     /// When set, this task only receives events from the named tasks.
     /// When not set, the task receives from the previous task in the list (linear chain).
@@ -23,6 +27,9 @@ pub struct Processor {
     /// Optional retry configuration (overrides app-level retry config).
     #[serde(default)]
     pub retry: Option<crate::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<crate::error_policy::ErrorPolicy>,
 }
 
 /// Log level options.
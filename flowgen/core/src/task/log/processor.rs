@@ -48,6 +48,8 @@ impl EventHandler {
             return Ok(());
         }
 
+        crate::event::continue_trace(&event);
+
         if self.config.structured {
             // Structured logging mode for Grafana/Loki.
             match self.config.level {
@@ -57,6 +59,16 @@ impl EventHandler {
                 super::config::LogLevel::Warn => warn!(event = ?event),
                 super::config::LogLevel::Error => error!(event = ?event),
             }
+        } else if let Some(max_rows) = self.config.sample_rows {
+            // Sampled mode for large events, shown as a table where possible.
+            let sample = event.display_sample(max_rows);
+            match self.config.level {
+                super::config::LogLevel::Trace => trace!("event sample:\n{}", sample),
+                super::config::LogLevel::Debug => debug!("event sample:\n{}", sample),
+                super::config::LogLevel::Info => info!("event sample:\n{}", sample),
+                super::config::LogLevel::Warn => warn!("event sample:\n{}", sample),
+                super::config::LogLevel::Error => error!("event sample:\n{}", sample),
+            }
         } else {
             // Pretty-printed mode for console readability.
             match self.config.level {
@@ -293,8 +305,10 @@ mod tests {
             name: "test".to_string(),
             level: crate::task::log::config::LogLevel::Info,
             structured: false,
+            sample_rows: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
         let (tx, rx) = mpsc::channel(100);
 
@@ -330,8 +344,10 @@ mod tests {
             name: "test".to_string(),
             level: crate::task::log::config::LogLevel::Info,
             structured: false,
+            sample_rows: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
 
         let (tx, _rx) = mpsc::channel(100);
@@ -361,8 +377,10 @@ mod tests {
             name: "test".to_string(),
             level: crate::task::log::config::LogLevel::Info,
             structured: false,
+            sample_rows: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
 
         let (tx, _rx) = mpsc::channel(100);
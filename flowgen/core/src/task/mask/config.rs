@@ -0,0 +1,114 @@
+//! Configuration for the mask task processor.
+//!
+//! The mask task replaces sensitive column values (PII) with redacted, hashed,
+//! truncated or regex-rewritten values before data leaves the flow.
+
+use crate::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for mask processor task.
+///
+/// # Examples
+///
+/// ```yaml
+/// mask:
+///   name: "mask_contacts"
+///   mask_rules:
+///     - column: email
+///       strategy: hash
+///     - column: ssn
+///       strategy: redact
+///     - column: postal_code
+///       strategy:
+///         truncate: 3
+///     - column: phone
+///       strategy:
+///         regex:
+///           pattern: "^(\\+?\\d{2})\\d+"
+///           replacement: "${1}******"
+/// ```
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Processor {
+    /// The unique name / identifier of the task.
+    pub name: String,
+    /// Columns to mask and how to mask them.
+    pub mask_rules: Vec<MaskRule>,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    #[serde(default)]
+    pub retry: Option<crate::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<crate::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Processor {}
+
+/// Masking applied to a single column.
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct MaskRule {
+    /// Column (or top-level JSON field) to mask.
+    pub column: String,
+    /// How values in the column are masked.
+    pub strategy: MaskStrategy,
+}
+
+/// Supported masking strategies.
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MaskStrategy {
+    /// Replace the value with `***`.
+    Redact,
+    /// Replace the value with its SHA-256 hex digest.
+    Hash,
+    /// Keep only the first N characters.
+    Truncate(usize),
+    /// Replace every match of `pattern` with `replacement`.
+    /// The replacement may reference capture groups (e.g. `$1`).
+    Regex {
+        pattern: String,
+        replacement: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_processor_deserialization() {
+        let yaml = r#"
+name: mask_contacts
+mask_rules:
+  - column: email
+    strategy: hash
+  - column: ssn
+    strategy: redact
+  - column: postal_code
+    strategy:
+      truncate: 3
+  - column: phone
+    strategy:
+      regex:
+        pattern: "\\d"
+        replacement: "*"
+"#;
+        let processor: Processor = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(processor.mask_rules.len(), 4);
+        assert_eq!(processor.mask_rules[0].strategy, MaskStrategy::Hash);
+        assert_eq!(processor.mask_rules[1].strategy, MaskStrategy::Redact);
+        assert_eq!(processor.mask_rules[2].strategy, MaskStrategy::Truncate(3));
+        assert_eq!(
+            processor.mask_rules[3].strategy,
+            MaskStrategy::Regex {
+                pattern: "\\d".to_string(),
+                replacement: "*".to_string(),
+            }
+        );
+    }
+}
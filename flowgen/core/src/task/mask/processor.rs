@@ -0,0 +1,524 @@
+//! Data masking processor for PII fields.
+//!
+//! Rewrites configured columns of Arrow RecordBatch events (or top-level fields
+//! of JSON events) using redaction, hashing, truncation or regex replacement.
+//! All other columns pass through unchanged.
+
+use super::config::{MaskRule, MaskStrategy};
+use crate::event::{Event, EventBuilder, EventData, EventExt};
+use arrow::array::{Array, ArrayRef, AsArray, RecordBatch, StringArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use serde_json::Value;
+use sha2::Digest;
+use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tracing::{error, Instrument};
+
+/// Replacement used by the `redact` strategy.
+const REDACTED: &str = "***";
+
+/// Errors that can occur during mask processing operations.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Invalid mask regex for column '{column}': {source}")]
+    InvalidRegex {
+        column: String,
+        #[source]
+        source: regex::Error,
+    },
+    #[error("Arrow error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+    #[error("Avro event data cannot be masked, convert it to JSON or Arrow first.")]
+    AvroNotSupported,
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+    #[error("Task failed after all retry attempts: {source}")]
+    RetryExhausted {
+        #[source]
+        source: Box<Error>,
+    },
+}
+
+/// Mask strategy with its regex compiled once at init.
+#[derive(Debug)]
+enum Masker {
+    Redact,
+    Hash,
+    Truncate(usize),
+    Regex {
+        regex: regex::Regex,
+        replacement: String,
+    },
+}
+
+impl Masker {
+    fn new(rule: &MaskRule) -> Result<Self, Error> {
+        Ok(match &rule.strategy {
+            MaskStrategy::Redact => Masker::Redact,
+            MaskStrategy::Hash => Masker::Hash,
+            MaskStrategy::Truncate(len) => Masker::Truncate(*len),
+            MaskStrategy::Regex {
+                pattern,
+                replacement,
+            } => Masker::Regex {
+                regex: regex::Regex::new(pattern).map_err(|source| Error::InvalidRegex {
+                    column: rule.column.clone(),
+                    source,
+                })?,
+                replacement: replacement.clone(),
+            },
+        })
+    }
+
+    fn mask(&self, value: &str) -> String {
+        match self {
+            Masker::Redact => REDACTED.to_string(),
+            Masker::Hash => format!("{:x}", sha2::Sha256::digest(value.as_bytes())),
+            Masker::Truncate(len) => value.chars().take(*len).collect(),
+            Masker::Regex { regex, replacement } => {
+                regex.replace_all(value, replacement.as_str()).into_owned()
+            }
+        }
+    }
+}
+
+/// Masks the configured columns of a record batch.
+///
+/// Masked columns are cast to strings and become `Utf8`; nulls stay null.
+/// Columns not present in the batch are ignored.
+fn mask_batch(batch: &RecordBatch, maskers: &[(String, Masker)]) -> Result<RecordBatch, Error> {
+    let schema = batch.schema();
+    let mut fields: Vec<Field> = schema.fields().iter().map(|f| f.as_ref().clone()).collect();
+    let mut columns: Vec<ArrayRef> = batch.columns().to_vec();
+
+    for (column, masker) in maskers {
+        let Ok(index) = schema.index_of(column) else {
+            continue;
+        };
+        let strings = arrow::compute::cast(columns[index].as_ref(), &DataType::Utf8)
+            .map_err(|source| Error::Arrow { source })?;
+        let masked: StringArray = strings
+            .as_string::<i32>()
+            .iter()
+            .map(|value| value.map(|v| masker.mask(v)))
+            .collect();
+
+        fields[index] = Field::new(column, DataType::Utf8, fields[index].is_nullable())
+            .with_metadata(fields[index].metadata().clone());
+        columns[index] = Arc::new(masked);
+    }
+
+    RecordBatch::try_new(
+        Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone())),
+        columns,
+    )
+    .map_err(|source| Error::Arrow { source })
+}
+
+/// Masks the configured top-level fields of a JSON object or array of objects.
+///
+/// Non-string values are masked by their JSON text; nulls stay null.
+fn mask_json(value: &mut Value, maskers: &[(String, Masker)]) {
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                mask_json(item, maskers);
+            }
+        }
+        Value::Object(object) => {
+            for (column, masker) in maskers {
+                if let Some(field) = object.get_mut(column) {
+                    let masked = match field {
+                        Value::Null => continue,
+                        Value::String(s) => masker.mask(s),
+                        other => masker.mask(&other.to_string()),
+                    };
+                    *field = Value::String(masked);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Handles individual event processing by masking configured columns.
+pub struct EventHandler {
+    /// Compiled mask strategies keyed by column name.
+    maskers: Vec<(String, Masker)>,
+    /// Processor configuration settings.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for processed events (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event tracking.
+    task_id: usize,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+}
+
+impl EventHandler {
+    /// Processes an event by masking its configured columns.
+    #[tracing::instrument(skip(self, event), name = "task.handle")]
+    async fn handle(&self, event: Event) -> Result<(), Error> {
+        if self.task_context.cancellation_token.is_cancelled() {
+            return Ok(());
+        }
+
+        let event = Arc::new(event);
+        let completion_tx_arc = Arc::clone(&event).completion_tx.clone();
+        crate::event::with_event_context(&Arc::clone(&event), async move {
+            // Masking keeps the row count, so an empty input stays an empty output.
+            let event_builder = EventBuilder::new().allow_empty(true);
+            let data = match &event.data {
+                EventData::ArrowRecordBatch(batch) => {
+                    EventData::ArrowRecordBatch(mask_batch(batch, &self.maskers)?)
+                }
+                EventData::Json(data) => {
+                    let mut data = data.clone();
+                    mask_json(&mut data, &self.maskers);
+                    EventData::Json(data)
+                }
+                // Passing Avro through unmasked would leak the fields this task exists to hide.
+                EventData::Avro(_) => return Err(Error::AvroNotSupported),
+            };
+
+            let mut e = event_builder
+                .data(data)
+                .subject(self.config.name.to_owned())
+                .task_id(self.task_id)
+                .task_type(self.task_type)
+                .build()
+                .map_err(|source| Error::EventBuilder { source })?;
+
+            // Signal completion or pass through to next task.
+            match self.tx {
+                None => {
+                    if let Some(arc) = completion_tx_arc.as_ref() {
+                        arc.signal_completion(e.data_as_json().ok());
+                    }
+                }
+                Some(_) => {
+                    e.completion_tx = completion_tx_arc.clone();
+                }
+            }
+
+            e.send_with_logging(self.tx.as_ref())
+                .await
+                .map_err(|source| Error::SendMessage { source })?;
+            Ok(())
+        })
+        .await
+    }
+}
+
+/// Data masking processor that rewrites PII columns.
+#[derive(Debug)]
+pub struct Processor {
+    /// Mask task configuration.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for masked events.
+    tx: Option<Sender<Event>>,
+    /// Channel receiver for incoming events to mask.
+    rx: Receiver<Event>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl crate::task::runner::Runner for Processor {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Initializes the processor by compiling the mask rules.
+    async fn init(&self) -> Result<Self::EventHandler, Self::Error> {
+        let maskers = self
+            .config
+            .mask_rules
+            .iter()
+            .map(|rule| Ok((rule.column.clone(), Masker::new(rule)?)))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(EventHandler {
+            maskers,
+            config: Arc::clone(&self.config),
+            tx: self.tx.clone(),
+            task_id: self.task_id,
+            task_type: self.task_type,
+            task_context: Arc::clone(&self.task_context),
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(mut self) -> Result<(), Error> {
+        let retry_config =
+            crate::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        let event_handler = match tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+            match self.init().await {
+                Ok(handler) => Ok(handler),
+                Err(e @ Error::InvalidRegex { .. }) => {
+                    error!(error = %e, "Invalid mask configuration");
+                    Err(tokio_retry::RetryError::permanent(e))
+                }
+                Err(e) => {
+                    error!(error = %e, "Failed to initialize mask processor");
+                    Err(tokio_retry::RetryError::transient(e))
+                }
+            }
+        })
+        .await
+        {
+            Ok(handler) => Arc::new(handler),
+            Err(e) => {
+                return Err(e);
+            }
+        };
+
+        loop {
+            match self.rx.recv().await {
+                Some(event) => {
+                    let event_handler = Arc::clone(&event_handler);
+                    let retry_strategy = retry_config.strategy();
+                    tokio::spawn(
+                        async move {
+                            let result = tokio_retry::Retry::spawn(retry_strategy, || async {
+                                match event_handler.handle(event.clone()).await {
+                                    Ok(result) => Ok(result),
+                                    Err(e) => {
+                                        error!(error = %e, "Failed to mask event");
+                                        Err(tokio_retry::RetryError::transient(e))
+                                    }
+                                }
+                            })
+                            .await;
+
+                            if let Err(err) = result {
+                                error!(error = %err, "Mask failed after all retry attempts.");
+                                // Emit error event downstream for error handling.
+                                // The original data is dropped so unmasked PII never travels on.
+                                let mut error_event = event.clone();
+                                error_event.data = EventData::Json(Value::Null);
+                                error_event.error = Some(err.to_string());
+                                if let Some(ref tx) = event_handler.tx {
+                                    tx.send(error_event).await.ok();
+                                }
+                            }
+                        }
+                        .instrument(tracing::Span::current()),
+                    );
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Builder for constructing Processor instances with validation.
+#[derive(Debug, Default)]
+pub struct ProcessorBuilder {
+    /// Processor configuration (required for build).
+    config: Option<Arc<super::config::Processor>>,
+    /// Event sender for passing events to next task (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Event receiver for incoming events (required for build).
+    rx: Option<Receiver<Event>>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Option<Arc<crate::task::context::TaskContext>>,
+    /// Task type for event categorization and logging.
+    task_type: Option<&'static str>,
+}
+
+impl ProcessorBuilder {
+    pub fn new() -> ProcessorBuilder {
+        ProcessorBuilder {
+            ..Default::default()
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Processor>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn receiver(mut self, receiver: Receiver<Event>) -> Self {
+        self.rx = Some(receiver);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = task_id;
+        self
+    }
+
+    pub fn task_context(mut self, task_context: Arc<crate::task::context::TaskContext>) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Processor, Error> {
+        Ok(Processor {
+            config: self
+                .config
+                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            rx: self
+                .rx
+                .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
+            tx: self.tx,
+            task_id: self.task_id,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::Int64Array;
+    use serde_json::json;
+
+    fn rule(column: &str, strategy: MaskStrategy) -> MaskRule {
+        MaskRule {
+            column: column.to_string(),
+            strategy,
+        }
+    }
+
+    fn maskers(rules: &[MaskRule]) -> Vec<(String, Masker)> {
+        rules
+            .iter()
+            .map(|r| (r.column.clone(), Masker::new(r).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_masker_strategies() {
+        assert_eq!(
+            Masker::new(&rule("c", MaskStrategy::Redact))
+                .unwrap()
+                .mask("secret"),
+            "***"
+        );
+        assert_eq!(
+            Masker::new(&rule("c", MaskStrategy::Hash))
+                .unwrap()
+                .mask("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            Masker::new(&rule("c", MaskStrategy::Truncate(3)))
+                .unwrap()
+                .mask("Zürich"),
+            "Zür"
+        );
+        let regex = MaskStrategy::Regex {
+            pattern: r"^(\d{3})\d+".to_string(),
+            replacement: "${1}****".to_string(),
+        };
+        assert_eq!(
+            Masker::new(&rule("c", regex)).unwrap().mask("5551234567"),
+            "555****"
+        );
+    }
+
+    #[test]
+    fn test_masker_invalid_regex() {
+        let strategy = MaskStrategy::Regex {
+            pattern: "(".to_string(),
+            replacement: String::new(),
+        };
+        assert!(matches!(
+            Masker::new(&rule("phone", strategy)),
+            Err(Error::InvalidRegex { column, .. }) if column == "phone"
+        ));
+    }
+
+    #[test]
+    fn test_mask_batch() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("id", DataType::Int64, false),
+                Field::new("email", DataType::Utf8, true),
+                Field::new("ssn", DataType::Int64, true),
+            ])),
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec![Some("a@example.com"), None])),
+                Arc::new(Int64Array::from(vec![Some(123456789), Some(987654321)])),
+            ],
+        )
+        .unwrap();
+        let maskers = maskers(&[
+            rule("email", MaskStrategy::Redact),
+            rule("ssn", MaskStrategy::Truncate(3)),
+            rule("missing", MaskStrategy::Hash),
+        ]);
+
+        let masked = mask_batch(&batch, &maskers).unwrap();
+
+        assert_eq!(masked.num_columns(), 3);
+        assert_eq!(masked.column(0).as_ref(), batch.column(0).as_ref());
+        let email = masked.column(1).as_string::<i32>();
+        assert_eq!(email.value(0), "***");
+        assert!(email.is_null(1));
+        assert_eq!(masked.schema().field(2).data_type(), &DataType::Utf8);
+        assert_eq!(masked.column(2).as_string::<i32>().value(1), "987");
+    }
+
+    #[test]
+    fn test_mask_json() {
+        let mut value = json!([
+            {"id": 1, "email": "a@example.com", "ssn": 123456789},
+            {"id": 2, "email": null}
+        ]);
+        let maskers = maskers(&[
+            rule("email", MaskStrategy::Redact),
+            rule("ssn", MaskStrategy::Truncate(3)),
+        ]);
+
+        mask_json(&mut value, &maskers);
+
+        assert_eq!(
+            value,
+            json!([
+                {"id": 1, "email": "***", "ssn": "123"},
+                {"id": 2, "email": null}
+            ])
+        );
+    }
+}
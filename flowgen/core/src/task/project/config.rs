@@ -0,0 +1,63 @@
+//! Configuration for the project task processor.
+//!
+//! The project task narrows events to a fixed list of columns, e.g. to match
+//! the schema of a destination table before writing.
+
+use crate::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for project processor task.
+///
+/// # Examples
+///
+/// ```yaml
+/// project:
+///   name: "select_order_columns"
+///   columns:
+///     - order_id
+///     - customer_id
+///     - amount
+///   ignore_missing: true
+/// ```
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Processor {
+    /// The unique name / identifier of the task.
+    pub name: String,
+    /// Columns (or top-level JSON fields) to keep, in output order.
+    pub columns: Vec<String>,
+    /// Skip listed columns that are absent from an event instead of failing.
+    #[serde(default)]
+    pub ignore_missing: bool,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    #[serde(default)]
+    pub retry: Option<crate::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<crate::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Processor {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_processor_deserialization() {
+        let yaml = r#"
+name: select_order_columns
+columns:
+  - order_id
+  - amount
+"#;
+        let processor: Processor = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(processor.columns, vec!["order_id", "amount"]);
+        assert!(!processor.ignore_missing);
+    }
+}
@@ -0,0 +1,403 @@
+//! Column projection processor.
+//!
+//! Keeps only the configured columns of Arrow RecordBatch events (or top-level
+//! fields of JSON events), in the configured order. All other columns are
+//! dropped.
+
+use crate::event::{Event, EventBuilder, EventData, EventExt};
+use arrow::array::RecordBatch;
+use serde_json::{Map, Value};
+use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tracing::{error, Instrument};
+
+/// Errors that can occur during project processing operations.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Arrow error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+    #[error(
+        "Column '{}' is missing from the event, set ignore_missing to skip it",
+        _0
+    )]
+    MissingColumn(String),
+    #[error("Avro event data cannot be projected, convert it to JSON or Arrow first.")]
+    AvroNotSupported,
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+    #[error("Task failed after all retry attempts: {source}")]
+    RetryExhausted {
+        #[source]
+        source: Box<Error>,
+    },
+}
+
+/// Keeps the configured columns of a record batch, in the configured order.
+fn project_batch(
+    batch: &RecordBatch,
+    columns: &[String],
+    ignore_missing: bool,
+) -> Result<RecordBatch, Error> {
+    let schema = batch.schema();
+    let mut indices = Vec::with_capacity(columns.len());
+    for column in columns {
+        match schema.index_of(column) {
+            Ok(index) => indices.push(index),
+            Err(_) if ignore_missing => {}
+            Err(_) => return Err(Error::MissingColumn(column.clone())),
+        }
+    }
+
+    batch
+        .project(&indices)
+        .map_err(|source| Error::Arrow { source })
+}
+
+/// Keeps the configured top-level fields of a JSON object or array of objects.
+///
+/// Values that are not objects pass through unchanged.
+fn project_json(value: &Value, columns: &[String], ignore_missing: bool) -> Result<Value, Error> {
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .map(|item| project_json(item, columns, ignore_missing))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        Value::Object(object) => {
+            let mut projected = Map::with_capacity(columns.len());
+            for column in columns {
+                match object.get(column) {
+                    Some(field) => {
+                        projected.insert(column.clone(), field.clone());
+                    }
+                    None if ignore_missing => {}
+                    None => return Err(Error::MissingColumn(column.clone())),
+                }
+            }
+            Ok(Value::Object(projected))
+        }
+        other => Ok(other.clone()),
+    }
+}
+
+/// Handles individual event processing by projecting configured columns.
+pub struct EventHandler {
+    /// Processor configuration settings.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for processed events (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event tracking.
+    task_id: usize,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+}
+
+impl EventHandler {
+    /// Processes an event by keeping only its configured columns.
+    #[tracing::instrument(skip(self, event), name = "task.handle")]
+    async fn handle(&self, event: Event) -> Result<(), Error> {
+        if self.task_context.cancellation_token.is_cancelled() {
+            return Ok(());
+        }
+
+        let event = Arc::new(event);
+        let completion_tx_arc = Arc::clone(&event).completion_tx.clone();
+        crate::event::with_event_context(&Arc::clone(&event), async move {
+            // Projection keeps the row count, so an empty input stays an empty output.
+            let event_builder = EventBuilder::new().allow_empty(true);
+            let data =
+                match &event.data {
+                    EventData::ArrowRecordBatch(batch) => EventData::ArrowRecordBatch(
+                        project_batch(batch, &self.config.columns, self.config.ignore_missing)?,
+                    ),
+                    EventData::Json(data) => EventData::Json(project_json(
+                        data,
+                        &self.config.columns,
+                        self.config.ignore_missing,
+                    )?),
+                    EventData::Avro(_) => return Err(Error::AvroNotSupported),
+                };
+
+            let mut e = event_builder
+                .data(data)
+                .subject(self.config.name.to_owned())
+                .task_id(self.task_id)
+                .task_type(self.task_type)
+                .build()
+                .map_err(|source| Error::EventBuilder { source })?;
+
+            // Signal completion or pass through to next task.
+            match self.tx {
+                None => {
+                    if let Some(arc) = completion_tx_arc.as_ref() {
+                        arc.signal_completion(e.data_as_json().ok());
+                    }
+                }
+                Some(_) => {
+                    e.completion_tx = completion_tx_arc.clone();
+                }
+            }
+
+            e.send_with_logging(self.tx.as_ref())
+                .await
+                .map_err(|source| Error::SendMessage { source })?;
+            Ok(())
+        })
+        .await
+    }
+}
+
+/// Projection processor that keeps a subset of columns.
+#[derive(Debug)]
+pub struct Processor {
+    /// Project task configuration.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for projected events.
+    tx: Option<Sender<Event>>,
+    /// Channel receiver for incoming events to project.
+    rx: Receiver<Event>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl crate::task::runner::Runner for Processor {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Initializes the processor event handler.
+    async fn init(&self) -> Result<Self::EventHandler, Self::Error> {
+        Ok(EventHandler {
+            config: Arc::clone(&self.config),
+            tx: self.tx.clone(),
+            task_id: self.task_id,
+            task_type: self.task_type,
+            task_context: Arc::clone(&self.task_context),
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(mut self) -> Result<(), Error> {
+        let retry_config =
+            crate::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        let event_handler = match tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+            match self.init().await {
+                Ok(handler) => Ok(handler),
+                Err(e) => {
+                    error!(error = %e, "Failed to initialize project processor");
+                    Err(tokio_retry::RetryError::transient(e))
+                }
+            }
+        })
+        .await
+        {
+            Ok(handler) => Arc::new(handler),
+            Err(e) => {
+                return Err(e);
+            }
+        };
+
+        loop {
+            match self.rx.recv().await {
+                Some(event) => {
+                    let event_handler = Arc::clone(&event_handler);
+                    let retry_strategy = retry_config.strategy();
+                    tokio::spawn(
+                        async move {
+                            let result = tokio_retry::Retry::spawn(retry_strategy, || async {
+                                match event_handler.handle(event.clone()).await {
+                                    Ok(result) => Ok(result),
+                                    Err(e) => {
+                                        error!(error = %e, "Failed to project event");
+                                        Err(tokio_retry::RetryError::transient(e))
+                                    }
+                                }
+                            })
+                            .await;
+
+                            if let Err(err) = result {
+                                error!(error = %err, "Project failed after all retry attempts.");
+                                // Emit error event downstream for error handling.
+                                let mut error_event = event.clone();
+                                error_event.error = Some(err.to_string());
+                                if let Some(ref tx) = event_handler.tx {
+                                    tx.send(error_event).await.ok();
+                                }
+                            }
+                        }
+                        .instrument(tracing::Span::current()),
+                    );
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Builder for constructing Processor instances with validation.
+#[derive(Debug, Default)]
+pub struct ProcessorBuilder {
+    /// Processor configuration (required for build).
+    config: Option<Arc<super::config::Processor>>,
+    /// Event sender for passing events to next task (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Event receiver for incoming events (required for build).
+    rx: Option<Receiver<Event>>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Option<Arc<crate::task::context::TaskContext>>,
+    /// Task type for event categorization and logging.
+    task_type: Option<&'static str>,
+}
+
+impl ProcessorBuilder {
+    pub fn new() -> ProcessorBuilder {
+        ProcessorBuilder {
+            ..Default::default()
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Processor>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn receiver(mut self, receiver: Receiver<Event>) -> Self {
+        self.rx = Some(receiver);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = task_id;
+        self
+    }
+
+    pub fn task_context(mut self, task_context: Arc<crate::task::context::TaskContext>) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Processor, Error> {
+        Ok(Processor {
+            config: self
+                .config
+                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            rx: self
+                .rx
+                .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
+            tx: self.tx,
+            task_id: self.task_id,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int64Array, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use serde_json::json;
+
+    fn columns(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    fn orders_batch() -> RecordBatch {
+        RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("order_id", DataType::Int64, false),
+                Field::new("customer", DataType::Utf8, true),
+                Field::new("amount", DataType::Int64, true),
+            ])),
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec![Some("acme"), None])),
+                Arc::new(Int64Array::from(vec![Some(100), Some(250)])),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_project_batch() {
+        let batch = orders_batch();
+
+        let projected = project_batch(&batch, &columns(&["amount", "order_id"]), false).unwrap();
+
+        assert_eq!(projected.num_rows(), 2);
+        assert_eq!(projected.schema().field(0).name(), "amount");
+        assert_eq!(projected.schema().field(1).name(), "order_id");
+        assert_eq!(projected.column(0).as_ref(), batch.column(2).as_ref());
+    }
+
+    #[test]
+    fn test_project_batch_missing_column() {
+        let batch = orders_batch();
+
+        assert!(matches!(
+            project_batch(&batch, &columns(&["order_id", "missing"]), false),
+            Err(Error::MissingColumn(column)) if column == "missing"
+        ));
+
+        let projected = project_batch(&batch, &columns(&["order_id", "missing"]), true).unwrap();
+        assert_eq!(projected.num_columns(), 1);
+    }
+
+    #[test]
+    fn test_project_json() {
+        let value = json!([
+            {"order_id": 1, "customer": "acme", "amount": 100},
+            {"order_id": 2, "amount": 250}
+        ]);
+
+        assert!(matches!(
+            project_json(&value, &columns(&["order_id", "customer"]), false),
+            Err(Error::MissingColumn(column)) if column == "customer"
+        ));
+        assert_eq!(
+            project_json(&value, &columns(&["order_id", "customer"]), true).unwrap(),
+            json!([
+                {"order_id": 1, "customer": "acme"},
+                {"order_id": 2}
+            ])
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! Configuration for the rename task processor.
+//!
+//! The rename task changes column names without touching the data, e.g. to
+//! map the camelCase fields of a source system onto snake_case table columns.
+
+use crate::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Bulk rename applied to columns without an explicit mapping.
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RenameFunction {
+    /// `AccountId` and `accountId` become `account_id`.
+    ToSnakeCase,
+    /// `AccountId` becomes `accountid`.
+    ToLowerCase,
+    /// `account_id` and `AccountId` become `accountId`.
+    ToCamelCase,
+}
+
+/// Configuration for rename processor task.
+///
+/// # Examples
+///
+/// ```yaml
+/// rename:
+///   name: "rename_account_columns"
+///   renames:
+///     Id: account_id
+///     CreatedDate: created_at
+///   rename_fn: to_snake_case
+///   strip_object_prefix: true
+///   strip_custom_suffix: true
+/// ```
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Processor {
+    /// The unique name / identifier of the task.
+    pub name: String,
+    /// New column name keyed by old column name.
+    #[serde(default)]
+    pub renames: HashMap<String, String>,
+    /// Optional bulk rename for columns not listed in `renames`.
+    #[serde(default)]
+    pub rename_fn: Option<RenameFunction>,
+    /// Strip the object prefix of qualified names, e.g. `Account.BillingCity`
+    /// becomes `BillingCity`. Applied to columns without an explicit mapping,
+    /// before `rename_fn`.
+    #[serde(default)]
+    pub strip_object_prefix: bool,
+    /// Strip the Salesforce custom field suffix `__c`, e.g. `Region__c`
+    /// becomes `Region`. Applied to columns without an explicit mapping,
+    /// before `rename_fn`.
+    #[serde(default)]
+    pub strip_custom_suffix: bool,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    #[serde(default)]
+    pub retry: Option<crate::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<crate::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Processor {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_processor_deserialization() {
+        let yaml = r#"
+name: rename_account_columns
+renames:
+  Id: account_id
+rename_fn: to_snake_case
+"#;
+        let processor: Processor = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(processor.renames.get("Id").unwrap(), "account_id");
+        assert_eq!(processor.rename_fn, Some(RenameFunction::ToSnakeCase));
+    }
+
+    #[test]
+    fn test_processor_strip_defaults() {
+        let processor: Processor = serde_yaml::from_str("name: rename").unwrap();
+
+        assert!(!processor.strip_object_prefix);
+        assert!(!processor.strip_custom_suffix);
+    }
+}
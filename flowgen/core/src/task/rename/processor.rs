@@ -0,0 +1,537 @@
+//! Column rename processor.
+//!
+//! Renames columns of Arrow RecordBatch events (or top-level fields of JSON
+//! events) from an explicit mapping. All other columns can have their
+//! Salesforce object prefix and custom field suffix stripped and a bulk case
+//! conversion applied. Column data is passed through unchanged.
+
+use super::config::{Processor as Config, RenameFunction};
+use crate::event::{Event, EventBuilder, EventData, EventExt};
+use arrow::array::RecordBatch;
+use arrow::datatypes::{Field, Schema};
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tracing::{error, Instrument};
+
+/// Errors that can occur during rename processing operations.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Arrow error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+    #[error("Renaming produces duplicate column '{}'", _0)]
+    DuplicateColumn(String),
+    #[error("Avro event data cannot be renamed, convert it to JSON or Arrow first.")]
+    AvroNotSupported,
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+    #[error("Task failed after all retry attempts: {source}")]
+    RetryExhausted {
+        #[source]
+        source: Box<Error>,
+    },
+}
+
+/// Splits a column name into lowercase words on separators and case changes.
+///
+/// Runs of capitals are kept together, so `HTTPStatus` yields `http` and
+/// `status`.
+fn split_words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if c.is_uppercase() && !current.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower) {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.extend(c.to_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// Applies a bulk rename function to a column name.
+fn apply_rename_fn(rename_fn: &RenameFunction, name: &str) -> String {
+    match rename_fn {
+        RenameFunction::ToSnakeCase => split_words(name).join("_"),
+        RenameFunction::ToLowerCase => name.to_lowercase(),
+        RenameFunction::ToCamelCase => split_words(name)
+            .into_iter()
+            .enumerate()
+            .map(|(i, word)| {
+                if i == 0 {
+                    return word;
+                }
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => word,
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Salesforce custom field suffix.
+const CUSTOM_FIELD_SUFFIX: &str = "__c";
+
+/// Resolves the new name of a column: explicit mapping first, then the
+/// configured prefix/suffix stripping and bulk rename function.
+fn rename_column(name: &str, config: &Config) -> String {
+    if let Some(new_name) = config.renames.get(name) {
+        return new_name.clone();
+    }
+    let mut name = name;
+    if config.strip_object_prefix {
+        name = name.rsplit('.').next().unwrap_or(name);
+    }
+    if config.strip_custom_suffix {
+        name = name.strip_suffix(CUSTOM_FIELD_SUFFIX).unwrap_or(name);
+    }
+    match &config.rename_fn {
+        Some(rename_fn) => apply_rename_fn(rename_fn, name),
+        None => name.to_string(),
+    }
+}
+
+/// Renames the fields of a record batch, keeping its columns unchanged.
+fn rename_batch(batch: &RecordBatch, config: &Config) -> Result<RecordBatch, Error> {
+    let schema = batch.schema();
+    let mut seen = HashSet::with_capacity(schema.fields().len());
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let name = rename_column(field.name(), config);
+            if !seen.insert(name.clone()) {
+                return Err(Error::DuplicateColumn(name));
+            }
+            Ok(Field::clone(field).with_name(name))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    RecordBatch::try_new(
+        Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone())),
+        batch.columns().to_vec(),
+    )
+    .map_err(|source| Error::Arrow { source })
+}
+
+/// Renames the top-level fields of a JSON object, or of each object in an array.
+fn rename_json(value: &Value, config: &Config) -> Result<Value, Error> {
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .map(|item| rename_json(item, config))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        Value::Object(object) => {
+            let mut renamed = Map::with_capacity(object.len());
+            for (key, field) in object {
+                let name = rename_column(key, config);
+                if renamed.contains_key(&name) {
+                    return Err(Error::DuplicateColumn(name));
+                }
+                renamed.insert(name, field.clone());
+            }
+            Ok(Value::Object(renamed))
+        }
+        other => Ok(other.clone()),
+    }
+}
+
+/// Handles individual event processing by renaming columns.
+pub struct EventHandler {
+    /// Processor configuration settings.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for processed events (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event tracking.
+    task_id: usize,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+}
+
+impl EventHandler {
+    /// Processes an event by renaming its columns.
+    #[tracing::instrument(skip(self, event), name = "task.handle")]
+    async fn handle(&self, event: Event) -> Result<(), Error> {
+        if self.task_context.cancellation_token.is_cancelled() {
+            return Ok(());
+        }
+
+        let event = Arc::new(event);
+        let completion_tx_arc = Arc::clone(&event).completion_tx.clone();
+        crate::event::with_event_context(&Arc::clone(&event), async move {
+            // Renaming keeps the row count, so an empty input stays an empty output.
+            let event_builder = EventBuilder::new().allow_empty(true);
+            let data = match &event.data {
+                EventData::ArrowRecordBatch(batch) => {
+                    EventData::ArrowRecordBatch(rename_batch(batch, &self.config)?)
+                }
+                EventData::Json(data) => EventData::Json(rename_json(data, &self.config)?),
+                EventData::Avro(_) => return Err(Error::AvroNotSupported),
+            };
+
+            let mut e = event_builder
+                .data(data)
+                .subject(self.config.name.to_owned())
+                .task_id(self.task_id)
+                .task_type(self.task_type)
+                .build()
+                .map_err(|source| Error::EventBuilder { source })?;
+
+            // Signal completion or pass through to next task.
+            match self.tx {
+                None => {
+                    if let Some(arc) = completion_tx_arc.as_ref() {
+                        arc.signal_completion(e.data_as_json().ok());
+                    }
+                }
+                Some(_) => {
+                    e.completion_tx = completion_tx_arc.clone();
+                }
+            }
+
+            e.send_with_logging(self.tx.as_ref())
+                .await
+                .map_err(|source| Error::SendMessage { source })?;
+            Ok(())
+        })
+        .await
+    }
+}
+
+/// Rename processor that changes column names.
+#[derive(Debug)]
+pub struct Processor {
+    /// Rename task configuration.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for renamed events.
+    tx: Option<Sender<Event>>,
+    /// Channel receiver for incoming events to rename.
+    rx: Receiver<Event>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl crate::task::runner::Runner for Processor {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Initializes the processor event handler.
+    async fn init(&self) -> Result<Self::EventHandler, Self::Error> {
+        Ok(EventHandler {
+            config: Arc::clone(&self.config),
+            tx: self.tx.clone(),
+            task_id: self.task_id,
+            task_type: self.task_type,
+            task_context: Arc::clone(&self.task_context),
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(mut self) -> Result<(), Error> {
+        let retry_config =
+            crate::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        let event_handler = match tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+            match self.init().await {
+                Ok(handler) => Ok(handler),
+                Err(e) => {
+                    error!(error = %e, "Failed to initialize rename processor");
+                    Err(tokio_retry::RetryError::transient(e))
+                }
+            }
+        })
+        .await
+        {
+            Ok(handler) => Arc::new(handler),
+            Err(e) => {
+                return Err(e);
+            }
+        };
+
+        loop {
+            match self.rx.recv().await {
+                Some(event) => {
+                    let event_handler = Arc::clone(&event_handler);
+                    let retry_strategy = retry_config.strategy();
+                    tokio::spawn(
+                        async move {
+                            let result = tokio_retry::Retry::spawn(retry_strategy, || async {
+                                match event_handler.handle(event.clone()).await {
+                                    Ok(result) => Ok(result),
+                                    Err(e) => {
+                                        error!(error = %e, "Failed to rename event");
+                                        Err(tokio_retry::RetryError::transient(e))
+                                    }
+                                }
+                            })
+                            .await;
+
+                            if let Err(err) = result {
+                                error!(error = %err, "Rename failed after all retry attempts.");
+                                // Emit error event downstream for error handling.
+                                let mut error_event = event.clone();
+                                error_event.error = Some(err.to_string());
+                                if let Some(ref tx) = event_handler.tx {
+                                    tx.send(error_event).await.ok();
+                                }
+                            }
+                        }
+                        .instrument(tracing::Span::current()),
+                    );
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Builder for constructing Processor instances with validation.
+#[derive(Debug, Default)]
+pub struct ProcessorBuilder {
+    /// Processor configuration (required for build).
+    config: Option<Arc<super::config::Processor>>,
+    /// Event sender for passing events to next task (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Event receiver for incoming events (required for build).
+    rx: Option<Receiver<Event>>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Option<Arc<crate::task::context::TaskContext>>,
+    /// Task type for event categorization and logging.
+    task_type: Option<&'static str>,
+}
+
+impl ProcessorBuilder {
+    pub fn new() -> ProcessorBuilder {
+        ProcessorBuilder {
+            ..Default::default()
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Processor>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn receiver(mut self, receiver: Receiver<Event>) -> Self {
+        self.rx = Some(receiver);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = task_id;
+        self
+    }
+
+    pub fn task_context(mut self, task_context: Arc<crate::task::context::TaskContext>) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Processor, Error> {
+        Ok(Processor {
+            config: self
+                .config
+                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            rx: self
+                .rx
+                .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
+            tx: self.tx,
+            task_id: self.task_id,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{ArrayRef, Int64Array, StringArray};
+    use arrow::datatypes::DataType;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn batch() -> RecordBatch {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("Id", DataType::Utf8, false),
+            Field::new("AccountNumber", DataType::Int64, true),
+            Field::new("BillingCity", DataType::Utf8, true),
+        ]));
+        RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from(vec!["001"])) as ArrayRef,
+                Arc::new(Int64Array::from(vec![Some(42)])),
+                Arc::new(StringArray::from(vec![Some("Zurich")])),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_apply_rename_fn() {
+        let snake = RenameFunction::ToSnakeCase;
+        assert_eq!(apply_rename_fn(&snake, "AccountId"), "account_id");
+        assert_eq!(apply_rename_fn(&snake, "accountId"), "account_id");
+        assert_eq!(
+            apply_rename_fn(&snake, "HTTPStatusCode"),
+            "http_status_code"
+        );
+        assert_eq!(apply_rename_fn(&snake, "Address2Line"), "address2_line");
+        assert_eq!(apply_rename_fn(&snake, "already_snake"), "already_snake");
+        assert_eq!(apply_rename_fn(&snake, "Custom_Field__c"), "custom_field_c");
+
+        let camel = RenameFunction::ToCamelCase;
+        assert_eq!(apply_rename_fn(&camel, "account_id"), "accountId");
+        assert_eq!(apply_rename_fn(&camel, "AccountId"), "accountId");
+
+        let lower = RenameFunction::ToLowerCase;
+        assert_eq!(apply_rename_fn(&lower, "AccountId"), "accountid");
+    }
+
+    #[test]
+    fn test_rename_batch() {
+        let config = Config {
+            renames: HashMap::from([("Id".to_string(), "account_id".to_string())]),
+            rename_fn: Some(RenameFunction::ToSnakeCase),
+            ..Default::default()
+        };
+
+        let renamed = rename_batch(&batch(), &config).unwrap();
+
+        let names: Vec<_> = renamed
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect();
+        assert_eq!(names, vec!["account_id", "account_number", "billing_city"]);
+        assert_eq!(renamed.columns(), batch().columns());
+        assert!(!renamed.schema().field(0).is_nullable());
+    }
+
+    #[test]
+    fn test_rename_batch_without_rename_fn_keeps_other_names() {
+        let config = Config {
+            renames: HashMap::from([("Id".to_string(), "account_id".to_string())]),
+            ..Default::default()
+        };
+
+        let renamed = rename_batch(&batch(), &config).unwrap();
+
+        assert_eq!(renamed.schema().field(0).name(), "account_id");
+        assert_eq!(renamed.schema().field(1).name(), "AccountNumber");
+    }
+
+    #[test]
+    fn test_rename_batch_duplicate_column() {
+        let config = Config {
+            renames: HashMap::from([("Id".to_string(), "BillingCity".to_string())]),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            rename_batch(&batch(), &config),
+            Err(Error::DuplicateColumn(name)) if name == "BillingCity"
+        ));
+    }
+
+    #[test]
+    fn test_rename_json() {
+        let config = Config {
+            renames: HashMap::from([("Id".to_string(), "account_id".to_string())]),
+            rename_fn: Some(RenameFunction::ToSnakeCase),
+            ..Default::default()
+        };
+        let value = json!([{"Id": "001", "CreatedDate": "2024-01-01"}, 7]);
+
+        let renamed = rename_json(&value, &config).unwrap();
+
+        assert_eq!(
+            renamed,
+            json!([{"account_id": "001", "created_date": "2024-01-01"}, 7])
+        );
+    }
+
+    #[test]
+    fn test_rename_column_salesforce_names() {
+        let config = Config {
+            renames: HashMap::from([("Contact.Id".to_string(), "contact_id".to_string())]),
+            rename_fn: Some(RenameFunction::ToSnakeCase),
+            strip_object_prefix: true,
+            strip_custom_suffix: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            rename_column("Account.BillingCity__c", &config),
+            "billing_city"
+        );
+        assert_eq!(rename_column("Contact.Email", &config), "email");
+        assert_eq!(rename_column("Region__c", &config), "region");
+        assert_eq!(rename_column("Contact.Id", &config), "contact_id");
+
+        let config = Config {
+            strip_object_prefix: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            rename_column("Account.BillingCity__c", &config),
+            "BillingCity__c"
+        );
+    }
+}
@@ -0,0 +1,277 @@
+//! Configuration for the router task processor.
+//!
+//! The router evaluates conditions against event data and forwards a copy of
+//! the event for every matching route with the route's subject, so downstream
+//! publishers can send records to different targets.
+
+use crate::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Configuration for router processor task.
+///
+/// # Examples
+///
+/// ```yaml
+/// router:
+///   name: "route_orders"
+///   routes:
+///     - condition:
+///         field: amount
+///         op: gte
+///         value: 1000
+///       target_subject: "orders.high_value"
+///     - condition:
+///         all:
+///           - field: customer.tier
+///             op: eq
+///             value: gold
+///           - not:
+///               field: region
+///               op: in
+///               value: [US, CA]
+///       target_subject: "orders.gold_international"
+///   default_subject: "orders.standard"
+/// ```
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct Processor {
+    /// Unique name identifier for this router task.
+    pub name: String,
+    /// Routes evaluated for every record. A record is forwarded once per matching route.
+    pub routes: Vec<RouteRule>,
+    /// Subject for records that match no route. When not set, such records are dropped.
+    pub default_subject: Option<String>,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    pub retry: Option<crate::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    pub error_policy: Option<crate::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Processor {}
+
+/// A condition and the subject matching records are sent to.
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct RouteRule {
+    /// Condition a record must satisfy.
+    pub condition: PredicateSpec,
+    /// Subject set on events forwarded by this route.
+    pub target_subject: String,
+}
+
+/// Condition evaluated against a JSON record.
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum PredicateSpec {
+    /// Matches when every nested condition matches.
+    All { all: Vec<PredicateSpec> },
+    /// Matches when at least one nested condition matches.
+    Any { any: Vec<PredicateSpec> },
+    /// Matches when the nested condition does not.
+    Not { not: Box<PredicateSpec> },
+    /// Compares a field against a value.
+    Compare {
+        /// Dot-separated path to the field, e.g. `customer.tier` or `items.0.sku`.
+        field: String,
+        /// Comparison operator.
+        op: CompareOp,
+        /// Value to compare against. Not needed for `exists`.
+        #[serde(default)]
+        value: Value,
+    },
+}
+
+/// Comparison operators for [`PredicateSpec::Compare`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompareOp {
+    /// Field equals the value. Numbers compare by value, so `1` equals `1.0`.
+    Eq,
+    /// Field does not equal the value.
+    Ne,
+    /// Field is greater than the value (numbers or strings).
+    Gt,
+    /// Field is greater than or equal to the value (numbers or strings).
+    Gte,
+    /// Field is less than the value (numbers or strings).
+    Lt,
+    /// Field is less than or equal to the value (numbers or strings).
+    Lte,
+    /// Field equals one of the values in the array.
+    In,
+    /// Field string contains the value string, or field array contains the value.
+    Contains,
+    /// Field is present and not null.
+    Exists,
+}
+
+impl PredicateSpec {
+    /// Evaluates the condition against a record. Missing fields are treated as null.
+    pub fn matches(&self, record: &Value) -> bool {
+        match self {
+            PredicateSpec::All { all } => all.iter().all(|p| p.matches(record)),
+            PredicateSpec::Any { any } => any.iter().any(|p| p.matches(record)),
+            PredicateSpec::Not { not } => !not.matches(record),
+            PredicateSpec::Compare { field, op, value } => {
+                let actual = lookup(record, field).unwrap_or(&Value::Null);
+                compare(actual, *op, value)
+            }
+        }
+    }
+}
+
+/// Resolves a dot-separated path, indexing arrays by numeric segments.
+fn lookup<'a>(record: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(record, |current, segment| match current {
+            Value::Object(map) => map.get(segment),
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => None,
+        })
+}
+
+fn equals(actual: &Value, expected: &Value) -> bool {
+    match (actual, expected) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        _ => actual == expected,
+    }
+}
+
+fn ordering(actual: &Value, expected: &Value) -> Option<std::cmp::Ordering> {
+    match (actual, expected) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64()?.partial_cmp(&b.as_f64()?),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+fn compare(actual: &Value, op: CompareOp, expected: &Value) -> bool {
+    use std::cmp::Ordering::{Equal, Greater, Less};
+    match op {
+        CompareOp::Eq => equals(actual, expected),
+        CompareOp::Ne => !equals(actual, expected),
+        CompareOp::Gt => ordering(actual, expected) == Some(Greater),
+        CompareOp::Gte => matches!(ordering(actual, expected), Some(Greater | Equal)),
+        CompareOp::Lt => ordering(actual, expected) == Some(Less),
+        CompareOp::Lte => matches!(ordering(actual, expected), Some(Less | Equal)),
+        CompareOp::In => expected
+            .as_array()
+            .is_some_and(|values| values.iter().any(|v| equals(actual, v))),
+        CompareOp::Contains => match (actual, expected) {
+            (Value::String(a), Value::String(b)) => a.contains(b.as_str()),
+            (Value::Array(items), _) => items.iter().any(|item| equals(item, expected)),
+            _ => false,
+        },
+        CompareOp::Exists => !actual.is_null(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn compare_spec(field: &str, op: CompareOp, value: Value) -> PredicateSpec {
+        PredicateSpec::Compare {
+            field: field.to_string(),
+            op,
+            value,
+        }
+    }
+
+    #[test]
+    fn test_processor_deserialization() {
+        let yaml = r#"
+name: route_orders
+routes:
+  - condition:
+      field: amount
+      op: gte
+      value: 1000
+    target_subject: orders.high_value
+  - condition:
+      any:
+        - field: tags
+          op: contains
+          value: vip
+        - not:
+            field: region
+            op: exists
+    target_subject: orders.review
+default_subject: orders.standard
+"#;
+        let processor: Processor = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(processor.routes.len(), 2);
+        assert_eq!(
+            processor.routes[0].condition,
+            compare_spec("amount", CompareOp::Gte, json!(1000))
+        );
+        assert_eq!(
+            processor.routes[1].condition,
+            PredicateSpec::Any {
+                any: vec![
+                    compare_spec("tags", CompareOp::Contains, json!("vip")),
+                    PredicateSpec::Not {
+                        not: Box::new(compare_spec("region", CompareOp::Exists, Value::Null)),
+                    },
+                ],
+            }
+        );
+        assert_eq!(
+            processor.default_subject,
+            Some("orders.standard".to_string())
+        );
+    }
+
+    #[test]
+    fn test_compare_operators() {
+        let record = json!({
+            "amount": 1500,
+            "customer": {"tier": "gold"},
+            "tags": ["vip", "new"],
+            "items": [{"sku": "A-1"}],
+            "note": null
+        });
+
+        assert!(compare_spec("amount", CompareOp::Eq, json!(1500.0)).matches(&record));
+        assert!(compare_spec("amount", CompareOp::Gt, json!(1000)).matches(&record));
+        assert!(!compare_spec("amount", CompareOp::Lt, json!(1000)).matches(&record));
+        assert!(compare_spec("customer.tier", CompareOp::Ne, json!("silver")).matches(&record));
+        assert!(compare_spec("customer.tier", CompareOp::Lte, json!("gold")).matches(&record));
+        assert!(
+            compare_spec("customer.tier", CompareOp::In, json!(["gold", "platinum"]))
+                .matches(&record)
+        );
+        assert!(compare_spec("tags", CompareOp::Contains, json!("vip")).matches(&record));
+        assert!(compare_spec("items.0.sku", CompareOp::Contains, json!("A-")).matches(&record));
+        assert!(compare_spec("items.0.sku", CompareOp::Exists, Value::Null).matches(&record));
+        assert!(!compare_spec("note", CompareOp::Exists, Value::Null).matches(&record));
+        assert!(!compare_spec("missing", CompareOp::Gt, json!(0)).matches(&record));
+        assert!(!compare_spec("amount", CompareOp::Gt, json!("1000")).matches(&record));
+    }
+
+    #[test]
+    fn test_composite_predicates() {
+        let record = json!({"amount": 50, "region": "EU"});
+        let high_value = compare_spec("amount", CompareOp::Gte, json!(1000));
+        let eu = compare_spec("region", CompareOp::Eq, json!("EU"));
+
+        assert!(!PredicateSpec::All {
+            all: vec![high_value.clone(), eu.clone()]
+        }
+        .matches(&record));
+        assert!(PredicateSpec::Any {
+            any: vec![high_value.clone(), eu]
+        }
+        .matches(&record));
+        assert!(PredicateSpec::Not {
+            not: Box::new(high_value)
+        }
+        .matches(&record));
+    }
+}
@@ -0,0 +1,580 @@
+//! Conditional router processor.
+//!
+//! Evaluates route conditions against each record and forwards one event per
+//! matching route, with the event subject set to the route's target subject.
+//! Arrow batches and JSON arrays are split so each route only receives the
+//! rows that matched it.
+
+use crate::event::{
+    new_completion_channel, spawn_fan_in_completion, Event, EventBuilder, EventData, EventExt,
+};
+use arrow::array::BooleanArray;
+use serde_json::Value;
+use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tracing::{debug, error, Instrument};
+
+/// Errors that can occur during router processing operations.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Failed to convert event data to JSON: {source}")]
+    EventConversion {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Arrow error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+    #[error("Router requires at least one route or a default subject")]
+    NoRoutes,
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+    #[error("Task failed after all retry attempts: {source}")]
+    RetryExhausted {
+        #[source]
+        source: Box<Error>,
+    },
+}
+
+/// Handles individual event processing by routing records to subjects.
+pub struct EventHandler {
+    /// Processor configuration settings.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for processed events (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event tracking.
+    task_id: usize,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+}
+
+impl EventHandler {
+    /// Subjects in route order, followed by the default subject when configured.
+    fn subjects(&self) -> impl Iterator<Item = &str> {
+        self.config
+            .routes
+            .iter()
+            .map(|route| route.target_subject.as_str())
+            .chain(self.config.default_subject.as_deref())
+    }
+
+    /// Returns, for one record, whether it goes to each subject from [`Self::subjects`].
+    fn targets(&self, record: &Value) -> Vec<bool> {
+        let mut targets: Vec<bool> = self
+            .config
+            .routes
+            .iter()
+            .map(|route| route.condition.matches(record))
+            .collect();
+        if self.config.default_subject.is_some() {
+            targets.push(!targets.iter().any(|matched| *matched));
+        }
+        targets
+    }
+
+    /// Splits event data into one payload per subject that received records.
+    fn route(&self, data: &EventData) -> Result<Vec<(String, EventData)>, Error> {
+        let json = Value::try_from(data).map_err(|source| Error::EventConversion { source })?;
+
+        let Value::Array(rows) = &json else {
+            // A single record is forwarded unchanged, keeping its original format.
+            let targets = self.targets(&json);
+            return Ok(self
+                .subjects()
+                .zip(targets)
+                .filter(|(_, matched)| *matched)
+                .map(|(subject, _)| (subject.to_string(), data.clone()))
+                .collect());
+        };
+
+        let row_targets: Vec<Vec<bool>> = rows.iter().map(|row| self.targets(row)).collect();
+        let mut routed = Vec::new();
+        for (index, subject) in self.subjects().enumerate() {
+            let mask: Vec<bool> = row_targets.iter().map(|t| t[index]).collect();
+            if !mask.iter().any(|matched| *matched) {
+                continue;
+            }
+            let payload = match data {
+                EventData::ArrowRecordBatch(batch) => EventData::ArrowRecordBatch(
+                    arrow::compute::filter_record_batch(batch, &BooleanArray::from(mask))
+                        .map_err(|source| Error::Arrow { source })?,
+                ),
+                _ => EventData::Json(Value::Array(
+                    rows.iter()
+                        .zip(&mask)
+                        .filter(|(_, matched)| **matched)
+                        .map(|(row, _)| row.clone())
+                        .collect(),
+                )),
+            };
+            routed.push((subject.to_string(), payload));
+        }
+        Ok(routed)
+    }
+
+    /// Processes an event by forwarding a copy per matching route.
+    #[tracing::instrument(skip(self, event), name = "task.handle")]
+    async fn handle(&self, event: Event) -> Result<(), Error> {
+        if self.task_context.cancellation_token.is_cancelled() {
+            return Ok(());
+        }
+
+        let event = Arc::new(event);
+        let completion_tx_arc = Arc::clone(&event).completion_tx.clone();
+        crate::event::with_event_context(&Arc::clone(&event), async move {
+            let routed = self.route(&event.data)?;
+            let upstream_leaf_share = self.task_context.leaf_count.max(1);
+
+            if routed.is_empty() {
+                debug!("Event matched no route, dropping");
+                // The pipeline ends here for this event. Signal once per leaf
+                // reachable from this task so the source is not left waiting
+                // for completions that will never arrive.
+                if let Some(arc) = completion_tx_arc.as_ref() {
+                    for _ in 0..upstream_leaf_share {
+                        arc.signal_completion(None);
+                    }
+                }
+                return Ok(());
+            }
+
+            let fan_out = routed.len() > 1;
+            let mut per_event_receivers = Vec::new();
+            for (subject, data) in routed {
+                let mut e = EventBuilder::new()
+                    .data(data)
+                    .subject(subject)
+                    .task_id(self.task_id)
+                    .task_type(self.task_type)
+                    .build()
+                    .map_err(|source| Error::EventBuilder { source })?;
+
+                // Copies sent to several routes each get their own completion
+                // channel; the upstream source is signalled once all of them finish.
+                if self.tx.is_some() {
+                    if fan_out {
+                        let (per_state, per_rx) = new_completion_channel(upstream_leaf_share);
+                        e.completion_tx = Some(per_state);
+                        per_event_receivers.push(per_rx);
+                    } else {
+                        e.completion_tx = completion_tx_arc.clone();
+                    }
+                }
+
+                e.send_with_logging(self.tx.as_ref())
+                    .await
+                    .map_err(|source| Error::SendMessage { source })?;
+            }
+
+            if let Some(upstream) = completion_tx_arc.as_ref() {
+                if self.tx.is_none() {
+                    upstream.signal_completion(None);
+                } else if fan_out {
+                    spawn_fan_in_completion(
+                        Arc::clone(upstream),
+                        per_event_receivers,
+                        upstream_leaf_share,
+                    );
+                }
+            }
+            Ok(())
+        })
+        .await
+    }
+}
+
+/// Conditional router processor that fans events out to subjects.
+#[derive(Debug)]
+pub struct Processor {
+    /// Router task configuration.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for routed events.
+    tx: Option<Sender<Event>>,
+    /// Channel receiver for incoming events to route.
+    rx: Receiver<Event>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl crate::task::runner::Runner for Processor {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Initializes the processor.
+    async fn init(&self) -> Result<Self::EventHandler, Self::Error> {
+        Ok(EventHandler {
+            config: Arc::clone(&self.config),
+            tx: self.tx.clone(),
+            task_id: self.task_id,
+            task_type: self.task_type,
+            task_context: Arc::clone(&self.task_context),
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(mut self) -> Result<(), Error> {
+        let retry_config =
+            crate::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        let event_handler = match tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+            match self.init().await {
+                Ok(handler) => Ok(handler),
+                Err(e) => {
+                    error!(error = %e, "Failed to initialize router processor");
+                    Err(tokio_retry::RetryError::transient(e))
+                }
+            }
+        })
+        .await
+        {
+            Ok(handler) => Arc::new(handler),
+            Err(e) => {
+                return Err(e);
+            }
+        };
+
+        loop {
+            match self.rx.recv().await {
+                Some(event) => {
+                    let event_handler = Arc::clone(&event_handler);
+                    let retry_strategy = retry_config.strategy();
+                    tokio::spawn(
+                        async move {
+                            let result = tokio_retry::Retry::spawn(retry_strategy, || async {
+                                match event_handler.handle(event.clone()).await {
+                                    Ok(result) => Ok(result),
+                                    Err(e) => {
+                                        error!(error = %e, "Failed to route event");
+                                        Err(tokio_retry::RetryError::transient(e))
+                                    }
+                                }
+                            })
+                            .await;
+
+                            if let Err(err) = result {
+                                error!(error = %err, "Routing failed after all retry attempts.");
+                                // Emit error event downstream for error handling.
+                                let mut error_event = event.clone();
+                                error_event.error = Some(err.to_string());
+                                if let Some(ref tx) = event_handler.tx {
+                                    tx.send(error_event).await.ok();
+                                }
+                            }
+                        }
+                        .instrument(tracing::Span::current()),
+                    );
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Builder for constructing Processor instances with validation.
+#[derive(Debug, Default)]
+pub struct ProcessorBuilder {
+    /// Processor configuration (required for build).
+    config: Option<Arc<super::config::Processor>>,
+    /// Event sender for passing events to next task (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Event receiver for incoming events (required for build).
+    rx: Option<Receiver<Event>>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Option<Arc<crate::task::context::TaskContext>>,
+    /// Task type for event categorization and logging.
+    task_type: Option<&'static str>,
+}
+
+impl ProcessorBuilder {
+    pub fn new() -> ProcessorBuilder {
+        ProcessorBuilder {
+            ..Default::default()
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Processor>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn receiver(mut self, receiver: Receiver<Event>) -> Self {
+        self.rx = Some(receiver);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = task_id;
+        self
+    }
+
+    pub fn task_context(mut self, task_context: Arc<crate::task::context::TaskContext>) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Processor, Error> {
+        let config = self
+            .config
+            .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?;
+        if config.routes.is_empty() && config.default_subject.is_none() {
+            return Err(Error::NoRoutes);
+        }
+
+        Ok(Processor {
+            config,
+            rx: self
+                .rx
+                .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
+            tx: self.tx,
+            task_id: self.task_id,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::config::{CompareOp, PredicateSpec, RouteRule};
+    use super::*;
+    use crate::task::runner::Runner;
+    use arrow::array::{AsArray, Int64Array, RecordBatch, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use serde_json::{json, Map};
+    use tokio::sync::mpsc;
+
+    fn create_mock_task_context() -> Arc<crate::task::context::TaskContext> {
+        let mut labels = Map::new();
+        labels.insert(
+            "description".to_string(),
+            Value::String("Router Test".to_string()),
+        );
+        let task_manager = Arc::new(
+            crate::task::manager::TaskManagerBuilder::new()
+                .build()
+                .unwrap(),
+        );
+        let cache =
+            Arc::new(crate::cache::memory::MemoryCache::new()) as Arc<dyn crate::cache::Cache>;
+        Arc::new(
+            crate::task::context::TaskContextBuilder::new()
+                .flow_name("test-flow".to_string())
+                .flow_labels(Some(labels))
+                .task_manager(task_manager)
+                .cache(cache)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn config(default_subject: Option<&str>) -> super::super::config::Processor {
+        super::super::config::Processor {
+            name: "route_orders".to_string(),
+            routes: vec![
+                RouteRule {
+                    condition: PredicateSpec::Compare {
+                        field: "amount".to_string(),
+                        op: CompareOp::Gte,
+                        value: json!(1000),
+                    },
+                    target_subject: "orders.high_value".to_string(),
+                },
+                RouteRule {
+                    condition: PredicateSpec::Compare {
+                        field: "region".to_string(),
+                        op: CompareOp::Eq,
+                        value: json!("EU"),
+                    },
+                    target_subject: "orders.eu".to_string(),
+                },
+            ],
+            default_subject: default_subject.map(str::to_string),
+            depends_on: None,
+            retry: None,
+            error_policy: None,
+        }
+    }
+
+    async fn handler(default_subject: Option<&str>, tx: Sender<Event>) -> EventHandler {
+        let (_in_tx, rx) = mpsc::channel(1);
+        ProcessorBuilder::new()
+            .config(Arc::new(config(default_subject)))
+            .receiver(rx)
+            .sender(tx)
+            .task_type("router")
+            .task_context(create_mock_task_context())
+            .build()
+            .await
+            .unwrap()
+            .init()
+            .await
+            .unwrap()
+    }
+
+    fn event(data: EventData) -> Event {
+        EventBuilder::new()
+            .data(data)
+            .subject("orders".to_string())
+            .task_id(0)
+            .task_type("test")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_fan_out_to_every_matching_route() {
+        let (tx, mut rx) = mpsc::channel(10);
+        let handler = handler(Some("orders.standard"), tx).await;
+
+        handler
+            .handle(event(EventData::Json(
+                json!({"amount": 2000, "region": "EU"}),
+            )))
+            .await
+            .unwrap();
+        drop(handler);
+
+        let mut subjects = Vec::new();
+        while let Some(e) = rx.recv().await {
+            subjects.push(e.subject);
+        }
+        assert_eq!(subjects, vec!["orders.high_value", "orders.eu"]);
+    }
+
+    #[tokio::test]
+    async fn test_unmatched_goes_to_default_or_is_dropped() {
+        let record = json!({"amount": 10, "region": "US"});
+
+        let (tx, mut rx) = mpsc::channel(10);
+        let handler_with_default = handler(Some("orders.standard"), tx).await;
+        handler_with_default
+            .handle(event(EventData::Json(record.clone())))
+            .await
+            .unwrap();
+        assert_eq!(rx.recv().await.unwrap().subject, "orders.standard");
+
+        let (tx, mut rx) = mpsc::channel(10);
+        let handler_without_default = handler(None, tx).await;
+        handler_without_default
+            .handle(event(EventData::Json(record)))
+            .await
+            .unwrap();
+        drop(handler_without_default);
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_batch_rows_are_split_by_route() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("amount", DataType::Int64, false),
+                Field::new("region", DataType::Utf8, false),
+            ])),
+            vec![
+                Arc::new(Int64Array::from(vec![5000, 20, 30])),
+                Arc::new(StringArray::from(vec!["US", "EU", "US"])),
+            ],
+        )
+        .unwrap();
+        let (tx, mut rx) = mpsc::channel(10);
+        let handler = handler(Some("orders.standard"), tx).await;
+
+        handler
+            .handle(event(EventData::ArrowRecordBatch(batch)))
+            .await
+            .unwrap();
+        drop(handler);
+
+        let mut routed = Vec::new();
+        while let Some(e) = rx.recv().await {
+            let EventData::ArrowRecordBatch(batch) = e.data else {
+                panic!("expected arrow data");
+            };
+            let amounts: Vec<i64> = batch
+                .column(0)
+                .as_primitive::<arrow::datatypes::Int64Type>()
+                .values()
+                .to_vec();
+            routed.push((e.subject, amounts));
+        }
+        assert_eq!(
+            routed,
+            vec![
+                ("orders.high_value".to_string(), vec![5000]),
+                ("orders.eu".to_string(), vec![20]),
+                ("orders.standard".to_string(), vec![30]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fan_out_completion_waits_for_every_copy() {
+        let (tx, mut rx) = mpsc::channel(10);
+        let handler = handler(None, tx).await;
+        let (completion_tx, mut completion_rx) = new_completion_channel(1);
+        let mut e = event(EventData::Json(json!({"amount": 2000, "region": "EU"})));
+        e.completion_tx = Some(completion_tx);
+
+        handler.handle(e).await.unwrap();
+
+        let first = rx.recv().await.unwrap();
+        let second = rx.recv().await.unwrap();
+        first.completion_tx.unwrap().signal_completion(None);
+        tokio::task::yield_now().await;
+        assert!(completion_rx.try_recv().is_err());
+
+        second.completion_tx.unwrap().signal_completion(None);
+        assert!(completion_rx.await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_builder_requires_routes() {
+        let (_tx, rx) = mpsc::channel(1);
+        let mut empty = config(None);
+        empty.routes.clear();
+        let result = ProcessorBuilder::new()
+            .config(Arc::new(empty))
+            .receiver(rx)
+            .task_type("router")
+            .task_context(create_mock_task_context())
+            .build()
+            .await;
+        assert!(matches!(result, Err(Error::NoRoutes)));
+    }
+}
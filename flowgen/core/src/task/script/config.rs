@@ -53,6 +53,9 @@ pub struct Processor {
     /// Optional retry configuration (overrides app-level retry config).
     #[serde(default)]
     pub retry: Option<crate::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<crate::error_policy::ErrorPolicy>,
 }
 
 impl Default for Processor {
@@ -65,6 +68,7 @@ impl Default for Processor {
             limits: RhaiLimits::default(),
             depends_on: None,
             retry: None,
+            error_policy: None,
         }
     }
 }
@@ -146,6 +150,7 @@ mod tests {
             limits: RhaiLimits::default(),
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         assert_eq!(config.name, "test_script");
@@ -182,6 +187,7 @@ mod tests {
             limits: RhaiLimits::default(),
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         let serialized = serde_json::to_string(&config).unwrap();
@@ -199,6 +205,7 @@ mod tests {
             limits: RhaiLimits::default(),
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         let cloned = config.clone();
@@ -217,6 +224,7 @@ mod tests {
             limits: RhaiLimits::default(),
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         assert_eq!(config.name, "resource_script");
@@ -1071,6 +1071,7 @@ mod tests {
             limits: crate::task::script::config::RhaiLimits::default(),
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
         let (tx, rx) = mpsc::channel(100);
 
@@ -1123,6 +1124,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
         };
 
         // Drop the original tx so recv can complete
@@ -1162,6 +1164,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
             task_id: 0,
             id: None,
             timestamp: 123456789,
@@ -1302,6 +1305,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
         };
 
         tokio::spawn(async move {
@@ -1358,6 +1362,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
         };
 
         tokio::spawn(async move {
@@ -1419,6 +1424,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
         };
 
         let result = event_handler.handle(input_event).await;
@@ -1445,6 +1451,7 @@ mod tests {
                 limits: crate::task::script::config::RhaiLimits::default(),
                 depends_on: None,
                 retry: None,
+                error_policy: None,
             }),
             tx: Some(tx),
             rx: mpsc::channel(100).1,
@@ -1468,6 +1475,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
         };
 
         event_handler
@@ -1501,6 +1509,7 @@ mod tests {
                 limits: crate::task::script::config::RhaiLimits::default(),
                 depends_on: None,
                 retry: None,
+                error_policy: None,
             }),
             tx: Some(tx),
             rx: mpsc::channel(100).1,
@@ -1524,6 +1533,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
         };
 
         event_handler
@@ -1559,6 +1569,7 @@ mod tests {
                     limits: crate::task::script::config::RhaiLimits::default(),
                     depends_on: None,
                     retry: None,
+                    error_policy: None,
                 }),
                 tx: Some(tx),
                 rx: mpsc::channel(100).1,
@@ -1581,6 +1592,7 @@ mod tests {
                 meta: None,
                 error: None,
                 completion_tx: None,
+                span: None,
             };
             handler
                 .handle(event)
@@ -1623,6 +1635,7 @@ mod tests {
                 limits: crate::task::script::config::RhaiLimits::default(),
                 depends_on: None,
                 retry: None,
+                error_policy: None,
             }),
             tx: Some(tx),
             rx: mpsc::channel(100).1,
@@ -1646,6 +1659,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
         };
 
         event_handler
@@ -1688,6 +1702,7 @@ mod tests {
                 limits: crate::task::script::config::RhaiLimits::default(),
                 depends_on: None,
                 retry: None,
+                error_policy: None,
             }),
             tx: Some(tx),
             rx: mpsc::channel(100).1,
@@ -1711,6 +1726,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
         };
 
         event_handler
@@ -1755,6 +1771,7 @@ mod tests {
                 limits: crate::task::script::config::RhaiLimits::default(),
                 depends_on: None,
                 retry: None,
+                error_policy: None,
             }),
             tx: Some(tx),
             rx: mpsc::channel(100).1,
@@ -1778,6 +1795,7 @@ mod tests {
             meta: None,
             error: None,
             completion_tx: None,
+            span: None,
         };
 
         event_handler
@@ -0,0 +1,53 @@
+//! Configuration for the split task processor.
+//!
+//! The split task breaks large Arrow RecordBatch events into smaller batches
+//! of a fixed number of rows.
+
+use crate::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for split processor task.
+///
+/// # Examples
+///
+/// ```yaml
+/// split:
+///   name: "chunk_rows"
+///   chunk_size: 1000
+/// ```
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct Processor {
+    /// Unique name identifier for this split task.
+    pub name: String,
+    /// Maximum number of rows per emitted batch. The last chunk may be smaller.
+    pub chunk_size: usize,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    pub retry: Option<crate::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    pub error_policy: Option<crate::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Processor {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_processor_deserialization() {
+        let yaml = r#"
+name: chunk_rows
+chunk_size: 1000
+"#;
+        let processor: Processor = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(processor.name, "chunk_rows");
+        assert_eq!(processor.chunk_size, 1000);
+        assert!(processor.depends_on.is_none());
+    }
+}
@@ -0,0 +1,467 @@
+//! Split processor for breaking large record batches into smaller ones.
+//!
+//! Slices every incoming Arrow RecordBatch into chunks of at most
+//! `chunk_size` rows and emits one event per chunk. Chunks keep the subject
+//! and meta of the input event and record their position under
+//! `_chunk_index`.
+
+use crate::event::{
+    new_completion_channel, spawn_fan_in_completion, Event, EventBuilder, EventData, EventExt,
+    META_CHUNK_INDEX,
+};
+use arrow::record_batch::RecordBatch;
+use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tracing::{error, Instrument};
+
+/// Errors that can occur during split processing.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Expected ArrowRecordBatch event data, got JSON")]
+    ExpectedRecordBatchGotJson,
+    #[error("Expected ArrowRecordBatch event data, got Avro")]
+    ExpectedRecordBatchGotAvro,
+    #[error("Chunk size must be greater than zero")]
+    ZeroChunkSize,
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+    #[error("Task failed after all retry attempts: {source}")]
+    RetryExhausted {
+        #[source]
+        source: Box<Error>,
+    },
+}
+
+/// Slices a batch into chunks of at most `chunk_size` rows.
+///
+/// An empty batch yields a single empty chunk so the event still flows
+/// downstream.
+fn split_batch(batch: &RecordBatch, chunk_size: usize) -> Vec<RecordBatch> {
+    if batch.num_rows() == 0 {
+        return vec![batch.clone()];
+    }
+
+    (0..batch.num_rows())
+        .step_by(chunk_size)
+        .map(|offset| batch.slice(offset, chunk_size.min(batch.num_rows() - offset)))
+        .collect()
+}
+
+/// Handles individual event processing by splitting record batches.
+pub struct EventHandler {
+    /// Split processor configuration settings.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for processed events (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event tracking.
+    task_id: usize,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+}
+
+impl EventHandler {
+    /// Processes an event by slicing its record batch and emitting one event per chunk.
+    #[tracing::instrument(skip(self, event), name = "task.handle")]
+    async fn handle(&self, event: Event) -> Result<(), Error> {
+        if self.task_context.cancellation_token.is_cancelled() {
+            return Ok(());
+        }
+
+        let event = Arc::new(event);
+        let completion_tx_arc = Arc::clone(&event).completion_tx.clone();
+        crate::event::with_event_context(&Arc::clone(&event), async move {
+            // Empty input batches are forwarded as a single empty chunk.
+            let chunks = match &event.data {
+                EventData::ArrowRecordBatch(batch) => split_batch(batch, self.config.chunk_size),
+                EventData::Json(_) => return Err(Error::ExpectedRecordBatchGotJson),
+                EventData::Avro(_) => return Err(Error::ExpectedRecordBatchGotAvro),
+            };
+
+            // Split is the only leaf in its subtree when it is the final task,
+            // so a single signal completes the upstream contract.
+            if self.tx.is_none() {
+                if let Some(arc) = completion_tx_arc.as_ref() {
+                    arc.signal_completion(None);
+                }
+                return Ok(());
+            }
+
+            // Attach a per-chunk completion channel to every emitted event and
+            // fan-in their signals so the upstream source sees completion only
+            // after every chunk finishes. See spawn_fan_in_completion for details.
+            let downstream_leaves = self.task_context.leaf_count.max(1);
+            let mut per_event_receivers = Vec::with_capacity(chunks.len());
+
+            for (index, chunk) in chunks.into_iter().enumerate() {
+                if self.task_context.cancellation_token.is_cancelled() {
+                    return Ok(());
+                }
+
+                let mut e = EventBuilder::new()
+                    .data(EventData::ArrowRecordBatch(chunk))
+                    .subject(event.subject.clone())
+                    .task_id(self.task_id)
+                    .task_type(self.task_type)
+                    .allow_empty(true)
+                    .build()
+                    .map_err(|source| Error::EventBuilder { source })?;
+                e.set_meta(META_CHUNK_INDEX, index.into());
+
+                let (per_state, per_rx) = new_completion_channel(downstream_leaves);
+                e.completion_tx = Some(per_state);
+                per_event_receivers.push(per_rx);
+
+                e.send_with_logging(self.tx.as_ref())
+                    .await
+                    .map_err(|source| Error::SendMessage { source })?;
+            }
+
+            if let Some(upstream) = completion_tx_arc.as_ref() {
+                spawn_fan_in_completion(
+                    Arc::clone(upstream),
+                    per_event_receivers,
+                    downstream_leaves,
+                );
+            }
+
+            Ok(())
+        })
+        .await
+    }
+}
+
+/// Split processor that breaks record batches into fixed-size chunks.
+#[derive(Debug)]
+pub struct Processor {
+    /// Split processor configuration.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for processed events (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Channel receiver for incoming events.
+    rx: Receiver<Event>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl crate::task::runner::Runner for Processor {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Initializes the split processor.
+    async fn init(&self) -> Result<Self::EventHandler, Self::Error> {
+        let event_handler = EventHandler {
+            config: Arc::clone(&self.config),
+            tx: self.tx.clone(),
+            task_id: self.task_id,
+            task_type: self.task_type,
+            task_context: Arc::clone(&self.task_context),
+        };
+
+        Ok(event_handler)
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(mut self) -> Result<(), Error> {
+        let retry_config =
+            crate::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        let event_handler = match tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+            match self.init().await {
+                Ok(handler) => Ok(handler),
+                Err(e) => {
+                    error!(error = %e, "Failed to initialize split processor");
+                    Err(tokio_retry::RetryError::transient(e))
+                }
+            }
+        })
+        .await
+        {
+            Ok(handler) => Arc::new(handler),
+            Err(e) => {
+                return Err(e);
+            }
+        };
+
+        loop {
+            match self.rx.recv().await {
+                Some(event) => {
+                    let event_handler = Arc::clone(&event_handler);
+                    let retry_strategy = retry_config.strategy();
+                    tokio::spawn(
+                        async move {
+                            let result = tokio_retry::Retry::spawn(retry_strategy, || async {
+                                match event_handler.handle(event.clone()).await {
+                                    Ok(result) => Ok(result),
+                                    Err(e) => {
+                                        error!(error = %e, "Failed to split event");
+                                        Err(tokio_retry::RetryError::transient(e))
+                                    }
+                                }
+                            })
+                            .await;
+
+                            if let Err(err) = result {
+                                error!(error = %err, "Split failed after all retry attempts.");
+                                // Emit error event downstream for error handling.
+                                let mut error_event = event.clone();
+                                error_event.error = Some(err.to_string());
+                                if let Some(ref tx) = event_handler.tx {
+                                    tx.send(error_event).await.ok();
+                                }
+                            }
+                        }
+                        .instrument(tracing::Span::current()),
+                    );
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Builder for constructing Processor instances with validation.
+#[derive(Debug, Default)]
+pub struct ProcessorBuilder {
+    /// Split processor configuration (required for build).
+    config: Option<Arc<super::config::Processor>>,
+    /// Event sender for passing events to next task (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Event receiver for incoming events (required for build).
+    rx: Option<Receiver<Event>>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Option<Arc<crate::task::context::TaskContext>>,
+    /// Task type for event categorization and logging.
+    task_type: Option<&'static str>,
+}
+
+impl ProcessorBuilder {
+    pub fn new() -> ProcessorBuilder {
+        ProcessorBuilder {
+            ..Default::default()
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Processor>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn receiver(mut self, receiver: Receiver<Event>) -> Self {
+        self.rx = Some(receiver);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = task_id;
+        self
+    }
+
+    pub fn task_context(mut self, task_context: Arc<crate::task::context::TaskContext>) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Processor, Error> {
+        let config = self
+            .config
+            .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?;
+        if config.chunk_size == 0 {
+            return Err(Error::ZeroChunkSize);
+        }
+
+        Ok(Processor {
+            config,
+            rx: self
+                .rx
+                .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
+            tx: self.tx,
+            task_id: self.task_id,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Array, Int64Array};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use serde_json::{json, Map, Value};
+    use tokio::sync::mpsc;
+
+    fn create_mock_task_context() -> Arc<crate::task::context::TaskContext> {
+        let mut labels = Map::new();
+        labels.insert(
+            "description".to_string(),
+            Value::String("Split Test".to_string()),
+        );
+        let task_manager = Arc::new(
+            crate::task::manager::TaskManagerBuilder::new()
+                .build()
+                .unwrap(),
+        );
+        let cache =
+            Arc::new(crate::cache::memory::MemoryCache::new()) as Arc<dyn crate::cache::Cache>;
+        Arc::new(
+            crate::task::context::TaskContextBuilder::new()
+                .flow_name("test-flow".to_string())
+                .flow_labels(Some(labels))
+                .task_manager(task_manager)
+                .cache(cache)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn create_config(chunk_size: usize) -> Arc<super::super::config::Processor> {
+        Arc::new(super::super::config::Processor {
+            name: "test".to_string(),
+            chunk_size,
+            depends_on: None,
+            retry: None,
+            error_policy: None,
+        })
+    }
+
+    fn create_batch(ids: Vec<i64>) -> RecordBatch {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+        RecordBatch::try_new(schema, vec![Arc::new(Int64Array::from(ids))]).unwrap()
+    }
+
+    fn create_event(data: EventData) -> Event {
+        let mut meta = Map::new();
+        meta.insert("tenant".to_string(), json!("acme"));
+        Event {
+            data,
+            subject: "input.subject".to_string(),
+            task_id: 0,
+            id: None,
+            timestamp: 123456789,
+            task_type: "test",
+            meta: Some(meta),
+            error: None,
+            completion_tx: None,
+            span: None,
+        }
+    }
+
+    #[test]
+    fn test_split_batch_last_chunk_smaller() {
+        let chunks = split_batch(&create_batch(vec![1, 2, 3, 4, 5]), 2);
+
+        let sizes: Vec<usize> = chunks.iter().map(RecordBatch::num_rows).collect();
+        assert_eq!(sizes, vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn test_split_batch_empty_batch() {
+        let chunks = split_batch(&create_batch(vec![]), 10);
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].num_rows(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_processor_builder_rejects_zero_chunk_size() {
+        let (_tx, rx) = mpsc::channel(100);
+        let result = ProcessorBuilder::new()
+            .config(create_config(0))
+            .receiver(rx)
+            .task_type("test")
+            .task_context(create_mock_task_context())
+            .build()
+            .await;
+
+        assert!(matches!(result, Err(Error::ZeroChunkSize)));
+    }
+
+    #[tokio::test]
+    async fn test_event_handler_emits_chunks_with_subject_and_meta() {
+        let (tx, mut rx) = mpsc::channel(100);
+        let event_handler = EventHandler {
+            config: create_config(2),
+            tx: Some(tx),
+            task_id: 1,
+            task_type: "test",
+            task_context: create_mock_task_context(),
+        };
+
+        let input_event = create_event(EventData::ArrowRecordBatch(create_batch(vec![1, 2, 3])));
+        event_handler.handle(input_event).await.unwrap();
+
+        let mut ids = Vec::new();
+        for expected_index in 0..2 {
+            let event = rx.recv().await.expect("expected chunk event");
+            assert_eq!(event.subject, "input.subject");
+            assert_eq!(event.get_meta("tenant"), Some(&json!("acme")));
+            assert_eq!(
+                event.get_meta(META_CHUNK_INDEX),
+                Some(&json!(expected_index))
+            );
+            let EventData::ArrowRecordBatch(batch) = event.data else {
+                panic!("Expected ArrowRecordBatch data");
+            };
+            let column = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .unwrap();
+            ids.extend(column.values().iter().copied());
+        }
+
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_event_handler_json_error() {
+        let (tx, _rx) = mpsc::channel(100);
+        let event_handler = EventHandler {
+            config: create_config(2),
+            tx: Some(tx),
+            task_id: 1,
+            task_type: "test",
+            task_context: create_mock_task_context(),
+        };
+
+        let result = event_handler
+            .handle(create_event(EventData::Json(json!([1, 2, 3]))))
+            .await;
+
+        assert!(matches!(result, Err(Error::ExpectedRecordBatchGotJson)));
+    }
+}
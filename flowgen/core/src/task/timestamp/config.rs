@@ -0,0 +1,81 @@
+//! Configuration for the timestamp task processor.
+//!
+//! The timestamp task parses string columns into UTC timestamps, e.g. to turn
+//! the offset-bearing date strings returned by Salesforce into the
+//! microsecond timestamps a destination table expects.
+
+use crate::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for timestamp processor task.
+///
+/// # Examples
+///
+/// ```yaml
+/// timestamp:
+///   name: "normalize_timestamps"
+///   timestamp_columns:
+///     - column: updated_at
+///       input_format: "%Y-%m-%dT%H:%M:%S%.3f%z"
+///     - column: closed_on
+///       input_format: "%Y-%m-%d %H:%M:%S"
+///       output_tz: "Europe/Zurich"
+///   fail_on_parse_error: true
+/// ```
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Processor {
+    /// The unique name / identifier of the task.
+    pub name: String,
+    /// Columns to parse and how to parse them.
+    pub timestamp_columns: Vec<TimestampColumnConfig>,
+    /// Fail the event on the first unparseable value instead of writing null.
+    #[serde(default)]
+    pub fail_on_parse_error: bool,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    #[serde(default)]
+    pub retry: Option<crate::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<crate::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Processor {}
+
+/// Parsing applied to a single string column.
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct TimestampColumnConfig {
+    /// Column holding the timestamp strings.
+    pub column: String,
+    /// strftime-style format of the input values (e.g. `%Y-%m-%dT%H:%M:%S%.f%z`).
+    /// Values without an offset are read as UTC.
+    pub input_format: String,
+    /// Time zone attached to the output column, an IANA name or a fixed
+    /// offset such as `+05:30`. Defaults to `UTC`.
+    #[serde(default)]
+    pub output_tz: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_processor_deserialization() {
+        let yaml = r#"
+name: normalize_timestamps
+timestamp_columns:
+  - column: updated_at
+    input_format: "%Y-%m-%dT%H:%M:%S%.3f%z"
+"#;
+        let processor: Processor = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(processor.timestamp_columns[0].column, "updated_at");
+        assert_eq!(processor.timestamp_columns[0].output_tz, None);
+        assert!(!processor.fail_on_parse_error);
+    }
+}
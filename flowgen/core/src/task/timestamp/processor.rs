@@ -0,0 +1,507 @@
+//! Timestamp normalization processor.
+//!
+//! Parses configured string columns of Arrow RecordBatch events with a
+//! strftime-style format, converts the values to UTC and replaces the
+//! columns with microsecond timestamp columns.
+
+use super::config::TimestampColumnConfig;
+use crate::event::{Event, EventBuilder, EventData, EventExt};
+use arrow::array::{Array, ArrayRef, AsArray, RecordBatch, TimestampMicrosecondArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use chrono::{DateTime, NaiveDateTime};
+use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tracing::{error, Instrument};
+
+/// Time zone attached to output columns without an explicit `output_tz`.
+const DEFAULT_OUTPUT_TZ: &str = "UTC";
+
+/// Errors that can occur during timestamp processing operations.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Arrow error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+    #[error("Invalid output time zone '{tz}' for column '{column}'")]
+    InvalidTimezone { column: String, tz: String },
+    #[error("Column '{column}' must be a string column, found {data_type}")]
+    NotStringColumn { column: String, data_type: DataType },
+    #[error("Cannot parse value '{value}' of column '{column}' with format '{format}'")]
+    ParseTimestamp {
+        column: String,
+        value: String,
+        format: String,
+    },
+    #[error(
+        "Timestamp parsing requires Arrow record batch data, convert the event to Arrow first."
+    )]
+    NotRecordBatch,
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+    #[error("Task failed after all retry attempts: {source}")]
+    RetryExhausted {
+        #[source]
+        source: Box<Error>,
+    },
+}
+
+/// Parses a timestamp string into UTC microseconds since the epoch.
+///
+/// Values whose format carries no offset are read as UTC.
+fn parse_timestamp(value: &str, format: &str) -> Option<i64> {
+    DateTime::parse_from_str(value, format)
+        .map(|datetime| datetime.timestamp_micros())
+        .or_else(|_| {
+            NaiveDateTime::parse_from_str(value, format)
+                .map(|datetime| datetime.and_utc().timestamp_micros())
+        })
+        .ok()
+}
+
+/// Parses a string column into a microsecond timestamp column.
+fn parse_column(
+    array: &ArrayRef,
+    config: &TimestampColumnConfig,
+    fail_on_parse_error: bool,
+) -> Result<TimestampMicrosecondArray, Error> {
+    let Some(strings) = array.as_string_opt::<i32>() else {
+        return Err(Error::NotStringColumn {
+            column: config.column.clone(),
+            data_type: array.data_type().clone(),
+        });
+    };
+
+    let values = strings
+        .iter()
+        .map(|value| match value {
+            None => Ok(None),
+            Some(value) => match parse_timestamp(value.trim(), &config.input_format) {
+                Some(micros) => Ok(Some(micros)),
+                None if fail_on_parse_error => Err(Error::ParseTimestamp {
+                    column: config.column.clone(),
+                    value: value.to_string(),
+                    format: config.input_format.clone(),
+                }),
+                None => Ok(None),
+            },
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let tz = config.output_tz.as_deref().unwrap_or(DEFAULT_OUTPUT_TZ);
+    Ok(TimestampMicrosecondArray::from(values).with_timezone(tz))
+}
+
+/// Replaces the configured string columns of a record batch with timestamps.
+///
+/// Columns not present in the batch are ignored.
+fn normalize_batch(
+    batch: &RecordBatch,
+    timestamp_columns: &[TimestampColumnConfig],
+    fail_on_parse_error: bool,
+) -> Result<RecordBatch, Error> {
+    let schema = batch.schema();
+    let mut fields: Vec<Field> = schema.fields().iter().map(|f| f.as_ref().clone()).collect();
+    let mut columns: Vec<ArrayRef> = batch.columns().to_vec();
+
+    for config in timestamp_columns {
+        let Ok(index) = schema.index_of(&config.column) else {
+            continue;
+        };
+        let parsed = parse_column(&columns[index], config, fail_on_parse_error)?;
+        // Unparseable values become nulls, so the field may need to turn nullable.
+        let nullable = fields[index].is_nullable() || parsed.null_count() > 0;
+        fields[index] = fields[index]
+            .clone()
+            .with_data_type(parsed.data_type().clone())
+            .with_nullable(nullable);
+        columns[index] = Arc::new(parsed);
+    }
+
+    RecordBatch::try_new(
+        Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone())),
+        columns,
+    )
+    .map_err(|source| Error::Arrow { source })
+}
+
+/// Handles individual event processing by parsing timestamp columns.
+pub struct EventHandler {
+    /// Processor configuration settings.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for processed events (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event tracking.
+    task_id: usize,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+}
+
+impl EventHandler {
+    /// Processes an event by parsing its configured timestamp columns.
+    #[tracing::instrument(skip(self, event), name = "task.handle")]
+    async fn handle(&self, event: Event) -> Result<(), Error> {
+        if self.task_context.cancellation_token.is_cancelled() {
+            return Ok(());
+        }
+
+        let event = Arc::new(event);
+        let completion_tx_arc = Arc::clone(&event).completion_tx.clone();
+        crate::event::with_event_context(&Arc::clone(&event), async move {
+            // Parsing keeps the row count, so an empty input stays an empty output.
+            let event_builder = EventBuilder::new().allow_empty(true);
+            let data = match &event.data {
+                EventData::ArrowRecordBatch(batch) => EventData::ArrowRecordBatch(normalize_batch(
+                    batch,
+                    &self.config.timestamp_columns,
+                    self.config.fail_on_parse_error,
+                )?),
+                EventData::Json(_) | EventData::Avro(_) => return Err(Error::NotRecordBatch),
+            };
+
+            let mut e = event_builder
+                .data(data)
+                .subject(self.config.name.to_owned())
+                .task_id(self.task_id)
+                .task_type(self.task_type)
+                .build()
+                .map_err(|source| Error::EventBuilder { source })?;
+
+            // Signal completion or pass through to next task.
+            match self.tx {
+                None => {
+                    if let Some(arc) = completion_tx_arc.as_ref() {
+                        arc.signal_completion(e.data_as_json().ok());
+                    }
+                }
+                Some(_) => {
+                    e.completion_tx = completion_tx_arc.clone();
+                }
+            }
+
+            e.send_with_logging(self.tx.as_ref())
+                .await
+                .map_err(|source| Error::SendMessage { source })?;
+            Ok(())
+        })
+        .await
+    }
+}
+
+/// Timestamp processor that parses string columns into UTC timestamps.
+#[derive(Debug)]
+pub struct Processor {
+    /// Timestamp task configuration.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for normalized events.
+    tx: Option<Sender<Event>>,
+    /// Channel receiver for incoming events to normalize.
+    rx: Receiver<Event>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl crate::task::runner::Runner for Processor {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Initializes the processor by validating the output time zones.
+    async fn init(&self) -> Result<Self::EventHandler, Self::Error> {
+        for column in &self.config.timestamp_columns {
+            if let Some(tz) = &column.output_tz {
+                if tz.parse::<arrow::array::timezone::Tz>().is_err() {
+                    return Err(Error::InvalidTimezone {
+                        column: column.column.clone(),
+                        tz: tz.clone(),
+                    });
+                }
+            }
+        }
+
+        Ok(EventHandler {
+            config: Arc::clone(&self.config),
+            tx: self.tx.clone(),
+            task_id: self.task_id,
+            task_type: self.task_type,
+            task_context: Arc::clone(&self.task_context),
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(mut self) -> Result<(), Error> {
+        let retry_config =
+            crate::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        let event_handler = match tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+            match self.init().await {
+                Ok(handler) => Ok(handler),
+                Err(e @ Error::InvalidTimezone { .. }) => {
+                    error!(error = %e, "Invalid timestamp configuration");
+                    Err(tokio_retry::RetryError::permanent(e))
+                }
+                Err(e) => {
+                    error!(error = %e, "Failed to initialize timestamp processor");
+                    Err(tokio_retry::RetryError::transient(e))
+                }
+            }
+        })
+        .await
+        {
+            Ok(handler) => Arc::new(handler),
+            Err(e) => {
+                return Err(e);
+            }
+        };
+
+        loop {
+            match self.rx.recv().await {
+                Some(event) => {
+                    let event_handler = Arc::clone(&event_handler);
+                    let retry_strategy = retry_config.strategy();
+                    tokio::spawn(
+                        async move {
+                            let result = tokio_retry::Retry::spawn(retry_strategy, || async {
+                                match event_handler.handle(event.clone()).await {
+                                    Ok(result) => Ok(result),
+                                    Err(e) => {
+                                        error!(error = %e, "Failed to parse timestamps");
+                                        Err(tokio_retry::RetryError::transient(e))
+                                    }
+                                }
+                            })
+                            .await;
+
+                            if let Err(err) = result {
+                                error!(error = %err, "Timestamp parsing failed after all retry attempts.");
+                                // Emit error event downstream for error handling.
+                                let mut error_event = event.clone();
+                                error_event.error = Some(err.to_string());
+                                if let Some(ref tx) = event_handler.tx {
+                                    tx.send(error_event).await.ok();
+                                }
+                            }
+                        }
+                        .instrument(tracing::Span::current()),
+                    );
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Builder for constructing Processor instances with validation.
+#[derive(Debug, Default)]
+pub struct ProcessorBuilder {
+    /// Processor configuration (required for build).
+    config: Option<Arc<super::config::Processor>>,
+    /// Event sender for passing events to next task (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Event receiver for incoming events (required for build).
+    rx: Option<Receiver<Event>>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Option<Arc<crate::task::context::TaskContext>>,
+    /// Task type for event categorization and logging.
+    task_type: Option<&'static str>,
+}
+
+impl ProcessorBuilder {
+    pub fn new() -> ProcessorBuilder {
+        ProcessorBuilder {
+            ..Default::default()
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Processor>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn receiver(mut self, receiver: Receiver<Event>) -> Self {
+        self.rx = Some(receiver);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = task_id;
+        self
+    }
+
+    pub fn task_context(mut self, task_context: Arc<crate::task::context::TaskContext>) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Processor, Error> {
+        Ok(Processor {
+            config: self
+                .config
+                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            rx: self
+                .rx
+                .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
+            tx: self.tx,
+            task_id: self.task_id,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::StringArray;
+    use arrow::datatypes::TimeUnit;
+
+    fn column(name: &str, format: &str, tz: Option<&str>) -> TimestampColumnConfig {
+        TimestampColumnConfig {
+            column: name.to_string(),
+            input_format: format.to_string(),
+            output_tz: tz.map(str::to_string),
+        }
+    }
+
+    fn batch(values: Vec<Option<&str>>) -> RecordBatch {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("updated_at", DataType::Utf8, false),
+            Field::new("id", DataType::Utf8, false),
+        ]));
+        let ids: Vec<_> = (0..values.len()).map(|i| i.to_string()).collect();
+        RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from(values)) as ArrayRef,
+                Arc::new(StringArray::from(ids)),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(
+            parse_timestamp("2024-01-01T00:00:00.000+0530", "%Y-%m-%dT%H:%M:%S%.3f%z"),
+            Some(1_704_047_400_000_000)
+        );
+        assert_eq!(
+            parse_timestamp("2024-01-01 00:00:00", "%Y-%m-%d %H:%M:%S"),
+            Some(1_704_067_200_000_000)
+        );
+        assert_eq!(parse_timestamp("yesterday", "%Y-%m-%d %H:%M:%S"), None);
+    }
+
+    #[test]
+    fn test_normalize_batch() {
+        let batch = batch(vec![
+            Some("2024-01-01T00:00:00.000+0530"),
+            Some("not a date"),
+        ]);
+        let columns = vec![
+            column("updated_at", "%Y-%m-%dT%H:%M:%S%.3f%z", None),
+            column("missing", "%Y", None),
+        ];
+
+        let normalized = normalize_batch(&batch, &columns, false).unwrap();
+
+        let field = normalized.schema().field(0).clone();
+        assert_eq!(
+            field.data_type(),
+            &DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+        );
+        assert!(field.is_nullable());
+        let values = normalized
+            .column(0)
+            .as_primitive::<arrow::datatypes::TimestampMicrosecondType>();
+        assert_eq!(values.value(0), 1_704_047_400_000_000);
+        assert!(values.is_null(1));
+        assert_eq!(normalized.schema().field(1).data_type(), &DataType::Utf8);
+    }
+
+    #[test]
+    fn test_normalize_batch_output_tz() {
+        let batch = batch(vec![Some("2024-01-01 00:00:00")]);
+        let columns = vec![column(
+            "updated_at",
+            "%Y-%m-%d %H:%M:%S",
+            Some("Europe/Zurich"),
+        )];
+
+        let normalized = normalize_batch(&batch, &columns, false).unwrap();
+
+        assert_eq!(
+            normalized.schema().field(0).data_type(),
+            &DataType::Timestamp(TimeUnit::Microsecond, Some("Europe/Zurich".into()))
+        );
+        assert!(!normalized.schema().field(0).is_nullable());
+    }
+
+    #[test]
+    fn test_normalize_batch_fail_on_parse_error() {
+        let batch = batch(vec![Some("2024-01-01 00:00:00"), Some("garbage")]);
+        let columns = vec![column("updated_at", "%Y-%m-%d %H:%M:%S", None)];
+
+        match normalize_batch(&batch, &columns, true) {
+            Err(Error::ParseTimestamp { column, value, .. }) => {
+                assert_eq!(column, "updated_at");
+                assert_eq!(value, "garbage");
+            }
+            other => panic!("expected ParseTimestamp, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_normalize_batch_rejects_non_string_column() {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "updated_at",
+            DataType::Int64,
+            false,
+        )]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(arrow::array::Int64Array::from(vec![1])) as ArrayRef],
+        )
+        .unwrap();
+        let columns = vec![column("updated_at", "%s", None)];
+
+        assert!(matches!(
+            normalize_batch(&batch, &columns, false),
+            Err(Error::NotStringColumn { .. })
+        ));
+    }
+}
@@ -0,0 +1,71 @@
+//! Configuration for the window task processor.
+//!
+//! The window task collects Arrow RecordBatch events into wall-clock aligned
+//! tumbling windows and emits one concatenated batch per window.
+
+use crate::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Configuration for window processor task.
+///
+/// Windows are aligned to the Unix epoch, so a `1m` window closes at every
+/// full minute (12:00:00, 12:01:00, ...) regardless of when the first event
+/// arrived.
+///
+/// # Examples
+///
+/// ```yaml
+/// window:
+///   name: "orders_per_minute"
+///   window: "1m"
+///   emit_empty: true
+/// ```
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct Processor {
+    /// Unique name identifier for this window task.
+    pub name: String,
+    /// Length of each tumbling window. Accepts duration strings: "500ms", "30s", "5m", etc.
+    #[serde(with = "humantime_serde")]
+    pub window: Duration,
+    /// Emit an empty batch for windows without events. Requires at least one
+    /// batch to have been received, so the schema of the empty batch is known.
+    #[serde(default)]
+    pub emit_empty: bool,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    pub retry: Option<crate::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    pub error_policy: Option<crate::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Processor {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_processor_deserialization() {
+        let yaml = r#"
+name: orders_per_minute
+window: 1m
+emit_empty: true
+"#;
+        let processor: Processor = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(processor.window, Duration::from_secs(60));
+        assert!(processor.emit_empty);
+    }
+
+    #[test]
+    fn test_processor_emit_empty_defaults_to_false() {
+        let processor: Processor = serde_yaml::from_str("name: w\nwindow: 10s").unwrap();
+
+        assert!(!processor.emit_empty);
+    }
+}
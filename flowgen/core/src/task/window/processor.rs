@@ -0,0 +1,497 @@
+//! Window processor for collecting record batches over time windows.
+//!
+//! Accumulates Arrow RecordBatch events into wall-clock aligned tumbling
+//! windows. When a window closes, the batches received in it are concatenated
+//! and emitted as a single event.
+
+use crate::event::{
+    new_completion_channel, spawn_merged_completion, Event, EventBatch, EventBuilder, EventData,
+    EventExt, SharedCompletionTx,
+};
+use arrow::array::RecordBatch;
+use arrow::datatypes::SchemaRef;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::mpsc::{Receiver, Sender};
+use tokio::time::{interval_at, Instant, MissedTickBehavior};
+use tracing::{error, warn};
+
+/// Errors that can occur during window processing operations.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Expected ArrowRecordBatch event data, got JSON")]
+    ExpectedRecordBatchGotJson,
+    #[error("Expected ArrowRecordBatch event data, got Avro")]
+    ExpectedRecordBatchGotAvro,
+    #[error("Arrow error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+    #[error("Window must be greater than zero")]
+    ZeroWindow,
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+}
+
+/// Returns the time from `now` until the next multiple of `window` since the Unix epoch.
+fn until_next_boundary(window: Duration, now: SystemTime) -> Duration {
+    let window_nanos = window.as_nanos();
+    let elapsed = now
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos()
+        % window_nanos;
+    Duration::from_nanos((window_nanos - elapsed) as u64)
+}
+
+/// Window processor that concatenates record batches over tumbling windows.
+///
+/// Like the aggregate processor, this keeps state across events and uses
+/// `tokio::select!` to react to incoming events, window ticks and shutdown.
+#[derive(Debug)]
+pub struct Processor {
+    /// Window processor configuration.
+    config: Arc<super::config::Processor>,
+    /// Channel sender for processed events.
+    tx: Option<Sender<Event>>,
+    /// Channel receiver for incoming events.
+    rx: Receiver<Event>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<crate::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+impl Processor {
+    /// Emits the batches of the closed window as one concatenated batch.
+    ///
+    /// The meta of the first event in the window is attached to the output
+    /// event. The completion channels of all events in the window are
+    /// signalled once the output event has completed, or right away when this
+    /// task is a leaf.
+    async fn flush_window(&self, mut window: EventBatch, schema: SchemaRef) -> Result<(), Error> {
+        let batch = if window.is_empty() {
+            RecordBatch::new_empty(schema)
+        } else {
+            window
+                .merge_to_record_batch()
+                .map_err(|source| Error::Arrow { source })?
+        };
+        let events = window.drain();
+        let meta = events.first().and_then(|event| event.meta.clone());
+        let completions: Vec<SharedCompletionTx> = events
+            .into_iter()
+            .filter_map(|event| event.completion_tx)
+            .collect();
+
+        // An empty window yields an empty batch, which is only sent on request.
+        let mut event_builder = EventBuilder::new()
+            .data(EventData::ArrowRecordBatch(batch))
+            .subject(self.config.name.to_owned())
+            .task_id(self.task_id)
+            .task_type(self.task_type)
+            .allow_empty(true);
+        if let Some(meta) = meta {
+            event_builder = event_builder.meta(meta);
+        }
+        let mut event = event_builder
+            .build()
+            .map_err(|source| Error::EventBuilder { source })?;
+
+        let downstream_leaves = self.task_context.leaf_count.max(1);
+        let mut per_event_receivers = Vec::new();
+        if self.tx.is_some() && !completions.is_empty() {
+            let (per_state, per_rx) = new_completion_channel(downstream_leaves);
+            event.completion_tx = Some(per_state);
+            per_event_receivers.push(per_rx);
+        }
+
+        event
+            .send_with_logging(self.tx.as_ref())
+            .await
+            .map_err(|source| Error::SendMessage { source })?;
+
+        spawn_merged_completion(completions, per_event_receivers, downstream_leaves);
+        Ok(())
+    }
+
+    /// Runs the window loop until the channel closes or the task is cancelled.
+    async fn process_events(&mut self) -> Result<(), Error> {
+        let window = self.config.window;
+        let first_boundary = until_next_boundary(window, SystemTime::now());
+        let mut ticker = interval_at(Instant::now() + first_boundary, window);
+        // Stay on the wall-clock grid if a flush takes longer than a window.
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        // Size limits are disabled; the window only closes on ticks.
+        let mut open = EventBatch::default();
+        // Schema of the open window, kept after it closes for empty windows.
+        let mut schema: Option<SchemaRef> = None;
+
+        loop {
+            if self.task_context.cancellation_token.is_cancelled() {
+                return Ok(());
+            }
+
+            tokio::select! {
+                result = self.rx.recv() => {
+                    match result {
+                        Some(event) => {
+                            let batch_schema = match &event.data {
+                                EventData::ArrowRecordBatch(batch) => batch.schema(),
+                                EventData::Json(_) => {
+                                    warn!(error = %Error::ExpectedRecordBatchGotJson, "Skipping event");
+                                    continue;
+                                }
+                                EventData::Avro(_) => {
+                                    warn!(error = %Error::ExpectedRecordBatchGotAvro, "Skipping event");
+                                    continue;
+                                }
+                            };
+
+                            // Batches of different schemas cannot be concatenated, so a
+                            // schema change closes the open window early.
+                            if let Some(current) = schema.as_ref().filter(|s| *s != &batch_schema) {
+                                if !open.is_empty() {
+                                    let closed = std::mem::take(&mut open);
+                                    self.flush_window(closed, Arc::clone(current)).await?;
+                                }
+                            }
+                            schema = Some(batch_schema);
+                            open.push(event);
+                        }
+                        None => {
+                            // Channel closed, flush the partial window and exit.
+                            if let Some(schema) = schema.filter(|_| !open.is_empty()) {
+                                self.flush_window(open, schema).await?;
+                            }
+                            return Ok(());
+                        }
+                    }
+                }
+
+                _ = ticker.tick() => {
+                    let Some(schema) = schema.as_ref() else {
+                        continue;
+                    };
+                    if !open.is_empty() || self.config.emit_empty {
+                        let closed = std::mem::take(&mut open);
+                        self.flush_window(closed, Arc::clone(schema)).await?;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::task::runner::Runner for Processor {
+    type Error = Error;
+    type EventHandler = ();
+
+    /// Initializes the window processor.
+    ///
+    /// Window processor keeps window state across events, so there is no
+    /// separate EventHandler and this returns unit type.
+    async fn init(&self) -> Result<Self::EventHandler, Self::Error> {
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(mut self) -> Result<(), Error> {
+        if let Err(e) = self.process_events().await {
+            error!(error = %e, "Failed to process events");
+            return Err(e);
+        }
+
+        Ok(())
+    }
+}
+
+/// Builder for constructing Processor instances with validation.
+#[derive(Debug, Default)]
+pub struct ProcessorBuilder {
+    /// Window processor configuration (required for build).
+    config: Option<Arc<super::config::Processor>>,
+    /// Event sender for passing events to next task (optional if this is the last task).
+    tx: Option<Sender<Event>>,
+    /// Event receiver for incoming events (required for build).
+    rx: Option<Receiver<Event>>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Option<Arc<crate::task::context::TaskContext>>,
+    /// Task type for event categorization and logging.
+    task_type: Option<&'static str>,
+}
+
+impl ProcessorBuilder {
+    pub fn new() -> ProcessorBuilder {
+        ProcessorBuilder {
+            ..Default::default()
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Processor>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn receiver(mut self, receiver: Receiver<Event>) -> Self {
+        self.rx = Some(receiver);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = task_id;
+        self
+    }
+
+    pub fn task_context(mut self, task_context: Arc<crate::task::context::TaskContext>) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Processor, Error> {
+        let config = self
+            .config
+            .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?;
+        if config.window.is_zero() {
+            return Err(Error::ZeroWindow);
+        }
+
+        Ok(Processor {
+            config,
+            rx: self
+                .rx
+                .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
+            tx: self.tx,
+            task_id: self.task_id,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int64Array, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use serde_json::{Map, Value};
+    use tokio::sync::mpsc;
+
+    fn create_mock_task_context() -> Arc<crate::task::context::TaskContext> {
+        let mut labels = Map::new();
+        labels.insert(
+            "description".to_string(),
+            Value::String("Window Test".to_string()),
+        );
+        let task_manager = Arc::new(
+            crate::task::manager::TaskManagerBuilder::new()
+                .build()
+                .unwrap(),
+        );
+        let cache =
+            Arc::new(crate::cache::memory::MemoryCache::new()) as Arc<dyn crate::cache::Cache>;
+        Arc::new(
+            crate::task::context::TaskContextBuilder::new()
+                .flow_name("test-flow".to_string())
+                .flow_labels(Some(labels))
+                .task_manager(task_manager)
+                .cache(cache)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn create_config(window: Duration) -> Arc<super::super::config::Processor> {
+        Arc::new(super::super::config::Processor {
+            name: "window".to_string(),
+            window,
+            emit_empty: false,
+            depends_on: None,
+            retry: None,
+            error_policy: None,
+        })
+    }
+
+    fn create_event(batch: RecordBatch) -> Event {
+        EventBuilder::new()
+            .data(EventData::ArrowRecordBatch(batch))
+            .subject("input".to_string())
+            .task_id(0)
+            .task_type("test")
+            .build()
+            .unwrap()
+    }
+
+    fn amounts(values: Vec<i64>) -> RecordBatch {
+        RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new(
+                "amount",
+                DataType::Int64,
+                false,
+            )])),
+            vec![Arc::new(Int64Array::from(values))],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_until_next_boundary() {
+        let window = Duration::from_secs(60);
+
+        let now = UNIX_EPOCH + Duration::from_secs(3600 + 15);
+        assert_eq!(until_next_boundary(window, now), Duration::from_secs(45));
+
+        // On a boundary, the next window closes a full window later.
+        let now = UNIX_EPOCH + Duration::from_secs(3600);
+        assert_eq!(until_next_boundary(window, now), window);
+    }
+
+    #[tokio::test]
+    async fn test_processor_concatenates_window() {
+        let (in_tx, in_rx) = mpsc::channel(10);
+        let (out_tx, mut out_rx) = mpsc::channel(10);
+
+        let processor = ProcessorBuilder::new()
+            .config(create_config(Duration::from_secs(3600)))
+            .receiver(in_rx)
+            .sender(out_tx)
+            .task_type("test")
+            .task_context(create_mock_task_context())
+            .build()
+            .await
+            .unwrap();
+
+        in_tx.send(create_event(amounts(vec![1, 2]))).await.unwrap();
+        in_tx.send(create_event(amounts(vec![3]))).await.unwrap();
+        drop(in_tx);
+
+        // Closing the channel flushes the partial window.
+        crate::task::runner::Runner::run(processor).await.unwrap();
+
+        let event = out_rx.try_recv().unwrap();
+        let EventData::ArrowRecordBatch(batch) = event.data else {
+            panic!("expected record batch");
+        };
+        assert_eq!(batch, amounts(vec![1, 2, 3]));
+        assert!(out_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_leaf_processor_completes_every_event() {
+        let (in_tx, in_rx) = mpsc::channel(10);
+
+        let processor = ProcessorBuilder::new()
+            .config(create_config(Duration::from_secs(3600)))
+            .receiver(in_rx)
+            .task_type("test")
+            .task_context(create_mock_task_context())
+            .build()
+            .await
+            .unwrap();
+
+        let mut source_receivers = Vec::new();
+        for value in [1, 2] {
+            let (completion_tx, completion_rx) = crate::event::new_completion_channel(1);
+            let mut event = create_event(amounts(vec![value]));
+            event.completion_tx = Some(completion_tx);
+            source_receivers.push(completion_rx);
+            in_tx.send(event).await.unwrap();
+        }
+        drop(in_tx);
+
+        crate::task::runner::Runner::run(processor).await.unwrap();
+
+        for rx in source_receivers {
+            tokio::time::timeout(Duration::from_secs(1), rx)
+                .await
+                .expect("input event was not completed")
+                .unwrap()
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_processor_schema_change_closes_window() {
+        let (in_tx, in_rx) = mpsc::channel(10);
+        let (out_tx, mut out_rx) = mpsc::channel(10);
+
+        let processor = ProcessorBuilder::new()
+            .config(create_config(Duration::from_secs(3600)))
+            .receiver(in_rx)
+            .sender(out_tx)
+            .task_type("test")
+            .task_context(create_mock_task_context())
+            .build()
+            .await
+            .unwrap();
+
+        let regions = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new(
+                "region",
+                DataType::Utf8,
+                false,
+            )])),
+            vec![Arc::new(StringArray::from(vec!["eu"]))],
+        )
+        .unwrap();
+        in_tx.send(create_event(amounts(vec![1]))).await.unwrap();
+        in_tx.send(create_event(regions.clone())).await.unwrap();
+        drop(in_tx);
+
+        crate::task::runner::Runner::run(processor).await.unwrap();
+
+        let mut results = Vec::new();
+        while let Ok(event) = out_rx.try_recv() {
+            let EventData::ArrowRecordBatch(batch) = event.data else {
+                panic!("expected record batch");
+            };
+            results.push(batch);
+        }
+        assert_eq!(results, vec![amounts(vec![1]), regions]);
+    }
+
+    #[tokio::test]
+    async fn test_processor_builder_rejects_zero_window() {
+        let (_tx, rx) = mpsc::channel(1);
+        let result = ProcessorBuilder::new()
+            .config(create_config(Duration::ZERO))
+            .receiver(rx)
+            .task_type("test")
+            .task_context(create_mock_task_context())
+            .build()
+            .await;
+        assert!(matches!(result, Err(Error::ZeroWindow)));
+    }
+}
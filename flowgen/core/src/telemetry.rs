@@ -2,12 +2,26 @@
 //!
 //! Provides OTLP exporter configuration and integration with the existing tracing infrastructure.
 //! Metrics are automatically collected from tracing spans and exported to an OTLP endpoint.
+//! Spans reach the exporter through the layer returned by [`tracing_layer`].
 
+use opentelemetry::trace::TracerProvider as _;
 use opentelemetry::KeyValue;
 use opentelemetry_otlp::WithExportConfig;
 use opentelemetry_sdk::metrics::SdkMeterProvider;
 use opentelemetry_sdk::Resource;
+use std::sync::OnceLock;
 use std::time::Duration;
+use tracing_subscriber::{reload, Registry};
+
+/// Instrumentation scope name used for exported spans.
+const TRACER_NAME: &str = "flowgen";
+
+/// OpenTelemetry layer slot, empty until telemetry is initialized.
+type OtelLayer =
+    Option<tracing_opentelemetry::OpenTelemetryLayer<Registry, opentelemetry_sdk::trace::Tracer>>;
+
+/// Handle used by [`init_telemetry`] to fill in the layer returned by [`tracing_layer`].
+static OTEL_LAYER_HANDLE: OnceLock<reload::Handle<OtelLayer, Registry>> = OnceLock::new();
 
 /// Errors that can occur during telemetry initialization.
 #[derive(thiserror::Error, Debug)]
@@ -23,6 +37,22 @@ pub enum Error {
         #[source]
         source: opentelemetry::trace::TraceError,
     },
+    #[error("Failed to install OpenTelemetry tracing layer: {source}")]
+    TracingLayer {
+        #[source]
+        source: reload::Error,
+    },
+}
+
+/// Returns a tracing layer that exports spans once [`init_telemetry`] has run.
+///
+/// The tracing subscriber is installed at startup, before the app config
+/// (and therefore the OTLP endpoint) is known, so the layer starts empty.
+/// It must be added directly on top of [`tracing_subscriber::registry()`].
+pub fn tracing_layer() -> reload::Layer<OtelLayer, Registry> {
+    let (layer, handle) = reload::Layer::new(None);
+    let _ = OTEL_LAYER_HANDLE.set(handle);
+    layer
 }
 
 /// OpenTelemetry configuration for metrics and tracing export.
@@ -54,7 +84,7 @@ impl Default for TelemetryConfig {
 /// This sets up:
 /// - A MeterProvider for metrics collection
 /// - A TracerProvider for distributed tracing
-/// - Integration with the tracing-subscriber layer
+/// - Integration with the tracing-subscriber layer returned by [`tracing_layer`]
 ///
 /// Metrics are automatically collected from tracing spans with the `otel.` prefix.
 pub fn init_telemetry(config: TelemetryConfig) -> Result<TelemetryGuard, Error> {
@@ -102,6 +132,14 @@ pub fn init_telemetry(config: TelemetryConfig) -> Result<TelemetryGuard, Error>
     // Set global tracer provider.
     opentelemetry::global::set_tracer_provider(tracer_provider.clone());
 
+    // Bridge tracing spans to the tracer if the layer was installed at startup.
+    if let Some(handle) = OTEL_LAYER_HANDLE.get() {
+        let layer = tracing_opentelemetry::layer().with_tracer(tracer_provider.tracer(TRACER_NAME));
+        handle
+            .reload(Some(layer))
+            .map_err(|source| Error::TracingLayer { source })?;
+    }
+
     Ok(TelemetryGuard {
         meter_provider,
         tracer_provider,
@@ -0,0 +1,167 @@
+//! Test helpers: record batch assertions and mock source and sink tasks.
+//!
+//! Available in this crate's tests and, through the `testing` feature, in the
+//! tests of other workspace crates:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! flowgen_core = { path = "../core", features = ["testing"] }
+//! ```
+
+pub mod mock;
+
+pub use mock::{MockPublisher, MockSubscriber};
+
+use arrow::record_batch::RecordBatch;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Renders a batch as a table, falling back to debug output if formatting fails.
+fn pretty(batch: &RecordBatch) -> String {
+    arrow::util::pretty::pretty_format_batches(std::slice::from_ref(batch))
+        .map(|table| table.to_string())
+        .unwrap_or_else(|_| format!("{batch:?}"))
+}
+
+/// Converts the rows of a batch to JSON objects, keeping null values.
+fn batch_rows(batch: &RecordBatch) -> Vec<Map<String, Value>> {
+    let mut writer = arrow_json::WriterBuilder::new()
+        .with_explicit_nulls(true)
+        .build::<_, arrow_json::writer::JsonArray>(Vec::new());
+    writer.write(batch).expect("batch should encode as JSON");
+    writer.finish().expect("batch should encode as JSON");
+    let rows: Vec<Map<String, Value>> =
+        serde_json::from_slice(&writer.into_inner()).expect("encoded rows should be JSON objects");
+    rows
+}
+
+/// Asserts that two batches have the same schema and column values.
+///
+/// # Panics
+/// Panics with both batches rendered as tables when they differ.
+#[track_caller]
+pub fn assert_batch_eq(actual: &RecordBatch, expected: &RecordBatch) {
+    if actual == expected {
+        return;
+    }
+
+    let mut message = String::from("record batches differ\n");
+    if actual.schema() != expected.schema() {
+        message.push_str(&format!(
+            "\nschemas differ:\n  actual:   {:?}\n  expected: {:?}\n",
+            actual.schema().fields(),
+            expected.schema().fields()
+        ));
+    }
+    message.push_str(&format!(
+        "\nactual:\n{}\n\nexpected:\n{}",
+        pretty(actual),
+        pretty(expected)
+    ));
+    panic!("{message}");
+}
+
+/// Asserts that every expected row matches a distinct row of the batch, in any order.
+///
+/// A row matches when each column named in the expected row holds the given
+/// JSON value. Columns left out of the expected row are not compared.
+///
+/// # Panics
+/// Panics listing the unmatched rows, with the batch rendered as a table.
+#[track_caller]
+pub fn assert_batch_contains_rows(actual: &RecordBatch, expected_rows: &[HashMap<&str, Value>]) {
+    let rows = batch_rows(actual);
+    let mut matched = vec![false; rows.len()];
+    let mut missing = Vec::new();
+
+    for expected in expected_rows {
+        let found = rows.iter().enumerate().position(|(index, row)| {
+            !matched[index]
+                && expected
+                    .iter()
+                    .all(|(column, value)| row.get(*column) == Some(value))
+        });
+        match found {
+            Some(index) => matched[index] = true,
+            None => missing.push(expected),
+        }
+    }
+
+    if !missing.is_empty() {
+        panic!(
+            "record batch is missing {} of {} expected rows:\n{:#?}\n\nactual:\n{}",
+            missing.len(),
+            expected_rows.len(),
+            missing,
+            pretty(actual)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int64Array, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn create_batch(ids: Vec<i64>, names: Vec<Option<&str>>) -> RecordBatch {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int64Array::from(ids)),
+                Arc::new(StringArray::from(names)),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_assert_batch_eq_equal() {
+        let batch = create_batch(vec![1, 2], vec![Some("a"), None]);
+
+        assert_batch_eq(&batch, &batch.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "record batches differ")]
+    fn test_assert_batch_eq_different_values() {
+        let actual = create_batch(vec![1, 2], vec![Some("a"), Some("b")]);
+        let expected = create_batch(vec![1, 2], vec![Some("a"), Some("c")]);
+
+        assert_batch_eq(&actual, &expected);
+    }
+
+    #[test]
+    fn test_assert_batch_contains_rows_any_order() {
+        let batch = create_batch(vec![1, 2, 3], vec![Some("a"), None, Some("c")]);
+
+        assert_batch_contains_rows(
+            &batch,
+            &[
+                HashMap::from([("id", json!(3)), ("name", json!("c"))]),
+                HashMap::from([("id", json!(2)), ("name", Value::Null)]),
+                HashMap::from([("id", json!(1))]),
+            ],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "missing 1 of 2 expected rows")]
+    fn test_assert_batch_contains_rows_missing() {
+        let batch = create_batch(vec![1, 2], vec![Some("a"), Some("b")]);
+
+        assert_batch_contains_rows(
+            &batch,
+            &[
+                HashMap::from([("id", json!(1))]),
+                HashMap::from([("id", json!(1))]),
+            ],
+        );
+    }
+}
@@ -0,0 +1,229 @@
+//! Mock source and sink tasks for wiring processor chains in tests.
+//!
+//! A [`MockSubscriber`] feeds record batches into the first task of a chain
+//! and a [`MockPublisher`] collects what comes out of the last one, so a chain
+//! of processors can be tested without external services.
+
+use crate::event::{Event, EventBuilder, EventData, EventExt};
+use arrow::record_batch::RecordBatch;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc::{Receiver, Sender};
+
+/// Subject set on events emitted by [`MockSubscriber`].
+pub const DEFAULT_MOCK_SUBJECT: &str = "mock";
+/// Task type set on events emitted by [`MockSubscriber`].
+const MOCK_TASK_TYPE: &str = "mock";
+
+/// Errors that can occur in mock tasks.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: crate::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: crate::event::Error,
+    },
+}
+
+/// Subscriber that emits a fixed list of record batches, one event per batch.
+///
+/// The sender is dropped once every batch is sent, so downstream tasks see
+/// their channel close and finish.
+#[derive(Debug)]
+pub struct MockSubscriber {
+    /// Batches to emit, in order.
+    batches: Vec<RecordBatch>,
+    /// Channel sender for emitted events.
+    tx: Sender<Event>,
+    /// Pause between two emitted events.
+    delay: Duration,
+}
+
+impl MockSubscriber {
+    /// Creates a subscriber that sends `batches` on `tx` without delay.
+    pub fn new(batches: Vec<RecordBatch>, tx: Sender<Event>) -> Self {
+        Self {
+            batches,
+            tx,
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// Sets the pause between two emitted events, in milliseconds.
+    pub fn delay_ms(mut self, delay_ms: u64) -> Self {
+        self.delay = Duration::from_millis(delay_ms);
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::task::runner::Runner for MockSubscriber {
+    type Error = Error;
+    type EventHandler = ();
+
+    async fn init(&self) -> Result<Self::EventHandler, Self::Error> {
+        Ok(())
+    }
+
+    async fn run(self) -> Result<(), Error> {
+        for (index, batch) in self.batches.into_iter().enumerate() {
+            if index > 0 && !self.delay.is_zero() {
+                tokio::time::sleep(self.delay).await;
+            }
+
+            let event = EventBuilder::new()
+                .data(EventData::ArrowRecordBatch(batch))
+                .subject(DEFAULT_MOCK_SUBJECT.to_string())
+                .task_id(0)
+                .task_type(MOCK_TASK_TYPE)
+                .allow_empty(true)
+                .build()
+                .map_err(|source| Error::EventBuilder { source })?;
+            event
+                .send_with_logging(Some(&self.tx))
+                .await
+                .map_err(|source| Error::SendMessage { source })?;
+        }
+        Ok(())
+    }
+}
+
+/// Publisher that collects the record batches it receives.
+///
+/// Clones share the collected batches, so keep a clone to read them with
+/// [`MockPublisher::collected`] after running the publisher.
+#[derive(Debug, Clone)]
+pub struct MockPublisher {
+    /// Channel receiver for incoming events.
+    rx: Arc<tokio::sync::Mutex<Receiver<Event>>>,
+    /// Batches received so far.
+    collected: Arc<Mutex<Vec<RecordBatch>>>,
+}
+
+impl MockPublisher {
+    /// Creates a publisher that reads events from `rx`.
+    pub fn new(rx: Receiver<Event>) -> Self {
+        Self {
+            rx: Arc::new(tokio::sync::Mutex::new(rx)),
+            collected: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Returns the record batches received so far, in arrival order.
+    pub fn collected(&self) -> Vec<RecordBatch> {
+        self.collected
+            .lock()
+            .map(|batches| batches.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::task::runner::Runner for MockPublisher {
+    type Error = Error;
+    type EventHandler = ();
+
+    async fn init(&self) -> Result<Self::EventHandler, Self::Error> {
+        Ok(())
+    }
+
+    /// Collects events until the channel closes. Events that do not carry a
+    /// record batch are acknowledged but not collected.
+    async fn run(self) -> Result<(), Error> {
+        let mut rx = self.rx.lock().await;
+        while let Some(event) = rx.recv().await {
+            if let EventData::ArrowRecordBatch(batch) = &event.data {
+                if let Ok(mut collected) = self.collected.lock() {
+                    collected.push(batch.clone());
+                }
+            }
+            if let Some(arc) = event.completion_tx.as_ref() {
+                arc.signal_completion(None);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::runner::Runner;
+    use crate::testing::assert_batch_eq;
+    use arrow::array::Int64Array;
+    use arrow::datatypes::{DataType, Field, Schema};
+    use tokio::sync::mpsc;
+
+    fn create_batch(ids: Vec<i64>) -> RecordBatch {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+        RecordBatch::try_new(schema, vec![Arc::new(Int64Array::from(ids))]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_mock_subscriber_to_publisher() {
+        let batches = vec![create_batch(vec![1, 2]), create_batch(vec![3])];
+        let (tx, rx) = mpsc::channel(10);
+        let publisher = MockPublisher::new(rx);
+
+        let subscriber = MockSubscriber::new(batches.clone(), tx).delay_ms(5);
+        tokio::spawn(subscriber.run());
+        publisher.clone().run().await.unwrap();
+
+        let collected = publisher.collected();
+        assert_eq!(collected.len(), 2);
+        assert_batch_eq(&collected[0], &batches[0]);
+        assert_batch_eq(&collected[1], &batches[1]);
+    }
+
+    /// A processor wired between the mocks runs as it would in a flow.
+    #[tokio::test]
+    async fn test_mock_tasks_around_processor() {
+        let (source_tx, processor_rx) = mpsc::channel(10);
+        let (processor_tx, sink_rx) = mpsc::channel(10);
+        let publisher = MockPublisher::new(sink_rx);
+
+        let task_context = Arc::new(
+            crate::task::context::TaskContextBuilder::new()
+                .flow_name("test-flow".to_string())
+                .task_manager(Arc::new(
+                    crate::task::manager::TaskManagerBuilder::new()
+                        .build()
+                        .unwrap(),
+                ))
+                .cache(Arc::new(crate::cache::memory::MemoryCache::new()))
+                .build()
+                .unwrap(),
+        );
+        let processor = crate::task::split::processor::ProcessorBuilder::new()
+            .config(Arc::new(crate::task::split::config::Processor {
+                name: "split".to_string(),
+                chunk_size: 2,
+                depends_on: None,
+                retry: None,
+                error_policy: None,
+            }))
+            .receiver(processor_rx)
+            .sender(processor_tx)
+            .task_id(1)
+            .task_type("split")
+            .task_context(task_context)
+            .build()
+            .await
+            .unwrap();
+
+        tokio::spawn(MockSubscriber::new(vec![create_batch(vec![1, 2, 3])], source_tx).run());
+        tokio::spawn(processor.run());
+        publisher.clone().run().await.unwrap();
+
+        let collected = publisher.collected();
+        assert_eq!(collected.len(), 2);
+        assert_batch_eq(&collected[0], &create_batch(vec![1, 2]));
+        assert_batch_eq(&collected[1], &create_batch(vec![3]));
+    }
+}
@@ -0,0 +1,13 @@
+//! Remote file transfer integrations for flowgen workers.
+//!
+//! Provides subscribers that pick up files from file transfer servers and
+//! parse them into events with the same CSV, Parquet, Avro and JSON readers
+//! used by the object store tasks.
+
+/// SFTP — poll a remote directory over SSH and emit the parsed files.
+pub mod sftp {
+    /// Configuration for the SFTP subscriber.
+    pub mod config;
+    /// SFTP subscriber implementation.
+    pub mod subscriber;
+}
@@ -0,0 +1,175 @@
+//! Configuration for the `sftp_subscriber` task.
+//!
+//! Defines the SSH connection and authentication settings, the remote
+//! directory to poll, and how downloaded files are parsed.
+
+use flowgen_core::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Default SSH port.
+pub const DEFAULT_PORT: u16 = 22;
+/// Default delay between two listings of the remote directory.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+fn default_port() -> u16 {
+    DEFAULT_PORT
+}
+
+fn default_poll_interval() -> Duration {
+    DEFAULT_POLL_INTERVAL
+}
+
+/// Configuration for the SFTP subscriber.
+///
+/// Every poll lists `directory`, downloads the files matching `glob` and
+/// emits their parsed contents. A file is moved to `processed_dir` once the
+/// flow has completed for all of its events, so it is picked up only once.
+/// Files that fail stay in place and are retried on the next poll.
+///
+/// # Example YAML
+///
+/// ```yaml
+/// - sftp_subscriber:
+///     name: partner_orders
+///     host: sftp.partner.example.com
+///     username: flowgen
+///     private_key_path: /etc/flowgen/sftp/id_ed25519
+///     known_hosts_path: /etc/flowgen/sftp/known_hosts
+///     directory: /outbound/orders
+///     glob: "*.csv"
+///     processed_dir: /outbound/orders/processed
+///     poll_interval: 5m
+/// ```
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct Subscriber {
+    /// Unique task name.
+    pub name: String,
+    /// SFTP server hostname or IP address.
+    pub host: String,
+    /// SFTP server port (defaults to 22).
+    #[serde(default = "default_port")]
+    pub port: u16,
+    /// Username to authenticate as.
+    pub username: String,
+    /// Path to the private key used for public key authentication.
+    pub private_key_path: PathBuf,
+    /// Optional passphrase for an encrypted private key.
+    #[serde(default)]
+    pub private_key_passphrase: Option<String>,
+    /// Path to an OpenSSH `known_hosts` file used to verify the server host key.
+    /// Required unless `insecure_skip_host_key_verification` is set.
+    #[serde(default)]
+    pub known_hosts_path: Option<PathBuf>,
+    /// Connect without verifying the server host key when no `known_hosts_path`
+    /// is set. Leaves the connection open to man-in-the-middle attacks, so only
+    /// use it for local testing. Defaults to false.
+    #[serde(default)]
+    pub insecure_skip_host_key_verification: bool,
+    /// Remote directory to list files from.
+    pub directory: String,
+    /// Optional glob pattern matched against file names, e.g. `*.csv`.
+    /// All regular files in `directory` are read when omitted.
+    #[serde(default)]
+    pub glob: Option<String>,
+    /// Remote directory successfully processed files are moved to.
+    pub processed_dir: String,
+    /// Delay between two listings of the remote directory.
+    #[serde(default = "default_poll_interval", with = "humantime_serde")]
+    pub poll_interval: Duration,
+    /// Number of records per emitted batch for CSV and Parquet files.
+    #[serde(default)]
+    pub batch_size: Option<usize>,
+    /// Whether CSV files have a header row (defaults to true).
+    #[serde(default)]
+    pub has_header: Option<bool>,
+    /// CSV delimiter character (defaults to comma).
+    #[serde(default)]
+    pub delimiter: Option<String>,
+    /// Maximum number of CSV rows sampled to infer the schema. All rows are
+    /// scanned when omitted.
+    #[serde(default)]
+    pub infer_schema_max_records: Option<usize>,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    #[serde(default)]
+    pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Subscriber {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscriber_config_defaults() {
+        let json = r#"{
+            "name": "orders",
+            "host": "sftp.example.com",
+            "username": "flowgen",
+            "private_key_path": "/etc/sftp/key",
+            "directory": "/outbound",
+            "processed_dir": "/outbound/processed"
+        }"#;
+        let config: Subscriber = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.port, DEFAULT_PORT);
+        assert_eq!(config.poll_interval, DEFAULT_POLL_INTERVAL);
+        assert!(config.known_hosts_path.is_none());
+        assert!(!config.insecure_skip_host_key_verification);
+        assert!(config.glob.is_none());
+    }
+
+    #[test]
+    fn test_subscriber_config_full() {
+        let yaml = r#"
+name: orders
+host: sftp.example.com
+port: 2222
+username: flowgen
+private_key_path: /etc/sftp/key
+known_hosts_path: /etc/sftp/known_hosts
+directory: /outbound
+glob: "*.csv"
+processed_dir: /outbound/processed
+poll_interval: 5m
+delimiter: ";"
+"#;
+        let config: Subscriber = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(config.port, 2222);
+        assert_eq!(
+            config.known_hosts_path,
+            Some(PathBuf::from("/etc/sftp/known_hosts"))
+        );
+        assert_eq!(config.glob.as_deref(), Some("*.csv"));
+        assert_eq!(config.poll_interval, Duration::from_secs(300));
+        assert_eq!(config.delimiter.as_deref(), Some(";"));
+    }
+
+    #[test]
+    fn test_subscriber_config_insecure_skip_host_key_verification() {
+        let yaml = r#"
+name: orders
+host: localhost
+username: flowgen
+private_key_path: /etc/sftp/key
+insecure_skip_host_key_verification: true
+directory: /outbound
+processed_dir: /outbound/processed
+"#;
+        let config: Subscriber = serde_yaml::from_str(yaml).unwrap();
+
+        assert!(config.known_hosts_path.is_none());
+        assert!(config.insecure_skip_host_key_verification);
+    }
+}
@@ -0,0 +1,645 @@
+//! SFTP subscriber that polls a remote directory and emits the parsed files.
+//!
+//! Each poll opens an SSH session, lists the configured directory and
+//! downloads every matching file to a local temporary file. Files are parsed
+//! by content type detected from the file extension and emitted as events.
+//! Once the flow has completed for a file it is moved to the processed
+//! directory on the server. All libssh2 calls are blocking and run on a
+//! dedicated thread.
+
+use flowgen_core::buffer::{ContentType, FromReader};
+use flowgen_core::config::ConfigExt;
+use flowgen_core::event::{new_completion_channel, Event, EventBuilder, EventData, EventExt};
+use ssh2::{CheckResult, FileStat, KnownHostFileKind, Session, Sftp};
+use std::io::BufReader;
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tempfile::NamedTempFile;
+use tokio::sync::mpsc::Sender;
+use tokio::task;
+use tracing::{error, info, warn, Instrument};
+
+/// Default batch size for files.
+const DEFAULT_BATCH_SIZE: usize = 10000;
+/// Default files have headers.
+const DEFAULT_HAS_HEADER: bool = true;
+
+/// Errors that can occur during SFTP subscription.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Config template rendering error: {source}")]
+    ConfigRender {
+        #[source]
+        source: flowgen_core::config::Error,
+    },
+    #[error("Failed to connect to {host}:{port}: {source}")]
+    Connect {
+        host: String,
+        port: u16,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("SSH error: {source}")]
+    Ssh {
+        #[source]
+        source: ssh2::Error,
+    },
+    #[error("Server {host} did not present a host key")]
+    MissingHostKey { host: String },
+    #[error("Host key for {host} does not match the known_hosts entry")]
+    HostKeyMismatch { host: String },
+    #[error("Host {host} is not listed in the known_hosts file")]
+    UnknownHost { host: String },
+    #[error("Host key check for {host} could not be performed")]
+    HostKeyCheckFailed { host: String },
+    #[error("No known_hosts_path configured for {host}, set insecure_skip_host_key_verification to connect without verifying the host key")]
+    MissingKnownHosts { host: String },
+    #[error("Invalid glob pattern '{pattern}': {source}")]
+    InvalidGlob {
+        pattern: String,
+        #[source]
+        source: glob::PatternError,
+    },
+    #[error("IO error: {source}")]
+    IO {
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("SFTP operation panicked or was cancelled: {source}")]
+    JoinError {
+        #[source]
+        source: tokio::task::JoinError,
+    },
+    #[error("Flow did not complete for file '{path}', leaving it in place")]
+    FlowNotCompleted { path: String },
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+}
+
+/// Applies the configured batch size and CSV options to a detected content type.
+fn apply_read_options(
+    content_type: ContentType,
+    config: &super::config::Subscriber,
+) -> ContentType {
+    let batch_size = config.batch_size.unwrap_or(DEFAULT_BATCH_SIZE);
+    match content_type {
+        ContentType::Csv {
+            infer_schema_max_records,
+            ..
+        } => ContentType::Csv {
+            batch_size,
+            has_header: config.has_header.unwrap_or(DEFAULT_HAS_HEADER),
+            delimiter: config
+                .delimiter
+                .as_ref()
+                .and_then(|d| d.as_bytes().first().copied()),
+            infer_schema_max_records: config.infer_schema_max_records.or(infer_schema_max_records),
+        },
+        ContentType::Parquet { .. } => ContentType::Parquet { batch_size },
+        ContentType::Compressed { inner, codec } => ContentType::Compressed {
+            inner: Box::new(apply_read_options(*inner, config)),
+            codec,
+        },
+        other => other,
+    }
+}
+
+/// Returns the regular files of a directory listing whose name matches the
+/// optional glob pattern, sorted by path so files are processed in a stable order.
+fn matching_files(
+    entries: Vec<(PathBuf, FileStat)>,
+    pattern: Option<&glob::Pattern>,
+) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = entries
+        .into_iter()
+        .filter(|(_, stat)| stat.is_file())
+        .map(|(path, _)| path)
+        .filter(|path| match pattern {
+            Some(pattern) => path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| pattern.matches(name)),
+            None => true,
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Returns the path a remote file is moved to after it has been processed.
+fn processed_path(processed_dir: &str, remote_path: &Path) -> PathBuf {
+    Path::new(processed_dir).join(remote_path.file_name().unwrap_or_default())
+}
+
+/// Opens an authenticated SFTP channel. Runs on a blocking thread.
+fn connect_blocking(config: &super::config::Subscriber) -> Result<Sftp, Error> {
+    let tcp = TcpStream::connect((config.host.as_str(), config.port)).map_err(|source| {
+        Error::Connect {
+            host: config.host.clone(),
+            port: config.port,
+            source,
+        }
+    })?;
+
+    let mut session = Session::new().map_err(|source| Error::Ssh { source })?;
+    session.set_tcp_stream(tcp);
+    session
+        .handshake()
+        .map_err(|source| Error::Ssh { source })?;
+
+    verify_host_key(&session, config)?;
+
+    session
+        .userauth_pubkey_file(
+            &config.username,
+            None,
+            &config.private_key_path,
+            config.private_key_passphrase.as_deref(),
+        )
+        .map_err(|source| Error::Ssh { source })?;
+
+    session.sftp().map_err(|source| Error::Ssh { source })
+}
+
+/// Checks the server host key against the configured known_hosts file.
+/// Verification is only skipped when explicitly opted out of.
+fn verify_host_key(session: &Session, config: &super::config::Subscriber) -> Result<(), Error> {
+    let Some(known_hosts_path) = &config.known_hosts_path else {
+        if !config.insecure_skip_host_key_verification {
+            return Err(Error::MissingKnownHosts {
+                host: config.host.clone(),
+            });
+        }
+        warn!(
+            host = %config.host,
+            "Connecting without verifying the SFTP host key, insecure_skip_host_key_verification is set"
+        );
+        return Ok(());
+    };
+
+    let mut known_hosts = session
+        .known_hosts()
+        .map_err(|source| Error::Ssh { source })?;
+    known_hosts
+        .read_file(known_hosts_path, KnownHostFileKind::OpenSSH)
+        .map_err(|source| Error::Ssh { source })?;
+
+    let (key, _) = session.host_key().ok_or_else(|| Error::MissingHostKey {
+        host: config.host.clone(),
+    })?;
+
+    match known_hosts.check_port(&config.host, config.port, key) {
+        CheckResult::Match => Ok(()),
+        CheckResult::Mismatch => Err(Error::HostKeyMismatch {
+            host: config.host.clone(),
+        }),
+        CheckResult::NotFound => Err(Error::UnknownHost {
+            host: config.host.clone(),
+        }),
+        CheckResult::Failure => Err(Error::HostKeyCheckFailed {
+            host: config.host.clone(),
+        }),
+    }
+}
+
+/// Downloads a remote file into a local temporary file. Runs on a blocking thread.
+fn download_blocking(sftp: &Sftp, remote_path: &Path) -> Result<NamedTempFile, Error> {
+    let mut remote = sftp
+        .open(remote_path)
+        .map_err(|source| Error::Ssh { source })?;
+    let mut local = NamedTempFile::new().map_err(|source| Error::IO { source })?;
+    std::io::copy(&mut remote, &mut local).map_err(|source| Error::IO { source })?;
+    Ok(local)
+}
+
+/// Event handler owning a single SFTP session.
+pub struct EventHandler {
+    sftp: Arc<Sftp>,
+    pattern: Option<glob::Pattern>,
+    tx: Option<Sender<Event>>,
+    task_id: usize,
+    config: Arc<super::config::Subscriber>,
+    task_type: &'static str,
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+}
+
+impl EventHandler {
+    /// Runs a blocking SFTP operation on a dedicated thread.
+    async fn blocking<T, F>(&self, f: F) -> Result<T, Error>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Sftp) -> Result<T, Error> + Send + 'static,
+    {
+        let sftp = Arc::clone(&self.sftp);
+        // Keep the flow/task fields on log lines emitted from the worker thread.
+        let span = tracing::Span::current();
+        task::spawn_blocking(move || {
+            let _enter = span.enter();
+            f(&sftp)
+        })
+        .await
+        .map_err(|source| Error::JoinError { source })?
+    }
+
+    /// Lists the remote directory and processes every matching file.
+    async fn handle(&self) -> Result<(), Error> {
+        let directory = PathBuf::from(&self.config.directory);
+        let entries = self
+            .blocking(move |sftp| {
+                sftp.readdir(&directory)
+                    .map_err(|source| Error::Ssh { source })
+            })
+            .await?;
+
+        for remote_path in matching_files(entries, self.pattern.as_ref()) {
+            if self.task_context.cancellation_token.is_cancelled() {
+                return Ok(());
+            }
+
+            // A failed file stays in place and is retried on the next poll.
+            if let Err(e) = self.process_file(&remote_path).await {
+                error!(error = %e, file = %remote_path.display(), "Failed to process SFTP file");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Downloads and emits a single file, then moves it to the processed directory.
+    async fn process_file(&self, remote_path: &Path) -> Result<(), Error> {
+        let content_type = match ContentType::from_path(remote_path) {
+            Some(content_type) => apply_read_options(content_type, &self.config),
+            None => {
+                warn!("Unsupported file extension: {}", remote_path.display());
+                return Ok(());
+            }
+        };
+
+        let download_path = remote_path.to_path_buf();
+        let local = self
+            .blocking(move |sftp| download_blocking(sftp, &download_path))
+            .await?;
+        let reader = BufReader::new(local.reopen().map_err(|source| Error::IO { source })?);
+
+        let iter = EventData::from_reader(reader, content_type)
+            .map_err(|source| Error::EventBuilder { source })?;
+
+        // Every event carries its own completion channel, since downstream
+        // tasks handle events concurrently and the file may only be moved
+        // once all of them have completed. Without downstream tasks nobody
+        // signals completion, so only wait for the flow when there is a sender.
+        let mut completion_rxs = Vec::new();
+        for item_result in iter {
+            let event_data = item_result.map_err(|source| Error::EventBuilder { source })?;
+            let num_records = match &event_data {
+                EventData::ArrowRecordBatch(batch) => batch.num_rows(),
+                _ => 1,
+            };
+
+            let mut e = EventBuilder::new()
+                .subject(self.config.name.to_owned())
+                .data(event_data)
+                .task_id(self.task_id)
+                .task_type(self.task_type)
+                .build()
+                .map_err(|source| Error::EventBuilder { source })?;
+
+            if self.tx.is_some() {
+                let (completion_state, completion_rx) =
+                    new_completion_channel(self.task_context.leaf_count);
+                e.completion_tx = Some(completion_state);
+                completion_rxs.push(completion_rx);
+            }
+
+            e.send_with_logging(self.tx.as_ref())
+                .context("num_records", num_records)
+                .await
+                .map_err(|source| Error::SendMessage { source })?;
+            self.task_context.metrics.record_received();
+        }
+
+        for completion_rx in completion_rxs {
+            if !matches!(completion_rx.await, Ok(Ok(_))) {
+                return Err(Error::FlowNotCompleted {
+                    path: remote_path.display().to_string(),
+                });
+            }
+        }
+
+        let target_path = processed_path(&self.config.processed_dir, remote_path);
+        if self.task_context.dry_run {
+            info!(
+                "Dry run, leaving {} in place instead of moving it to {}",
+                remote_path.display(),
+                target_path.display()
+            );
+            return Ok(());
+        }
+
+        let source_path = remote_path.to_path_buf();
+        let log_path = target_path.clone();
+        self.blocking(move |sftp| {
+            sftp.rename(&source_path, &target_path, None)
+                .map_err(|source| Error::Ssh { source })
+        })
+        .await?;
+        info!("Moved processed file to {}", log_path.display());
+
+        Ok(())
+    }
+}
+
+/// SFTP subscriber that polls a remote directory and converts files to flowgen events.
+#[derive(Debug)]
+pub struct Subscriber {
+    /// Subscriber configuration including connection and polling settings.
+    config: Arc<super::config::Subscriber>,
+    /// Sender for forwarding parsed events.
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event tagging.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl flowgen_core::task::runner::Runner for Subscriber {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Opens an authenticated SFTP session to the configured server.
+    async fn init(&self) -> Result<EventHandler, Error> {
+        let init_config = self
+            .config
+            .render(&serde_json::json!({}))
+            .map_err(|source| Error::ConfigRender { source })?;
+
+        let pattern = init_config
+            .glob
+            .as_deref()
+            .map(|pattern| {
+                glob::Pattern::new(pattern).map_err(|source| Error::InvalidGlob {
+                    pattern: pattern.to_string(),
+                    source,
+                })
+            })
+            .transpose()?;
+
+        // Fail before connecting when the host key cannot be verified.
+        if init_config.known_hosts_path.is_none()
+            && !init_config.insecure_skip_host_key_verification
+        {
+            return Err(Error::MissingKnownHosts {
+                host: init_config.host.clone(),
+            });
+        }
+
+        let init_config = Arc::new(init_config);
+        let connect_config = Arc::clone(&init_config);
+        let span = tracing::Span::current();
+        let sftp = task::spawn_blocking(move || {
+            let _enter = span.enter();
+            connect_blocking(&connect_config)
+        })
+        .await
+        .map_err(|source| Error::JoinError { source })??;
+
+        Ok(EventHandler {
+            sftp: Arc::new(sftp),
+            pattern,
+            tx: self.tx.clone(),
+            task_id: self.task_id,
+            config: init_config,
+            task_type: self.task_type,
+            task_context: Arc::clone(&self.task_context),
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(self) -> Result<(), Error> {
+        let retry_config =
+            flowgen_core::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        tokio::spawn(
+            async move {
+                // Poll loop: a fresh session is opened for every poll so a
+                // dropped connection only costs a single poll.
+                loop {
+                    let result = tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+                        match self.init().await {
+                            Ok(handler) => Ok(handler),
+                            Err(e) => {
+                                let is_retriable = !matches!(
+                                    &e,
+                                    Error::InvalidGlob { .. }
+                                        | Error::HostKeyMismatch { .. }
+                                        | Error::UnknownHost { .. }
+                                        | Error::MissingKnownHosts { .. }
+                                );
+
+                                if is_retriable {
+                                    error!(error = %e, "SFTP connection failed");
+                                    Err(tokio_retry::RetryError::transient(e))
+                                } else {
+                                    error!(error = %e, "Permanent initialization error");
+                                    Err(tokio_retry::RetryError::permanent(e))
+                                }
+                            }
+                        }
+                    })
+                    .await;
+
+                    match result {
+                        Ok(event_handler) => {
+                            if let Err(e) = event_handler.handle().await {
+                                error!(error = %e, "SFTP poll failed");
+                            }
+                        }
+                        Err(e) => {
+                            error!(error = %e, "SFTP connection exhausted retry attempts, will retry on next poll");
+                        }
+                    }
+
+                    tokio::select! {
+                        _ = self.task_context.cancellation_token.cancelled() => return,
+                        _ = tokio::time::sleep(self.config.poll_interval) => {}
+                    }
+                }
+            }
+            .instrument(tracing::Span::current()),
+        );
+
+        Ok(())
+    }
+}
+
+/// Builder for configuring and creating SFTP subscribers.
+#[derive(Default)]
+pub struct SubscriberBuilder {
+    config: Option<Arc<super::config::Subscriber>>,
+    tx: Option<Sender<Event>>,
+    task_id: usize,
+    task_context: Option<Arc<flowgen_core::task::context::TaskContext>>,
+    task_type: Option<&'static str>,
+}
+
+impl SubscriberBuilder {
+    pub fn new() -> SubscriberBuilder {
+        SubscriberBuilder {
+            ..Default::default()
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Subscriber>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = task_id;
+        self
+    }
+
+    pub fn task_context(
+        mut self,
+        task_context: Arc<flowgen_core::task::context::TaskContext>,
+    ) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Subscriber, Error> {
+        Ok(Subscriber {
+            config: self
+                .config
+                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            tx: self.tx,
+            task_id: self.task_id,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stat(perm: u32) -> FileStat {
+        FileStat {
+            size: None,
+            uid: None,
+            gid: None,
+            perm: Some(perm),
+            atime: None,
+            mtime: None,
+        }
+    }
+
+    fn test_config() -> super::super::config::Subscriber {
+        serde_json::from_value(serde_json::json!({
+            "name": "orders",
+            "host": "sftp.example.com",
+            "username": "flowgen",
+            "private_key_path": "/etc/sftp/key",
+            "directory": "/outbound",
+            "processed_dir": "/outbound/processed"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_matching_files_filters_by_glob_and_type() {
+        let entries = vec![
+            (PathBuf::from("/outbound/b.csv"), stat(0o100644)),
+            (PathBuf::from("/outbound/a.csv"), stat(0o100644)),
+            (PathBuf::from("/outbound/notes.txt"), stat(0o100644)),
+            (PathBuf::from("/outbound/processed"), stat(0o040755)),
+        ];
+        let pattern = glob::Pattern::new("*.csv").unwrap();
+
+        let files = matching_files(entries, Some(&pattern));
+
+        assert_eq!(
+            files,
+            vec![
+                PathBuf::from("/outbound/a.csv"),
+                PathBuf::from("/outbound/b.csv")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_matching_files_without_glob_skips_directories() {
+        let entries = vec![
+            (PathBuf::from("/outbound/a.json"), stat(0o100644)),
+            (PathBuf::from("/outbound/processed"), stat(0o040755)),
+        ];
+
+        let files = matching_files(entries, None);
+
+        assert_eq!(files, vec![PathBuf::from("/outbound/a.json")]);
+    }
+
+    #[test]
+    fn test_processed_path() {
+        let path = processed_path("/outbound/processed", Path::new("/outbound/orders.csv"));
+        assert_eq!(path, PathBuf::from("/outbound/processed/orders.csv"));
+    }
+
+    #[test]
+    fn test_apply_read_options_to_csv() {
+        let mut config = test_config();
+        config.batch_size = Some(500);
+        config.delimiter = Some(";".to_string());
+
+        let content_type =
+            apply_read_options(ContentType::from_path(Path::new("a.csv")).unwrap(), &config);
+
+        assert_eq!(
+            content_type,
+            ContentType::Csv {
+                batch_size: 500,
+                has_header: true,
+                delimiter: Some(b';'),
+                infer_schema_max_records: None,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subscriber_builder_missing_config() {
+        let result = SubscriberBuilder::new().task_type("test").build().await;
+        assert!(
+            matches!(result.unwrap_err(), Error::MissingBuilderAttribute(attr) if attr == "config")
+        );
+    }
+}
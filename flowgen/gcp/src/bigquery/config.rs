@@ -198,6 +198,9 @@ pub struct Query {
     /// Optional retry configuration (overrides app-level retry config).
     #[serde(default)]
     pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
 }
 
 impl ConfigExt for Query {}
@@ -384,6 +387,9 @@ pub struct Job {
     /// Optional retry configuration.
     #[serde(default)]
     pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
 }
 
 impl ConfigExt for Job {}
@@ -658,6 +664,7 @@ mod tests {
             default_dataset: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         assert_eq!(query.name, "test_query");
@@ -687,6 +694,7 @@ mod tests {
             default_dataset: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         let json = serde_json::to_string(&query).unwrap();
@@ -718,6 +726,7 @@ mod tests {
             default_dataset: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         assert!(query.parameters.is_some());
@@ -748,6 +757,7 @@ mod tests {
             default_dataset: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         let cloned = query.clone();
@@ -773,6 +783,7 @@ mod tests {
             default_dataset: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         assert_eq!(query.get_job_project_id(), "billing-project");
@@ -797,6 +808,7 @@ mod tests {
             default_dataset: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         assert_eq!(query.get_job_project_id(), "my-project");
@@ -831,6 +843,37 @@ mod tests {
 
         assert_eq!(storage_read.get_job_project_id(), "my-project");
     }
+
+    #[test]
+    fn test_insert_all_config_deserialization() {
+        let json = r#"{
+            "name": "insert_orders",
+            "project_id": "my-project",
+            "dataset_id": "sales",
+            "table_id": "orders",
+            "insert_id_field": "order_id"
+        }"#;
+
+        let config: InsertAll = serde_json::from_str(json).unwrap();
+        assert_eq!(config.insert_id_field, Some("order_id".to_string()));
+        assert!(!config.skip_invalid_rows);
+        assert!(!config.ignore_unknown_values);
+        assert_eq!(config.max_rows(), DEFAULT_INSERT_ALL_MAX_ROWS);
+    }
+
+    #[test]
+    fn test_insert_all_max_rows_capped_at_limit() {
+        let mut config: InsertAll = serde_json::from_str(
+            r#"{"name": "t", "project_id": "p", "dataset_id": "d", "table_id": "t"}"#,
+        )
+        .unwrap();
+
+        config.max_rows_per_request = Some(100_000);
+        assert_eq!(config.max_rows(), DEFAULT_INSERT_ALL_MAX_ROWS);
+
+        config.max_rows_per_request = Some(500);
+        assert_eq!(config.max_rows(), 500);
+    }
 }
 
 /// Data format for BigQuery Storage Read API.
@@ -1003,6 +1046,9 @@ pub struct StorageRead {
     /// Optional retry configuration (overrides app-level retry config).
     #[serde(default)]
     pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
 }
 
 impl ConfigExt for StorageRead {}
@@ -1113,6 +1159,9 @@ pub struct StorageWrite {
     /// Optional retry configuration (overrides app-level retry config).
     #[serde(default)]
     pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
 }
 
 /// BigQuery CDC change type for Storage Write API.
@@ -1135,3 +1184,75 @@ impl std::fmt::Display for ChangeType {
 }
 
 impl ConfigExt for StorageWrite {}
+
+/// Default maximum number of rows per streaming insert request (BigQuery limit).
+pub const DEFAULT_INSERT_ALL_MAX_ROWS: usize = 50_000;
+
+/// Default maximum request payload size in bytes for streaming inserts (BigQuery limit).
+pub const DEFAULT_INSERT_ALL_MAX_BYTES: usize = 10 * 1024 * 1024;
+
+/// Configuration for BigQuery legacy streaming inserts (`tabledata.insertAll`).
+///
+/// Rows are sent as JSON objects, batched up to the per-request row and size limits.
+/// Rows rejected with `insertErrors` are retried individually using the task retry settings.
+///
+/// # Examples
+///
+/// ```yaml
+/// gcp_bigquery_insert_all:
+///   name: insert_orders
+///   credentials_path: /etc/gcp/credentials.json
+///   project_id: my-project
+///   dataset_id: sales
+///   table_id: orders
+///   insert_id_field: order_id
+/// ```
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct InsertAll {
+    /// The unique name / identifier of the task.
+    pub name: String,
+    /// Path to GCP service account credentials JSON file.
+    /// When omitted, falls back to Application Default Credentials (ADC).
+    #[serde(default)]
+    pub credentials_path: Option<PathBuf>,
+    /// GCP project ID where BigQuery resources are located.
+    pub project_id: String,
+    /// BigQuery dataset ID containing the table.
+    pub dataset_id: String,
+    /// BigQuery table ID to insert into.
+    pub table_id: String,
+    /// Optional row field used as the `insertId` for best-effort deduplication.
+    #[serde(default)]
+    pub insert_id_field: Option<String>,
+    /// Insert valid rows even if other rows in the request are invalid.
+    #[serde(default)]
+    pub skip_invalid_rows: bool,
+    /// Accept rows with values that do not match the table schema and drop them.
+    #[serde(default)]
+    pub ignore_unknown_values: bool,
+    /// Maximum number of rows per request, capped at the BigQuery limit.
+    #[serde(default)]
+    pub max_rows_per_request: Option<usize>,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    #[serde(default)]
+    pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
+}
+
+impl InsertAll {
+    /// Returns the maximum number of rows per request, never above the BigQuery limit.
+    pub fn max_rows(&self) -> usize {
+        self.max_rows_per_request
+            .unwrap_or(DEFAULT_INSERT_ALL_MAX_ROWS)
+            .clamp(1, DEFAULT_INSERT_ALL_MAX_ROWS)
+    }
+}
+
+impl ConfigExt for InsertAll {}
@@ -0,0 +1,691 @@
+//! BigQuery streaming insert processor using the legacy `tabledata.insertAll` API.
+//!
+//! Converts each event into JSON rows and inserts them into a BigQuery table,
+//! splitting large events into requests that stay within the per-request row and
+//! payload limits. Rows reported in `insertErrors` are retried on their own so rows
+//! that were already accepted are not inserted twice. Set `insert_id_field` to
+//! enable BigQuery's best-effort deduplication across retries.
+
+use flowgen_core::{
+    config::ConfigExt,
+    event::{Event, EventBuilder, EventData, EventExt},
+};
+use google_cloud_bigquery::{
+    client::{Client, ClientConfig},
+    http::tabledata::insert_all::{InsertAllRequest, Row},
+};
+use serde_json::Value as JsonValue;
+use std::collections::{BTreeSet, HashSet};
+use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tracing::{error, warn, Instrument};
+
+/// Estimated per-row JSON envelope overhead (`{"insertId":..,"json":..}`) in bytes.
+const ROW_OVERHEAD_BYTES: usize = 32;
+
+/// Errors that can occur during BigQuery streaming inserts.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Error converting event data: {source}")]
+    EventData {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("JSON error: {source}")]
+    SerdeJson {
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("Config template rendering error: {source}")]
+    ConfigRender {
+        #[source]
+        source: flowgen_core::config::Error,
+    },
+    #[error("BigQuery client authentication error: {source}")]
+    ClientAuth {
+        #[source]
+        source: gcloud_auth::error::Error,
+    },
+    #[error("BigQuery client creation error: {source}")]
+    ClientCreation {
+        #[source]
+        source: gcloud_auth::error::Error,
+    },
+    #[error("BigQuery client connection error: {source}")]
+    ClientConnection {
+        #[source]
+        source: gcloud_gax::conn::Error,
+    },
+    #[error("Table metadata retrieval error: {source}")]
+    TableMetadata {
+        #[source]
+        source: google_cloud_bigquery::http::error::Error,
+    },
+    #[error("BigQuery streaming insert error: {source}")]
+    InsertAll {
+        #[source]
+        source: google_cloud_bigquery::http::error::Error,
+    },
+    #[error("Arrow conversion error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+    #[error("Table schema missing")]
+    MissingSchema,
+    #[error("Columns not found in table schema: {}", _0.join(", "))]
+    UnknownColumns(Vec<String>),
+    #[error("Expected JSON object row, got: {0}")]
+    InvalidRow(String),
+    #[error("{failed} rows failed to insert after all retry attempts")]
+    InsertErrors { failed: usize },
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+}
+
+impl From<flowgen_core::event::Error> for Error {
+    fn from(source: flowgen_core::event::Error) -> Self {
+        Error::EventData { source }
+    }
+}
+
+/// Converts event data into JSON rows.
+///
+/// Record batches produce one row per record. JSON arrays produce one row per
+/// element and any other JSON value is treated as a single row.
+fn event_rows(event: &Event) -> Result<Vec<JsonValue>, Error> {
+    let rows = match &event.data {
+        EventData::ArrowRecordBatch(batch) => {
+            let mut json_writer = arrow_json::ArrayWriter::new(Vec::new());
+            json_writer
+                .write(batch)
+                .map_err(|source| Error::Arrow { source })?;
+            json_writer
+                .finish()
+                .map_err(|source| Error::Arrow { source })?;
+            serde_json::from_slice(&json_writer.into_inner())
+                .map_err(|source| Error::SerdeJson { source })?
+        }
+        _ => match event.data_as_json()? {
+            JsonValue::Array(rows) => rows,
+            row => vec![row],
+        },
+    };
+
+    if let Some(row) = rows.iter().find(|row| !row.is_object()) {
+        return Err(Error::InvalidRow(row.to_string()));
+    }
+    Ok(rows)
+}
+
+/// Returns the insert ID for a row from the configured field, if present.
+fn insert_id(row: &JsonValue, field: Option<&str>) -> Option<String> {
+    match row.get(field?)? {
+        JsonValue::Null => None,
+        JsonValue::String(s) => Some(s.clone()),
+        value => Some(value.to_string()),
+    }
+}
+
+/// Returns the sorted set of row columns that do not exist in the table schema.
+fn unknown_columns(rows: &[JsonValue], table_columns: &HashSet<String>) -> Vec<String> {
+    rows.iter()
+        .filter_map(JsonValue::as_object)
+        .flat_map(|row| row.keys())
+        .filter(|column| !table_columns.contains(*column))
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Estimates the serialized size of a row in an insert request.
+fn row_size(row: &Row<JsonValue>) -> usize {
+    let json_size = serde_json::to_vec(&row.json).map(|v| v.len()).unwrap_or(0);
+    let insert_id_size = row.insert_id.as_ref().map(String::len).unwrap_or(0);
+    json_size + insert_id_size + ROW_OVERHEAD_BYTES
+}
+
+/// Splits rows into batches of at most `max_rows` rows and roughly `max_bytes` bytes.
+///
+/// A single row larger than `max_bytes` is sent on its own and left for BigQuery to reject.
+fn chunk_rows(
+    rows: Vec<Row<JsonValue>>,
+    max_rows: usize,
+    max_bytes: usize,
+) -> Vec<Vec<Row<JsonValue>>> {
+    let mut batches = Vec::new();
+    let mut batch = Vec::new();
+    let mut batch_bytes = 0;
+
+    for row in rows {
+        let size = row_size(&row);
+        if !batch.is_empty() && (batch.len() >= max_rows || batch_bytes + size > max_bytes) {
+            batches.push(std::mem::take(&mut batch));
+            batch_bytes = 0;
+        }
+        batch_bytes += size;
+        batch.push(row);
+    }
+
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+    batches
+}
+
+/// Event handler for processing individual streaming insert events.
+pub struct EventHandler {
+    client: Arc<Client>,
+    task_id: usize,
+    tx: Option<Sender<Event>>,
+    config: Arc<super::config::InsertAll>,
+    task_type: &'static str,
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+    retry_config: flowgen_core::retry::RetryConfig,
+    /// Column names of the target table, fetched once during init.
+    table_columns: HashSet<String>,
+}
+
+impl EventHandler {
+    /// Inserts a single batch, retrying rows reported in `insertErrors` and failed requests.
+    async fn insert_batch(
+        &self,
+        config: &super::config::InsertAll,
+        rows: Vec<Row<JsonValue>>,
+    ) -> Result<(), Error> {
+        let mut delays = self.retry_config.strategy();
+        let mut pending = rows;
+
+        loop {
+            let request = InsertAllRequest {
+                skip_invalid_rows: Some(config.skip_invalid_rows),
+                ignore_unknown_values: Some(config.ignore_unknown_values),
+                rows: pending,
+                ..Default::default()
+            };
+
+            let result = self
+                .client
+                .tabledata()
+                .insert(
+                    &config.project_id,
+                    &config.dataset_id,
+                    &config.table_id,
+                    &request,
+                )
+                .await;
+
+            pending = match result {
+                Ok(response) => {
+                    let insert_errors = response.insert_errors.unwrap_or_default();
+                    if insert_errors.is_empty() {
+                        return Ok(());
+                    }
+
+                    let failed: HashSet<usize> = insert_errors
+                        .iter()
+                        .map(|insert_error| insert_error.index as usize)
+                        .collect();
+                    for insert_error in &insert_errors {
+                        warn!(
+                            row_index = insert_error.index,
+                            errors = ?insert_error.errors,
+                            "Row insert failed"
+                        );
+                    }
+
+                    request
+                        .rows
+                        .into_iter()
+                        .enumerate()
+                        .filter(|(i, _)| failed.contains(i))
+                        .map(|(_, row)| row)
+                        .collect()
+                }
+                Err(source) => {
+                    let e = Error::InsertAll { source };
+                    warn!(error = %e, "Streaming insert request failed");
+                    request.rows
+                }
+            };
+
+            match delays.next() {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => {
+                    return Err(Error::InsertErrors {
+                        failed: pending.len(),
+                    })
+                }
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self, event), name = "task.handle")]
+    async fn handle(&self, event: Event) -> Result<(), Error> {
+        if self.task_context.cancellation_token.is_cancelled() {
+            return Ok(());
+        }
+
+        let event = Arc::new(event);
+        let completion_tx_arc = Arc::clone(&event).completion_tx.clone();
+
+        flowgen_core::event::with_event_context(&Arc::clone(&event), async move {
+            // Render config to support templates.
+            let event_value = JsonValue::try_from(event.as_ref())
+                .map_err(|source| Error::EventBuilder { source })?;
+            let config = self
+                .config
+                .render(&event_value)
+                .map_err(|source| Error::ConfigRender { source })?;
+
+            let json_rows = event_rows(&event)?;
+
+            // Reject columns the table does not have unless BigQuery is told to drop them.
+            if !config.ignore_unknown_values {
+                let unknown = unknown_columns(&json_rows, &self.table_columns);
+                if !unknown.is_empty() {
+                    return Err(Error::UnknownColumns(unknown));
+                }
+            }
+
+            let rows: Vec<Row<JsonValue>> = json_rows
+                .into_iter()
+                .map(|json| Row {
+                    insert_id: insert_id(&json, config.insert_id_field.as_deref()),
+                    json,
+                })
+                .collect();
+            let rows_inserted = rows.len();
+
+            let batches = chunk_rows(
+                rows,
+                config.max_rows(),
+                super::config::DEFAULT_INSERT_ALL_MAX_BYTES,
+            );
+            let request_count = batches.len();
+            for batch in batches {
+                self.insert_batch(&config, batch).await?;
+            }
+
+            // Build result event with insert metadata.
+            let result = serde_json::json!({
+                "rows_inserted": rows_inserted,
+                "requests": request_count,
+                "table": format!(
+                    "{}.{}.{}",
+                    config.project_id, config.dataset_id, config.table_id
+                ),
+            });
+
+            let mut result_event = EventBuilder::new()
+                .data(EventData::Json(result))
+                .subject(format!("{}.{}", event.subject, config.name))
+                .task_id(self.task_id)
+                .task_type(self.task_type)
+                .build()
+                .map_err(|source| Error::EventBuilder { source })?;
+
+            // Signal completion or pass through to next task.
+            match self.tx {
+                None => {
+                    // Leaf task: signal completion.
+                    if let Some(arc) = completion_tx_arc.as_ref() {
+                        arc.signal_completion(result_event.data_as_json().ok());
+                    }
+                }
+                Some(_) => {
+                    // Pass through completion_tx to next task.
+                    result_event.completion_tx = completion_tx_arc.clone();
+                }
+            }
+
+            result_event
+                .send_with_logging(self.tx.as_ref())
+                .context("num_records", rows_inserted)
+                .await
+                .map_err(|source| Error::SendMessage { source })?;
+
+            Ok(())
+        })
+        .await
+    }
+}
+
+/// BigQuery streaming insert processor that writes rows with `tabledata.insertAll`.
+#[derive(Debug)]
+pub struct Processor {
+    /// Streaming insert configuration including credentials and table details.
+    config: Arc<super::config::InsertAll>,
+    /// Receiver for incoming events to process.
+    rx: Receiver<Event>,
+    /// Channel sender for result events.
+    tx: Option<Sender<Event>>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl flowgen_core::task::runner::Runner for Processor {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Initializes the processor by creating the BigQuery client and loading the table schema.
+    ///
+    /// The schema is fetched with `tables.get` so rows with unknown columns are
+    /// rejected before they are sent.
+    async fn init(&self) -> Result<EventHandler, Error> {
+        let init_config = self
+            .config
+            .render(&serde_json::json!({}))
+            .map_err(|source| Error::ConfigRender { source })?;
+
+        let credentials = crate::resolve_credentials(&init_config.credentials_path)
+            .await
+            .map_err(|source| Error::ClientAuth { source })?;
+
+        let (client_config, _project_id) = ClientConfig::new_with_credentials(credentials)
+            .await
+            .map_err(|source| Error::ClientCreation { source })?;
+
+        let client = Arc::new(
+            Client::new(client_config)
+                .await
+                .map_err(|source| Error::ClientConnection { source })?,
+        );
+
+        let table_info = client
+            .table()
+            .get(
+                &init_config.project_id,
+                &init_config.dataset_id,
+                &init_config.table_id,
+            )
+            .await
+            .map_err(|source| Error::TableMetadata { source })?;
+
+        let table_schema = table_info.schema.ok_or_else(|| Error::MissingSchema)?;
+        let table_columns = table_schema
+            .fields
+            .into_iter()
+            .map(|field| field.name)
+            .collect();
+
+        Ok(EventHandler {
+            client,
+            task_id: self.task_id,
+            tx: self.tx.clone(),
+            config: Arc::clone(&self.config),
+            task_type: self.task_type,
+            task_context: Arc::clone(&self.task_context),
+            retry_config: flowgen_core::retry::RetryConfig::merge(
+                &self.task_context.retry,
+                &self.config.retry,
+            ),
+            table_columns,
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(mut self) -> Result<(), Self::Error> {
+        let retry_config =
+            flowgen_core::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        let event_handler = match tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+            match self.init().await {
+                Ok(handler) => Ok(handler),
+                Err(e) => {
+                    error!(error = %e, "Failed to initialize streaming insert processor");
+                    Err(tokio_retry::RetryError::transient(e))
+                }
+            }
+        })
+        .await
+        {
+            Ok(handler) => Arc::new(handler),
+            Err(e) => {
+                return Err(e);
+            }
+        };
+
+        loop {
+            match self.rx.recv().await {
+                Some(event) => {
+                    let event_handler = Arc::clone(&event_handler);
+                    let metrics = self.task_context.metrics.clone();
+                    tokio::spawn(
+                        async move {
+                            // Retries happen per batch inside the handler so accepted
+                            // batches are not inserted again.
+                            let bytes = event.data_size() as u64;
+                            match event_handler.handle(event.clone()).await {
+                                Ok(()) => metrics.record_processed(bytes),
+                                Err(e) => {
+                                    metrics.record_failed();
+                                    error!(error = %e, "Streaming insert failed after all retry attempts");
+                                    // Emit error event downstream for error handling.
+                                    let mut error_event = event;
+                                    error_event.error = Some(e.to_string());
+                                    if let Some(ref tx) = event_handler.tx {
+                                        tx.send(error_event).await.ok();
+                                    }
+                                }
+                            }
+                        }
+                        .instrument(tracing::Span::current()),
+                    );
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Builder for creating BigQuery streaming insert processor instances.
+pub struct ProcessorBuilder {
+    config: Option<Arc<super::config::InsertAll>>,
+    rx: Option<Receiver<Event>>,
+    tx: Option<Sender<Event>>,
+    task_id: Option<usize>,
+    task_context: Option<Arc<flowgen_core::task::context::TaskContext>>,
+    task_type: Option<&'static str>,
+}
+
+impl ProcessorBuilder {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            rx: None,
+            tx: None,
+            task_id: None,
+            task_context: None,
+            task_type: None,
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::InsertAll>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn receiver(mut self, rx: Receiver<Event>) -> Self {
+        self.rx = Some(rx);
+        self
+    }
+
+    pub fn sender(mut self, tx: Sender<Event>) -> Self {
+        self.tx = Some(tx);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = Some(task_id);
+        self
+    }
+
+    pub fn task_context(
+        mut self,
+        task_context: Arc<flowgen_core::task::context::TaskContext>,
+    ) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Processor, Error> {
+        Ok(Processor {
+            config: self
+                .config
+                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            rx: self
+                .rx
+                .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
+            tx: self.tx,
+            task_id: self
+                .task_id
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_id".to_string()))?,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+impl Default for ProcessorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int64Array, RecordBatch, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use serde_json::json;
+
+    fn row(json: JsonValue) -> Row<JsonValue> {
+        Row {
+            insert_id: None,
+            json,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_processor_builder_missing_config() {
+        let result = ProcessorBuilder::new().build().await;
+        assert!(
+            matches!(result.unwrap_err(), Error::MissingBuilderAttribute(attr) if attr == "config")
+        );
+    }
+
+    #[test]
+    fn test_event_rows_from_record_batch() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec![Some("a"), Some("b")])),
+            ],
+        )
+        .unwrap();
+        let event = EventBuilder::new()
+            .data(EventData::ArrowRecordBatch(batch))
+            .subject("test".to_string())
+            .task_type("test")
+            .build()
+            .unwrap();
+
+        let rows = event_rows(&event).unwrap();
+        assert_eq!(
+            rows,
+            vec![json!({"id": 1, "name": "a"}), json!({"id": 2, "name": "b"})]
+        );
+    }
+
+    #[test]
+    fn test_event_rows_rejects_non_object() {
+        let event = EventBuilder::new()
+            .data(EventData::Json(json!([{"id": 1}, 2])))
+            .subject("test".to_string())
+            .task_type("test")
+            .build()
+            .unwrap();
+
+        assert!(matches!(event_rows(&event), Err(Error::InvalidRow(_))));
+    }
+
+    #[test]
+    fn test_insert_id_from_field() {
+        let row = json!({"id": 42, "key": "abc", "missing": null});
+        assert_eq!(insert_id(&row, Some("id")), Some("42".to_string()));
+        assert_eq!(insert_id(&row, Some("key")), Some("abc".to_string()));
+        assert_eq!(insert_id(&row, Some("missing")), None);
+        assert_eq!(insert_id(&row, Some("other")), None);
+        assert_eq!(insert_id(&row, None), None);
+    }
+
+    #[test]
+    fn test_unknown_columns() {
+        let table_columns: HashSet<String> = ["id", "name"].iter().map(|c| c.to_string()).collect();
+        let rows = vec![
+            json!({"id": 1, "name": "a"}),
+            json!({"id": 2, "email": "b@example.com", "age": 3}),
+        ];
+
+        assert_eq!(
+            unknown_columns(&rows, &table_columns),
+            vec!["age".to_string(), "email".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_chunk_rows_by_row_count() {
+        let rows = (0..5).map(|i| row(json!({"id": i}))).collect();
+        let batches = chunk_rows(rows, 2, usize::MAX);
+        let sizes: Vec<usize> = batches.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn test_chunk_rows_by_size() {
+        let rows: Vec<_> = (0..4).map(|i| row(json!({"id": i}))).collect();
+        let one_row = row_size(&rows[0]);
+        let batches = chunk_rows(rows, 100, one_row * 2);
+        let sizes: Vec<usize> = batches.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![2, 2]);
+    }
+
+    #[test]
+    fn test_chunk_rows_oversized_row_sent_alone() {
+        let rows = vec![row(json!({"id": 1})), row(json!({"id": 2}))];
+        let batches = chunk_rows(rows, 100, 1);
+        assert_eq!(batches.len(), 2);
+    }
+}
@@ -748,6 +748,7 @@ mod tests {
             max_poll_duration: std::time::Duration::from_secs(600),
             depends_on: None,
             retry: None,
+            error_policy: None,
             schema: None,
         });
 
@@ -791,7 +792,9 @@ mod tests {
             http_server: None,
             mcp_server: None,
             cancellation_token: tokio_util::sync::CancellationToken::new(),
+            metrics: flowgen_core::metrics::FlowMetrics::default(),
             leaf_count: 1,
+            dry_run: false,
         });
 
         let result = ProcessorBuilder::new()
@@ -837,6 +840,7 @@ mod tests {
             max_poll_duration: std::time::Duration::from_secs(600),
             depends_on: None,
             retry: None,
+            error_policy: None,
             schema: None,
         });
         let (tx, _rx) = tokio::sync::mpsc::channel(1);
@@ -859,7 +863,9 @@ mod tests {
             http_server: None,
             mcp_server: None,
             cancellation_token: tokio_util::sync::CancellationToken::new(),
+            metrics: flowgen_core::metrics::FlowMetrics::default(),
             leaf_count: 1,
+            dry_run: false,
         });
 
         let result = ProcessorBuilder::new()
@@ -142,6 +142,8 @@ impl EventHandler {
             // Build result event.
             let mut event_builder = EventBuilder::new()
                 .data(EventData::ArrowRecordBatch(record_batch))
+                // A query without results is sent as an empty batch.
+                .allow_empty(true)
                 .subject(format!("{}.{}", event.subject, config.name))
                 .task_id(self.task_id)
                 .task_type(self.task_type);
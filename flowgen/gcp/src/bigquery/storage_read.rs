@@ -162,6 +162,7 @@ impl EventHandler {
 
                 let mut result_event = EventBuilder::new()
                     .data(EventData::ArrowRecordBatch(record_batch))
+                    .allow_empty(true)
                     .subject(format!("{}.{}", event.subject, config.name))
                     .task_id(self.task_id)
                     .task_type(self.task_type)
@@ -571,8 +571,10 @@ impl flowgen_core::task::runner::Runner for Processor {
                 Some(event) => {
                     let event_handler = Arc::clone(&event_handler);
                     let retry_strategy = retry_config.strategy();
+                    let metrics = self.task_context.metrics.clone();
                     tokio::spawn(
                         async move {
+                            let bytes = event.data_size() as u64;
                             let result = tokio_retry::Retry::spawn(retry_strategy, || async {
                                 match event_handler.handle(event.clone()).await {
                                     Ok(result) => Ok(result),
@@ -584,7 +586,12 @@ impl flowgen_core::task::runner::Runner for Processor {
                             })
                             .await;
 
+                            if result.is_ok() {
+                                metrics.record_processed(bytes);
+                            }
+
                             if let Err(e) = result {
+                                metrics.record_failed();
                                 error!(error = %e, "Storage write failed after all retry attempts");
                                 // Emit error event downstream for error handling.
                                 let mut error_event = event.clone();
@@ -714,6 +721,7 @@ mod tests {
             change_type: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
 
         let result = ProcessorBuilder::new().config(config).build().await;
@@ -736,6 +744,7 @@ mod tests {
             change_type: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
         let (_tx, rx) = mpsc::channel(10);
 
@@ -763,6 +772,7 @@ mod tests {
             change_type: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
         let (tx, rx) = mpsc::channel(10);
 
@@ -792,6 +802,7 @@ mod tests {
             change_type: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
         let (tx, rx) = mpsc::channel(10);
         let task_manager = Arc::new(
@@ -28,6 +28,8 @@ pub async fn resolve_credentials(
 pub mod bigquery {
     /// Configuration structures for BigQuery operations.
     pub mod config;
+    /// BigQuery streaming insert processor using the `tabledata.insertAll` API.
+    pub mod insert_all;
     /// Unified BigQuery job processor for all job operations (create, get, cancel, delete).
     pub mod job;
     /// BigQuery query processor implementation for executing SQL queries.
@@ -37,3 +39,17 @@ pub mod bigquery {
     /// BigQuery Storage Write API processor for high-throughput streaming inserts.
     pub mod storage_write;
 }
+
+/// Cloud Storage helpers.
+pub mod storage {
+    /// Resumable uploads for large objects over the JSON API.
+    pub mod upload;
+}
+
+/// Pub/Sub functionality for consuming Google Cloud messaging subscriptions.
+pub mod pubsub {
+    /// Configuration structures for Pub/Sub operations.
+    pub mod config;
+    /// Pub/Sub subscriber implementation using streaming pull.
+    pub mod subscriber;
+}
@@ -0,0 +1,147 @@
+//! Configuration for the `gcp_pubsub_subscriber` task.
+//!
+//! Subscribes to an existing Google Cloud Pub/Sub subscription using a
+//! streaming pull. Messages are acknowledged once they have been handed to the
+//! next task, so unacknowledged messages are redelivered by Pub/Sub after the
+//! subscription's ack deadline.
+
+use flowgen_core::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Default maximum number of unacknowledged messages held by the subscriber.
+pub const DEFAULT_MAX_OUTSTANDING_MESSAGES: usize = 1000;
+
+/// Default maximum outstanding messages function for serde.
+fn default_max_outstanding_messages() -> usize {
+    DEFAULT_MAX_OUTSTANDING_MESSAGES
+}
+
+/// Configuration for the Google Cloud Pub/Sub subscriber.
+///
+/// # Example YAML
+///
+/// ```yaml
+/// - gcp_pubsub_subscriber:
+///     name: bucket_notifications
+///     credentials_path: /etc/gcp/service-account.json
+///     project_id: my-project
+///     subscription_id: gcs-notifications
+///     format: json
+///     max_outstanding_messages: 500
+/// ```
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Subscriber {
+    /// The unique name / identifier of the task.
+    pub name: String,
+    /// Path to GCP service account credentials JSON file.
+    /// When omitted, falls back to Application Default Credentials (ADC).
+    #[serde(default)]
+    pub credentials_path: Option<PathBuf>,
+    /// GCP project ID that owns the subscription.
+    pub project_id: String,
+    /// Pub/Sub subscription ID (without the `projects/.../subscriptions/` prefix).
+    pub subscription_id: String,
+    /// How message data is decoded into event data.
+    #[serde(default)]
+    pub format: PayloadFormat,
+    /// Avro schema (JSON) used to read message data. Required when `format` is `avro`.
+    #[serde(default)]
+    pub schema: Option<String>,
+    /// Maximum number of messages delivered but not yet acknowledged.
+    /// Pub/Sub stops sending messages once this limit is reached. Defaults to 1000.
+    #[serde(default = "default_max_outstanding_messages")]
+    pub max_outstanding_messages: usize,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    #[serde(default)]
+    pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Subscriber {}
+
+impl Subscriber {
+    /// Returns the fully qualified subscription name.
+    pub fn subscription_name(&self) -> String {
+        format!(
+            "projects/{}/subscriptions/{}",
+            self.project_id, self.subscription_id
+        )
+    }
+}
+
+/// Decoding applied to each received message's data.
+#[derive(PartialEq, Eq, Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PayloadFormat {
+    /// Parse the data as JSON.
+    #[default]
+    Json,
+    /// Keep the data as binary Avro, read with the configured `schema`.
+    Avro,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscriber_config_defaults() {
+        let json = r#"{
+            "name": "notifications",
+            "project_id": "my-project",
+            "subscription_id": "gcs-notifications"
+        }"#;
+        let config: Subscriber = serde_json::from_str(json).unwrap();
+
+        assert!(config.credentials_path.is_none());
+        assert_eq!(config.format, PayloadFormat::Json);
+        assert!(config.schema.is_none());
+        assert_eq!(
+            config.max_outstanding_messages,
+            DEFAULT_MAX_OUTSTANDING_MESSAGES
+        );
+    }
+
+    #[test]
+    fn test_subscriber_config_avro() {
+        let json = r#"{
+            "name": "orders",
+            "credentials_path": "/etc/gcp/sa.json",
+            "project_id": "my-project",
+            "subscription_id": "orders",
+            "format": "avro",
+            "schema": "{\"type\": \"record\", \"name\": \"Order\", \"fields\": []}",
+            "max_outstanding_messages": 50
+        }"#;
+        let config: Subscriber = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            config.credentials_path,
+            Some(PathBuf::from("/etc/gcp/sa.json"))
+        );
+        assert_eq!(config.format, PayloadFormat::Avro);
+        assert!(config.schema.is_some());
+        assert_eq!(config.max_outstanding_messages, 50);
+    }
+
+    #[test]
+    fn test_subscription_name() {
+        let config = Subscriber {
+            project_id: "my-project".to_string(),
+            subscription_id: "orders".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.subscription_name(),
+            "projects/my-project/subscriptions/orders"
+        );
+    }
+}
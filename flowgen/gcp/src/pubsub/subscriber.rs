@@ -0,0 +1,449 @@
+//! Google Cloud Pub/Sub subscriber that emits one event per received message.
+//!
+//! Opens a streaming pull on the configured subscription and decodes message
+//! data as JSON or Avro. A message is acknowledged only after its event has been
+//! sent to the next task; messages that cannot be decoded or sent are nacked so
+//! Pub/Sub redelivers them, or moves them to the subscription's dead-letter
+//! topic. When the stream ends the subscriber reconnects until the flow is
+//! cancelled.
+
+use super::config::PayloadFormat;
+use flowgen_core::{
+    config::ConfigExt,
+    event::{AvroData, Event, EventBuilder, EventData, EventExt},
+};
+use futures_util::StreamExt;
+use gcloud_googleapis::pubsub::v1::PubsubMessage;
+use gcloud_pubsub::client::{Client, ClientConfig};
+use gcloud_pubsub::subscriber::SubscriberConfig;
+use gcloud_pubsub::subscription::{MessageStream, SubscribeConfig};
+use serde_json::{Map, Value};
+use std::sync::Arc;
+use tokio::sync::mpsc::Sender;
+use tracing::{error, warn, Instrument};
+
+/// Errors that can occur during Pub/Sub subscription operations.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("JSON error: {source}")]
+    SerdeJson {
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("Pub/Sub client authentication error: {source}")]
+    ClientAuth {
+        #[source]
+        source: gcloud_auth::error::Error,
+    },
+    #[error("Pub/Sub client creation error: {source}")]
+    ClientCreation {
+        #[source]
+        source: gcloud_auth::error::Error,
+    },
+    #[error("Pub/Sub client connection error: {source}")]
+    ClientConnection {
+        #[source]
+        source: gcloud_gax::conn::Error,
+    },
+    #[error("Pub/Sub subscribe error: {source}")]
+    Subscribe {
+        #[source]
+        source: gcloud_gax::grpc::Status,
+    },
+    #[error("Avro format requires a schema")]
+    MissingAvroSchema,
+    #[error("Subscription stream ended unexpectedly, connection may have been lost")]
+    SubscriptionEnded,
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+    #[error("Config template rendering error: {source}")]
+    ConfigRender {
+        #[source]
+        source: flowgen_core::config::Error,
+    },
+}
+
+/// Converts a Pub/Sub message into an event according to the configured format.
+///
+/// Message attributes are carried in the event metadata.
+fn message_to_event(
+    message: &PubsubMessage,
+    config: &super::config::Subscriber,
+    task_type: &'static str,
+    task_id: usize,
+) -> Result<Event, Error> {
+    let data = match config.format {
+        PayloadFormat::Json => serde_json::from_slice(&message.data)
+            .map(EventData::Json)
+            .map_err(|source| Error::SerdeJson { source })?,
+        PayloadFormat::Avro => EventData::Avro(AvroData {
+            schema: config.schema.clone().ok_or(Error::MissingAvroSchema)?,
+            raw_bytes: message.data.clone(),
+        }),
+    };
+
+    let mut event_builder = EventBuilder::new()
+        .data(data)
+        .subject(config.subscription_id.clone())
+        .task_id(task_id)
+        .task_type(task_type);
+    if !message.message_id.is_empty() {
+        event_builder = event_builder.id(message.message_id.clone());
+    }
+    if !message.attributes.is_empty() {
+        let meta: Map<String, Value> = message
+            .attributes
+            .iter()
+            .map(|(key, value)| (key.clone(), Value::String(value.clone())))
+            .collect();
+        event_builder = event_builder.meta(meta);
+    }
+    event_builder
+        .build()
+        .map_err(|source| Error::EventBuilder { source })
+}
+
+/// Event handler owning a single Pub/Sub streaming pull.
+pub struct EventHandler {
+    stream: MessageStream,
+    tx: Option<Sender<Event>>,
+    task_id: usize,
+    config: Arc<super::config::Subscriber>,
+    task_type: &'static str,
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+}
+
+impl EventHandler {
+    /// Reads messages until the stream ends or the flow is cancelled.
+    async fn handle(mut self) -> Result<(), Error> {
+        let cancellation_token = self.task_context.cancellation_token.clone();
+
+        loop {
+            let message = tokio::select! {
+                // Dropping the stream cancels the streaming pull; unacked messages are redelivered.
+                _ = cancellation_token.cancelled() => return Ok(()),
+                message = self.stream.next() => match message {
+                    Some(message) => message,
+                    None => return Err(Error::SubscriptionEnded),
+                },
+            };
+
+            let e = match message_to_event(
+                &message.message,
+                &self.config,
+                self.task_type,
+                self.task_id,
+            ) {
+                Ok(e) => e,
+                Err(e) => {
+                    // A malformed message should not tear down the subscription.
+                    warn!(error = %e, message_id = %message.message.message_id, "Failed to decode Pub/Sub message, nacking");
+                    message.nack().await.ok();
+                    continue;
+                }
+            };
+
+            if let Err(source) = e.send_with_logging(self.tx.as_ref()).await {
+                message.nack().await.ok();
+                return Err(Error::SendMessage { source });
+            }
+            self.task_context.metrics.record_received();
+
+            if let Err(e) = message.ack().await {
+                warn!(error = %e, "Failed to acknowledge Pub/Sub message, it may be redelivered");
+            }
+        }
+    }
+}
+
+/// Pub/Sub subscriber that consumes messages and converts them to flowgen events.
+#[derive(Debug)]
+pub struct Subscriber {
+    /// Subscriber configuration including subscription and payload format.
+    config: Arc<super::config::Subscriber>,
+    /// Sender for forwarding converted events.
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event tagging.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl flowgen_core::task::runner::Runner for Subscriber {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Authenticates with GCP and opens a streaming pull on the subscription.
+    async fn init(&self) -> Result<EventHandler, Error> {
+        let init_config = self
+            .config
+            .render(&serde_json::json!({}))
+            .map_err(|source| Error::ConfigRender { source })?;
+
+        if init_config.format == PayloadFormat::Avro && init_config.schema.is_none() {
+            return Err(Error::MissingAvroSchema);
+        }
+
+        let credentials = crate::resolve_credentials(&init_config.credentials_path)
+            .await
+            .map_err(|source| Error::ClientAuth { source })?;
+
+        let client_config = ClientConfig::default()
+            .with_credentials(credentials)
+            .await
+            .map_err(|source| Error::ClientCreation { source })?;
+
+        let client = Client::new(client_config)
+            .await
+            .map_err(|source| Error::ClientConnection { source })?;
+
+        let subscriber_config = SubscriberConfig {
+            max_outstanding_messages: i64::try_from(init_config.max_outstanding_messages)
+                .unwrap_or(i64::MAX),
+            ..Default::default()
+        };
+        let stream = client
+            .subscription(&init_config.subscription_name())
+            .subscribe(Some(
+                SubscribeConfig::default().with_subscriber_config(subscriber_config),
+            ))
+            .await
+            .map_err(|source| Error::Subscribe { source })?;
+
+        Ok(EventHandler {
+            stream,
+            tx: self.tx.clone(),
+            task_id: self.task_id,
+            config: Arc::new(init_config),
+            task_type: self.task_type,
+            task_context: Arc::clone(&self.task_context),
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(self) -> Result<(), Error> {
+        let retry_config =
+            flowgen_core::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        tokio::spawn(
+            async move {
+                // Infinite retry loop: subscribers must maintain connectivity indefinitely.
+                loop {
+                    let event_handler = match tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+                        self.init().await.map_err(|e| {
+                            error!(error = %e, "Subscriber initialization failed");
+                            match e {
+                                // A missing schema will not be fixed by retrying.
+                                Error::MissingAvroSchema => tokio_retry::RetryError::permanent(e),
+                                e => tokio_retry::RetryError::transient(e),
+                            }
+                        })
+                    })
+                    .await
+                    {
+                        Ok(handler) => handler,
+                        Err(Error::MissingAvroSchema) => return,
+                        Err(e) => {
+                            error!(error = %e, "Subscriber initialization exhausted retry attempts, will retry after backoff");
+                            tokio::time::sleep(retry_config.initial_backoff).await;
+                            continue;
+                        }
+                    };
+
+                    // Run event loop until the stream ends, then resubscribe.
+                    match event_handler.handle().await {
+                        Ok(()) => return,
+                        Err(e) => {
+                            if self.task_context.cancellation_token.is_cancelled() {
+                                return;
+                            }
+                            warn!(error = %e, "Subscriber lost connectivity, reinitializing");
+                        }
+                    }
+
+                    tokio::select! {
+                        _ = self.task_context.cancellation_token.cancelled() => return,
+                        _ = tokio::time::sleep(retry_config.initial_backoff) => {}
+                    }
+                }
+            }
+            .instrument(tracing::Span::current()),
+        );
+
+        Ok(())
+    }
+}
+
+/// Builder for configuring and creating Pub/Sub subscribers.
+#[derive(Default)]
+pub struct SubscriberBuilder {
+    /// Optional subscriber configuration.
+    config: Option<Arc<super::config::Subscriber>>,
+    /// Optional event sender.
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event processing.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Option<Arc<flowgen_core::task::context::TaskContext>>,
+    /// Task type for event categorization.
+    task_type: Option<&'static str>,
+}
+
+impl SubscriberBuilder {
+    pub fn new() -> SubscriberBuilder {
+        SubscriberBuilder {
+            ..Default::default()
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Subscriber>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = task_id;
+        self
+    }
+
+    pub fn task_context(
+        mut self,
+        task_context: Arc<flowgen_core::task::context::TaskContext>,
+    ) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Subscriber, Error> {
+        Ok(Subscriber {
+            config: self
+                .config
+                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            tx: self.tx,
+            task_id: self.task_id,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn message(data: &[u8]) -> PubsubMessage {
+        PubsubMessage {
+            data: data.to_vec(),
+            attributes: HashMap::from([("bucketId".to_string(), "uploads".to_string())]),
+            message_id: "123".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn config(format: PayloadFormat, schema: Option<&str>) -> super::super::config::Subscriber {
+        super::super::config::Subscriber {
+            name: "test".to_string(),
+            project_id: "my-project".to_string(),
+            subscription_id: "gcs-notifications".to_string(),
+            format,
+            schema: schema.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_message_to_event_json() {
+        let e = message_to_event(
+            &message(br#"{"name": "report.csv"}"#),
+            &config(PayloadFormat::Json, None),
+            "test",
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(e.subject, "gcs-notifications");
+        assert_eq!(e.id, Some("123".to_string()));
+        assert_eq!(
+            e.meta.unwrap().get("bucketId"),
+            Some(&Value::String("uploads".to_string()))
+        );
+        assert!(
+            matches!(e.data, EventData::Json(v) if v == serde_json::json!({"name": "report.csv"}))
+        );
+    }
+
+    #[test]
+    fn test_message_to_event_avro() {
+        let schema = r#"{"type": "string"}"#;
+        let e = message_to_event(
+            &message(&[0x02, b'a']),
+            &config(PayloadFormat::Avro, Some(schema)),
+            "test",
+            0,
+        )
+        .unwrap();
+
+        assert!(
+            matches!(e.data, EventData::Avro(data) if data.schema == schema && data.raw_bytes == vec![0x02, b'a'])
+        );
+    }
+
+    #[test]
+    fn test_message_to_event_avro_missing_schema() {
+        let result = message_to_event(
+            &message(&[0x02, b'a']),
+            &config(PayloadFormat::Avro, None),
+            "test",
+            0,
+        );
+        assert!(matches!(result, Err(Error::MissingAvroSchema)));
+    }
+
+    #[test]
+    fn test_message_to_event_invalid_json() {
+        let result = message_to_event(
+            &message(b"not json"),
+            &config(PayloadFormat::Json, None),
+            "test",
+            0,
+        );
+        assert!(matches!(result, Err(Error::SerdeJson { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_subscriber_builder_missing_config() {
+        let result = SubscriberBuilder::new().task_type("test").build().await;
+        assert!(
+            matches!(result.unwrap_err(), Error::MissingBuilderAttribute(attr) if attr == "config")
+        );
+    }
+}
@@ -0,0 +1,481 @@
+//! Resumable uploads to Google Cloud Storage over the JSON API.
+//!
+//! A resumable upload opens an upload session and sends the object in chunks.
+//! When a chunk fails, the session is queried for the bytes it has persisted
+//! and the upload continues from there after a backoff, so large objects do
+//! not have to be sent again from the start. Sessions stay valid for a week.
+
+use bytes::Bytes;
+use gcloud_auth::project::{create_token_source_from_credentials, Config};
+use gcloud_auth::token_source::TokenSource;
+use reqwest::{header, StatusCode};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tracing::warn;
+
+/// Default Cloud Storage endpoint.
+pub const DEFAULT_ENDPOINT: &str = "https://storage.googleapis.com";
+
+/// Chunk sizes must be a multiple of this many bytes, except for the last chunk.
+pub const CHUNK_SIZE_MULTIPLE: usize = 256 * 1024;
+
+/// Default chunk size (8 MiB).
+pub const DEFAULT_CHUNK_SIZE: usize = 32 * CHUNK_SIZE_MULTIPLE;
+
+/// Default content type for uploaded objects.
+const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// OAuth scope required to write objects.
+const STORAGE_SCOPES: [&str; 1] = ["https://www.googleapis.com/auth/devstorage.read_write"];
+
+/// Status code Cloud Storage uses for an incomplete upload ("Resume Incomplete").
+const RESUME_INCOMPLETE: u16 = 308;
+
+/// Errors that can occur during resumable uploads.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Cloud Storage authentication error: {source}")]
+    Auth {
+        #[source]
+        source: gcloud_auth::error::Error,
+    },
+    #[error("Failed to build HTTP client: {source}")]
+    ClientInit {
+        #[source]
+        source: reqwest::Error,
+    },
+    #[error("Cloud Storage request failed: {source}")]
+    Request {
+        #[source]
+        source: reqwest::Error,
+    },
+    #[error("Cloud Storage returned status {status}: {body}")]
+    UnexpectedStatus { status: u16, body: String },
+    #[error("Upload session response has no Location header")]
+    MissingSessionUri,
+    #[error("Upload session expired or was cancelled")]
+    SessionExpired,
+    #[error("Chunk size must be a non-zero multiple of {CHUNK_SIZE_MULTIPLE} bytes, got {0}")]
+    InvalidChunkSize(usize),
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+}
+
+impl Error {
+    /// Returns true if retrying the request may succeed.
+    fn is_retriable(&self) -> bool {
+        match self {
+            Error::Request { .. } | Error::Auth { .. } => true,
+            Error::UnexpectedStatus { status, .. } => {
+                *status == 408 || *status == 429 || *status >= 500
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Upload progress reported after every persisted chunk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UploadProgress {
+    /// Bytes persisted by Cloud Storage so far.
+    pub bytes_uploaded: u64,
+    /// Total size of the object.
+    pub total_bytes: u64,
+}
+
+/// Callback invoked with upload progress.
+pub type ProgressCallback = Arc<dyn Fn(UploadProgress) + Send + Sync>;
+
+/// State of an upload session after a chunk request.
+enum ChunkStatus {
+    /// The upload is incomplete; the session has persisted this many bytes.
+    Incomplete(u64),
+    /// The upload is complete; contains the object resource.
+    Complete(serde_json::Value),
+}
+
+/// Returns the `Content-Range` header value for a chunk.
+///
+/// `end` is exclusive. An empty range queries the session status.
+fn content_range(start: u64, end: u64, total: u64) -> String {
+    if start == end {
+        format!("bytes */{total}")
+    } else {
+        format!("bytes {}-{}/{}", start, end - 1, total)
+    }
+}
+
+/// Returns the number of persisted bytes from a `Range` response header.
+///
+/// Cloud Storage answers with `bytes=0-<last byte>`; a missing header means
+/// nothing has been persisted.
+fn persisted_bytes(range: Option<&str>) -> u64 {
+    range
+        .and_then(|range| range.strip_prefix("bytes=0-"))
+        .and_then(|last| last.parse::<u64>().ok())
+        .map_or(0, |last| last + 1)
+}
+
+/// Resumable upload of a single object to Cloud Storage.
+pub struct ResumableUpload {
+    client: reqwest::Client,
+    token_source: Arc<dyn TokenSource>,
+    endpoint: String,
+    bucket: String,
+    object_name: String,
+    content_type: String,
+    chunk_size: usize,
+    retry_config: flowgen_core::retry::RetryConfig,
+    on_progress: Option<ProgressCallback>,
+}
+
+impl std::fmt::Debug for ResumableUpload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResumableUpload")
+            .field("endpoint", &self.endpoint)
+            .field("bucket", &self.bucket)
+            .field("object_name", &self.object_name)
+            .field("chunk_size", &self.chunk_size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ResumableUpload {
+    /// Uploads `data` and returns the created object resource.
+    pub async fn upload(&self, data: Bytes) -> Result<serde_json::Value, Error> {
+        let total = data.len() as u64;
+        let session_uri = self.start_session(total).await?;
+
+        let mut offset = 0;
+        loop {
+            let end = (offset + self.chunk_size as u64).min(total);
+            match self
+                .put_chunk_with_retry(&session_uri, &data, offset, end, total)
+                .await?
+            {
+                ChunkStatus::Complete(object) => {
+                    self.report_progress(total, total);
+                    return Ok(object);
+                }
+                ChunkStatus::Incomplete(persisted) => {
+                    self.report_progress(persisted, total);
+                    offset = persisted;
+                }
+            }
+        }
+    }
+
+    /// Returns the `Authorization` header value.
+    async fn authorization(&self) -> Result<String, Error> {
+        let token = self
+            .token_source
+            .token()
+            .await
+            .map_err(|source| Error::Auth { source })?;
+        Ok(token.value())
+    }
+
+    /// Opens an upload session and returns its URI.
+    async fn start_session(&self, total: u64) -> Result<String, Error> {
+        let url = format!(
+            "{}/upload/storage/v1/b/{}/o",
+            self.endpoint.trim_end_matches('/'),
+            self.bucket
+        );
+        let response = self
+            .client
+            .post(url)
+            .query(&[
+                ("uploadType", "resumable"),
+                ("name", self.object_name.as_str()),
+            ])
+            .header(header::AUTHORIZATION, self.authorization().await?)
+            .header("X-Upload-Content-Type", &self.content_type)
+            .header("X-Upload-Content-Length", total)
+            .header(header::CONTENT_LENGTH, 0)
+            .send()
+            .await
+            .map_err(|source| Error::Request { source })?;
+
+        if !response.status().is_success() {
+            return Err(unexpected_status(response).await);
+        }
+        response
+            .headers()
+            .get(header::LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .map(str::to_string)
+            .ok_or(Error::MissingSessionUri)
+    }
+
+    /// Sends a chunk, resuming from the persisted offset after failures.
+    async fn put_chunk_with_retry(
+        &self,
+        session_uri: &str,
+        data: &Bytes,
+        start: u64,
+        end: u64,
+        total: u64,
+    ) -> Result<ChunkStatus, Error> {
+        let mut delays = self.retry_config.strategy();
+        let mut start = start;
+
+        loop {
+            let e = match self
+                .put_chunk(
+                    session_uri,
+                    data.slice(start as usize..end as usize),
+                    start,
+                    end,
+                    total,
+                )
+                .await
+            {
+                Ok(status) => return Ok(status),
+                Err(e) if e.is_retriable() => e,
+                Err(e) => return Err(e),
+            };
+
+            match delays.next() {
+                Some(delay) => {
+                    warn!(error = %e, offset = start, "Chunk upload failed, resuming after backoff");
+                    tokio::time::sleep(delay).await;
+                }
+                None => return Err(e),
+            }
+
+            // Ask the session how much it kept, so only the missing bytes are resent.
+            match self.put_chunk(session_uri, Bytes::new(), 0, 0, total).await {
+                Ok(ChunkStatus::Complete(object)) => return Ok(ChunkStatus::Complete(object)),
+                Ok(ChunkStatus::Incomplete(persisted)) if persisted >= end => {
+                    return Ok(ChunkStatus::Incomplete(persisted))
+                }
+                Ok(ChunkStatus::Incomplete(persisted)) => start = persisted,
+                Err(e) if e.is_retriable() => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Sends a single chunk covering `start..end`. An empty chunk queries the session status.
+    async fn put_chunk(
+        &self,
+        session_uri: &str,
+        chunk: Bytes,
+        start: u64,
+        end: u64,
+        total: u64,
+    ) -> Result<ChunkStatus, Error> {
+        let response = self
+            .client
+            .put(session_uri)
+            .header(header::AUTHORIZATION, self.authorization().await?)
+            .header(header::CONTENT_LENGTH, chunk.len())
+            .header(header::CONTENT_RANGE, content_range(start, end, total))
+            .body(chunk)
+            .send()
+            .await
+            .map_err(|source| Error::Request { source })?;
+
+        match response.status() {
+            status if status.as_u16() == RESUME_INCOMPLETE => {
+                let range = response
+                    .headers()
+                    .get(header::RANGE)
+                    .and_then(|range| range.to_str().ok());
+                Ok(ChunkStatus::Incomplete(persisted_bytes(range)))
+            }
+            status if status.is_success() => {
+                let object = response
+                    .json()
+                    .await
+                    .map_err(|source| Error::Request { source })?;
+                Ok(ChunkStatus::Complete(object))
+            }
+            StatusCode::NOT_FOUND | StatusCode::GONE => Err(Error::SessionExpired),
+            _ => Err(unexpected_status(response).await),
+        }
+    }
+
+    /// Invokes the progress callback, if any.
+    fn report_progress(&self, bytes_uploaded: u64, total_bytes: u64) {
+        if let Some(on_progress) = &self.on_progress {
+            on_progress(UploadProgress {
+                bytes_uploaded,
+                total_bytes,
+            });
+        }
+    }
+}
+
+/// Converts an error response into [`Error::UnexpectedStatus`].
+async fn unexpected_status(response: reqwest::Response) -> Error {
+    let status = response.status().as_u16();
+    let body = response.text().await.unwrap_or_default();
+    Error::UnexpectedStatus {
+        status,
+        body: body.trim().to_string(),
+    }
+}
+
+/// Builder for creating resumable uploads.
+#[derive(Default)]
+pub struct ResumableUploadBuilder {
+    credentials_path: Option<PathBuf>,
+    endpoint: Option<String>,
+    bucket: Option<String>,
+    object_name: Option<String>,
+    content_type: Option<String>,
+    chunk_size: Option<usize>,
+    retry_config: Option<flowgen_core::retry::RetryConfig>,
+    on_progress: Option<ProgressCallback>,
+}
+
+impl ResumableUploadBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the service account credentials file. Falls back to Application Default Credentials.
+    pub fn credentials_path(mut self, credentials_path: Option<PathBuf>) -> Self {
+        self.credentials_path = credentials_path;
+        self
+    }
+
+    /// Overrides the Cloud Storage endpoint (e.g., for an emulator).
+    pub fn endpoint(mut self, endpoint: String) -> Self {
+        self.endpoint = Some(endpoint);
+        self
+    }
+
+    pub fn bucket(mut self, bucket: String) -> Self {
+        self.bucket = Some(bucket);
+        self
+    }
+
+    pub fn object_name(mut self, object_name: String) -> Self {
+        self.object_name = Some(object_name);
+        self
+    }
+
+    pub fn content_type(mut self, content_type: String) -> Self {
+        self.content_type = Some(content_type);
+        self
+    }
+
+    /// Sets the chunk size in bytes. Must be a multiple of 256 KiB.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = Some(chunk_size);
+        self
+    }
+
+    /// Sets the backoff used to retry failed chunks.
+    pub fn retry(mut self, retry_config: flowgen_core::retry::RetryConfig) -> Self {
+        self.retry_config = Some(retry_config);
+        self
+    }
+
+    /// Sets a callback invoked after every persisted chunk.
+    pub fn on_progress(mut self, on_progress: ProgressCallback) -> Self {
+        self.on_progress = Some(on_progress);
+        self
+    }
+
+    pub async fn build(self) -> Result<ResumableUpload, Error> {
+        let bucket = self
+            .bucket
+            .ok_or_else(|| Error::MissingBuilderAttribute("bucket".to_string()))?;
+        let object_name = self
+            .object_name
+            .ok_or_else(|| Error::MissingBuilderAttribute("object_name".to_string()))?;
+        let chunk_size = self.chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE);
+        if chunk_size == 0 || chunk_size % CHUNK_SIZE_MULTIPLE != 0 {
+            return Err(Error::InvalidChunkSize(chunk_size));
+        }
+
+        let credentials = crate::resolve_credentials(&self.credentials_path)
+            .await
+            .map_err(|source| Error::Auth { source })?;
+        let token_source = create_token_source_from_credentials(
+            &credentials,
+            &Config::default().with_scopes(&STORAGE_SCOPES),
+        )
+        .await
+        .map_err(|source| Error::Auth { source })?;
+
+        // Cloud Storage answers incomplete chunks with 308, which must not be followed.
+        let client = reqwest::ClientBuilder::new()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(|source| Error::ClientInit { source })?;
+
+        Ok(ResumableUpload {
+            client,
+            token_source: Arc::from(token_source),
+            endpoint: self
+                .endpoint
+                .unwrap_or_else(|| DEFAULT_ENDPOINT.to_string()),
+            bucket,
+            object_name,
+            content_type: self
+                .content_type
+                .unwrap_or_else(|| DEFAULT_CONTENT_TYPE.to_string()),
+            chunk_size,
+            retry_config: self.retry_config.unwrap_or_default(),
+            on_progress: self.on_progress,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_range() {
+        assert_eq!(content_range(0, 262144, 1000000), "bytes 0-262143/1000000");
+        assert_eq!(
+            content_range(262144, 1000000, 1000000),
+            "bytes 262144-999999/1000000"
+        );
+        assert_eq!(content_range(0, 0, 1000000), "bytes */1000000");
+        assert_eq!(content_range(0, 0, 0), "bytes */0");
+    }
+
+    #[test]
+    fn test_persisted_bytes() {
+        assert_eq!(persisted_bytes(Some("bytes=0-262143")), 262144);
+        assert_eq!(persisted_bytes(None), 0);
+        assert_eq!(persisted_bytes(Some("garbage")), 0);
+    }
+
+    #[test]
+    fn test_error_is_retriable() {
+        let status = |status| Error::UnexpectedStatus {
+            status,
+            body: String::new(),
+        };
+        assert!(status(503).is_retriable());
+        assert!(status(429).is_retriable());
+        assert!(!status(403).is_retriable());
+        assert!(!Error::SessionExpired.is_retriable());
+    }
+
+    #[tokio::test]
+    async fn test_builder_rejects_invalid_chunk_size() {
+        let result = ResumableUploadBuilder::new()
+            .bucket("bucket".to_string())
+            .object_name("data.parquet".to_string())
+            .chunk_size(1000)
+            .build()
+            .await;
+        assert!(matches!(result, Err(Error::InvalidChunkSize(1000))));
+    }
+
+    #[tokio::test]
+    async fn test_builder_missing_bucket() {
+        let result = ResumableUploadBuilder::new().build().await;
+        assert!(matches!(result, Err(Error::MissingBuilderAttribute(attr)) if attr == "bucket"));
+    }
+}
@@ -57,6 +57,9 @@ pub struct Processor {
     /// Optional retry configuration (overrides app-level retry config).
     #[serde(default)]
     pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
 }
 
 impl ConfigExt for Processor {}
@@ -100,6 +103,7 @@ mod tests {
             html_field: Some("body".to_string()),
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         let json = serde_json::to_string(&processor).unwrap();
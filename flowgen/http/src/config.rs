@@ -33,6 +33,10 @@ pub struct Processor {
     pub headers: Option<HashMap<String, String>>,
     /// Optional path to credentials file.
     pub credentials_path: Option<PathBuf>,
+    /// Optional outbound authentication (http_request only). Cannot be
+    /// combined with `credentials_path`.
+    #[serde(default)]
+    pub client_auth: Option<HttpAuth>,
     /// Timeout for waiting on flow completion before responding to webhook (webhook only).
     /// If not specified, waits indefinitely for flow completion.
     #[serde(default, with = "humantime_serde")]
@@ -46,6 +50,26 @@ pub struct Processor {
     /// to 10s.
     #[serde(default = "default_connect_timeout", with = "humantime_serde")]
     pub connect_timeout: Option<Duration>,
+    /// Allow plain `http://` endpoints (http_request only). Defaults to
+    /// false, so requests to non-HTTPS URLs are rejected by the client.
+    #[serde(default)]
+    pub allow_http: bool,
+    /// Optional outbound proxy (http_request only).
+    #[serde(default)]
+    pub proxy: Option<ProxyConfig>,
+    /// Optional TLS certificate pinning (http_request only). When set, only
+    /// the given certificate is trusted and the built-in roots are disabled.
+    #[serde(default)]
+    pub tls_pin: Option<TlsPinConfig>,
+    /// Gzip-compress JSON request bodies and send `Content-Encoding: gzip`
+    /// (http_request only).
+    #[serde(default)]
+    pub compress_request: bool,
+    /// Only compress once the server has advertised `gzip` in an
+    /// `Accept-Encoding` response header (http_request only). Requests are
+    /// sent uncompressed until then.
+    #[serde(default)]
+    pub require_server_accepts_gzip: bool,
     /// Maximum accepted request body size in bytes (webhook only).
     /// Defaults to 10 MiB. Requests larger than this are rejected
     /// with HTTP 413 before being read into memory, preventing a
@@ -63,6 +87,28 @@ pub struct Processor {
     /// The resolved user context is injected into `event.meta.auth`.
     #[serde(default)]
     pub auth: Option<flowgen_core::auth::TaskAuthConfig>,
+    /// Optional response columns to extract (http_request only).
+    /// Maps output column names to JSONPath expressions (e.g. `$.data[*].id`)
+    /// evaluated against the JSON response. When set, the response is emitted as
+    /// a RecordBatch containing only these columns instead of the raw body.
+    #[serde(default)]
+    pub response_fields: Option<HashMap<String, String>>,
+    /// Header-based event routes (webhook only). Evaluated in order; the
+    /// first route whose header matches sets the event subject.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub routes: Vec<WebhookRoute>,
+    /// Subject for requests that match no route (webhook only). When unset
+    /// and routes are configured, unmatched requests are rejected with 400.
+    #[serde(default)]
+    pub default_subject: Option<String>,
+    /// JSON body returned once the event is processed (webhook only).
+    /// Sent with `Content-Type: application/json`. Empty when unset.
+    #[serde(default)]
+    pub response_body: Option<Value>,
+    /// HTTP status returned once the event is processed (webhook only).
+    /// Defaults to 200.
+    #[serde(default)]
+    pub response_status: Option<u16>,
     /// Optional list of upstream task names this task depends on.
     /// When set, this task only receives events from the named tasks.
     /// When not set, the task receives from the previous task in the list (linear chain).
@@ -71,6 +117,9 @@ pub struct Processor {
     /// Optional retry configuration (overrides app-level retry config).
     #[serde(default)]
     pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
 }
 
 impl ConfigExt for Processor {}
@@ -84,14 +133,26 @@ impl Default for Processor {
             payload: None,
             headers: None,
             credentials_path: None,
+            client_auth: None,
             ack_timeout: None,
             timeout: default_request_timeout(),
             connect_timeout: default_connect_timeout(),
+            allow_http: false,
+            proxy: None,
+            tls_pin: None,
+            compress_request: false,
+            require_server_accepts_gzip: false,
             max_body_bytes: default_max_body_bytes(),
             stream: false,
             auth: None,
+            response_fields: None,
+            routes: vec![],
+            default_subject: None,
+            response_body: None,
+            response_status: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         }
     }
 }
@@ -108,6 +169,92 @@ pub struct Payload {
     pub from_event: bool,
     /// Format for sending the payload.
     pub send_as: PayloadSendAs,
+    /// Form parts sent when `send_as` is `multipart`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parts: Vec<MultipartPart>,
+}
+
+/// A single part of a `multipart/form-data` request body.
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct MultipartPart {
+    /// Form field name.
+    pub name: String,
+    /// Part content. Supports templating, e.g. `{{event.data.csv}}`.
+    pub value: String,
+    /// Optional file name, sent for file upload fields.
+    pub filename: Option<String>,
+    /// Optional MIME type of the part (e.g. `text/csv`).
+    pub content_type: Option<String>,
+}
+
+/// Authentication applied to outbound requests.
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HttpAuth {
+    /// Bearer token read from a file on each request, so rotated tokens are picked up.
+    BearerTokenFile(PathBuf),
+    /// HTTP basic authentication.
+    BasicAuth { username: String, password: String },
+    /// OAuth2 client credentials grant. Tokens are cached until they expire.
+    #[serde(rename = "oauth2_client_credentials")]
+    OAuth2ClientCredentials(OAuth2ClientCredentials),
+}
+
+/// OAuth2 client credentials grant settings.
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct OAuth2ClientCredentials {
+    /// Token endpoint URL.
+    pub token_url: String,
+    /// OAuth2 client identifier.
+    pub client_id: String,
+    /// OAuth2 client secret.
+    pub client_secret: String,
+    /// Optional space-separated scopes to request.
+    #[serde(default)]
+    pub scope: Option<String>,
+}
+
+/// Maps an inbound request header value to an event subject.
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct WebhookRoute {
+    /// Header carrying the event type (e.g. `X-GitHub-Event`).
+    pub event_type_header: String,
+    /// Header value that selects this route (e.g. `push`).
+    pub event_type_value: String,
+    /// Subject set on events matching this route.
+    pub subject: String,
+}
+
+/// Outbound HTTP proxy settings.
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ProxyConfig {
+    /// Proxy URL (e.g. `http://proxy.corp.example.com:3128`).
+    pub url: String,
+    /// Hosts, domains or IP ranges that bypass the proxy
+    /// (e.g. `localhost`, `.internal.example.com`, `10.0.0.0/8`).
+    #[serde(default)]
+    pub no_proxy: Option<Vec<String>>,
+    /// Optional basic auth credentials for the proxy.
+    #[serde(default)]
+    pub credentials: Option<ProxyCredentials>,
+}
+
+impl ConfigExt for ProxyConfig {}
+
+/// TLS certificate pinning settings for outbound requests.
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TlsPinConfig {
+    /// Path to the pinned certificate, PEM or DER encoded.
+    pub cert_path: PathBuf,
+}
+
+/// Basic auth credentials for an outbound proxy.
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ProxyCredentials {
+    /// Proxy username.
+    pub username: String,
+    /// Proxy password.
+    pub password: String,
 }
 
 /// Payload encoding format options.
@@ -121,6 +268,8 @@ pub enum PayloadSendAs {
     UrlEncoded,
     /// Send payload as query parameters.
     QueryParams,
+    /// Send `parts` as a `multipart/form-data` body.
+    Multipart,
 }
 
 /// HTTP method types supported by the processor.
@@ -158,6 +307,125 @@ mod tests {
         assert_eq!(processor.headers, None);
         assert_eq!(processor.credentials_path, None);
         assert_eq!(processor.retry, None);
+        assert!(!processor.allow_http);
+    }
+
+    #[test]
+    fn test_webhook_routes_deserialization() {
+        let json = r#"{
+            "name": "github",
+            "endpoint": "/github",
+            "routes": [
+                {"event_type_header": "X-GitHub-Event", "event_type_value": "push", "subject": "github.push"}
+            ],
+            "default_subject": "github.other"
+        }"#;
+        let processor: Processor = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            processor.routes,
+            vec![WebhookRoute {
+                event_type_header: "X-GitHub-Event".to_string(),
+                event_type_value: "push".to_string(),
+                subject: "github.push".to_string(),
+            }]
+        );
+        assert_eq!(processor.default_subject, Some("github.other".to_string()));
+    }
+
+    #[test]
+    fn test_client_auth_deserialization() {
+        let json = r#"{
+            "name": "api",
+            "endpoint": "https://api.example.com",
+            "client_auth": {
+                "oauth2_client_credentials": {
+                    "token_url": "https://login.example.com/oauth/token",
+                    "client_id": "id",
+                    "client_secret": "secret",
+                    "scope": "read write"
+                }
+            }
+        }"#;
+        let processor: Processor = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            processor.client_auth,
+            Some(HttpAuth::OAuth2ClientCredentials(OAuth2ClientCredentials {
+                token_url: "https://login.example.com/oauth/token".to_string(),
+                client_id: "id".to_string(),
+                client_secret: "secret".to_string(),
+                scope: Some("read write".to_string()),
+            }))
+        );
+
+        let auth: HttpAuth =
+            serde_json::from_str(r#"{"bearer_token_file": "/etc/http/token"}"#).unwrap();
+        assert_eq!(
+            auth,
+            HttpAuth::BearerTokenFile(PathBuf::from("/etc/http/token"))
+        );
+
+        let auth: HttpAuth =
+            serde_json::from_str(r#"{"basic_auth": {"username": "u", "password": "p"}}"#).unwrap();
+        assert_eq!(
+            auth,
+            HttpAuth::BasicAuth {
+                username: "u".to_string(),
+                password: "p".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_processor_proxy_deserialization() {
+        let json = r#"{
+            "name": "external",
+            "endpoint": "https://api.example.com",
+            "proxy": {
+                "url": "http://proxy.example.com:3128",
+                "no_proxy": ["localhost", ".internal.example.com"],
+                "credentials": {"username": "user", "password": "secret"}
+            }
+        }"#;
+        let processor: Processor = serde_json::from_str(json).unwrap();
+        let proxy = processor.proxy.unwrap();
+        assert_eq!(proxy.url, "http://proxy.example.com:3128");
+        assert_eq!(
+            proxy.no_proxy,
+            Some(vec![
+                "localhost".to_string(),
+                ".internal.example.com".to_string()
+            ])
+        );
+        assert_eq!(
+            proxy.credentials,
+            Some(ProxyCredentials {
+                username: "user".to_string(),
+                password: "secret".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_processor_tls_pin_deserialization() {
+        let json = r#"{
+            "name": "pinned",
+            "endpoint": "https://api.example.com",
+            "tls_pin": {"cert_path": "/etc/flowgen/api.pem"}
+        }"#;
+        let processor: Processor = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            processor.tls_pin,
+            Some(TlsPinConfig {
+                cert_path: PathBuf::from("/etc/flowgen/api.pem"),
+            })
+        );
+    }
+
+    #[test]
+    fn test_processor_allow_http_deserialization() {
+        let json = r#"{"name": "local", "endpoint": "http://localhost:8080", "allow_http": true}"#;
+        let processor: Processor = serde_json::from_str(json).unwrap();
+        assert!(processor.allow_http);
     }
 
     #[test]
@@ -175,6 +443,7 @@ mod tests {
             input: Some("{\"test\": \"input\"}".to_string()),
             from_event: false,
             send_as: PayloadSendAs::Json,
+            parts: vec![],
         };
 
         let processor = Processor {
@@ -184,14 +453,26 @@ mod tests {
             payload: Some(payload),
             headers: Some(headers.clone()),
             credentials_path: Some(PathBuf::from("/path/to/creds.json")),
+            client_auth: None,
             ack_timeout: None,
             timeout: default_request_timeout(),
             connect_timeout: default_connect_timeout(),
+            allow_http: false,
+            proxy: None,
+            tls_pin: None,
+            compress_request: false,
+            require_server_accepts_gzip: false,
             max_body_bytes: default_max_body_bytes(),
             stream: false,
             auth: None,
+            response_fields: None,
+            routes: vec![],
+            default_subject: None,
+            response_body: None,
+            response_status: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         assert_eq!(processor.name, "test_processor".to_string());
@@ -214,14 +495,26 @@ mod tests {
             payload: None,
             headers: None,
             credentials_path: Some(PathBuf::from("/test/credentials.json")),
+            client_auth: None,
             ack_timeout: None,
             timeout: default_request_timeout(),
             connect_timeout: default_connect_timeout(),
+            allow_http: false,
+            proxy: None,
+            tls_pin: None,
+            compress_request: false,
+            require_server_accepts_gzip: false,
             max_body_bytes: default_max_body_bytes(),
             stream: false,
             auth: None,
+            response_fields: None,
+            routes: vec![],
+            default_subject: None,
+            response_body: None,
+            response_status: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         let json = serde_json::to_string(&processor).unwrap();
@@ -238,14 +531,26 @@ mod tests {
             payload: None,
             headers: None,
             credentials_path: None,
+            client_auth: None,
             ack_timeout: None,
             timeout: default_request_timeout(),
             connect_timeout: default_connect_timeout(),
+            allow_http: false,
+            proxy: None,
+            tls_pin: None,
+            compress_request: false,
+            require_server_accepts_gzip: false,
             max_body_bytes: default_max_body_bytes(),
             stream: false,
             auth: None,
+            response_fields: None,
+            routes: vec![],
+            default_subject: None,
+            response_body: None,
+            response_status: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         let cloned = processor.clone();
@@ -271,6 +576,7 @@ mod tests {
             input: Some("{\"input\": \"data\"}".to_string()),
             from_event: false,
             send_as: PayloadSendAs::UrlEncoded,
+            parts: vec![],
         };
 
         assert_eq!(payload.object, Some(object));
@@ -288,6 +594,7 @@ mod tests {
             input: None,
             from_event: false,
             send_as: PayloadSendAs::QueryParams,
+            parts: vec![],
         };
 
         let json = serde_json::to_string(&payload).unwrap();
@@ -314,6 +621,7 @@ mod tests {
             PayloadSendAs::Json,
             PayloadSendAs::UrlEncoded,
             PayloadSendAs::QueryParams,
+            PayloadSendAs::Multipart,
         ];
 
         for variant in variants {
@@ -323,6 +631,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_multipart_payload_deserialization() {
+        let json = r#"{
+            "send_as": "multipart",
+            "parts": [
+                {"name": "job", "value": "{\"object\": \"Account\"}", "content_type": "application/json"},
+                {"name": "content", "value": "Id,Name", "filename": "accounts.csv"}
+            ]
+        }"#;
+
+        let payload: Payload = serde_json::from_str(json).unwrap();
+        assert_eq!(payload.send_as, PayloadSendAs::Multipart);
+        assert_eq!(payload.parts.len(), 2);
+        assert_eq!(
+            payload.parts[0].content_type.as_deref(),
+            Some("application/json")
+        );
+        assert_eq!(payload.parts[1].filename.as_deref(), Some("accounts.csv"));
+    }
+
     #[test]
     fn test_method_default() {
         assert_eq!(Method::default(), Method::Get);
@@ -386,6 +714,7 @@ mod tests {
             input: Some("{\"alternative\": \"input\"}".to_string()),
             from_event: false,
             send_as: PayloadSendAs::Json,
+            parts: vec![],
         };
 
         let mut headers = HashMap::new();
@@ -398,14 +727,26 @@ mod tests {
             payload: Some(payload),
             headers: Some(headers),
             credentials_path: Some(PathBuf::from("/secure/path/to/creds.json")),
+            client_auth: None,
             ack_timeout: None,
             timeout: default_request_timeout(),
             connect_timeout: default_connect_timeout(),
+            allow_http: false,
+            proxy: None,
+            tls_pin: None,
+            compress_request: false,
+            require_server_accepts_gzip: false,
             max_body_bytes: default_max_body_bytes(),
             stream: false,
             auth: None,
+            response_fields: None,
+            routes: vec![],
+            default_subject: None,
+            response_body: None,
+            response_status: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         let json = serde_json::to_string(&processor).unwrap();
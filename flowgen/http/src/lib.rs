@@ -5,6 +5,8 @@
 
 /// Configuration structures for HTTP processors.
 pub mod config;
+/// OAuth2 client credentials token cache for outbound calls.
+pub mod oauth;
 /// HTTP request processor for outbound calls.
 pub mod request;
 /// Shared HTTP server management.
@@ -0,0 +1,218 @@
+//! OAuth2 client credentials token fetching and caching.
+//!
+//! Requests access tokens from the configured token endpoint and caches them
+//! until shortly before they expire, so each outbound request reuses the same
+//! token instead of hitting the token endpoint.
+
+use crate::config::OAuth2ClientCredentials;
+use serde::Deserialize;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// Tokens are refreshed this long before they expire to avoid sending a
+/// token that lapses in flight.
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+
+/// Errors that can occur while obtaining an OAuth2 access token.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Token request to {token_url} failed: {source}")]
+    Request {
+        token_url: String,
+        #[source]
+        source: reqwest::Error,
+    },
+    #[error("Token endpoint {token_url} returned {status}: {body}")]
+    TokenEndpoint {
+        token_url: String,
+        status: u16,
+        body: String,
+    },
+    #[error("Invalid token response from {token_url}: {source}")]
+    InvalidResponse {
+        token_url: String,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// Token endpoint response fields used by the cache.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+/// An access token with its refresh deadline.
+#[derive(Debug, Clone)]
+struct CachedToken {
+    access_token: String,
+    /// When the token should be refreshed. `None` when the endpoint did not
+    /// report `expires_in`; such tokens are kept until invalidated.
+    refresh_at: Option<Instant>,
+}
+
+impl CachedToken {
+    fn new(access_token: String, expires_in: Option<u64>, now: Instant) -> Self {
+        Self {
+            access_token,
+            refresh_at: expires_in
+                .map(|secs| now + Duration::from_secs(secs).saturating_sub(TOKEN_EXPIRY_MARGIN)),
+        }
+    }
+
+    fn is_valid(&self, now: Instant) -> bool {
+        self.refresh_at.is_none_or(|refresh_at| now < refresh_at)
+    }
+}
+
+/// Caches a single OAuth2 access token and refreshes it when expired.
+#[derive(Debug, Default)]
+pub struct TokenCache {
+    token: Mutex<Option<CachedToken>>,
+}
+
+impl TokenCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a valid access token, requesting a new one if none is cached
+    /// or the cached token has expired.
+    ///
+    /// The lock is held while fetching so concurrent requests share a single
+    /// token refresh.
+    pub async fn token(
+        &self,
+        client: &reqwest::Client,
+        credentials: &OAuth2ClientCredentials,
+    ) -> Result<String, Error> {
+        let mut cached = self.token.lock().await;
+        if let Some(token) = cached.as_ref() {
+            if token.is_valid(Instant::now()) {
+                return Ok(token.access_token.clone());
+            }
+        }
+
+        let token = fetch_token(client, credentials).await?;
+        let access_token = token.access_token.clone();
+        *cached = Some(token);
+        Ok(access_token)
+    }
+
+    /// Drops the cached token so the next request fetches a new one.
+    pub async fn invalidate(&self) {
+        *self.token.lock().await = None;
+    }
+}
+
+/// Requests a token from the endpoint using the client credentials grant.
+async fn fetch_token(
+    client: &reqwest::Client,
+    credentials: &OAuth2ClientCredentials,
+) -> Result<CachedToken, Error> {
+    let mut form = vec![
+        ("grant_type", "client_credentials"),
+        ("client_id", credentials.client_id.as_str()),
+        ("client_secret", credentials.client_secret.as_str()),
+    ];
+    if let Some(scope) = &credentials.scope {
+        form.push(("scope", scope.as_str()));
+    }
+
+    let request_error = |source| Error::Request {
+        token_url: credentials.token_url.clone(),
+        source,
+    };
+    let response = client
+        .post(&credentials.token_url)
+        .form(&form)
+        .send()
+        .await
+        .map_err(request_error)?;
+    let status = response.status();
+    let body = response.text().await.map_err(request_error)?;
+
+    if !status.is_success() {
+        return Err(Error::TokenEndpoint {
+            token_url: credentials.token_url.clone(),
+            status: status.as_u16(),
+            body,
+        });
+    }
+
+    let token: TokenResponse =
+        serde_json::from_str(&body).map_err(|source| Error::InvalidResponse {
+            token_url: credentials.token_url.clone(),
+            source,
+        })?;
+    Ok(CachedToken::new(
+        token.access_token,
+        token.expires_in,
+        Instant::now(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cached_token_valid_until_margin() {
+        let now = Instant::now();
+        let token = CachedToken::new("abc".to_string(), Some(3600), now);
+
+        assert!(token.is_valid(now));
+        assert!(token.is_valid(now + Duration::from_secs(3500)));
+        assert!(!token.is_valid(now + Duration::from_secs(3571)));
+    }
+
+    #[test]
+    fn test_cached_token_short_lifetime_expires_immediately() {
+        let now = Instant::now();
+        let token = CachedToken::new("abc".to_string(), Some(10), now);
+        assert!(!token.is_valid(now));
+    }
+
+    #[test]
+    fn test_cached_token_without_expiry() {
+        let now = Instant::now();
+        let token = CachedToken::new("abc".to_string(), None, now);
+        assert!(token.is_valid(now + Duration::from_secs(86_400)));
+    }
+
+    #[test]
+    fn test_token_response_deserialization() {
+        let json = r#"{"access_token": "abc", "token_type": "Bearer", "expires_in": 3600}"#;
+        let response: TokenResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.access_token, "abc");
+        assert_eq!(response.expires_in, Some(3600));
+    }
+
+    #[tokio::test]
+    async fn test_token_cache_returns_cached_token() {
+        let cache = TokenCache::new();
+        *cache.token.lock().await = Some(CachedToken::new(
+            "cached".to_string(),
+            Some(3600),
+            Instant::now(),
+        ));
+
+        let credentials = OAuth2ClientCredentials {
+            token_url: "https://login.invalid/token".to_string(),
+            client_id: "id".to_string(),
+            client_secret: "secret".to_string(),
+            scope: None,
+        };
+        let token = cache
+            .token(&reqwest::Client::new(), &credentials)
+            .await
+            .unwrap();
+        assert_eq!(token, "cached");
+
+        cache.invalidate().await;
+        assert!(cache.token.lock().await.is_none());
+    }
+}
@@ -4,20 +4,36 @@
 //! and various payload formats. Processes events by making HTTP requests
 //! and publishing the responses as new events.
 
+use crate::config::HttpAuth;
+use arrow::array::{
+    ArrayRef, BooleanArray, Float64Array, Int64Array, NullArray, RecordBatch, RecordBatchOptions,
+    StringArray,
+};
+use arrow::datatypes::{DataType, Field, Schema};
+use flate2::{write::GzEncoder, Compression};
 use flowgen_core::credentials::HttpCredentials;
 use flowgen_core::{
     config::ConfigExt,
     event::{Event, EventBuilder, EventData, EventExt},
 };
 use futures_util::future;
-use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::header::{
+    HeaderMap, HeaderName, HeaderValue, ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_TYPE,
+};
 use serde_json::{json, Value};
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    io::Write,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 use tokio::{
     fs,
     sync::mpsc::{Receiver, Sender},
 };
-use tracing::{error, Instrument};
+use tracing::{error, warn, Instrument};
 
 /// Formats an error and every entry in its `source()` chain into a single
 /// colon-separated string.
@@ -73,6 +89,40 @@ pub enum Error {
         #[source]
         source: reqwest::Error,
     },
+    #[error("Error reading bearer token file at {path}: {source}")]
+    ReadBearerTokenFile {
+        path: std::path::PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("OAuth2 token error: {source}")]
+    OAuth2 {
+        #[source]
+        source: crate::oauth::Error,
+    },
+    #[error("Error compressing request body: {source}")]
+    Compress {
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Invalid proxy URL {url}: {}", error_chain(.source))]
+    InvalidProxy {
+        url: String,
+        #[source]
+        source: reqwest::Error,
+    },
+    #[error("Error reading pinned TLS certificate at {path}: {source}")]
+    TlsCertLoad {
+        path: std::path::PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Invalid pinned TLS certificate at {path}: {}", error_chain(.source))]
+    TlsCertParse {
+        path: std::path::PathBuf,
+        #[source]
+        source: reqwest::Error,
+    },
     #[error(
         "HTTP request error for {method} {endpoint}: {}",
         error_chain(.source)
@@ -95,6 +145,31 @@ pub enum Error {
     },
     #[error("Either payload json or payload input is required")]
     PayloadConfig,
+    #[error("Invalid configuration: {0}")]
+    InvalidConfiguration(String),
+    #[error("Invalid JSONPath for response field '{field}': {source}")]
+    InvalidJsonPath {
+        field: String,
+        #[source]
+        source: serde_json_path::ParseError,
+    },
+    #[error("Response field '{field}' matched {actual} values, expected 1 or {expected}")]
+    ResponseFieldLength {
+        field: String,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("Arrow error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+    #[error("Invalid multipart part '{name}': {}", error_chain(.source))]
+    MultipartPart {
+        name: String,
+        #[source]
+        source: reqwest::Error,
+    },
     #[error("Event data was not found on the event payload")]
     MissingEventData,
     #[error("Missing required builder attribute: {}", _0)]
@@ -113,6 +188,229 @@ pub enum Error {
     },
 }
 
+/// Builds a `multipart/form-data` body from the configured parts.
+fn multipart_form(
+    parts: &[crate::config::MultipartPart],
+) -> Result<reqwest::multipart::Form, Error> {
+    parts
+        .iter()
+        .try_fold(reqwest::multipart::Form::new(), |form, part_config| {
+            let mut part = reqwest::multipart::Part::text(part_config.value.clone());
+            if let Some(filename) = &part_config.filename {
+                part = part.file_name(filename.clone());
+            }
+            if let Some(content_type) = &part_config.content_type {
+                part = part
+                    .mime_str(content_type)
+                    .map_err(|source| Error::MultipartPart {
+                        name: part_config.name.clone(),
+                        source,
+                    })?;
+            }
+            Ok(form.part(part_config.name.clone(), part))
+        })
+}
+
+/// Parses the configured response field JSONPaths.
+fn parse_response_fields(
+    fields: &HashMap<String, String>,
+) -> Result<Vec<(String, serde_json_path::JsonPath)>, Error> {
+    let mut parsed = fields
+        .iter()
+        .map(|(field, path)| {
+            serde_json_path::JsonPath::parse(path)
+                .map(|json_path| (field.clone(), json_path))
+                .map_err(|source| Error::InvalidJsonPath {
+                    field: field.clone(),
+                    source,
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    // Sort columns by name so the output schema is stable across requests.
+    parsed.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(parsed)
+}
+
+/// Builds an Arrow column from JSON values, typed by its non-null values.
+///
+/// Booleans, integers, numbers and strings become `Boolean`, `Int64`, `Float64`
+/// and `Utf8` columns, with integers widened to `Float64` when mixed with floats.
+/// A column of only nulls is `Null`. Any other mix of types, or objects and
+/// arrays, falls back to `Utf8` with non-string values as their JSON text.
+fn json_column(values: &[Value]) -> (DataType, ArrayRef) {
+    let non_null = || values.iter().filter(|value| !value.is_null());
+
+    if non_null().next().is_none() {
+        (DataType::Null, Arc::new(NullArray::new(values.len())))
+    } else if non_null().all(Value::is_boolean) {
+        let array: BooleanArray = values.iter().map(Value::as_bool).collect();
+        (DataType::Boolean, Arc::new(array))
+    } else if non_null().all(Value::is_i64) {
+        let array: Int64Array = values.iter().map(Value::as_i64).collect();
+        (DataType::Int64, Arc::new(array))
+    } else if non_null().all(Value::is_number) {
+        let array: Float64Array = values.iter().map(Value::as_f64).collect();
+        (DataType::Float64, Arc::new(array))
+    } else {
+        let array: StringArray = values
+            .iter()
+            .map(|value| match value {
+                Value::Null => None,
+                Value::String(s) => Some(s.clone()),
+                other => Some(other.to_string()),
+            })
+            .collect();
+        (DataType::Utf8, Arc::new(array))
+    }
+}
+
+/// Extracts the configured response fields into a RecordBatch.
+///
+/// Each JSONPath may match any number of values. Columns matching a single
+/// value are repeated to the length of the longest column and columns matching
+/// nothing are filled with nulls. Any other length mismatch is an error.
+/// Column types are detected from the matched values, see [`json_column`].
+fn extract_response_fields(
+    response: &Value,
+    fields: &HashMap<String, String>,
+) -> Result<RecordBatch, Error> {
+    let columns: Vec<(String, Vec<Value>)> = parse_response_fields(fields)?
+        .into_iter()
+        .map(|(field, path)| {
+            let values = path.query(response).all().into_iter().cloned().collect();
+            (field, values)
+        })
+        .collect();
+
+    let num_rows = columns
+        .iter()
+        .map(|(_, values)| values.len())
+        .max()
+        .unwrap_or(0);
+
+    let mut schema_fields = Vec::with_capacity(columns.len());
+    let mut arrays: Vec<ArrayRef> = Vec::with_capacity(columns.len());
+    for (field, values) in columns {
+        let values = match values.len() {
+            len if len == num_rows => values,
+            0 => vec![Value::Null; num_rows],
+            1 => vec![values[0].clone(); num_rows],
+            actual => {
+                return Err(Error::ResponseFieldLength {
+                    field,
+                    expected: num_rows,
+                    actual,
+                })
+            }
+        };
+        let (data_type, array) = json_column(&values);
+        schema_fields.push(Field::new(field, data_type, true));
+        arrays.push(array);
+    }
+
+    RecordBatch::try_new_with_options(
+        Arc::new(Schema::new(schema_fields)),
+        arrays,
+        &RecordBatchOptions::new().with_row_count(Some(num_rows)),
+    )
+    .map_err(|source| Error::Arrow { source })
+}
+
+/// Serializes a JSON value and gzip-compresses it.
+fn gzip_json(value: &Value) -> Result<Vec<u8>, Error> {
+    let body = serde_json::to_vec(value).map_err(|source| Error::SerdeJson { source })?;
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(&body)
+        .map_err(|source| Error::Compress { source })?;
+    encoder
+        .finish()
+        .map_err(|source| Error::Compress { source })
+}
+
+/// Returns true when an `Accept-Encoding` header lists gzip.
+fn accepts_gzip(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(ACCEPT_ENCODING)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|coding| {
+            coding
+                .split(';')
+                .next()
+                .is_some_and(|c| c.trim().eq_ignore_ascii_case("gzip"))
+        })
+}
+
+/// Builds the outbound proxy from config, applying credentials and bypass hosts.
+fn build_proxy(config: &crate::config::ProxyConfig) -> Result<reqwest::Proxy, Error> {
+    let mut proxy = reqwest::Proxy::all(&config.url).map_err(|source| Error::InvalidProxy {
+        url: config.url.clone(),
+        source,
+    })?;
+    if let Some(credentials) = &config.credentials {
+        proxy = proxy.basic_auth(&credentials.username, &credentials.password);
+    }
+    if let Some(no_proxy) = &config.no_proxy {
+        proxy = proxy.no_proxy(reqwest::NoProxy::from_string(&no_proxy.join(",")));
+    }
+    Ok(proxy)
+}
+
+/// Loads the pinned certificate, accepting either PEM or DER encoding.
+async fn load_pinned_cert(
+    config: &crate::config::TlsPinConfig,
+) -> Result<reqwest::Certificate, Error> {
+    let bytes = tokio::fs::read(&config.cert_path)
+        .await
+        .map_err(|source| Error::TlsCertLoad {
+            path: config.cert_path.clone(),
+            source,
+        })?;
+    let cert = if bytes.starts_with(b"-----BEGIN") {
+        reqwest::Certificate::from_pem(&bytes)
+    } else {
+        reqwest::Certificate::from_der(&bytes)
+    };
+    cert.map_err(|source| Error::TlsCertParse {
+        path: config.cert_path.clone(),
+        source,
+    })
+}
+
+/// Rejects configurations that can never produce a valid request.
+fn validate_config(config: &crate::config::Processor) -> Result<(), Error> {
+    use crate::config::{Method, PayloadSendAs};
+
+    if let Some(response_fields) = &config.response_fields {
+        parse_response_fields(response_fields)?;
+    }
+
+    if config.client_auth.is_some() && config.credentials_path.is_some() {
+        return Err(Error::InvalidConfiguration(
+            "client_auth and credentials_path cannot be used together".to_string(),
+        ));
+    }
+
+    if let Some(payload) = &config.payload {
+        if payload.send_as == PayloadSendAs::Multipart {
+            if !matches!(config.method, Method::Post | Method::Put | Method::Patch) {
+                return Err(Error::InvalidConfiguration(format!(
+                    "multipart payloads require POST, PUT or PATCH, got {:?}",
+                    config.method
+                )));
+            }
+            if payload.parts.is_empty() {
+                return Err(Error::InvalidConfiguration(
+                    "multipart payloads require at least one part".to_string(),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Event handler for processing HTTP requests.
 #[derive(Debug)]
 pub struct EventHandler {
@@ -128,9 +426,20 @@ pub struct EventHandler {
     task_type: &'static str,
     /// Task execution context providing metadata and runtime configuration.
     task_context: Arc<flowgen_core::task::context::TaskContext>,
+    /// Cached OAuth2 access token for `client_auth`.
+    token_cache: crate::oauth::TokenCache,
+    /// Whether the server has advertised gzip request body support.
+    server_accepts_gzip: AtomicBool,
 }
 
 impl EventHandler {
+    /// Whether JSON request bodies should be gzip-compressed.
+    fn compress_body(&self, config: &crate::config::Processor) -> bool {
+        config.compress_request
+            && (!config.require_server_accepts_gzip
+                || self.server_accepts_gzip.load(Ordering::Relaxed))
+    }
+
     /// Processes an event by making an HTTP request.
     #[tracing::instrument(skip(self, event), name = "task.handle")]
     async fn handle(&self, event: Event) -> Result<(), Error> {
@@ -183,29 +492,38 @@ impl EventHandler {
             }
 
             if let Some(payload) = &config.payload {
-                let event_data = if payload.from_event {
-                    event_value
-                        .get("event")
-                        .and_then(|e| e.get("data"))
-                        .ok_or_else(|| Error::MissingEventData)?
+                if payload.send_as == crate::config::PayloadSendAs::Multipart {
+                    client = client.multipart(multipart_form(&payload.parts)?);
                 } else {
-                    &match &payload.object {
-                        Some(obj) => Value::Object(obj.to_owned()),
-                        None => match &payload.input {
-                            Some(input) => {
-                                serde_json::from_str::<serde_json::Value>(input.as_str())
-                                    .map_err(|source| Error::SerdeJson { source })?
-                            }
-                            None => return Err(Error::PayloadConfig),
-                        },
-                    }
-                };
+                    let event_data = if payload.from_event {
+                        event_value
+                            .get("event")
+                            .and_then(|e| e.get("data"))
+                            .ok_or_else(|| Error::MissingEventData)?
+                    } else {
+                        &match &payload.object {
+                            Some(obj) => Value::Object(obj.to_owned()),
+                            None => match &payload.input {
+                                Some(input) => {
+                                    serde_json::from_str::<serde_json::Value>(input.as_str())
+                                        .map_err(|source| Error::SerdeJson { source })?
+                                }
+                                None => return Err(Error::PayloadConfig),
+                            },
+                        }
+                    };
 
-                client = match payload.send_as {
-                    crate::config::PayloadSendAs::Json => client.json(&event_data),
-                    crate::config::PayloadSendAs::UrlEncoded => client.form(&event_data),
-                    crate::config::PayloadSendAs::QueryParams => client.query(&event_data),
-                };
+                    client = match payload.send_as {
+                        crate::config::PayloadSendAs::Json if self.compress_body(&config) => client
+                            .header(CONTENT_TYPE, "application/json")
+                            .header(CONTENT_ENCODING, "gzip")
+                            .body(gzip_json(event_data)?),
+                        crate::config::PayloadSendAs::Json => client.json(&event_data),
+                        crate::config::PayloadSendAs::UrlEncoded => client.form(&event_data),
+                        crate::config::PayloadSendAs::QueryParams => client.query(&event_data),
+                        crate::config::PayloadSendAs::Multipart => client,
+                    };
+                }
             }
 
             if let Some(credentials_path) = &config.credentials_path {
@@ -228,19 +546,77 @@ impl EventHandler {
                 }
             };
 
+            if let Some(client_auth) = &config.client_auth {
+                client = match client_auth {
+                    HttpAuth::BearerTokenFile(path) => {
+                        let token = fs::read_to_string(path).await.map_err(|source| {
+                            Error::ReadBearerTokenFile {
+                                path: path.clone(),
+                                source,
+                            }
+                        })?;
+                        client.bearer_auth(token.trim())
+                    }
+                    HttpAuth::BasicAuth { username, password } => {
+                        client.basic_auth(username, Some(password))
+                    }
+                    HttpAuth::OAuth2ClientCredentials(credentials) => {
+                        let token = self
+                            .token_cache
+                            .token(&self.client, credentials)
+                            .await
+                            .map_err(|source| Error::OAuth2 { source })?;
+                        client.bearer_auth(token)
+                    }
+                };
+            }
+
             let response = client.send().await.map_err(|source| Error::Reqwest {
                 endpoint: endpoint.clone(),
                 method: method.clone(),
                 source,
             })?;
 
+            // Reqwest strips `Content-Encoding` from responses it decoded, so a
+            // header that survives means the body is still in that encoding.
+            if let Some(content_encoding) =
+                response.headers().get(reqwest::header::CONTENT_ENCODING)
+            {
+                warn!(
+                    endpoint = %endpoint,
+                    content_encoding = ?content_encoding,
+                    "Response uses an unsupported Content-Encoding and was not decompressed"
+                );
+            }
+
             let status = response.status();
+            if config.compress_request && config.require_server_accepts_gzip {
+                // Servers advertise accepted request codings via Accept-Encoding
+                // (RFC 7694) and answer 415 when they reject one.
+                if accepts_gzip(response.headers()) {
+                    self.server_accepts_gzip.store(true, Ordering::Relaxed);
+                } else if status == reqwest::StatusCode::UNSUPPORTED_MEDIA_TYPE {
+                    self.server_accepts_gzip.store(false, Ordering::Relaxed);
+                }
+            }
+
             let body = response.text().await.map_err(|source| Error::Reqwest {
                 endpoint: endpoint.clone(),
                 method: method.clone(),
                 source,
             })?;
 
+            if status == reqwest::StatusCode::UNAUTHORIZED
+                && matches!(
+                    config.client_auth,
+                    Some(HttpAuth::OAuth2ClientCredentials(_))
+                )
+            {
+                // The token may have been revoked before it expired; fetch a
+                // fresh one for the next request.
+                self.token_cache.invalidate().await;
+            }
+
             if status.is_client_error() || status.is_server_error() {
                 return Err(Error::HttpError {
                     endpoint: endpoint.clone(),
@@ -250,10 +626,24 @@ impl EventHandler {
                 });
             }
 
-            let data = serde_json::from_str::<Value>(&body).unwrap_or_else(|_| json!(body));
+            let data = match &config.response_fields {
+                Some(response_fields) => {
+                    let response = serde_json::from_str::<Value>(&body)
+                        .map_err(|source| Error::SerdeJson { source })?;
+                    EventData::ArrowRecordBatch(extract_response_fields(
+                        &response,
+                        response_fields,
+                    )?)
+                }
+                None => EventData::Json(
+                    serde_json::from_str::<Value>(&body).unwrap_or_else(|_| json!(body)),
+                ),
+            };
 
+            // Extracted response fields may legitimately match no rows.
             let mut e = event_builder
-                .data(EventData::Json(data))
+                .data(data)
+                .allow_empty(config.response_fields.is_some())
                 .subject(self.config.name.to_owned())
                 .task_id(self.task_id)
                 .task_type(self.task_type)
@@ -317,7 +707,7 @@ impl flowgen_core::task::runner::Runner for Processor {
         // downstream consumers see plaintext. Servers that do not compress
         // simply ignore the request header.
         let mut builder = reqwest::ClientBuilder::new()
-            .https_only(true)
+            .https_only(!self.config.allow_http)
             .gzip(true)
             .brotli(true)
             .deflate(true);
@@ -330,6 +720,19 @@ impl flowgen_core::task::runner::Runner for Processor {
         if let Some(connect_timeout) = self.config.connect_timeout {
             builder = builder.connect_timeout(connect_timeout);
         }
+        if let Some(proxy) = &self.config.proxy {
+            // Render once at init so credentials can come from `{{env.VAR}}`.
+            let proxy = proxy
+                .render(&json!({}))
+                .map_err(|source| Error::ConfigRender { source })?;
+            builder = builder.proxy(build_proxy(&proxy)?);
+        }
+        if let Some(tls_pin) = &self.config.tls_pin {
+            // Trust only the pinned certificate.
+            builder = builder
+                .add_root_certificate(load_pinned_cert(tls_pin).await?)
+                .tls_built_in_root_certs(false);
+        }
         let client = builder
             .build()
             .map_err(|source| Error::ClientInit { source })?;
@@ -342,6 +745,8 @@ impl flowgen_core::task::runner::Runner for Processor {
             client,
             task_type: self.task_type,
             task_context: Arc::clone(&self.task_context),
+            token_cache: crate::oauth::TokenCache::new(),
+            server_accepts_gzip: AtomicBool::new(false),
         };
 
         Ok(event_handler)
@@ -485,10 +890,13 @@ impl ProcessorBuilder {
     }
 
     pub async fn build(self) -> Result<Processor, Error> {
+        let config = self
+            .config
+            .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?;
+        validate_config(&config)?;
+
         Ok(Processor {
-            config: self
-                .config
-                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            config,
             rx: self
                 .rx
                 .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
@@ -507,6 +915,8 @@ impl ProcessorBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use arrow::array::{Array, AsArray};
+    use arrow::datatypes::{Float64Type, Int64Type};
     use flowgen_core::credentials::BasicAuth;
     use serde_json::Map;
     use tokio::sync::mpsc;
@@ -624,6 +1034,248 @@ mod tests {
         assert!(matches!(error, Error::SerdeJson { .. }));
     }
 
+    #[test]
+    fn test_multipart_form() {
+        let parts = vec![
+            crate::config::MultipartPart {
+                name: "job".to_string(),
+                value: "{}".to_string(),
+                filename: None,
+                content_type: Some("application/json".to_string()),
+            },
+            crate::config::MultipartPart {
+                name: "content".to_string(),
+                value: "Id,Name".to_string(),
+                filename: Some("accounts.csv".to_string()),
+                content_type: None,
+            },
+        ];
+        assert!(multipart_form(&parts).is_ok());
+
+        let invalid = vec![crate::config::MultipartPart {
+            name: "bad".to_string(),
+            value: String::new(),
+            filename: None,
+            content_type: Some("not a mime type".to_string()),
+        }];
+        assert!(
+            matches!(multipart_form(&invalid), Err(Error::MultipartPart { name, .. }) if name == "bad")
+        );
+    }
+
+    #[test]
+    fn test_gzip_json_round_trip() {
+        let value = json!({"records": [{"id": 1}, {"id": 2}]});
+        let compressed = gzip_json(&value).unwrap();
+
+        let mut decoder = flate2::read::GzDecoder::new(compressed.as_slice());
+        let mut decompressed = String::new();
+        std::io::Read::read_to_string(&mut decoder, &mut decompressed).unwrap();
+        assert_eq!(serde_json::from_str::<Value>(&decompressed).unwrap(), value);
+    }
+
+    #[test]
+    fn test_accepts_gzip() {
+        let mut headers = HeaderMap::new();
+        assert!(!accepts_gzip(&headers));
+
+        headers.insert(ACCEPT_ENCODING, HeaderValue::from_static("br, GZIP;q=0.8"));
+        assert!(accepts_gzip(&headers));
+
+        headers.insert(
+            ACCEPT_ENCODING,
+            HeaderValue::from_static("deflate, identity"),
+        );
+        assert!(!accepts_gzip(&headers));
+    }
+
+    #[test]
+    fn test_build_proxy() {
+        let config = crate::config::ProxyConfig {
+            url: "http://proxy.example.com:3128".to_string(),
+            no_proxy: Some(vec!["localhost".to_string()]),
+            credentials: Some(crate::config::ProxyCredentials {
+                username: "user".to_string(),
+                password: "secret".to_string(),
+            }),
+        };
+        assert!(build_proxy(&config).is_ok());
+    }
+
+    #[test]
+    fn test_build_proxy_invalid_url() {
+        let config = crate::config::ProxyConfig {
+            url: "not a url".to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            build_proxy(&config),
+            Err(Error::InvalidProxy { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_load_pinned_cert_missing_file() {
+        let config = crate::config::TlsPinConfig {
+            cert_path: std::path::PathBuf::from("/nonexistent/pinned.pem"),
+        };
+        assert!(matches!(
+            load_pinned_cert(&config).await,
+            Err(Error::TlsCertLoad { .. })
+        ));
+    }
+
+    #[test]
+    fn test_extract_response_fields() {
+        let response = json!({
+            "status": "ok",
+            "data": [
+                {"id": 1, "name": "a", "active": true, "price": 1.5, "ref": 7},
+                {"id": 2, "name": null, "active": null, "price": 2, "ref": "x"}
+            ]
+        });
+        let fields = HashMap::from([
+            ("id".to_string(), "$.data[*].id".to_string()),
+            ("name".to_string(), "$.data[*].name".to_string()),
+            ("active".to_string(), "$.data[*].active".to_string()),
+            ("price".to_string(), "$.data[*].price".to_string()),
+            ("ref".to_string(), "$.data[*].ref".to_string()),
+            ("status".to_string(), "$.status".to_string()),
+            ("missing".to_string(), "$.nothing".to_string()),
+        ]);
+
+        let batch = extract_response_fields(&response, &fields).unwrap();
+        assert_eq!(batch.num_rows(), 2);
+
+        let schema = batch.schema();
+        let column_types: Vec<(&str, &DataType)> = schema
+            .fields()
+            .iter()
+            .map(|f| (f.name().as_str(), f.data_type()))
+            .collect();
+        assert_eq!(
+            column_types,
+            vec![
+                ("active", &DataType::Boolean),
+                ("id", &DataType::Int64),
+                ("missing", &DataType::Null),
+                ("name", &DataType::Utf8),
+                ("price", &DataType::Float64),
+                ("ref", &DataType::Utf8),
+                ("status", &DataType::Utf8),
+            ]
+        );
+
+        let column = |name: &str| batch.column_by_name(name).unwrap().clone();
+        assert_eq!(column("id").as_primitive::<Int64Type>().value(1), 2);
+        assert!(column("active").as_boolean().value(0));
+        assert!(column("active").is_null(1));
+        assert_eq!(column("price").as_primitive::<Float64Type>().value(1), 2.0);
+        assert_eq!(column("ref").as_string::<i32>().value(0), "7");
+        assert!(column("name").is_null(1));
+        assert_eq!(column("status").as_string::<i32>().value(1), "ok");
+        assert_eq!(column("missing").len(), 2);
+    }
+
+    #[test]
+    fn test_extract_response_fields_no_matches() {
+        let response = json!({"data": []});
+        let fields = HashMap::from([("id".to_string(), "$.data[*].id".to_string())]);
+
+        let batch = extract_response_fields(&response, &fields).unwrap();
+        assert_eq!(batch.num_rows(), 0);
+
+        let event = EventBuilder::new()
+            .data(EventData::ArrowRecordBatch(batch))
+            .allow_empty(true)
+            .subject("http_request".to_string())
+            .task_id(0)
+            .task_type("http_request")
+            .build();
+        assert!(event.is_ok());
+    }
+
+    #[test]
+    fn test_extract_response_fields_length_mismatch() {
+        let response = json!({"a": [1, 2, 3], "b": [1, 2]});
+        let fields = HashMap::from([
+            ("a".to_string(), "$.a[*]".to_string()),
+            ("b".to_string(), "$.b[*]".to_string()),
+        ]);
+
+        assert!(matches!(
+            extract_response_fields(&response, &fields),
+            Err(Error::ResponseFieldLength { field, expected: 3, actual: 2 }) if field == "b"
+        ));
+    }
+
+    #[test]
+    fn test_invalid_response_field_path() {
+        let fields = HashMap::from([("id".to_string(), "data[".to_string())]);
+        assert!(matches!(
+            parse_response_fields(&fields),
+            Err(Error::InvalidJsonPath { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_processor_builder_rejects_multipart_get() {
+        let config = Arc::new(crate::config::Processor {
+            name: "upload".to_string(),
+            endpoint: "https://test.com/upload".to_string(),
+            method: crate::config::Method::Get,
+            payload: Some(crate::config::Payload {
+                send_as: crate::config::PayloadSendAs::Multipart,
+                parts: vec![crate::config::MultipartPart {
+                    name: "file".to_string(),
+                    value: "data".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let (_tx, rx) = mpsc::channel(1);
+
+        let result = ProcessorBuilder::new()
+            .config(config)
+            .receiver(rx)
+            .task_type("test")
+            .task_context(create_mock_task_context())
+            .build()
+            .await;
+        assert!(matches!(
+            result.unwrap_err(),
+            Error::InvalidConfiguration(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_processor_builder_rejects_client_auth_with_credentials_path() {
+        let config = Arc::new(crate::config::Processor {
+            name: "api".to_string(),
+            endpoint: "https://test.com".to_string(),
+            credentials_path: Some(std::path::PathBuf::from("/etc/http/credentials.json")),
+            client_auth: Some(HttpAuth::BearerTokenFile(std::path::PathBuf::from(
+                "/etc/http/token",
+            ))),
+            ..Default::default()
+        });
+        let (_tx, rx) = mpsc::channel(1);
+
+        let result = ProcessorBuilder::new()
+            .config(config)
+            .receiver(rx)
+            .task_type("test")
+            .task_context(create_mock_task_context())
+            .build()
+            .await;
+        assert!(matches!(
+            result.unwrap_err(),
+            Error::InvalidConfiguration(_)
+        ));
+    }
+
     #[tokio::test]
     async fn test_processor_builder() {
         let config = Arc::new(crate::config::Processor {
@@ -633,14 +1285,26 @@ mod tests {
             payload: None,
             headers: None,
             credentials_path: None,
+            client_auth: None,
             ack_timeout: None,
             timeout: crate::config::default_request_timeout(),
             connect_timeout: crate::config::default_connect_timeout(),
+            allow_http: false,
+            proxy: None,
+            tls_pin: None,
+            compress_request: false,
+            require_server_accepts_gzip: false,
             max_body_bytes: crate::config::default_max_body_bytes(),
             stream: false,
             auth: None,
+            response_fields: None,
+            routes: vec![],
+            default_subject: None,
+            response_body: None,
+            response_status: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
         let (tx, rx) = mpsc::channel(100);
 
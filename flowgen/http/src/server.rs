@@ -4,8 +4,15 @@
 //! to register routes dynamically before starting the server.
 
 use axum::{http::StatusCode, routing::MethodRouter, Router};
-use flowgen_core::auth::AuthProvider;
-use std::{collections::HashMap, sync::Arc};
+use flowgen_core::{
+    auth::AuthProvider,
+    health::{HealthCheck, HealthCheckHandle, HealthStatus},
+    metrics::{FlowMetrics, MetricsSnapshot},
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Weak},
+};
 use tokio::sync::{Mutex, RwLock};
 use tracing::{info, warn};
 
@@ -18,6 +25,12 @@ const DEFAULT_ROUTES_PREFIX: &str = "/api/flowgen/workers";
 /// Default health check endpoint path for Kubernetes readiness and liveness probes.
 const DEFAULT_HEALTH_PATH: &str = "/healthz";
 
+/// Default endpoint path for flow throughput metrics in Prometheus text format.
+const DEFAULT_METRICS_PATH: &str = "/metrics";
+
+/// Default endpoint path for the aggregated component health checks.
+const DEFAULT_READINESS_PATH: &str = "/health/ready";
+
 /// Errors that can occur during HTTP server operations.
 #[derive(thiserror::Error, Debug)]
 #[non_exhaustive]
@@ -54,6 +67,11 @@ pub struct HttpServer {
     credentials_path: Option<std::path::PathBuf>,
     /// Optional auth provider for user identity resolution (JWT, OIDC, session).
     auth_provider: Option<Arc<dyn AuthProvider>>,
+    /// Throughput counters per flow name, rendered on the metrics endpoint.
+    metrics: Arc<RwLock<HashMap<String, FlowMetrics>>>,
+    /// Component health checks by name, run on the readiness endpoint. Only
+    /// weak references are held, so a check goes away with its handle.
+    health_checks: Arc<RwLock<HashMap<String, Weak<dyn HealthCheck>>>>,
 }
 
 impl std::fmt::Debug for HttpServer {
@@ -109,6 +127,8 @@ impl HttpServerBuilder {
             path: self.path,
             credentials_path: self.credentials_path,
             auth_provider: self.auth_provider,
+            metrics: Arc::new(RwLock::new(HashMap::new())),
+            health_checks: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 }
@@ -134,6 +154,109 @@ impl flowgen_core::http_server::HttpServer for HttpServer {
     fn auth_provider(&self) -> Option<Arc<dyn AuthProvider>> {
         self.auth_provider.clone()
     }
+
+    async fn register_metrics(&self, flow_name: String, metrics: FlowMetrics) {
+        let mut registered = self.metrics.write().await;
+        registered.insert(flow_name, metrics);
+    }
+
+    async fn register_health_check(
+        &self,
+        name: String,
+        check: Arc<dyn HealthCheck>,
+    ) -> HealthCheckHandle {
+        let mut registered = self.health_checks.write().await;
+        registered.retain(|_, check| check.strong_count() > 0);
+        registered.insert(name, Arc::downgrade(&check));
+        HealthCheckHandle::new(check)
+    }
+}
+
+/// Runs all health checks concurrently and returns the overall status with a
+/// JSON report of every check.
+async fn run_health_checks(
+    checks: &HashMap<String, Weak<dyn HealthCheck>>,
+) -> (HealthStatus, serde_json::Value) {
+    // Checks whose handle was dropped are skipped.
+    let results = futures_util::future::join_all(checks.iter().filter_map(|(name, check)| {
+        let check = check.upgrade()?;
+        Some(async move { (name.clone(), check.check().await) })
+    }))
+    .await;
+
+    let status = flowgen_core::health::overall_status(results.iter().map(|(_, result)| result));
+    let checks: serde_json::Map<_, _> = results
+        .into_iter()
+        .map(|(name, result)| (name, serde_json::json!(result)))
+        .collect();
+
+    (
+        status,
+        serde_json::json!({ "status": status, "checks": checks }),
+    )
+}
+
+/// Renders flow counters in the Prometheus text exposition format.
+///
+/// Reading the counters takes a snapshot, so the reported rate covers the
+/// interval since the previous scrape.
+fn render_metrics(metrics: &HashMap<String, FlowMetrics>) -> String {
+    let mut flows: Vec<_> = metrics.iter().collect();
+    flows.sort_by(|a, b| a.0.cmp(b.0));
+
+    let samples: Vec<_> = flows
+        .into_iter()
+        .map(|(name, metrics)| {
+            let rate = metrics.rate();
+            (name, metrics.snapshot(), rate)
+        })
+        .collect();
+
+    let mut output = String::new();
+    let counters: [(&str, &str, fn(&MetricsSnapshot) -> u64); 4] = [
+        (
+            "flowgen_events_received_total",
+            "Events emitted by source tasks.",
+            |s| s.events_received,
+        ),
+        (
+            "flowgen_events_processed_total",
+            "Events written by publisher tasks.",
+            |s| s.events_processed,
+        ),
+        (
+            "flowgen_events_failed_total",
+            "Events that failed after all retry attempts.",
+            |s| s.events_failed,
+        ),
+        (
+            "flowgen_bytes_processed_total",
+            "Payload bytes written by publisher tasks.",
+            |s| s.bytes_processed,
+        ),
+    ];
+
+    for (metric, help, value) in counters {
+        output.push_str(&format!(
+            "# HELP {metric} {help}\n# TYPE {metric} counter\n"
+        ));
+        for (name, snapshot, _) in &samples {
+            output.push_str(&format!(
+                "{metric}{{flow=\"{name}\"}} {}\n",
+                value(snapshot)
+            ));
+        }
+    }
+
+    output.push_str("# HELP flowgen_events_processed_per_second Processed events per second since the last scrape.\n");
+    output.push_str("# TYPE flowgen_events_processed_per_second gauge\n");
+    for (name, _, rate) in &samples {
+        output.push_str(&format!(
+            "flowgen_events_processed_per_second{{flow=\"{name}\"}} {rate}\n"
+        ));
+    }
+
+    output
 }
 
 impl HttpServer {
@@ -170,11 +293,33 @@ impl HttpServer {
             .clone()
             .unwrap_or_else(|| DEFAULT_ROUTES_PREFIX.to_string());
 
+        let metrics = Arc::clone(&self.metrics);
+        let health_checks = Arc::clone(&self.health_checks);
         let router = Router::new()
             .route(
                 DEFAULT_HEALTH_PATH,
                 axum::routing::get(|| async { StatusCode::OK }),
             )
+            .route(
+                DEFAULT_METRICS_PATH,
+                axum::routing::get(move || async move {
+                    let metrics = metrics.read().await;
+                    render_metrics(&metrics)
+                }),
+            )
+            .route(
+                DEFAULT_READINESS_PATH,
+                axum::routing::get(move || async move {
+                    let checks = health_checks.read().await;
+                    let (status, report) = run_health_checks(&checks).await;
+                    // Degraded components still serve traffic, only a down one fails the probe.
+                    let code = match status {
+                        HealthStatus::Down => StatusCode::SERVICE_UNAVAILABLE,
+                        HealthStatus::Ok | HealthStatus::Degraded => StatusCode::OK,
+                    };
+                    (code, axum::Json(report))
+                }),
+            )
             .nest(&base_path, api_router);
         let server_port = port.unwrap_or(DEFAULT_HTTP_PORT);
         let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{server_port}"))
@@ -203,6 +348,70 @@ mod tests {
     use super::*;
     use axum::routing::get;
 
+    #[test]
+    fn test_render_metrics() {
+        let metrics = FlowMetrics::new();
+        metrics.record_received();
+        metrics.record_processed(42);
+
+        let mut registered = HashMap::new();
+        registered.insert("orders".to_string(), metrics);
+
+        let output = render_metrics(&registered);
+        assert!(output.contains("# TYPE flowgen_events_received_total counter"));
+        assert!(output.contains("flowgen_events_received_total{flow=\"orders\"} 1"));
+        assert!(output.contains("flowgen_events_processed_total{flow=\"orders\"} 1"));
+        assert!(output.contains("flowgen_bytes_processed_total{flow=\"orders\"} 42"));
+        assert!(output.contains("flowgen_events_failed_total{flow=\"orders\"} 0"));
+    }
+
+    #[tokio::test]
+    async fn test_register_metrics() {
+        use flowgen_core::http_server::HttpServer as _;
+
+        let server = HttpServerBuilder::new().build();
+        server
+            .register_metrics("orders".to_string(), FlowMetrics::new())
+            .await;
+
+        assert!(server.metrics.read().await.contains_key("orders"));
+    }
+
+    #[tokio::test]
+    async fn test_run_health_checks() {
+        use flowgen_core::health::{HealthResult, HealthState};
+        use flowgen_core::http_server::HttpServer as _;
+
+        let server = HttpServerBuilder::new().build();
+        let subscriber = HealthState::new(HealthStatus::Ok);
+        let publisher = HealthState::new(HealthStatus::Ok);
+        let subscriber_handle = server
+            .register_health_check("orders.subscriber".to_string(), Arc::new(subscriber))
+            .await;
+        let _publisher_handle = server
+            .register_health_check("orders.publisher".to_string(), Arc::new(publisher.clone()))
+            .await;
+
+        let (status, report) = run_health_checks(&*server.health_checks.read().await).await;
+        assert_eq!(status, HealthStatus::Ok);
+        assert_eq!(report["checks"]["orders.subscriber"]["status"], "ok");
+
+        publisher.set(HealthResult::new(HealthStatus::Down).detail("state", "disconnected"));
+
+        let (status, report) = run_health_checks(&*server.health_checks.read().await).await;
+        assert_eq!(status, HealthStatus::Down);
+        assert_eq!(report["status"], "down");
+        assert_eq!(
+            report["checks"]["orders.publisher"]["details"]["state"],
+            "disconnected"
+        );
+
+        drop(subscriber_handle);
+        let (_, report) = run_health_checks(&*server.health_checks.read().await).await;
+        assert!(report["checks"].get("orders.subscriber").is_none());
+        assert!(report["checks"].get("orders.publisher").is_some());
+    }
+
     #[test]
     fn test_http_server_builder() {
         let server = HttpServerBuilder::new().build();
@@ -85,12 +85,18 @@ pub enum Error {
     FlowCompletionFailed,
     #[error("Request body exceeds configured max_body_bytes limit of {limit} bytes")]
     BodyTooLarge { limit: usize },
+    #[error("Request matched no configured webhook route")]
+    NoMatchingRoute,
+    #[error("Invalid webhook response_status: {0}")]
+    InvalidResponseStatus(u16),
 }
 
 impl IntoResponse for Error {
     fn into_response(self) -> axum::response::Response {
         let status = match &self {
-            Error::SerdeJson { .. } | Error::Axum { .. } => StatusCode::BAD_REQUEST,
+            Error::SerdeJson { .. } | Error::Axum { .. } | Error::NoMatchingRoute => {
+                StatusCode::BAD_REQUEST
+            }
             Error::BodyTooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         };
@@ -198,12 +204,35 @@ impl EventHandler {
             .map_err(|_| Error::InvalidCredentials)
     }
 
-    /// Parse and validate the incoming request, returning the JSON data and optional user context.
+    /// Resolve the event subject from the configured routes.
+    ///
+    /// Without routes every event uses the task name. Otherwise the first
+    /// route whose header value matches wins, falling back to `default_subject`.
+    fn resolve_subject(&self, headers: &HeaderMap) -> Result<String, Error> {
+        if self.config.routes.is_empty() {
+            return Ok(self.config.name.to_owned());
+        }
+
+        self.config
+            .routes
+            .iter()
+            .find(|route| {
+                headers
+                    .get(route.event_type_header.as_str())
+                    .and_then(|value| value.to_str().ok())
+                    .is_some_and(|value| value == route.event_type_value)
+            })
+            .map(|route| route.subject.to_owned())
+            .or_else(|| self.config.default_subject.to_owned())
+            .ok_or(Error::NoMatchingRoute)
+    }
+
+    /// Parse and validate the incoming request, returning the event subject, JSON data and optional user context.
     async fn parse_request(
         &self,
         headers: &HeaderMap,
         request: Request<Body>,
-    ) -> Result<(Value, Option<flowgen_core::auth::UserContext>), Error> {
+    ) -> Result<(String, Value, Option<flowgen_core::auth::UserContext>), Error> {
         if self.task_context.cancellation_token.is_cancelled() {
             return Err(Error::FlowCompletionFailed);
         }
@@ -212,6 +241,8 @@ impl EventHandler {
 
         let user_context = self.validate_user_auth(headers).await?;
 
+        let subject = self.resolve_subject(headers)?;
+
         // Bound body size to prevent oversized POSTs from exhausting
         // worker memory. Axum returns an error if the body exceeds the
         // limit; we map that to a typed BodyTooLarge so the response is
@@ -244,12 +275,13 @@ impl EventHandler {
             DEFAULT_PAYLOAD_KEY: json_body
         });
 
-        Ok((data, user_context))
+        Ok((subject, data, user_context))
     }
 
     /// Build an event with completion channel and inject into pipeline.
     async fn inject_event(
         &self,
+        subject: String,
         data: Value,
         meta: Option<serde_json::Map<String, Value>>,
     ) -> Result<CompletionRx, Error> {
@@ -260,7 +292,7 @@ impl EventHandler {
 
         let mut builder = EventBuilder::new()
             .data(EventData::Json(data))
-            .subject(self.config.name.to_owned())
+            .subject(subject)
             .task_id(self.task_id)
             .task_type(self.task_type)
             .completion_tx(completion_state);
@@ -280,20 +312,36 @@ impl EventHandler {
         Ok(completion_rx)
     }
 
+    /// Build the acknowledgement returned after the flow completes.
+    fn ack_response(&self) -> axum::response::Response {
+        let status = self
+            .config
+            .response_status
+            .and_then(|status| StatusCode::from_u16(status).ok())
+            .unwrap_or(StatusCode::OK);
+        match &self.config.response_body {
+            Some(body) => (status, axum::Json(body.clone())).into_response(),
+            None => status.into_response(),
+        }
+    }
+
     async fn handle(
         &self,
         headers: HeaderMap,
         request: Request<Body>,
-    ) -> Result<StatusCode, Error> {
-        let (data, user_context) = match self.parse_request(&headers, request).await {
+    ) -> Result<axum::response::Response, Error> {
+        let (subject, data, user_context) = match self.parse_request(&headers, request).await {
             Ok(result) => result,
-            Err(Error::FlowCompletionFailed) => return Ok(StatusCode::SERVICE_UNAVAILABLE),
+            Err(Error::FlowCompletionFailed) => {
+                return Ok(StatusCode::SERVICE_UNAVAILABLE.into_response())
+            }
             Err(
                 e
                 @ (Error::NoCredentials | Error::InvalidCredentials | Error::MalformedCredentials),
             ) => {
-                return Ok(e.into_response().status());
+                return Ok(e.into_response());
             }
+            Err(e @ Error::NoMatchingRoute) => return Ok(e.into_response()),
             Err(e) => return Err(e),
         };
 
@@ -305,24 +353,24 @@ impl EventHandler {
             meta
         });
 
-        let completion_rx = self.inject_event(data, meta).await?;
+        let completion_rx = self.inject_event(subject, data, meta).await?;
 
         // Wait for flow completion before responding to HTTP request.
         match self.config.ack_timeout {
             Some(timeout) => match tokio::time::timeout(timeout, completion_rx).await {
-                Ok(Ok(Ok(_))) => Ok(StatusCode::OK),
+                Ok(Ok(Ok(_))) => Ok(self.ack_response()),
                 Ok(Ok(Err(_))) | Ok(Err(_)) | Err(_) => {
                     error!("{}", Error::FlowCompletionFailed);
-                    Ok(StatusCode::INTERNAL_SERVER_ERROR)
+                    Ok(StatusCode::INTERNAL_SERVER_ERROR.into_response())
                 }
             },
             None => {
                 // No timeout configured, wait indefinitely.
                 match completion_rx.await {
-                    Ok(Ok(_)) => Ok(StatusCode::OK),
+                    Ok(Ok(_)) => Ok(self.ack_response()),
                     Ok(Err(_)) | Err(_) => {
                         error!("{}", Error::FlowCompletionFailed);
-                        Ok(StatusCode::INTERNAL_SERVER_ERROR)
+                        Ok(StatusCode::INTERNAL_SERVER_ERROR.into_response())
                     }
                 }
             }
@@ -335,7 +383,7 @@ impl EventHandler {
         headers: HeaderMap,
         request: Request<Body>,
     ) -> Result<axum::response::Response, Error> {
-        let (data, user_context) = match self.parse_request(&headers, request).await {
+        let (subject, data, user_context) = match self.parse_request(&headers, request).await {
             Ok(result) => result,
             Err(Error::FlowCompletionFailed) => {
                 return Ok(StatusCode::SERVICE_UNAVAILABLE.into_response());
@@ -346,6 +394,7 @@ impl EventHandler {
             ) => {
                 return Ok(e.into_response());
             }
+            Err(e @ Error::NoMatchingRoute) => return Ok(e.into_response()),
             Err(e) => return Err(e),
         };
 
@@ -380,7 +429,7 @@ impl EventHandler {
             }
         }
 
-        let completion_rx = self.inject_event(data, Some(meta)).await?;
+        let completion_rx = self.inject_event(subject, data, Some(meta)).await?;
 
         info!(
             webhook = %self.config.name,
@@ -685,10 +734,15 @@ impl ProcessorBuilder {
     }
 
     pub async fn build(self) -> Result<Processor, Error> {
+        let config = self
+            .config
+            .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?;
+        if let Some(status) = config.response_status {
+            StatusCode::from_u16(status).map_err(|_| Error::InvalidResponseStatus(status))?;
+        }
+
         Ok(Processor {
-            config: self
-                .config
-                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            config,
             tx: self.tx,
             task_id: self.task_id,
             task_context: self
@@ -773,14 +827,26 @@ mod tests {
             payload: None,
             headers: None,
             credentials_path: None,
+            client_auth: None,
             ack_timeout: None,
             timeout: crate::config::default_request_timeout(),
             connect_timeout: crate::config::default_connect_timeout(),
+            allow_http: false,
+            proxy: None,
+            tls_pin: None,
+            compress_request: false,
+            require_server_accepts_gzip: false,
             max_body_bytes: crate::config::default_max_body_bytes(),
             stream: false,
             auth: None,
+            response_fields: None,
+            routes: vec![],
+            default_subject: None,
+            response_body: None,
+            response_status: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
         let (tx, _rx) = mpsc::channel(100);
 
@@ -844,14 +910,26 @@ mod tests {
             payload: None,
             headers: Some(configured_headers),
             credentials_path: None,
+            client_auth: None,
             ack_timeout: None,
             timeout: crate::config::default_request_timeout(),
             connect_timeout: crate::config::default_connect_timeout(),
+            allow_http: false,
+            proxy: None,
+            tls_pin: None,
+            compress_request: false,
+            require_server_accepts_gzip: false,
             max_body_bytes: crate::config::default_max_body_bytes(),
             stream: false,
             auth: None,
+            response_fields: None,
+            routes: vec![],
+            default_subject: None,
+            response_body: None,
+            response_status: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
 
         let (tx, _rx) = mpsc::channel(100);
@@ -883,14 +961,26 @@ mod tests {
             payload: None,
             headers: None,
             credentials_path: None,
+            client_auth: None,
             ack_timeout: None,
             timeout: crate::config::default_request_timeout(),
             connect_timeout: crate::config::default_connect_timeout(),
+            allow_http: false,
+            proxy: None,
+            tls_pin: None,
+            compress_request: false,
+            require_server_accepts_gzip: false,
             max_body_bytes: crate::config::default_max_body_bytes(),
             stream: false,
             auth: None,
+            response_fields: None,
+            routes: vec![],
+            default_subject: None,
+            response_body: None,
+            response_status: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
 
         let (tx, _rx) = mpsc::channel(100);
@@ -908,4 +998,115 @@ mod tests {
 
         assert!(handler.config.headers.is_none());
     }
+
+    fn routed_handler(default_subject: Option<String>) -> EventHandler {
+        let config = Arc::new(crate::config::Processor {
+            name: "github".to_string(),
+            endpoint: "/github".to_string(),
+            routes: vec![
+                crate::config::WebhookRoute {
+                    event_type_header: "X-GitHub-Event".to_string(),
+                    event_type_value: "push".to_string(),
+                    subject: "github.push".to_string(),
+                },
+                crate::config::WebhookRoute {
+                    event_type_header: "X-GitHub-Event".to_string(),
+                    event_type_value: "pull_request".to_string(),
+                    subject: "github.pull_request".to_string(),
+                },
+            ],
+            default_subject,
+            ..Default::default()
+        });
+        let (tx, _rx) = mpsc::channel(1);
+
+        EventHandler {
+            config,
+            tx: Some(tx),
+            task_id: 1,
+            credentials: None,
+            task_type: "test",
+            task_context: create_mock_task_context(),
+            response_registry: None,
+            auth_provider: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ack_response() {
+        let mut handler = routed_handler(None);
+        let response = handler.ack_response();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        handler.config = Arc::new(crate::config::Processor {
+            response_body: Some(json!({"status": "ok"})),
+            response_status: Some(202),
+            ..Default::default()
+        });
+        let response = handler.ack_response();
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        assert_eq!(
+            response.headers().get("content-type").unwrap(),
+            "application/json"
+        );
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(
+            serde_json::from_slice::<Value>(&body).unwrap(),
+            json!({"status": "ok"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_processor_builder_rejects_invalid_response_status() {
+        let config = Arc::new(crate::config::Processor {
+            name: "hook".to_string(),
+            response_status: Some(42),
+            ..Default::default()
+        });
+        let result = ProcessorBuilder::new()
+            .config(config)
+            .task_type("test")
+            .task_context(create_mock_task_context())
+            .build()
+            .await;
+        assert!(matches!(result, Err(Error::InvalidResponseStatus(42))));
+    }
+
+    #[test]
+    fn test_resolve_subject_without_routes() {
+        let mut handler = routed_handler(None);
+        handler.config = Arc::new(crate::config::Processor {
+            name: "plain".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(handler.resolve_subject(&HeaderMap::new()).unwrap(), "plain");
+    }
+
+    #[test]
+    fn test_resolve_subject_matches_route() {
+        let handler = routed_handler(None);
+        let mut headers = HeaderMap::new();
+        headers.insert("x-github-event", "pull_request".parse().unwrap());
+        assert_eq!(
+            handler.resolve_subject(&headers).unwrap(),
+            "github.pull_request"
+        );
+    }
+
+    #[test]
+    fn test_resolve_subject_unmatched() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-github-event", "issues".parse().unwrap());
+
+        let handler = routed_handler(None);
+        assert!(matches!(
+            handler.resolve_subject(&headers),
+            Err(Error::NoMatchingRoute)
+        ));
+
+        let handler = routed_handler(Some("github.other".to_string()));
+        assert_eq!(handler.resolve_subject(&headers).unwrap(), "github.other");
+    }
 }
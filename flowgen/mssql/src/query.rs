@@ -141,6 +141,7 @@ impl EventHandler {
                 let empty_batch = RecordBatch::new_empty(Arc::new(Schema::empty()));
                 let mut result_event = EventBuilder::new()
                     .data(EventData::ArrowRecordBatch(empty_batch))
+                    .allow_empty(true)
                     .subject(format!("{}.{}", event.subject, config.name))
                     .task_id(self.task_id)
                     .task_type(self.task_type)
@@ -17,6 +17,15 @@ const DEFAULT_HISTORY: i64 = 10;
 /// have watchers that need to observe delete events after long disconnects.
 const DEFAULT_TOMBSTONE_TTL: Duration = Duration::from_secs(3600);
 
+/// Separator placed between the key prefix and the key.
+///
+/// NATS KV keys only allow `[-/_=.a-zA-Z0-9]`, so the prefix is joined with a
+/// dot, matching the dotted key hierarchy used elsewhere in flowgen.
+const KEY_PREFIX_SEPARATOR: char = '.';
+
+/// Maximum length of a normalized key prefix.
+const MAX_KEY_PREFIX_LENGTH: usize = 64;
+
 #[derive(thiserror::Error, Debug)]
 #[non_exhaustive]
 pub enum Error {
@@ -50,11 +59,21 @@ pub enum Error {
         #[source]
         source: async_nats::jetstream::kv::UpdateError,
     },
+    #[error("KV purge error: {source}")]
+    KVPurge {
+        #[source]
+        source: async_nats::jetstream::kv::PurgeError,
+    },
     #[error("KV bucket creation error: {source}")]
     KVBucketCreate {
         #[source]
         source: async_nats::jetstream::context::CreateKeyValueError,
     },
+    #[error("KV bucket deletion error: {source}")]
+    KVBucketDelete {
+        #[source]
+        source: async_nats::jetstream::context::KeyValueError,
+    },
     #[error("Missing required value KV Store")]
     MissingKVStore,
     #[error("Missing required value JetStream Context")]
@@ -73,8 +92,11 @@ pub enum Error {
 pub struct Cache {
     credentials_path: PathBuf,
     url: String,
+    tls: crate::client::TlsOptions,
     history: Option<i64>,
     tombstone_ttl: Option<Duration>,
+    key_prefix: Option<String>,
+    retry: Option<flowgen_core::retry::RetryConfig>,
     store: Option<async_nats::jetstream::kv::Store>,
     jetstream: Option<async_nats::jetstream::Context>,
 }
@@ -82,18 +104,7 @@ pub struct Cache {
 impl Cache {
     /// Connects to NATS and initializes the KV bucket.
     pub async fn init(mut self, bucket: &str) -> Result<Self, Error> {
-        let client = crate::client::ClientBuilder::new()
-            .credentials_path(self.credentials_path.clone())
-            .url(self.url.clone())
-            .build()
-            .map_err(|source| Error::ClientAuth { source })?
-            .connect()
-            .await
-            .map_err(|source| Error::ClientAuth { source })?;
-
-        let jetstream = client
-            .jetstream
-            .ok_or_else(|| Error::MissingJetStreamContext)?;
+        let jetstream = self.connect_jetstream().await?;
 
         let store = match jetstream.get_key_value(bucket).await {
             Ok(store) => store,
@@ -135,6 +146,76 @@ impl Cache {
         self.jetstream = Some(jetstream);
         Ok(self)
     }
+
+    /// Deletes the KV bucket and everything stored in it.
+    ///
+    /// Connects with the builder settings and does not require [`Cache::init`],
+    /// so it can be used to clean up buckets left behind by tests.
+    pub async fn delete_bucket(&self, bucket: &str) -> Result<(), Error> {
+        self.connect_jetstream()
+            .await?
+            .delete_key_value(bucket)
+            .await
+            .map_err(|source| Error::KVBucketDelete { source })?;
+        Ok(())
+    }
+
+    /// Connects to NATS and returns the JetStream context.
+    async fn connect_jetstream(&self) -> Result<async_nats::jetstream::Context, Error> {
+        let mut client_builder = crate::client::ClientBuilder::new();
+        client_builder
+            .credentials_path(self.credentials_path.clone())
+            .url(self.url.clone())
+            .tls(self.tls.clone());
+        if let Some(retry) = &self.retry {
+            client_builder.retry(retry.clone());
+        }
+        let client = client_builder
+            .build()
+            .map_err(|source| Error::ClientAuth { source })?
+            .connect()
+            .await
+            .map_err(|source| Error::ClientAuth { source })?;
+
+        client
+            .jetstream
+            .ok_or_else(|| Error::MissingJetStreamContext)
+    }
+
+    /// Returns the key as stored in the bucket, with the key prefix applied if configured.
+    fn prefixed_key(&self, key: &str) -> String {
+        match &self.key_prefix {
+            Some(prefix) => format!("{prefix}{KEY_PREFIX_SEPARATOR}{key}"),
+            None => key.to_string(),
+        }
+    }
+
+    /// Strips the key prefix from a stored key, returning `None` for keys outside the prefix.
+    fn unprefixed_key<'a>(&self, key: &'a str) -> Option<&'a str> {
+        match &self.key_prefix {
+            Some(prefix) => key
+                .strip_prefix(prefix.as_str())
+                .and_then(|rest| rest.strip_prefix(KEY_PREFIX_SEPARATOR)),
+            None => Some(key),
+        }
+    }
+}
+
+/// Returns whether `c` is allowed in a NATS KV key.
+fn is_valid_key_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '-' | '/' | '_' | '=' | '.')
+}
+
+/// Normalizes a key prefix by removing characters NATS KV keys do not allow,
+/// trimming leading and trailing `.` so no empty key token is produced, and
+/// truncating it to `MAX_KEY_PREFIX_LENGTH` characters.
+fn normalize_key_prefix(prefix: &str) -> String {
+    let valid: String = prefix.chars().filter(|c| is_valid_key_char(*c)).collect();
+    valid
+        .trim_matches(KEY_PREFIX_SEPARATOR)
+        .chars()
+        .take(MAX_KEY_PREFIX_LENGTH)
+        .collect()
 }
 
 #[async_trait::async_trait]
@@ -155,7 +236,7 @@ impl flowgen_core::cache::Cache for Cache {
             .as_ref()
             .ok_or_else(|| flowgen_core::cache::CacheError::StoreNotInitialized)?;
 
-        let subject = format!("{}{}", &store.prefix, key);
+        let subject = format!("{}{}", &store.prefix, self.prefixed_key(key));
 
         let mut headers = async_nats::HeaderMap::new();
         if let Some(ttl) = ttl_secs {
@@ -188,7 +269,7 @@ impl flowgen_core::cache::Cache for Cache {
             .store
             .as_ref()
             .ok_or_else(|| flowgen_core::cache::CacheError::StoreNotInitialized)?;
-        store.get(key).await.map_err(|e| {
+        store.get(self.prefixed_key(key)).await.map_err(|e| {
             flowgen_core::cache::CacheError::GetFailed(Box::new(Error::KVEntry { source: e }))
         })
     }
@@ -198,12 +279,52 @@ impl flowgen_core::cache::Cache for Cache {
             .store
             .as_ref()
             .ok_or_else(|| flowgen_core::cache::CacheError::StoreNotInitialized)?;
-        store.delete(key).await.map_err(|e| {
+        store.delete(self.prefixed_key(key)).await.map_err(|e| {
             flowgen_core::cache::CacheError::DeleteFailed(Box::new(Error::KVDelete { source: e }))
         })?;
         Ok(())
     }
 
+    async fn purge(&self, key: &str) -> Result<(), flowgen_core::cache::Error> {
+        let store = self
+            .store
+            .as_ref()
+            .ok_or_else(|| flowgen_core::cache::CacheError::StoreNotInitialized)?;
+        store.purge(self.prefixed_key(key)).await.map_err(|e| {
+            flowgen_core::cache::CacheError::DeleteFailed(Box::new(Error::KVPurge { source: e }))
+        })?;
+        Ok(())
+    }
+
+    /// Purges every key in the bucket, or only keys under the key prefix if one is set.
+    ///
+    /// The KV store has no bulk purge, so keys are listed and purged one by one.
+    async fn clear(&self) -> Result<(), flowgen_core::cache::Error> {
+        let store = self
+            .store
+            .as_ref()
+            .ok_or_else(|| flowgen_core::cache::CacheError::StoreNotInitialized)?;
+
+        use futures_util::StreamExt;
+        let mut key_stream = store.keys().await.map_err(|e| {
+            flowgen_core::cache::CacheError::DeleteFailed(Box::new(Error::KVKeys { source: e }))
+        })?;
+        while let Some(result) = key_stream.next().await {
+            let key = result.map_err(|e| {
+                flowgen_core::cache::CacheError::DeleteFailed(Box::new(Error::KVKeys { source: e }))
+            })?;
+            if self.unprefixed_key(&key).is_none() {
+                continue;
+            }
+            store.purge(&key).await.map_err(|e| {
+                flowgen_core::cache::CacheError::DeleteFailed(Box::new(Error::KVPurge {
+                    source: e,
+                }))
+            })?;
+        }
+        Ok(())
+    }
+
     async fn create(
         &self,
         key: &str,
@@ -220,7 +341,7 @@ impl flowgen_core::cache::Cache for Cache {
             .as_ref()
             .ok_or_else(|| flowgen_core::cache::CacheError::StoreNotInitialized)?;
 
-        let subject = format!("{}{}", &store.prefix, key);
+        let subject = format!("{}{}", &store.prefix, self.prefixed_key(key));
 
         let mut headers = async_nats::HeaderMap::new();
         if let Some(ttl) = ttl_secs {
@@ -272,7 +393,7 @@ impl flowgen_core::cache::Cache for Cache {
             .as_ref()
             .ok_or_else(|| flowgen_core::cache::CacheError::StoreNotInitialized)?;
 
-        let subject = format!("{}{}", &store.prefix, key);
+        let subject = format!("{}{}", &store.prefix, self.prefixed_key(key));
 
         let mut headers = async_nats::HeaderMap::new();
         if let Some(ttl) = ttl_secs {
@@ -326,7 +447,7 @@ impl flowgen_core::cache::Cache for Cache {
             .as_ref()
             .ok_or_else(|| flowgen_core::cache::CacheError::StoreNotInitialized)?;
 
-        match store.entry(key).await {
+        match store.entry(self.prefixed_key(key)).await {
             Ok(Some(entry)) if !entry.value.is_empty() => {
                 let revision = entry.revision;
                 let value = entry.value;
@@ -350,7 +471,7 @@ impl flowgen_core::cache::Cache for Cache {
             .ok_or_else(|| flowgen_core::cache::CacheError::StoreNotInitialized)?;
 
         store
-            .delete_expect_revision(key, Some(expected_revision))
+            .delete_expect_revision(self.prefixed_key(key), Some(expected_revision))
             .await
             .map_err(|e| {
                 flowgen_core::cache::CacheError::DeleteFailed(Box::new(Error::KVDelete {
@@ -365,7 +486,7 @@ impl flowgen_core::cache::Cache for Cache {
             .as_ref()
             .ok_or_else(|| flowgen_core::cache::CacheError::StoreNotInitialized)?;
 
-        match store.entry(key).await {
+        match store.entry(self.prefixed_key(key)).await {
             Ok(Some(entry)) => Ok(Some(entry.revision)),
             Ok(None) => Ok(None),
             Err(e) => Err(flowgen_core::cache::CacheError::GetFailed(Box::new(
@@ -386,8 +507,10 @@ impl flowgen_core::cache::Cache for Cache {
         })?;
         while let Some(result) = key_stream.next().await {
             if let Ok(key) = result {
-                if key.starts_with(prefix) {
-                    keys.push(key);
+                if let Some(key) = self.unprefixed_key(&key) {
+                    if key.starts_with(prefix) {
+                        keys.push(key.to_string());
+                    }
                 }
             }
         }
@@ -404,10 +527,16 @@ pub struct CacheBuilder {
     credentials_path: Option<PathBuf>,
     /// NATS server URL. Defaults to DEFAULT_NATS_URL if not set.
     url: Option<String>,
+    /// TLS settings for the NATS connection.
+    tls: crate::client::TlsOptions,
     /// Number of historical entries retained per key. Defaults to DEFAULT_HISTORY.
     history: Option<i64>,
     /// TTL for delete/purge tombstone markers. Defaults to DEFAULT_TOMBSTONE_TTL.
     tombstone_ttl: Option<Duration>,
+    /// Prefix prepended to every key, allowing several flows to share one bucket.
+    key_prefix: Option<String>,
+    /// Retry applied when connecting to NATS.
+    retry: Option<flowgen_core::retry::RetryConfig>,
 }
 
 impl CacheBuilder {
@@ -438,6 +567,15 @@ impl CacheBuilder {
         self
     }
 
+    /// Sets the TLS settings for the NATS connection.
+    ///
+    /// # Arguments
+    /// * `tls` - CA and client certificate paths; set cert and key for mutual TLS.
+    pub fn tls(mut self, tls: crate::client::TlsOptions) -> Self {
+        self.tls = tls;
+        self
+    }
+
     /// Sets the number of historical entries retained per key.
     ///
     /// Only applies when the bucket is created. Defaults to `DEFAULT_HISTORY` if unset.
@@ -455,6 +593,26 @@ impl CacheBuilder {
         self
     }
 
+    /// Sets a prefix prepended to every key.
+    ///
+    /// Lets multiple flow instances share a single KV bucket without key collisions.
+    /// The prefix is normalized on build: characters outside `[-/_=.a-zA-Z0-9]`
+    /// are removed, leading and trailing `.` are trimmed and it is truncated to
+    /// 64 characters. A prefix left empty is ignored.
+    pub fn key_prefix(mut self, prefix: String) -> Self {
+        self.key_prefix = Some(prefix);
+        self
+    }
+
+    /// Retries a failed NATS connect with exponential backoff.
+    ///
+    /// # Arguments
+    /// * `retry` - Maximum attempts and initial backoff
+    pub fn retry(mut self, retry: flowgen_core::retry::RetryConfig) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
     /// Builds the [`Cache`].
     ///
     /// Consumes builder. `Cache` is returned unconnected; call `init()` to connect.
@@ -463,6 +621,23 @@ impl CacheBuilder {
     /// * `Ok(Cache)` on success.
     /// * `Err(Error::MissingBuilderAttribute)` if `credentials_path` is missing.
     pub fn build(self) -> Result<Cache, Error> {
+        // A prefix that is empty after normalization would turn keys into
+        // `.key`, so it is treated as no prefix.
+        let key_prefix = self
+            .key_prefix
+            .map(|prefix| {
+                let normalized = normalize_key_prefix(&prefix);
+                if normalized != prefix {
+                    tracing::warn!(
+                        prefix = %prefix,
+                        normalized = %normalized,
+                        "Cache key prefix was normalized"
+                    );
+                }
+                normalized
+            })
+            .filter(|prefix| !prefix.is_empty());
+
         Ok(Cache {
             credentials_path: self
                 .credentials_path
@@ -470,8 +645,11 @@ impl CacheBuilder {
             url: self
                 .url
                 .unwrap_or_else(|| crate::client::DEFAULT_NATS_URL.to_string()),
+            tls: self.tls,
             history: self.history,
             tombstone_ttl: self.tombstone_ttl,
+            key_prefix,
+            retry: self.retry,
             ..Default::default()
         })
     }
@@ -504,6 +682,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cache_builder_tls() {
+        let tls = crate::client::TlsOptions {
+            ca_path: Some(PathBuf::from("/tls/ca.pem")),
+            ..Default::default()
+        };
+        let cache = CacheBuilder::new()
+            .credentials_path(PathBuf::from("/valid/path/creds.jwt"))
+            .tls(tls.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(cache.tls, tls);
+    }
+
     #[test]
     fn test_cache_builder_build_success() {
         let path = PathBuf::from("/valid/path/creds.jwt");
@@ -544,4 +737,73 @@ mod tests {
         assert_eq!(cache.credentials_path, path);
         assert!(cache.store.is_none());
     }
+
+    #[test]
+    fn test_cache_builder_key_prefix() {
+        let cache = CacheBuilder::new()
+            .credentials_path(PathBuf::from("/test/creds.jwt"))
+            .key_prefix("flow_a".to_string())
+            .build()
+            .unwrap();
+
+        assert_eq!(cache.key_prefix, Some("flow_a".to_string()));
+        assert_eq!(cache.prefixed_key("replay_id"), "flow_a.replay_id");
+    }
+
+    #[test]
+    fn test_cache_builder_key_prefix_normalized() {
+        let long_prefix = format!("a:b{}", "x".repeat(100));
+        let cache = CacheBuilder::new()
+            .credentials_path(PathBuf::from("/test/creds.jwt"))
+            .key_prefix(long_prefix)
+            .build()
+            .unwrap();
+
+        let prefix = cache.key_prefix.unwrap();
+        assert!(!prefix.contains(':'));
+        assert!(prefix.starts_with("ab"));
+        assert_eq!(prefix.len(), MAX_KEY_PREFIX_LENGTH);
+    }
+
+    #[test]
+    fn test_cache_builder_key_prefix_invalid_chars() {
+        let cache = CacheBuilder::new()
+            .credentials_path(PathBuf::from("/test/creds.jwt"))
+            .key_prefix(".flow a*>:b=1/c.".to_string())
+            .build()
+            .unwrap();
+
+        assert_eq!(cache.key_prefix, Some("flowab=1/c".to_string()));
+        assert_eq!(cache.prefixed_key("replay_id"), "flowab=1/c.replay_id");
+    }
+
+    #[test]
+    fn test_cache_builder_empty_key_prefix() {
+        for prefix in ["", ":", " *> ", "..."] {
+            let cache = CacheBuilder::new()
+                .credentials_path(PathBuf::from("/test/creds.jwt"))
+                .key_prefix(prefix.to_string())
+                .build()
+                .unwrap();
+
+            assert!(cache.key_prefix.is_none());
+            assert_eq!(cache.prefixed_key("replay_id"), "replay_id");
+        }
+    }
+
+    #[test]
+    fn test_cache_unprefixed_key() {
+        let cache = Cache {
+            key_prefix: Some("flow_a".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(cache.unprefixed_key("flow_a.replay_id"), Some("replay_id"));
+        assert_eq!(cache.unprefixed_key("flow_b.replay_id"), None);
+        assert_eq!(cache.unprefixed_key("flow_ab.replay_id"), None);
+
+        let unprefixed = Cache::default();
+        assert_eq!(unprefixed.prefixed_key("replay_id"), "replay_id");
+        assert_eq!(unprefixed.unprefixed_key("replay_id"), Some("replay_id"));
+    }
 }
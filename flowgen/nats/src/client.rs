@@ -20,6 +20,20 @@ pub struct NKeyCredentials {
     pub seed: String,
 }
 
+/// TLS settings for NATS connections.
+///
+/// Setting `ca_path` trusts the given root certificates; setting both
+/// `cert_path` and `key_path` enables mutual TLS. Either one requires TLS.
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq, Default)]
+pub struct TlsOptions {
+    /// Path to the PEM client certificate used for mutual TLS.
+    pub cert_path: Option<PathBuf>,
+    /// Path to the PEM private key of the client certificate.
+    pub key_path: Option<PathBuf>,
+    /// Path to PEM root certificates used to verify the server.
+    pub ca_path: Option<PathBuf>,
+}
+
 /// Errors that can occur during NATS client operations.
 #[derive(thiserror::Error, Debug)]
 #[non_exhaustive]
@@ -30,6 +44,12 @@ pub enum Error {
         #[source]
         source: std::io::Error,
     },
+    #[error("Error reading TLS file '{path}': {source}")]
+    ReadTlsFile {
+        path: std::path::PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
     #[error("Error parsing credentials file: {source}")]
     ParseCredentials {
         #[source]
@@ -53,16 +73,23 @@ pub enum Error {
 }
 
 /// NATS client with optional JetStream context for reliable messaging.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Client {
     /// Path to the NATS credentials file.
     /// This file contains authentication credentials in JSON format.
-    credentials_path: PathBuf,
+    /// When not set, the client connects without authentication.
+    credentials_path: Option<PathBuf>,
     /// NATS server URL (e.g., "nats://localhost:4222" or "localhost:4222").
     /// If not set, defaults to "localhost:4222".
     url: Option<String>,
+    /// TLS settings, including the client certificate for mutual TLS.
+    tls: TlsOptions,
+    /// Plain NATS client for core publish and subscribe operations.
+    pub nats_client: Option<async_nats::Client>,
     /// JetStream context for reliable messaging operations.
     pub jetstream: Option<async_nats::jetstream::Context>,
+    /// Optional retry applied when connecting.
+    retry: Option<flowgen_core::retry::RetryConfig>,
 }
 
 impl flowgen_core::client::Client for Client {
@@ -70,45 +97,131 @@ impl flowgen_core::client::Client for Client {
 
     /// Connects to the NATS server with the provided options.
     async fn connect(mut self) -> Result<Self, Error> {
-        // Read and parse credentials file.
-        let credentials: Credentials =
-            serde_json::from_str(&fs::read_to_string(&self.credentials_path).map_err(|e| {
-                Error::ReadCredentials {
-                    path: self.credentials_path.clone(),
-                    source: e,
+        let nats_client = match &self.retry {
+            Some(retry) => {
+                flowgen_core::client::connect_with_retry(retry, || self.connect_nats()).await?
+            }
+            None => self.connect_nats().await?,
+        };
+
+        // Initialize JetStream context.
+        let jetstream = async_nats::jetstream::new(nats_client.clone());
+
+        self.nats_client = Some(nats_client);
+        self.jetstream = Some(jetstream);
+        Ok(self)
+    }
+}
+
+impl Client {
+    /// Makes a single attempt to connect to the NATS server.
+    async fn connect_nats(&self) -> Result<async_nats::Client, Error> {
+        let mut connect_options = match &self.credentials_path {
+            Some(credentials_path) => {
+                // Read and parse credentials file.
+                let credentials: Credentials =
+                    serde_json::from_str(&fs::read_to_string(credentials_path).map_err(|e| {
+                        Error::ReadCredentials {
+                            path: credentials_path.clone(),
+                            source: e,
+                        }
+                    })?)
+                    .map_err(|e| Error::ParseCredentials { source: e })?;
+
+                // Build connect options based on credential type.
+                if let Some(nkey_creds) = credentials.nkey {
+                    // For NKey authentication, the seed (private key) is passed to async_nats.
+                    // The seed is used to sign authentication challenges, and the server validates against the public key.
+                    async_nats::ConnectOptions::with_nkey(nkey_creds.seed)
+                } else {
+                    return Err(Error::NoCredentials);
                 }
-            })?)
-            .map_err(|e| Error::ParseCredentials { source: e })?;
-
-        // Build connect options based on credential type.
-        let connect_options = if let Some(nkey_creds) = credentials.nkey {
-            // For NKey authentication, the seed (private key) is passed to async_nats.
-            // The seed is used to sign authentication challenges, and the server validates against the public key.
-            async_nats::ConnectOptions::with_nkey(nkey_creds.seed)
-        } else {
-            return Err(Error::NoCredentials);
+            }
+            // No credentials, e.g. a local development server.
+            None => async_nats::ConnectOptions::new(),
         };
 
+        // TLS files are only read when connecting, so check them up front for a clear error.
+        if let Some(ca_path) = &self.tls.ca_path {
+            check_tls_file(ca_path)?;
+            connect_options = connect_options
+                .add_root_certificates(ca_path.clone())
+                .require_tls(true);
+        }
+        if let (Some(cert_path), Some(key_path)) = (&self.tls.cert_path, &self.tls.key_path) {
+            check_tls_file(cert_path)?;
+            check_tls_file(key_path)?;
+            connect_options = connect_options
+                .add_client_certificate(cert_path.clone(), key_path.clone())
+                .require_tls(true);
+        }
+
         // Use provided URL or fall back to default.
         let url = self.url.as_deref().unwrap_or(DEFAULT_NATS_URL);
 
         // Connect to NATS server.
-        let nats_client = connect_options
+        connect_options
             .connect(url)
             .await
             .map_err(|e| Error::Connect {
                 url: url.to_string(),
                 source: e,
-            })?;
+            })
+    }
+}
 
-        // Initialize JetStream context.
-        let jetstream = async_nats::jetstream::new(nats_client);
+/// Health check reporting the connection state of one or more NATS clients.
+///
+/// Reports the worst state across all clients, so a pool with a single
+/// reconnecting connection is degraded.
+#[derive(Debug, Clone)]
+pub struct ConnectionHealth {
+    /// Clients whose connection state is reported.
+    clients: Vec<async_nats::Client>,
+}
 
-        self.jetstream = Some(jetstream);
-        Ok(self)
+impl ConnectionHealth {
+    /// Creates a health check for the given clients.
+    pub fn new(clients: Vec<async_nats::Client>) -> Self {
+        Self { clients }
     }
 }
 
+#[async_trait::async_trait]
+impl flowgen_core::health::HealthCheck for ConnectionHealth {
+    async fn check(&self) -> flowgen_core::health::HealthResult {
+        use async_nats::connection::State;
+        use flowgen_core::health::{HealthResult, HealthStatus};
+
+        let statuses: Vec<_> = self
+            .clients
+            .iter()
+            .map(|client| match client.connection_state() {
+                State::Connected => HealthStatus::Ok,
+                State::Pending => HealthStatus::Degraded,
+                State::Disconnected => HealthStatus::Down,
+            })
+            .collect();
+        let connected = statuses
+            .iter()
+            .filter(|status| **status == HealthStatus::Ok)
+            .count();
+
+        HealthResult::new(statuses.into_iter().max().unwrap_or(HealthStatus::Down))
+            .detail("connected", format!("{connected}/{}", self.clients.len()))
+    }
+}
+
+/// Checks that a TLS certificate or key file can be read.
+fn check_tls_file(path: &PathBuf) -> Result<(), Error> {
+    fs::File::open(path)
+        .map(drop)
+        .map_err(|source| Error::ReadTlsFile {
+            path: path.clone(),
+            source,
+        })
+}
+
 /// Builder for configuring and creating NATS clients.
 #[derive(Default)]
 pub struct ClientBuilder {
@@ -116,6 +229,10 @@ pub struct ClientBuilder {
     credentials_path: Option<PathBuf>,
     /// NATS server URL.
     url: Option<String>,
+    /// TLS settings.
+    tls: TlsOptions,
+    /// Retry applied when connecting.
+    retry: Option<flowgen_core::retry::RetryConfig>,
 }
 
 impl ClientBuilder {
@@ -148,17 +265,60 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets the path to the PEM client certificate used for mutual TLS.
+    pub fn tls_cert_path(&mut self, path: PathBuf) -> &mut ClientBuilder {
+        self.tls.cert_path = Some(path);
+        self
+    }
+
+    /// Sets the path to the private key of the client certificate.
+    pub fn tls_key_path(&mut self, path: PathBuf) -> &mut ClientBuilder {
+        self.tls.key_path = Some(path);
+        self
+    }
+
+    /// Sets the path to PEM root certificates used to verify the server.
+    pub fn tls_ca_path(&mut self, path: PathBuf) -> &mut ClientBuilder {
+        self.tls.ca_path = Some(path);
+        self
+    }
+
+    /// Sets all TLS settings at once, e.g. from task configuration.
+    pub fn tls(&mut self, tls: TlsOptions) -> &mut ClientBuilder {
+        self.tls = tls;
+        self
+    }
+
+    /// Retries a failed connect with exponential backoff, e.g. for connections
+    /// made once at startup that should survive DNS or network hiccups.
+    pub fn retry(&mut self, retry: flowgen_core::retry::RetryConfig) -> &mut ClientBuilder {
+        self.retry = Some(retry);
+        self
+    }
+
     /// Builds a new NATS client instance.
     ///
-    /// Returns an error if `credentials_path` is not provided.
+    /// Without `credentials_path` the client connects unauthenticated. Credentials
+    /// and TLS can be combined. Returns an error if only one of the TLS client
+    /// certificate and key is set.
     pub fn build(&self) -> Result<Client, Error> {
+        match (&self.tls.cert_path, &self.tls.key_path) {
+            (Some(_), None) => {
+                return Err(Error::MissingBuilderAttribute("tls_key_path".to_string()))
+            }
+            (None, Some(_)) => {
+                return Err(Error::MissingBuilderAttribute("tls_cert_path".to_string()))
+            }
+            _ => {}
+        }
+
         Ok(Client {
-            credentials_path: self
-                .credentials_path
-                .clone()
-                .ok_or_else(|| Error::MissingBuilderAttribute("credentials_path".to_string()))?,
+            credentials_path: self.credentials_path.clone(),
             url: self.url.clone(),
+            tls: self.tls.clone(),
+            nats_client: None,
             jetstream: None,
+            retry: self.retry.clone(),
         })
     }
 }
@@ -192,13 +352,83 @@ mod tests {
     }
 
     #[test]
-    fn test_client_builder_build_missing_credentials() {
-        let builder = ClientBuilder::new();
-        let result = builder.build();
-        assert!(result.is_err());
+    fn test_client_builder_build_without_credentials() {
+        let client = ClientBuilder::new().build().unwrap();
+        assert!(client.credentials_path.is_none());
+        assert_eq!(client.tls, TlsOptions::default());
+    }
+
+    #[test]
+    fn test_client_builder_tls() {
+        let client = ClientBuilder::new()
+            .credentials_path(PathBuf::from("/valid/nats.creds"))
+            .tls_cert_path(PathBuf::from("/tls/client.pem"))
+            .tls_key_path(PathBuf::from("/tls/client.key"))
+            .tls_ca_path(PathBuf::from("/tls/ca.pem"))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            client.tls,
+            TlsOptions {
+                cert_path: Some(PathBuf::from("/tls/client.pem")),
+                key_path: Some(PathBuf::from("/tls/client.key")),
+                ca_path: Some(PathBuf::from("/tls/ca.pem")),
+            }
+        );
+        assert!(client.credentials_path.is_some());
+    }
+
+    #[test]
+    fn test_client_builder_tls_requires_cert_and_key() {
+        let result = ClientBuilder::new()
+            .tls_cert_path(PathBuf::from("/tls/client.pem"))
+            .build();
         assert!(
-            matches!(result.unwrap_err(), Error::MissingBuilderAttribute(attr) if attr == "credentials_path")
+            matches!(result.unwrap_err(), Error::MissingBuilderAttribute(attr) if attr == "tls_key_path")
         );
+
+        let result = ClientBuilder::new()
+            .tls(TlsOptions {
+                key_path: Some(PathBuf::from("/tls/client.key")),
+                ..Default::default()
+            })
+            .build();
+        assert!(
+            matches!(result.unwrap_err(), Error::MissingBuilderAttribute(attr) if attr == "tls_cert_path")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_client_connect_missing_tls_file() {
+        use flowgen_core::client::Client as _;
+
+        let result = ClientBuilder::new()
+            .tls_ca_path(PathBuf::from("/nonexistent/ca.pem"))
+            .build()
+            .unwrap()
+            .connect()
+            .await;
+        assert!(
+            matches!(result.unwrap_err(), Error::ReadTlsFile { path, .. } if path == PathBuf::from("/nonexistent/ca.pem"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_client_connect_with_retry_returns_last_error() {
+        use flowgen_core::client::Client as _;
+
+        let result = ClientBuilder::new()
+            .tls_ca_path(PathBuf::from("/nonexistent/ca.pem"))
+            .retry(flowgen_core::retry::RetryConfig {
+                max_attempts: Some(2),
+                initial_backoff: std::time::Duration::from_millis(1),
+            })
+            .build()
+            .unwrap()
+            .connect()
+            .await;
+        assert!(matches!(result.unwrap_err(), Error::ReadTlsFile { .. }));
     }
 
     #[test]
@@ -210,7 +440,7 @@ mod tests {
 
         assert!(result.is_ok());
         let client = result.unwrap();
-        assert_eq!(client.credentials_path, path);
+        assert_eq!(client.credentials_path, Some(path));
         assert_eq!(client.url, None);
         assert!(client.jetstream.is_none());
     }
@@ -226,7 +456,7 @@ mod tests {
 
         assert!(result.is_ok());
         let client = result.unwrap();
-        assert_eq!(client.credentials_path, path);
+        assert_eq!(client.credentials_path, Some(path));
         assert_eq!(client.url, Some(url));
         assert!(client.jetstream.is_none());
     }
@@ -242,7 +472,7 @@ mod tests {
             .build()
             .unwrap();
 
-        assert_eq!(client.credentials_path, path);
+        assert_eq!(client.credentials_path, Some(path));
         assert_eq!(client.url, Some(url));
         assert!(client.jetstream.is_none());
     }
@@ -312,12 +542,15 @@ mod tests {
         let path = PathBuf::from("/test/nats.creds");
         let url = Some("nats://localhost:4222".to_string());
         let client = Client {
-            credentials_path: path.clone(),
+            credentials_path: Some(path.clone()),
             url: url.clone(),
+            tls: TlsOptions::default(),
+            nats_client: None,
             jetstream: None,
+            retry: None,
         };
 
-        assert_eq!(client.credentials_path, path);
+        assert_eq!(client.credentials_path, Some(path));
         assert_eq!(client.url, url);
         assert!(client.jetstream.is_none());
     }
@@ -0,0 +1,112 @@
+//! Configuration for the `nats_core_subscriber` task.
+//!
+//! Core NATS subscriptions work on any NATS server, without JetStream. Messages
+//! are delivered at most once: there is no stream, consumer or acknowledgment.
+
+use flowgen_core::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Default NATS server URL function for serde.
+fn default_nats_url() -> String {
+    crate::client::DEFAULT_NATS_URL.to_string()
+}
+
+/// Configuration for the core NATS subscriber.
+///
+/// # Example YAML
+///
+/// ```yaml
+/// - nats_core_subscriber:
+///     name: sensor_readings
+///     credentials_path: /etc/nats/credentials.json
+///     url: nats://localhost:4222
+///     subject: "sensors.>"
+///     queue_group: flowgen
+///     format: json
+/// ```
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Subscriber {
+    /// The unique name / identifier of the task.
+    pub name: String,
+    /// Path to credentials file containing NATS authentication details.
+    /// When not set, the task connects without authentication.
+    pub credentials_path: Option<PathBuf>,
+    /// NATS server URL (e.g., "nats://localhost:4222"). Defaults to "localhost:4222".
+    #[serde(default = "default_nats_url")]
+    pub url: String,
+    /// Optional TLS settings. Set `cert_path` and `key_path` for mutual TLS.
+    #[serde(default)]
+    pub tls: Option<crate::client::TlsOptions>,
+    /// Subject to subscribe to. Supports NATS wildcards (`*`, `>`).
+    pub subject: String,
+    /// Queue group shared by several subscribers.
+    /// Each message is delivered to only one member of the group.
+    pub queue_group: Option<String>,
+    /// How message payloads are decoded into event data.
+    #[serde(default)]
+    pub format: PayloadFormat,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    #[serde(default)]
+    pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Subscriber {}
+
+/// Decoding applied to each received message payload.
+#[derive(PartialEq, Eq, Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PayloadFormat {
+    /// Parse the payload as JSON.
+    #[default]
+    Json,
+    /// Emit the payload as a JSON string without parsing.
+    Text,
+    /// Decode the payload as written by the flowgen NATS publisher (Avro, JSON or Arrow).
+    Flowgen,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscriber_config_defaults() {
+        let json = r#"{"name": "readings", "subject": "sensors.>"}"#;
+        let config: Subscriber = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.url, crate::client::DEFAULT_NATS_URL);
+        assert!(config.credentials_path.is_none());
+        assert!(config.queue_group.is_none());
+        assert_eq!(config.format, PayloadFormat::Json);
+    }
+
+    #[test]
+    fn test_subscriber_config_full() {
+        let json = r#"{
+            "name": "readings",
+            "credentials_path": "/etc/nats/creds",
+            "url": "nats://nats:4222",
+            "subject": "sensors.>",
+            "queue_group": "flowgen",
+            "format": "text"
+        }"#;
+        let config: Subscriber = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            config.credentials_path,
+            Some(PathBuf::from("/etc/nats/creds"))
+        );
+        assert_eq!(config.url, "nats://nats:4222");
+        assert_eq!(config.queue_group, Some("flowgen".to_string()));
+        assert_eq!(config.format, PayloadFormat::Text);
+    }
+}
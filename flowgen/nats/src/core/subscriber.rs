@@ -0,0 +1,384 @@
+//! Core NATS subscriber that emits one event per received message.
+//!
+//! Subscribes to a subject on the plain NATS client, optionally as part of a
+//! queue group, and decodes payloads according to the configured format. Core
+//! NATS has no acknowledgments, so messages published while the subscriber is
+//! disconnected are lost. When the subscription ends the subscriber reconnects
+//! until the flow is cancelled.
+
+use super::config::PayloadFormat;
+use crate::jetstream::message::NatsMessageExt;
+use flowgen_core::{
+    client::Client,
+    config::ConfigExt,
+    event::{Event, EventBuilder, EventData, EventExt},
+};
+use futures_util::StreamExt;
+use serde_json::Value;
+use std::sync::Arc;
+use tokio::sync::mpsc::Sender;
+use tracing::{error, warn, Instrument};
+
+/// Errors that can occur during core NATS subscription operations.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("NATS client error: {source}")]
+    Client {
+        #[source]
+        source: crate::client::Error,
+    },
+    #[error("Message conversion error: {source}")]
+    MessageConversion {
+        #[source]
+        source: crate::jetstream::message::Error,
+    },
+    #[error("JSON error: {source}")]
+    SerdeJson {
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("Subscription error: {source}")]
+    Subscribe {
+        #[source]
+        source: async_nats::SubscribeError,
+    },
+    #[error("NATS client not connected")]
+    NotConnected,
+    #[error("Subscription ended unexpectedly, connection may have been lost")]
+    SubscriptionEnded,
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+    #[error("Config template rendering error: {source}")]
+    ConfigRender {
+        #[source]
+        source: flowgen_core::config::Error,
+    },
+}
+
+/// Converts a core NATS message into an event according to the configured format.
+fn message_to_event(
+    message: &async_nats::Message,
+    format: &PayloadFormat,
+    task_type: &'static str,
+    task_id: usize,
+) -> Result<Event, Error> {
+    let data = match format {
+        PayloadFormat::Flowgen => {
+            return message
+                .to_event(task_type, task_id)
+                .map_err(|source| Error::MessageConversion { source });
+        }
+        PayloadFormat::Json => serde_json::from_slice(&message.payload)
+            .map(EventData::Json)
+            .map_err(|source| Error::SerdeJson { source })?,
+        PayloadFormat::Text => EventData::Json(Value::String(
+            String::from_utf8_lossy(&message.payload).into_owned(),
+        )),
+    };
+
+    let mut event_builder = EventBuilder::new()
+        .data(data)
+        .subject(message.subject.to_string())
+        .task_id(task_id)
+        .task_type(task_type);
+    if let Some(id) = message
+        .headers
+        .as_ref()
+        .and_then(|headers| headers.get(async_nats::header::NATS_MESSAGE_ID))
+    {
+        event_builder = event_builder.id(id.to_string());
+    }
+    event_builder
+        .build()
+        .map_err(|source| Error::EventBuilder { source })
+}
+
+/// Event handler owning a single core NATS subscription.
+pub struct EventHandler {
+    subscription: async_nats::Subscriber,
+    tx: Option<Sender<Event>>,
+    task_id: usize,
+    config: Arc<super::config::Subscriber>,
+    task_type: &'static str,
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+}
+
+impl EventHandler {
+    /// Reads messages until the subscription ends or the flow is cancelled.
+    async fn handle(mut self) -> Result<(), Error> {
+        let cancellation_token = self.task_context.cancellation_token.clone();
+
+        loop {
+            let message = tokio::select! {
+                // Dropping the subscription unsubscribes from the server.
+                _ = cancellation_token.cancelled() => return Ok(()),
+                message = self.subscription.next() => match message {
+                    Some(message) => message,
+                    None => return Err(Error::SubscriptionEnded),
+                },
+            };
+
+            let e = match message_to_event(
+                &message,
+                &self.config.format,
+                self.task_type,
+                self.task_id,
+            ) {
+                Ok(e) => e,
+                Err(e) => {
+                    // A malformed payload should not tear down the subscription.
+                    warn!(error = %e, subject = %message.subject, "Failed to decode NATS message, skipping");
+                    continue;
+                }
+            };
+
+            e.send_with_logging(self.tx.as_ref())
+                .await
+                .map_err(|source| Error::SendMessage { source })?;
+            self.task_context.metrics.record_received();
+        }
+    }
+}
+
+/// Core NATS subscriber that consumes messages and converts them to flowgen events.
+#[derive(Debug)]
+pub struct Subscriber {
+    /// Subscriber configuration including subject and payload format.
+    config: Arc<super::config::Subscriber>,
+    /// Sender for forwarding converted events.
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event tagging.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl flowgen_core::task::runner::Runner for Subscriber {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Connects to NATS and subscribes to the configured subject.
+    async fn init(&self) -> Result<EventHandler, Error> {
+        let init_config = self
+            .config
+            .render(&serde_json::json!({}))
+            .map_err(|source| Error::ConfigRender { source })?;
+
+        let mut client_builder = crate::client::ClientBuilder::new();
+        client_builder
+            .url(init_config.url.clone())
+            .tls(init_config.tls.clone().unwrap_or_default());
+        if let Some(credentials_path) = &init_config.credentials_path {
+            client_builder.credentials_path(credentials_path.clone());
+        }
+        let client = client_builder
+            .build()
+            .map_err(|source| Error::Client { source })?
+            .connect()
+            .await
+            .map_err(|source| Error::Client { source })?;
+
+        let nats_client = client.nats_client.ok_or(Error::NotConnected)?;
+        let subscription = match &init_config.queue_group {
+            Some(queue_group) => {
+                nats_client
+                    .queue_subscribe(init_config.subject.clone(), queue_group.clone())
+                    .await
+            }
+            None => nats_client.subscribe(init_config.subject.clone()).await,
+        }
+        .map_err(|source| Error::Subscribe { source })?;
+
+        Ok(EventHandler {
+            subscription,
+            tx: self.tx.clone(),
+            task_id: self.task_id,
+            config: Arc::clone(&self.config),
+            task_type: self.task_type,
+            task_context: Arc::clone(&self.task_context),
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(self) -> Result<(), Error> {
+        let retry_config =
+            flowgen_core::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        tokio::spawn(
+            async move {
+                // Infinite retry loop: subscribers must maintain connectivity indefinitely.
+                loop {
+                    let event_handler = match tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+                        self.init().await.map_err(|e| {
+                            error!(error = %e, "Subscriber initialization failed");
+                            tokio_retry::RetryError::transient(e)
+                        })
+                    })
+                    .await
+                    {
+                        Ok(handler) => handler,
+                        Err(e) => {
+                            error!(error = %e, "Subscriber initialization exhausted retry attempts, will retry after backoff");
+                            tokio::time::sleep(retry_config.initial_backoff).await;
+                            continue;
+                        }
+                    };
+
+                    // Run event loop until the subscription ends, then resubscribe.
+                    match event_handler.handle().await {
+                        Ok(()) => return,
+                        Err(e) => {
+                            if self.task_context.cancellation_token.is_cancelled() {
+                                return;
+                            }
+                            warn!(error = %e, "Subscriber lost connectivity, reinitializing");
+                        }
+                    }
+
+                    tokio::select! {
+                        _ = self.task_context.cancellation_token.cancelled() => return,
+                        _ = tokio::time::sleep(retry_config.initial_backoff) => {}
+                    }
+                }
+            }
+            .instrument(tracing::Span::current()),
+        );
+
+        Ok(())
+    }
+}
+
+/// Builder for configuring and creating core NATS subscribers.
+#[derive(Default)]
+pub struct SubscriberBuilder {
+    /// Optional subscriber configuration.
+    config: Option<Arc<super::config::Subscriber>>,
+    /// Optional event sender.
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event processing.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Option<Arc<flowgen_core::task::context::TaskContext>>,
+    /// Task type for event categorization.
+    task_type: Option<&'static str>,
+}
+
+impl SubscriberBuilder {
+    pub fn new() -> SubscriberBuilder {
+        SubscriberBuilder {
+            ..Default::default()
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Subscriber>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = task_id;
+        self
+    }
+
+    pub fn task_context(
+        mut self,
+        task_context: Arc<flowgen_core::task::context::TaskContext>,
+    ) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Subscriber, Error> {
+        Ok(Subscriber {
+            config: self
+                .config
+                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            tx: self.tx,
+            task_id: self.task_id,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(payload: &'static [u8]) -> async_nats::Message {
+        let mut headers = async_nats::HeaderMap::new();
+        headers.insert(async_nats::header::NATS_MESSAGE_ID, "msg-1");
+        async_nats::Message {
+            subject: "sensors.temp".into(),
+            reply: None,
+            payload: payload.into(),
+            headers: Some(headers),
+            status: None,
+            description: None,
+            length: payload.len(),
+        }
+    }
+
+    #[test]
+    fn test_message_to_event_json() {
+        let e = message_to_event(
+            &message(br#"{"celsius": 21}"#),
+            &PayloadFormat::Json,
+            "test",
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(e.subject, "sensors.temp");
+        assert_eq!(e.id, Some("msg-1".to_string()));
+        assert!(matches!(e.data, EventData::Json(v) if v == serde_json::json!({"celsius": 21})));
+    }
+
+    #[test]
+    fn test_message_to_event_text() {
+        let e = message_to_event(&message(b"21.5"), &PayloadFormat::Text, "test", 0).unwrap();
+        assert!(matches!(e.data, EventData::Json(Value::String(s)) if s == "21.5"));
+    }
+
+    #[test]
+    fn test_message_to_event_invalid_json() {
+        let result = message_to_event(&message(b"not json"), &PayloadFormat::Json, "test", 0);
+        assert!(matches!(result, Err(Error::SerdeJson { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_subscriber_builder_missing_config() {
+        let result = SubscriberBuilder::new().task_type("test").build().await;
+        assert!(
+            matches!(result.unwrap_err(), Error::MissingBuilderAttribute(attr) if attr == "config")
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! Arrow IPC encoding for record batches sent over NATS.
+//!
+//! Record batches are written as a plain Arrow IPC stream, which carries the
+//! schema alongside the data. Any Arrow implementation can read the payload
+//! back without knowing the schema upfront.
+
+use arrow::ipc::reader::StreamReader;
+use arrow::ipc::writer::StreamWriter;
+use arrow::record_batch::RecordBatch;
+
+/// Marker that starts every message of an Arrow IPC stream.
+const CONTINUATION_MARKER: [u8; 4] = [0xFF; 4];
+
+/// Errors that can occur while encoding or decoding Arrow IPC payloads.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// Apache Arrow error during IPC serialization or deserialization.
+    #[error("Apache Arrow error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+    /// Error publishing the encoded batch to JetStream.
+    #[error("Error publishing message to NATS JetStream: {source}")]
+    Publish {
+        #[source]
+        source: async_nats::jetstream::context::PublishError,
+    },
+}
+
+/// Encodes a record batch as an Arrow IPC stream.
+pub fn write_to_bytes(batch: &RecordBatch) -> Result<Vec<u8>, Error> {
+    let mut buffer = Vec::new();
+    {
+        let mut stream_writer = StreamWriter::try_new(&mut buffer, &batch.schema())
+            .map_err(|source| Error::Arrow { source })?;
+        stream_writer
+            .write(batch)
+            .map_err(|source| Error::Arrow { source })?;
+        stream_writer
+            .finish()
+            .map_err(|source| Error::Arrow { source })?;
+    }
+    Ok(buffer)
+}
+
+/// Decodes an Arrow IPC stream into a single record batch.
+///
+/// Streams holding several batches are concatenated; a stream holding only
+/// a schema yields an empty batch with that schema.
+pub fn read_from_bytes(bytes: &[u8]) -> Result<RecordBatch, Error> {
+    let stream_reader = StreamReader::try_new(std::io::Cursor::new(bytes), None)
+        .map_err(|source| Error::Arrow { source })?;
+    let schema = stream_reader.schema();
+    let mut batches = stream_reader
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|source| Error::Arrow { source })?;
+
+    if batches.len() == 1 {
+        return Ok(batches.remove(0));
+    }
+    arrow::compute::concat_batches(&schema, &batches).map_err(|source| Error::Arrow { source })
+}
+
+/// Returns true when the payload starts like an Arrow IPC stream.
+pub fn is_ipc_stream(bytes: &[u8]) -> bool {
+    bytes.starts_with(&CONTINUATION_MARKER)
+}
+
+/// Publishes a record batch to a JetStream subject as an Arrow IPC stream
+/// and waits for the server acknowledgment.
+pub async fn publish(
+    jetstream: &async_nats::jetstream::Context,
+    subject: String,
+    batch: &RecordBatch,
+) -> Result<(), Error> {
+    let payload = write_to_bytes(batch)?;
+    jetstream
+        .publish(subject, payload.into())
+        .await
+        .map_err(|source| Error::Publish { source })?
+        .await
+        .map_err(|source| Error::Publish { source })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int64Array, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use std::sync::Arc;
+
+    fn record_batch() -> RecordBatch {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec![Some("a"), None])),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_ipc_roundtrip() {
+        let batch = record_batch();
+        let bytes = write_to_bytes(&batch).unwrap();
+
+        assert!(is_ipc_stream(&bytes));
+        assert_eq!(read_from_bytes(&bytes).unwrap(), batch);
+    }
+
+    #[test]
+    fn test_ipc_roundtrip_empty_batch() {
+        let batch = RecordBatch::new_empty(record_batch().schema());
+        let bytes = write_to_bytes(&batch).unwrap();
+
+        let decoded = read_from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.num_rows(), 0);
+        assert_eq!(decoded.schema(), batch.schema());
+    }
+
+    #[test]
+    fn test_is_ipc_stream() {
+        assert!(!is_ipc_stream(b"{\"key\": \"value\"}"));
+        assert!(!is_ipc_stream(&[]));
+        assert!(!is_ipc_stream(
+            &bincode::serialize(&vec![1u8, 2, 3]).unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_read_from_bytes_invalid() {
+        assert!(read_from_bytes(b"not arrow").is_err());
+    }
+}
@@ -45,6 +45,9 @@ where
     seq.end()
 }
 
+/// Prefix of the deliver subject used by push consumers when none is configured.
+pub const DEFAULT_DELIVER_SUBJECT_PREFIX: &str = "flowgen.deliver";
+
 /// Default NATS server URL function for serde.
 fn default_nats_url() -> String {
     crate::client::DEFAULT_NATS_URL.to_string()
@@ -56,18 +59,44 @@ pub struct Config {
     /// The unique name / identifier of the task.
     pub name: String,
     /// Path to credentials file containing NATS authentication details.
-    pub credentials_path: PathBuf,
+    /// When not set, the task connects without authentication.
+    pub credentials_path: Option<PathBuf>,
     /// NATS server URL (e.g., "nats://localhost:4222"). Defaults to "localhost:4222".
     #[serde(default = "default_nats_url")]
     pub url: String,
+    /// Optional TLS settings. Set `cert_path` and `key_path` for mutual TLS.
+    #[serde(default)]
+    pub tls: Option<crate::client::TlsOptions>,
     /// Subject name for publishing or subscribing to messages.
+    /// Required for subscribers. For publishers, an empty subject publishes to
+    /// the event's own subject.
+    #[serde(default)]
     pub subject: String,
+    /// Static prefix prepended to the publish subject with a `.` separator (publisher only).
+    pub subject_prefix: Option<String>,
+    /// Event subjects accepted for publishing (publisher only).
+    /// Supports NATS wildcards: `*` matches one token, `>` matches the remainder.
+    /// Events matching none of them go to `fallback_subject`, or are dropped if unset.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subjects: Vec<String>,
+    /// Subject for events that match none of `subjects` (publisher only).
+    /// Used as-is, without `subject_prefix`.
+    pub fallback_subject: Option<String>,
     /// Optional stream configuration.
     /// For publishers: if provided, ensures the stream exists.
     /// For subscribers: required to specify the stream to consume from.
     pub stream: Option<StreamOptions>,
     /// Durable consumer name (subscriber only).
     pub durable_name: Option<String>,
+    /// Consumer delivery mode (subscriber only). Defaults to `pull`.
+    #[serde(default)]
+    pub consumer_type: ConsumerType,
+    /// Subject the server delivers messages to for push consumers (subscriber only).
+    /// Defaults to `flowgen.deliver.<durable_name>`.
+    pub deliver_subject: Option<String>,
+    /// Queue group for push consumers shared by several subscribers (subscriber only).
+    /// Each message is delivered to only one member of the group.
+    pub deliver_group: Option<String>,
     /// Maximum number of messages to fetch per batch (subscriber only).
     pub max_messages: Option<usize>,
     /// Maximum number of unacknowledged messages allowed (subscriber only).
@@ -89,6 +118,10 @@ pub struct Config {
     /// Accepts duration strings: "100ms", "1s", etc.
     #[serde(default, with = "humantime_serde")]
     pub throttle: Option<Duration>,
+    /// Add JetStream metadata columns to Arrow record batch events (subscriber only).
+    /// Adds `_nats_sequence`, `_nats_subject`, `_nats_timestamp_ns` and `_nats_stream`.
+    #[serde(default)]
+    pub metadata_columns: bool,
     /// Acknowledgment timeout used for both publisher and subscriber contexts.
     /// For publishers: timeout for receiving publish acknowledgments from the server.
     /// For subscribers: sets the NATS consumer Ack Wait duration and the internal
@@ -112,6 +145,18 @@ pub struct Config {
         skip_serializing_if = "Vec::is_empty"
     )]
     pub backoff: Vec<Duration>,
+    /// Number of NATS connections used to publish (publisher only).
+    /// Events are spread across connections in round-robin order. Defaults to 1.
+    pub pool_size: Option<usize>,
+    /// Maximum time to wait for a free pooled connection (publisher only).
+    /// Accepts duration strings: "500ms", "5s", etc. Defaults to 30s.
+    #[serde(default, with = "humantime_serde")]
+    pub pool_acquire_timeout: Option<Duration>,
+    /// Wire format for Arrow record batch payloads (publisher only).
+    /// Defaults to `bincode` so subscribers that only read that format keep
+    /// working; set `arrow_ipc` once they are upgraded. Subscribers read both.
+    #[serde(default)]
+    pub format: WireFormat,
     /// Optional list of upstream task names this task depends on.
     /// When set, this task only receives events from the named tasks.
     /// When not set, the task receives from the previous task in the list (linear chain).
@@ -120,6 +165,32 @@ pub struct Config {
     /// Optional retry configuration (overrides app-level retry config).
     #[serde(default)]
     pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
+}
+
+/// JetStream consumer delivery mode.
+#[derive(PartialEq, Eq, Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConsumerType {
+    /// The subscriber fetches messages in batches.
+    #[default]
+    Pull,
+    /// The server pushes messages to a deliver subject as they arrive.
+    Push,
+}
+
+/// Wire format used to publish Arrow record batches.
+#[derive(PartialEq, Eq, Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WireFormat {
+    /// Plain Arrow IPC stream, readable by any Arrow implementation.
+    ArrowIpc,
+    /// Arrow IPC stream wrapped in bincode, as published by earlier versions.
+    /// Stays the default for one release while subscribers are upgraded.
+    #[default]
+    Bincode,
 }
 
 /// Type alias for backward compatibility with publisher code.
@@ -130,6 +201,61 @@ pub type Subscriber = Config;
 
 impl ConfigExt for Config {}
 
+impl Config {
+    /// Returns the deliver subject for push consumers (subscriber only).
+    pub fn push_deliver_subject(&self) -> Option<String> {
+        self.deliver_subject.clone().or_else(|| {
+            self.durable_name
+                .as_ref()
+                .map(|durable_name| format!("{DEFAULT_DELIVER_SUBJECT_PREFIX}.{durable_name}"))
+        })
+    }
+
+    /// Resolves the subject an event is published to (publisher only).
+    ///
+    /// Returns `None` when the event subject matches none of `subjects` and no
+    /// `fallback_subject` is configured, meaning the event should be dropped.
+    pub fn publish_subject(&self, event_subject: &str) -> Option<String> {
+        if !self.subjects.is_empty()
+            && !self
+                .subjects
+                .iter()
+                .any(|pattern| subject_matches(pattern, event_subject))
+        {
+            return self.fallback_subject.clone();
+        }
+
+        let subject = if self.subject.is_empty() {
+            event_subject
+        } else {
+            &self.subject
+        };
+
+        match self.subject_prefix.as_deref() {
+            Some(prefix) if !prefix.is_empty() => {
+                Some(format!("{}.{}", prefix.trim_end_matches('.'), subject))
+            }
+            _ => Some(subject.to_string()),
+        }
+    }
+}
+
+/// Returns true if `subject` matches a NATS subject `pattern`.
+///
+/// `*` matches exactly one token and `>` matches one or more trailing tokens.
+fn subject_matches(pattern: &str, subject: &str) -> bool {
+    let mut subject_tokens = subject.split('.');
+    for pattern_token in pattern.split('.') {
+        match (pattern_token, subject_tokens.next()) {
+            (">", Some(_)) => return true,
+            ("*", Some(_)) => {}
+            (expected, Some(token)) if expected == token => {}
+            _ => return false,
+        }
+    }
+    subject_tokens.next().is_none()
+}
+
 #[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(default)]
 pub struct StreamOptions {
@@ -153,6 +279,8 @@ pub struct StreamOptions {
     pub max_message_size: Option<i32>,
     /// Maximum number of consumers allowed.
     pub max_consumers: Option<i32>,
+    /// Number of replicas of the stream in a clustered deployment (1 to 5).
+    pub num_replicas: Option<usize>,
     /// Whether to create or update the stream if it doesn't exist or differs.
     pub create_or_update: bool,
     /// Retention policy for the stream.
@@ -175,6 +303,20 @@ pub struct StreamOptions {
     pub deny_delete: Option<bool>,
     /// Disables the ability to purge all messages from the stream.
     pub deny_purge: Option<bool>,
+    /// Creates the stream as a read-only mirror of another stream.
+    /// Mirror streams have no subjects of their own, so `subjects` is ignored,
+    /// and publishers cannot publish to them.
+    pub mirror: Option<MirrorOptions>,
+}
+
+/// Source of a mirror stream.
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct MirrorOptions {
+    /// Name of the stream to mirror.
+    pub source_stream: String,
+    /// Subjects of the source stream to mirror. Mirrors every subject when empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filter_subjects: Vec<String>,
 }
 
 /// NATS JetStream retention policies.
@@ -211,7 +353,7 @@ mod tests {
     fn test_config_default() {
         let config = Config::default();
         assert_eq!(config.name, String::new());
-        assert_eq!(config.credentials_path, PathBuf::new());
+        assert!(config.credentials_path.is_none());
         assert_eq!(config.subject, String::new());
         assert_eq!(config.stream, None);
         assert_eq!(config.durable_name, None);
@@ -219,11 +361,115 @@ mod tests {
         assert_eq!(config.delay, None);
     }
 
+    #[test]
+    fn test_subject_matches() {
+        assert!(subject_matches("orders.created", "orders.created"));
+        assert!(subject_matches("orders.*", "orders.created"));
+        assert!(subject_matches("orders.>", "orders.created.eu"));
+        assert!(!subject_matches("orders.*", "orders.created.eu"));
+        assert!(!subject_matches("orders.>", "orders"));
+        assert!(!subject_matches("orders.created", "orders"));
+        assert!(!subject_matches("orders", "orders.created"));
+    }
+
+    #[test]
+    fn test_push_deliver_subject() {
+        let subscriber = Subscriber {
+            durable_name: Some("orders".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(subscriber.consumer_type, ConsumerType::Pull);
+        assert_eq!(
+            subscriber.push_deliver_subject(),
+            Some("flowgen.deliver.orders".to_string())
+        );
+
+        let subscriber = Subscriber {
+            durable_name: Some("orders".to_string()),
+            deliver_subject: Some("custom.deliver".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            subscriber.push_deliver_subject(),
+            Some("custom.deliver".to_string())
+        );
+    }
+
+    #[test]
+    fn test_wire_format_deserialization() {
+        let config: Config = serde_json::from_str(r#"{"name": "test"}"#).unwrap();
+        assert_eq!(config.format, WireFormat::Bincode);
+
+        let config: Config =
+            serde_json::from_str(r#"{"name": "test", "format": "arrow_ipc"}"#).unwrap();
+        assert_eq!(config.format, WireFormat::ArrowIpc);
+    }
+
+    #[test]
+    fn test_consumer_type_deserialization() {
+        let json = r#"{"name": "sub", "credentials_path": "/creds", "consumer_type": "push"}"#;
+        let subscriber: Subscriber = serde_json::from_str(json).unwrap();
+        assert_eq!(subscriber.consumer_type, ConsumerType::Push);
+    }
+
+    #[test]
+    fn test_publish_subject_defaults_to_event_subject() {
+        let publisher = Publisher::default();
+        assert_eq!(
+            publisher.publish_subject("orders.created"),
+            Some("orders.created".to_string())
+        );
+
+        let publisher = Publisher {
+            subject: "static.subject".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            publisher.publish_subject("orders.created"),
+            Some("static.subject".to_string())
+        );
+    }
+
+    #[test]
+    fn test_publish_subject_with_prefix() {
+        let publisher = Publisher {
+            subject_prefix: Some("flowgen.".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            publisher.publish_subject("orders.created"),
+            Some("flowgen.orders.created".to_string())
+        );
+    }
+
+    #[test]
+    fn test_publish_subject_filters_and_falls_back() {
+        let publisher = Publisher {
+            subjects: vec!["orders.*".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            publisher.publish_subject("orders.created"),
+            Some("orders.created".to_string())
+        );
+        assert_eq!(publisher.publish_subject("accounts.created"), None);
+
+        let publisher = Publisher {
+            subjects: vec!["orders.*".to_string()],
+            fallback_subject: Some("unrouted".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            publisher.publish_subject("accounts.created"),
+            Some("unrouted".to_string())
+        );
+    }
+
     #[test]
     fn test_subscriber_creation() {
         let subscriber = Subscriber {
             name: "test_subscriber".to_string(),
-            credentials_path: PathBuf::from("/path/to/nats.creds"),
+            credentials_path: Some(PathBuf::from("/path/to/nats.creds")),
             subject: "test.subject".to_string(),
             stream: Some(StreamOptions {
                 name: "test_stream".to_string(),
@@ -242,7 +488,7 @@ mod tests {
         assert_eq!(subscriber.name, "test_subscriber");
         assert_eq!(
             subscriber.credentials_path,
-            PathBuf::from("/path/to/nats.creds")
+            Some(PathBuf::from("/path/to/nats.creds"))
         );
         assert_eq!(subscriber.subject, "test.subject");
         assert!(subscriber.stream.is_some());
@@ -255,7 +501,7 @@ mod tests {
     fn test_subscriber_serialization() {
         let subscriber = Subscriber {
             name: "serial_sub".to_string(),
-            credentials_path: PathBuf::from("/credentials/nats.jwt"),
+            credentials_path: Some(PathBuf::from("/credentials/nats.jwt")),
             subject: "my.subject.*".to_string(),
             stream: Some(StreamOptions {
                 name: "my_stream".to_string(),
@@ -280,7 +526,7 @@ mod tests {
     fn test_subscriber_clone() {
         let subscriber = Subscriber {
             name: "clone_sub".to_string(),
-            credentials_path: PathBuf::from("/test/creds.jwt"),
+            credentials_path: Some(PathBuf::from("/test/creds.jwt")),
             subject: "clone.subject".to_string(),
             stream: Some(StreamOptions {
                 name: "clone_stream".to_string(),
@@ -304,7 +550,7 @@ mod tests {
     fn test_publisher_default() {
         let publisher = Publisher::default();
         assert_eq!(publisher.name, String::new());
-        assert_eq!(publisher.credentials_path, PathBuf::new());
+        assert!(publisher.credentials_path.is_none());
         assert_eq!(publisher.subject, String::new());
         assert_eq!(publisher.stream, None);
         assert_eq!(publisher.durable_name, None);
@@ -328,7 +574,7 @@ mod tests {
 
         let publisher = Publisher {
             name: "test_publisher".to_string(),
-            credentials_path: PathBuf::from("/path/to/pub.creds"),
+            credentials_path: Some(PathBuf::from("/path/to/pub.creds")),
             subject: "pub.subject.1".to_string(),
             stream: Some(stream_opts.clone()),
             durable_name: None,
@@ -340,7 +586,7 @@ mod tests {
         assert_eq!(publisher.name, "test_publisher");
         assert_eq!(
             publisher.credentials_path,
-            PathBuf::from("/path/to/pub.creds")
+            Some(PathBuf::from("/path/to/pub.creds"))
         );
         assert_eq!(publisher.subject, "pub.subject.1");
         assert!(publisher.stream.is_some());
@@ -356,7 +602,7 @@ mod tests {
     fn test_publisher_serialization() {
         let publisher = Publisher {
             name: "serial_pub".to_string(),
-            credentials_path: PathBuf::from("/creds/publisher.jwt"),
+            credentials_path: Some(PathBuf::from("/creds/publisher.jwt")),
             subject: "test.serialize".to_string(),
             stream: Some(StreamOptions {
                 name: "serialization_stream".to_string(),
@@ -383,7 +629,7 @@ mod tests {
     fn test_publisher_clone() {
         let publisher = Publisher {
             name: "clone_pub".to_string(),
-            credentials_path: PathBuf::from("/test/pub.creds"),
+            credentials_path: Some(PathBuf::from("/test/pub.creds")),
             subject: "clone.subject".to_string(),
             stream: Some(StreamOptions {
                 name: "clone_stream".to_string(),
@@ -409,7 +655,7 @@ mod tests {
     fn test_publisher_without_stream() {
         let publisher = Publisher {
             name: "no_stream_pub".to_string(),
-            credentials_path: PathBuf::from("/no/stream.creds"),
+            credentials_path: Some(PathBuf::from("/no/stream.creds")),
             subject: "simple.subject".to_string(),
             stream: None,
             durable_name: None,
@@ -440,7 +686,7 @@ mod tests {
 
         let publisher = Publisher {
             name: "multi_pub".to_string(),
-            credentials_path: PathBuf::from("/multi/subjects.creds"),
+            credentials_path: Some(PathBuf::from("/multi/subjects.creds")),
             subject: "subject.1".to_string(),
             stream: Some(stream_opts),
             durable_name: None,
@@ -456,11 +702,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_stream_mirror_deserialization() {
+        let json = r#"{
+            "name": "orders_dr",
+            "credentials_path": "/etc/nats/creds",
+            "stream": {
+                "name": "orders_mirror",
+                "create_or_update": true,
+                "mirror": {
+                    "source_stream": "orders",
+                    "filter_subjects": ["orders.eu.>"]
+                }
+            }
+        }"#;
+        let subscriber: Subscriber = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            subscriber.stream.unwrap().mirror,
+            Some(MirrorOptions {
+                source_stream: "orders".to_string(),
+                filter_subjects: vec!["orders.eu.>".to_string()],
+            })
+        );
+    }
+
     #[test]
     fn test_config_equality() {
         let sub1 = Subscriber {
             name: "eq_sub".to_string(),
-            credentials_path: PathBuf::from("/path/creds.jwt"),
+            credentials_path: Some(PathBuf::from("/path/creds.jwt")),
             subject: "subject1".to_string(),
             stream: Some(StreamOptions {
                 name: "stream1".to_string(),
@@ -478,7 +749,7 @@ mod tests {
 
         let sub2 = Subscriber {
             name: "eq_sub".to_string(),
-            credentials_path: PathBuf::from("/path/creds.jwt"),
+            credentials_path: Some(PathBuf::from("/path/creds.jwt")),
             subject: "subject1".to_string(),
             stream: Some(StreamOptions {
                 name: "stream1".to_string(),
@@ -550,7 +821,7 @@ mod tests {
     fn test_backoff_skipped_in_serialization_when_empty() {
         let config = Config {
             name: "test".to_string(),
-            credentials_path: PathBuf::from("/path/to/creds.jwt"),
+            credentials_path: Some(PathBuf::from("/path/to/creds.jwt")),
             subject: "test.subject".to_string(),
             ..Default::default()
         };
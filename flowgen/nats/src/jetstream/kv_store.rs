@@ -38,10 +38,14 @@ pub struct Config {
     /// Task name.
     pub name: String,
     /// Path to credentials file containing NATS authentication details.
-    pub credentials_path: PathBuf,
+    /// When not set, the task connects without authentication.
+    pub credentials_path: Option<PathBuf>,
     /// NATS server URL. Defaults to "localhost:4222".
     #[serde(default = "default_nats_url")]
     pub url: String,
+    /// Optional TLS settings. Set `cert_path` and `key_path` for mutual TLS.
+    #[serde(default)]
+    pub tls: Option<crate::client::TlsOptions>,
     /// KV bucket name.
     pub bucket: String,
     /// KV operation to perform.
@@ -56,6 +60,9 @@ pub struct Config {
     /// Optional retry configuration.
     #[serde(default)]
     pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
 }
 
 impl ConfigExt for Config {}
@@ -374,9 +381,14 @@ impl flowgen_core::task::runner::Runner for Processor {
             .render(&serde_json::json!({}))
             .map_err(|source| Error::ConfigRender { source })?;
 
-        let client = crate::client::ClientBuilder::new()
-            .credentials_path(config.credentials_path.clone())
+        let mut client_builder = crate::client::ClientBuilder::new();
+        client_builder
             .url(config.url.clone())
+            .tls(config.tls.clone().unwrap_or_default());
+        if let Some(credentials_path) = &config.credentials_path {
+            client_builder.credentials_path(credentials_path.clone());
+        }
+        let client = client_builder
             .build()
             .map_err(|source| Error::Client { source })?
             .connect()
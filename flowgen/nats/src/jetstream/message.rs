@@ -1,7 +1,23 @@
-use arrow::ipc::writer::StreamWriter;
+use super::config::WireFormat;
 use async_nats::jetstream::message::PublishMessage;
 use bincode::{deserialize, serialize};
-use flowgen_core::event::{AvroData, EventBuilder, EventData};
+use flowgen_core::event::{AvroData, EventBuilder, EventData, META_RECEIVED_AT, META_SOURCE};
+use serde_json::{Map, Value};
+
+/// Header carrying the JSON-encoded event meta across flows.
+pub const META_HEADER: &str = "Flowgen-Meta";
+
+/// Returns the event meta forwarded in the [`META_HEADER`] header.
+///
+/// Credentials and the correlation ID are local to the publishing flow and
+/// are dropped, so they neither leak onto the wire nor tie unrelated flows
+/// together.
+fn publish_meta(meta: &Map<String, Value>) -> Map<String, Value> {
+    let mut meta = meta.clone();
+    meta.remove(flowgen_core::auth::AUTH);
+    meta.remove(flowgen_core::registry::CORRELATION_ID);
+    meta
+}
 
 /// Errors that can occur during message conversion between flowgen and NATS formats.
 #[derive(thiserror::Error, Debug)]
@@ -22,6 +38,9 @@ pub enum Error {
     /// Flowgen core event system error.
     #[error(transparent)]
     Event(#[from] flowgen_core::event::Error),
+    /// Arrow IPC encoding or decoding error.
+    #[error(transparent)]
+    Ipc(#[from] crate::ipc::Error),
     /// Binary encoding or decoding error.
     #[error("Binary encoding error: {source}")]
     Bincode {
@@ -36,8 +55,9 @@ pub enum Error {
 /// Trait for converting flowgen events to NATS publish messages.
 pub trait FlowgenMessageExt {
     type Error;
-    /// Convert a flowgen event to a NATS JetStream publish message.
-    fn to_publish(&self) -> Result<PublishMessage, Self::Error>;
+    /// Convert a flowgen event to a NATS JetStream publish message, encoding
+    /// Arrow record batches with the given wire format.
+    fn to_publish(&self, format: &WireFormat) -> Result<PublishMessage, Self::Error>;
 }
 
 /// Trait for converting NATS messages to flowgen events.
@@ -53,27 +73,26 @@ pub trait NatsMessageExt {
 
 impl FlowgenMessageExt for flowgen_core::event::Event {
     type Error = Error;
-    fn to_publish(&self) -> Result<PublishMessage, Self::Error> {
+    fn to_publish(&self, format: &WireFormat) -> Result<PublishMessage, Self::Error> {
         let mut event = PublishMessage::build();
         if let Some(id) = &self.id {
             event = event.message_id(id)
         }
+        if let Some(meta) = &self.meta {
+            let meta = serde_json::to_string(&publish_meta(meta))
+                .map_err(|e| Error::SerdeJson { source: e })?;
+            event = event.header(META_HEADER, meta);
+        }
 
         match &self.data {
             EventData::ArrowRecordBatch(data) => {
-                let mut buffer = Vec::new();
-                {
-                    let mut stream_writer = StreamWriter::try_new(&mut buffer, &data.schema())
-                        .map_err(|e| Error::Arrow { source: e })?;
-                    stream_writer
-                        .write(data)
-                        .map_err(|e| Error::Arrow { source: e })?;
-                    stream_writer
-                        .finish()
-                        .map_err(|e| Error::Arrow { source: e })?;
-                }
-
-                let serialized = serialize(&buffer).map_err(|e| Error::Bincode { source: e })?;
+                let buffer = crate::ipc::write_to_bytes(data)?;
+                let serialized = match format {
+                    WireFormat::ArrowIpc => buffer,
+                    WireFormat::Bincode => {
+                        serialize(&buffer).map_err(|e| Error::Bincode { source: e })?
+                    }
+                };
                 event = event.payload(serialized.into());
             }
             EventData::Avro(data) => {
@@ -106,31 +125,44 @@ impl NatsMessageExt for async_nats::Message {
             if let Some(id) = headers.get(async_nats::header::NATS_MESSAGE_ID) {
                 event_builder = event_builder.id(id.to_string());
             }
+            // Meta from a foreign publisher that is not a JSON object is ignored.
+            let meta = headers
+                .get(META_HEADER)
+                .and_then(|meta| serde_json::from_str::<Map<String, Value>>(meta.as_str()).ok());
+            if let Some(mut meta) = meta {
+                // Source and receive time describe this flow, so they are stamped anew.
+                meta.remove(META_SOURCE);
+                meta.remove(META_RECEIVED_AT);
+                // Identity is never taken from the wire.
+                meta.remove(flowgen_core::auth::AUTH);
+                event_builder = event_builder.meta(meta);
+            }
         }
 
-        let event_data = match deserialize::<AvroData>(&self.payload) {
-            Ok(data) => EventData::Avro(data),
-            Err(_) => match serde_json::from_slice(&self.payload) {
-                Ok(data) => EventData::Json(data),
-                Err(_) => {
-                    let arrow_bytes = deserialize::<Vec<u8>>(&self.payload)
-                        .map_err(|e| Error::Bincode { source: e })?;
-
-                    let cursor = std::io::Cursor::new(arrow_bytes);
-                    let mut stream_reader = arrow::ipc::reader::StreamReader::try_new(cursor, None)
-                        .map_err(|e| Error::Arrow { source: e })?;
-
-                    let recordbatch = stream_reader
-                        .next()
-                        .ok_or_else(Error::NoRecordBatch)?
-                        .map_err(|e| Error::Arrow { source: e })?;
-
-                    EventData::ArrowRecordBatch(recordbatch)
-                }
-            },
+        // Plain Arrow IPC streams are detected by their leading continuation marker.
+        let event_data = if crate::ipc::is_ipc_stream(&self.payload) {
+            EventData::ArrowRecordBatch(crate::ipc::read_from_bytes(&self.payload)?)
+        } else {
+            match deserialize::<AvroData>(&self.payload) {
+                Ok(data) => EventData::Avro(data),
+                Err(_) => match serde_json::from_slice(&self.payload) {
+                    Ok(data) => EventData::Json(data),
+                    Err(_) => {
+                        // Bincode-wrapped Arrow IPC stream published by earlier versions.
+                        let arrow_bytes = deserialize::<Vec<u8>>(&self.payload)
+                            .map_err(|e| Error::Bincode { source: e })?;
+                        EventData::ArrowRecordBatch(crate::ipc::read_from_bytes(&arrow_bytes)?)
+                    }
+                },
+            }
         };
 
-        event_builder.data(event_data).build().map_err(Error::Event)
+        // An empty batch was allowed by the task that published it.
+        event_builder
+            .data(event_data)
+            .allow_empty(true)
+            .build()
+            .map_err(Error::Event)
     }
 }
 
@@ -157,7 +189,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let result = event.to_publish();
+        let result = event.to_publish(&WireFormat::ArrowIpc);
         assert!(result.is_ok());
 
         // We can't easily verify the contents without accessing private fields,
@@ -179,7 +211,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let result = event.to_publish();
+        let result = event.to_publish(&WireFormat::ArrowIpc);
         assert!(result.is_ok());
     }
 
@@ -195,7 +227,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let result = event.to_publish();
+        let result = event.to_publish(&WireFormat::ArrowIpc);
         assert!(result.is_ok());
     }
 
@@ -254,6 +286,54 @@ mod tests {
         assert_eq!(event.task_type, "test");
     }
 
+    #[test]
+    fn test_nats_message_ext_with_meta_header() {
+        let upstream = EventBuilder::new()
+            .subject("test.meta".to_string())
+            .data(EventData::Json(json!({"with": "meta"})))
+            .task_id(0)
+            .task_type("upstream")
+            .build()
+            .unwrap();
+        assert!(upstream.to_publish(&WireFormat::ArrowIpc).is_ok());
+
+        let mut meta = upstream.meta.clone().unwrap();
+        meta.insert("tenant".to_string(), json!("acme"));
+        meta.insert(
+            flowgen_core::auth::AUTH.to_string(),
+            json!({"sub": "alice"}),
+        );
+        let meta = publish_meta(&meta);
+        assert!(!meta.contains_key(flowgen_core::auth::AUTH));
+        assert!(!meta.contains_key(flowgen_core::registry::CORRELATION_ID));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(META_HEADER, serde_json::to_string(&meta).unwrap().as_str());
+
+        let message = async_nats::Message {
+            subject: "test.meta".into(),
+            payload: serde_json::to_vec(&json!({"with": "meta"})).unwrap().into(),
+            reply: None,
+            headers: Some(headers),
+            status: None,
+            description: None,
+            length: 0,
+        };
+
+        let event = message.to_event("nats_jetstream_subscriber", 1).unwrap();
+        assert_eq!(event.get_meta("tenant"), Some(&json!("acme")));
+        assert!(event.get_meta(flowgen_core::auth::AUTH).is_none());
+        assert!(event.get_meta("correlation_id").is_some());
+        assert_ne!(
+            event.get_meta("correlation_id"),
+            upstream.get_meta("correlation_id")
+        );
+        assert_eq!(
+            event.get_meta(META_SOURCE),
+            Some(&json!("nats_jetstream_subscriber"))
+        );
+    }
+
     #[test]
     fn test_nats_message_ext_avro() {
         let avro_data = AvroData {
@@ -313,7 +393,38 @@ mod tests {
         accepts_nats_message_ext(message);
     }
 
-    // Note: Arrow RecordBatch tests would require more complex setup
-    // with actual Arrow schemas and data, but the basic trait functionality
-    // is covered by the tests above.
+    fn arrow_message(payload: Vec<u8>) -> async_nats::Message {
+        async_nats::Message {
+            subject: "arrow.test".into(),
+            payload: payload.into(),
+            reply: None,
+            headers: None,
+            status: None,
+            description: None,
+            length: 0,
+        }
+    }
+
+    #[test]
+    fn test_nats_message_ext_arrow_ipc_and_bincode() {
+        let schema = std::sync::Arc::new(arrow::datatypes::Schema::new(vec![
+            arrow::datatypes::Field::new("id", arrow::datatypes::DataType::Int64, false),
+        ]));
+        let batch = arrow::record_batch::RecordBatch::try_new(
+            schema,
+            vec![std::sync::Arc::new(arrow::array::Int64Array::from(vec![
+                1, 2, 3,
+            ]))],
+        )
+        .unwrap();
+        let ipc_bytes = crate::ipc::write_to_bytes(&batch).unwrap();
+
+        for payload in [ipc_bytes.clone(), serialize(&ipc_bytes).unwrap()] {
+            let event = arrow_message(payload).to_event("test", 0).unwrap();
+            match event.data {
+                EventData::ArrowRecordBatch(decoded) => assert_eq!(decoded, batch),
+                other => panic!("expected record batch, got {other:?}"),
+            }
+        }
+    }
 }
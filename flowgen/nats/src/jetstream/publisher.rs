@@ -1,14 +1,12 @@
 use super::message::FlowgenMessageExt;
+use async_nats::jetstream::context::PublishErrorKind;
 use flowgen_core::client::Client;
 use flowgen_core::config::ConfigExt;
 use flowgen_core::event::{Event, EventBuilder, EventData, EventExt};
 use futures_util::future;
 use std::sync::Arc;
-use tokio::sync::{
-    mpsc::{Receiver, Sender},
-    Mutex,
-};
-use tracing::{error, Instrument};
+use tokio::sync::mpsc::{Receiver, Sender};
+use tracing::{debug, error, Instrument};
 
 /// Serializable representation of a NATS JetStream publish acknowledgment.
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -74,8 +72,15 @@ pub enum Error {
         #[source]
         source: flowgen_core::config::Error,
     },
+    #[error("Client pool error: {source}")]
+    Pool {
+        #[source]
+        source: flowgen_core::client::Error,
+    },
     #[error("Stream configuration is missing")]
     NoStream,
+    #[error("Stream {0} is a mirror and cannot be published to")]
+    CannotPublishToMirror(String),
     #[error("Client is missing or not initialized")]
     MissingClient,
     #[error("Missing required builder attribute: {}", _0)]
@@ -88,11 +93,13 @@ pub enum Error {
 }
 
 pub struct EventHandler {
-    jetstream: Arc<Mutex<async_nats::jetstream::Context>>,
+    pool: flowgen_core::client::PooledClient<crate::client::Client>,
     task_id: usize,
     tx: Option<Sender<Event>>,
     config: Arc<super::config::Publisher>,
     task_type: &'static str,
+    /// Keeps the connection health check registered while the handler runs.
+    _health_check: Option<flowgen_core::health::HealthCheckHandle>,
 }
 
 impl EventHandler {
@@ -110,17 +117,43 @@ impl EventHandler {
                 .render(&event_value)
                 .map_err(|source| Error::ConfigRender { source })?;
 
+            let Some(subject) = config.publish_subject(&event.subject) else {
+                debug!(subject = %event.subject, "Event subject not in configured subjects, skipping publish");
+                // Keep the completion chain intact: leaves acknowledge, others pass the event on.
+                match self.tx {
+                    None => {
+                        if let Some(arc) = completion_tx_arc.as_ref() {
+                            arc.signal_completion(None);
+                        }
+                    }
+                    Some(_) => {
+                        let mut e = event.as_ref().clone();
+                        e.task_id = self.task_id;
+                        e.send_with_logging(self.tx.as_ref())
+                            .await
+                            .map_err(|source| Error::SendMessage { source })?;
+                    }
+                }
+                return Ok(());
+            };
+
             let e = event
-                .to_publish()
+                .to_publish(&self.config.format)
                 .map_err(|source| Error::MessageConversion { source })?;
 
-            let ack_future = self
-                .jetstream
-                .lock()
-                .await
-                .send_publish(config.subject.clone(), e)
-                .await
-                .map_err(|e| Error::Publish { source: e })?;
+            // Release the pooled client once the message is sent, before awaiting the ack.
+            let ack_future = {
+                let client = self
+                    .pool
+                    .acquire()
+                    .await
+                    .map_err(|source| Error::Pool { source })?;
+                let jetstream = client.jetstream.as_ref().ok_or(Error::MissingClient)?;
+                jetstream
+                    .send_publish(subject, e)
+                    .await
+                    .map_err(|e| Error::Publish { source: e })?
+            };
 
             // Timeout is now set on the JetStream context itself in init().
             let ack = ack_future.await.map_err(|e| Error::Publish { source: e })?;
@@ -193,43 +226,87 @@ impl flowgen_core::task::runner::Runner for Publisher {
             .render(&serde_json::json!({}))
             .map_err(|source| Error::ConfigRender { source })?;
 
-        let client = crate::client::ClientBuilder::new()
-            .credentials_path(init_config.credentials_path.clone())
+        let stream_opts = self.config.stream.as_ref().ok_or_else(|| Error::NoStream)?;
+        if stream_opts.mirror.is_some() {
+            return Err(Error::CannotPublishToMirror(stream_opts.name.clone()));
+        }
+
+        let mut client_builder = crate::client::ClientBuilder::new();
+        client_builder
             .url(init_config.url.clone())
+            .tls(init_config.tls.clone().unwrap_or_default());
+        if let Some(credentials_path) = &init_config.credentials_path {
+            client_builder.credentials_path(credentials_path.clone());
+        }
+        let client = client_builder
+            .build()
+            .map_err(|source| Error::ClientAuth { source })?;
+
+        let mut pool_builder = flowgen_core::client::PooledClientBuilder::new()
+            .client(client)
+            .pool_size(
+                init_config
+                    .pool_size
+                    .unwrap_or(flowgen_core::client::DEFAULT_POOL_SIZE),
+            );
+        if let Some(timeout) = init_config.pool_acquire_timeout {
+            pool_builder = pool_builder.acquire_timeout_ms(timeout.as_millis() as u64);
+        }
+
+        let mut pool = pool_builder
             .build()
-            .map_err(|source| Error::ClientAuth { source })?
+            .map_err(|source| Error::Pool { source })?
             .connect()
             .await
             .map_err(|source| Error::ClientAuth { source })?;
 
-        if let Some(mut jetstream) = client.jetstream {
-            let stream_opts = self.config.stream.as_ref().ok_or_else(|| Error::NoStream)?;
+        let health_check = match &self.task_context.http_server {
+            Some(http_server) => {
+                let nats_clients = pool
+                    .clients_mut()
+                    .iter()
+                    .filter_map(|client| client.nats_client.clone())
+                    .collect();
+                Some(
+                    http_server
+                        .register_health_check(
+                            format!("{}.{}", self.task_context.flow.name, self.config.name),
+                            Arc::new(crate::client::ConnectionHealth::new(nats_clients)),
+                        )
+                        .await,
+                )
+            }
+            None => None,
+        };
+
+        let mut stream_ensured = false;
+        for client in pool.clients_mut() {
+            let jetstream = client.jetstream.as_mut().ok_or(Error::MissingClient)?;
 
             // Set timeout on JetStream context if configured.
             if let Some(timeout) = init_config.ack_timeout {
                 jetstream.set_timeout(timeout);
             }
 
-            let jetstream = match stream_opts.create_or_update {
-                true => super::stream::create_or_update_stream(jetstream, stream_opts)
+            // The stream only needs to be created or updated once per pool.
+            if stream_opts.create_or_update && !stream_ensured {
+                super::stream::create_or_update_stream(jetstream.clone(), stream_opts)
                     .await
-                    .map_err(|source| Error::Stream { source })?,
-                false => jetstream,
-            };
+                    .map_err(|source| Error::Stream { source })?;
+                stream_ensured = true;
+            }
+        }
 
-            let jetstream = Arc::new(Mutex::new(jetstream));
-            let event_handler = EventHandler {
-                jetstream,
-                task_id: self.task_id,
-                tx: self.tx.clone(),
-                config: Arc::clone(&self.config),
-                task_type: self.task_type,
-            };
+        let event_handler = EventHandler {
+            pool,
+            task_id: self.task_id,
+            tx: self.tx.clone(),
+            config: Arc::clone(&self.config),
+            task_type: self.task_type,
+            _health_check: health_check,
+        };
 
-            Ok(event_handler)
-        } else {
-            Err(Error::MissingClient)
-        }
+        Ok(event_handler)
     }
 
     #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
@@ -243,7 +320,11 @@ impl flowgen_core::task::runner::Runner for Publisher {
                 Err(e) => {
                     let is_retriable = !matches!(
                         &e,
-                        Error::ConfigRender { .. } | Error::NoStream | Error::MissingClient
+                        Error::ConfigRender { .. }
+                            | Error::NoStream
+                            | Error::CannotPublishToMirror(_)
+                            | Error::MissingClient
+                            | Error::Pool { .. }
                     );
 
                     if is_retriable {
@@ -276,20 +357,39 @@ impl flowgen_core::task::runner::Runner for Publisher {
                 Some(event) => {
                     let event_handler = Arc::clone(&event_handler);
                     let retry_strategy = retry_config.strategy();
+                    let metrics = self.task_context.metrics.clone();
                     let handle = tokio::spawn(
                         async move {
+                            let bytes = event.data_size() as u64;
                             let result = tokio_retry::Retry::spawn(retry_strategy, || async {
                                 match event_handler.handle(event.clone()).await {
                                     Ok(result) => Ok(result),
                                     Err(e) => {
-                                        error!(error = %e, "Failed to publish message");
-                                        Err(tokio_retry::RetryError::transient(e))
+                                        // A message over the server's max payload fails on every attempt.
+                                        let is_retriable = !matches!(
+                                            &e,
+                                            Error::Publish { source }
+                                                if matches!(source.kind(), PublishErrorKind::MaxPayloadExceeded)
+                                        );
+
+                                        if is_retriable {
+                                            error!(error = %e, "Failed to publish message");
+                                            Err(tokio_retry::RetryError::transient(e))
+                                        } else {
+                                            error!(error = %e, "Non-retriable publish error");
+                                            Err(tokio_retry::RetryError::permanent(e))
+                                        }
                                     }
                                 }
                             })
                             .await;
 
+                            if result.is_ok() {
+                                metrics.record_processed(bytes);
+                            }
+
                             if let Err(err) = result {
+                                metrics.record_failed();
                                 error!(error = %err, "Failed to publish message after all retry attempts");
                             }
                         }
@@ -387,6 +487,7 @@ impl PublisherBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use flowgen_core::task::runner::Runner;
     use serde_json::{Map, Value};
     use std::path::PathBuf;
     use tokio::sync::mpsc;
@@ -420,7 +521,7 @@ mod tests {
     async fn test_publisher_builder() {
         let config = Arc::new(super::super::config::Publisher {
             name: "test_publisher".to_string(),
-            credentials_path: PathBuf::from("/test/creds.jwt"),
+            credentials_path: Some(PathBuf::from("/test/creds.jwt")),
             subject: "test.subject".to_string(),
             stream: Some(super::super::config::StreamOptions {
                 name: "test_stream".to_string(),
@@ -465,4 +566,37 @@ mod tests {
             Error::MissingBuilderAttribute(_)
         ));
     }
+
+    #[tokio::test]
+    async fn test_publisher_init_rejects_mirror_stream() {
+        let config = Arc::new(super::super::config::Publisher {
+            name: "test_publisher".to_string(),
+            credentials_path: Some(PathBuf::from("/test/creds.jwt")),
+            subject: "test.subject".to_string(),
+            stream: Some(super::super::config::StreamOptions {
+                name: "orders_mirror".to_string(),
+                mirror: Some(super::super::config::MirrorOptions {
+                    source_stream: "orders".to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let (_tx, rx) = mpsc::channel(100);
+
+        let publisher = PublisherBuilder::new()
+            .config(config)
+            .receiver(rx)
+            .task_type("test")
+            .task_context(create_mock_task_context())
+            .build()
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            publisher.init().await,
+            Err(Error::CannotPublishToMirror(name)) if name == "orders_mirror"
+        ));
+    }
 }
@@ -21,6 +21,31 @@ pub enum Error {
     },
 }
 
+/// Builds the JetStream mirror source for the configured mirror.
+///
+/// A single filter subject uses `filter_subject`; several are expressed as
+/// identity subject transforms, which is how JetStream filters by many subjects.
+fn mirror_source(mirror: &super::config::MirrorOptions) -> jetstream::stream::Source {
+    let mut source = jetstream::stream::Source {
+        name: mirror.source_stream.clone(),
+        ..Default::default()
+    };
+    match mirror.filter_subjects.as_slice() {
+        [] => {}
+        [subject] => source.filter_subject = Some(subject.clone()),
+        subjects => {
+            source.subject_transforms = subjects
+                .iter()
+                .map(|subject| jetstream::stream::SubjectTransform {
+                    source: subject.clone(),
+                    destination: subject.clone(),
+                })
+                .collect();
+        }
+    }
+    source
+}
+
 /// Creates or updates a JetStream stream based on the provided configuration.
 ///
 /// If the stream exists:
@@ -28,7 +53,7 @@ pub enum Error {
 /// - Uses new config values if provided, otherwise keeps existing values
 ///
 /// If the stream doesn't exist:
-/// - Creates it with provided config
+/// - Creates it with provided config, as a mirror if `mirror` is set
 /// - Uses sensible defaults for unspecified values
 ///
 /// Returns the JetStream context for further use.
@@ -50,8 +75,9 @@ pub async fn create_or_update_stream(
 
             // Merge subjects - additive update: new subjects are added to existing ones.
             // If stream_opts.subjects is empty, existing subjects are preserved.
+            // Mirror streams cannot have subjects of their own.
             let mut subjects = existing_config.subjects.clone();
-            if !stream_opts.subjects.is_empty() {
+            if existing_config.mirror.is_none() && !stream_opts.subjects.is_empty() {
                 subjects.extend(stream_opts.subjects.clone());
                 subjects.sort();
                 subjects.dedup();
@@ -108,6 +134,10 @@ pub async fn create_or_update_stream(
                 .max_consumers
                 .unwrap_or(existing_config.max_consumers);
 
+            let num_replicas = stream_opts
+                .num_replicas
+                .unwrap_or(existing_config.num_replicas);
+
             let duplicate_window = stream_opts
                 .duplicate_window
                 .unwrap_or(existing_config.duplicate_window);
@@ -134,6 +164,7 @@ pub async fn create_or_update_stream(
                 max_bytes,
                 max_message_size,
                 max_consumers,
+                num_replicas,
                 subjects,
                 discard,
                 retention,
@@ -155,8 +186,11 @@ pub async fn create_or_update_stream(
             // Stream doesn't exist, create it.
             // Only set values if they're explicitly configured, otherwise let NATS use its defaults.
 
-            // Default to wildcard if no subjects specified.
-            let subjects = if stream_opts.subjects.is_empty() {
+            // Mirror streams take their messages from the source stream and cannot
+            // have subjects. Otherwise, default to wildcard if no subjects specified.
+            let subjects = if stream_opts.mirror.is_some() {
+                Vec::new()
+            } else if stream_opts.subjects.is_empty() {
                 vec![">".to_string()]
             } else {
                 stream_opts.subjects.clone()
@@ -166,6 +200,7 @@ pub async fn create_or_update_stream(
             let mut stream_config = Config {
                 name: stream_opts.name.clone(),
                 subjects,
+                mirror: stream_opts.mirror.as_ref().map(mirror_source),
                 ..Default::default()
             };
 
@@ -219,6 +254,10 @@ pub async fn create_or_update_stream(
                 stream_config.max_consumers = max_cons;
             }
 
+            if let Some(num_replicas) = stream_opts.num_replicas {
+                stream_config.num_replicas = num_replicas;
+            }
+
             if let Some(duplicate_window) = stream_opts.duplicate_window {
                 stream_config.duplicate_window = duplicate_window;
             }
@@ -248,3 +287,40 @@ pub async fn create_or_update_stream(
 
     Ok(jetstream)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jetstream::config::MirrorOptions;
+
+    #[test]
+    fn test_mirror_source_filters() {
+        let mirror = |filter_subjects: Vec<&str>| MirrorOptions {
+            source_stream: "orders".to_string(),
+            filter_subjects: filter_subjects.into_iter().map(String::from).collect(),
+        };
+
+        let source = mirror_source(&mirror(vec![]));
+        assert_eq!(source.name, "orders");
+        assert!(source.filter_subject.is_none());
+        assert!(source.subject_transforms.is_empty());
+
+        let source = mirror_source(&mirror(vec!["orders.eu.>"]));
+        assert_eq!(source.filter_subject.as_deref(), Some("orders.eu.>"));
+
+        let source = mirror_source(&mirror(vec!["orders.eu.>", "orders.us.>"]));
+        assert!(source.filter_subject.is_none());
+        let transforms: Vec<(&str, &str)> = source
+            .subject_transforms
+            .iter()
+            .map(|t| (t.source.as_str(), t.destination.as_str()))
+            .collect();
+        assert_eq!(
+            transforms,
+            vec![
+                ("orders.eu.>", "orders.eu.>"),
+                ("orders.us.>", "orders.us.>")
+            ]
+        );
+    }
+}
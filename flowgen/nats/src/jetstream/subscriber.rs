@@ -1,9 +1,12 @@
 use super::message::NatsMessageExt;
+use arrow::array::{ArrayRef, Int64Array, StringArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
 use async_nats::jetstream::{self};
 use flowgen_core::{
     client::Client,
     config::ConfigExt,
-    event::{new_completion_channel, Event, EventExt},
+    event::{new_completion_channel, Event, EventData, EventExt},
 };
 use std::sync::Arc;
 use tokio::pin;
@@ -45,6 +48,11 @@ pub enum Error {
         #[source]
         source: async_nats::jetstream::consumer::pull::BatchError,
     },
+    #[error("Push consumer messages error: {source}")]
+    PushMessages {
+        #[source]
+        source: async_nats::jetstream::consumer::StreamError,
+    },
     #[error("Stream management error: {source}")]
     StreamManagement {
         #[source]
@@ -62,6 +70,16 @@ pub enum Error {
     },
     #[error("Consumer configuration check error")]
     ConsumerInfoFailed,
+    #[error("Error reading JetStream message metadata: {source}")]
+    MessageInfo {
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    #[error("Arrow error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
     #[error("Consumer '{consumer}' exists with different filter subject '{existing}', expected '{expected}'. Please delete the existing consumer or use a different durable name")]
     ConsumerFilterMismatch {
         consumer: String,
@@ -72,6 +90,8 @@ pub enum Error {
     MissingStreamConfig,
     #[error("Durable name is required for JetStream consumers")]
     MissingDurableName,
+    #[error("Subject is required for JetStream subscribers")]
+    MissingSubject,
     #[error("Other subscriber error")]
     Other(#[source] Box<dyn std::error::Error + Send + Sync>),
     #[error("Missing required builder attribute: {}", _0)]
@@ -90,14 +110,131 @@ pub enum Error {
     },
 }
 
+/// JetStream consumer in either delivery mode.
+enum Consumer {
+    Pull(jetstream::consumer::Consumer<jetstream::consumer::pull::Config>),
+    Push(jetstream::consumer::Consumer<jetstream::consumer::push::Config>),
+}
+
+/// Returns the existing durable consumer or creates it.
+///
+/// An existing consumer with a different filter subject is rejected rather than
+/// silently consuming the wrong messages.
+async fn get_or_create_consumer<T>(
+    stream: &jetstream::stream::Stream,
+    durable_name: &str,
+    filter_subject: &str,
+    consumer_config: T,
+) -> Result<jetstream::consumer::Consumer<T>, Error>
+where
+    T: jetstream::consumer::IntoConsumerConfig + jetstream::consumer::FromConsumer,
+{
+    match stream.get_consumer::<T>(durable_name).await {
+        Ok(mut existing_consumer) => {
+            let consumer_info = existing_consumer
+                .info()
+                .await
+                .map_err(|_| Error::ConsumerInfoFailed)?;
+            let current_filter = consumer_info.config.filter_subject.clone();
+
+            if current_filter != filter_subject {
+                Err(Error::ConsumerFilterMismatch {
+                    consumer: durable_name.to_string(),
+                    existing: current_filter,
+                    expected: filter_subject.to_string(),
+                })
+            } else {
+                Ok(existing_consumer)
+            }
+        }
+        Err(_) => stream
+            .create_consumer(consumer_config)
+            .await
+            .map_err(|e| Error::Consumer { source: e }),
+    }
+}
+
+/// JetStream metadata of a received message.
+#[derive(Debug, Clone, PartialEq)]
+struct MessageMetadata {
+    /// Stream sequence number of the message.
+    sequence: u64,
+    /// Subject the message was published to.
+    subject: String,
+    /// Publish time in nanoseconds since the Unix epoch.
+    timestamp_ns: i64,
+    /// Name of the stream holding the message.
+    stream: String,
+}
+
+impl MessageMetadata {
+    /// Reads the metadata from a JetStream message's reply subject.
+    fn from_message(message: &async_nats::jetstream::Message) -> Result<Self, Error> {
+        let info = message
+            .info()
+            .map_err(|source| Error::MessageInfo { source })?;
+        Ok(Self {
+            sequence: info.stream_sequence,
+            subject: message.subject.to_string(),
+            timestamp_ns: i64::try_from(info.published.unix_timestamp_nanos()).unwrap_or(i64::MAX),
+            stream: info.stream.to_string(),
+        })
+    }
+}
+
+/// Appends the message metadata as columns repeating the same value on every row.
+fn add_metadata_columns(
+    batch: &RecordBatch,
+    metadata: &MessageMetadata,
+) -> Result<RecordBatch, Error> {
+    let num_rows = batch.num_rows();
+    let sequence = i64::try_from(metadata.sequence).unwrap_or(i64::MAX);
+
+    let mut fields: Vec<Field> = batch
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| field.as_ref().clone())
+        .collect();
+    fields.extend([
+        Field::new("_nats_sequence", DataType::Int64, false),
+        Field::new("_nats_subject", DataType::Utf8, false),
+        Field::new("_nats_timestamp_ns", DataType::Int64, false),
+        Field::new("_nats_stream", DataType::Utf8, false),
+    ]);
+
+    let mut columns = batch.columns().to_vec();
+    columns.extend([
+        Arc::new(Int64Array::from(vec![sequence; num_rows])) as ArrayRef,
+        Arc::new(StringArray::from(vec![metadata.subject.as_str(); num_rows])),
+        Arc::new(Int64Array::from(vec![metadata.timestamp_ns; num_rows])),
+        Arc::new(StringArray::from(vec![metadata.stream.as_str(); num_rows])),
+    ]);
+
+    let schema = Schema::new_with_metadata(fields, batch.schema().metadata().clone());
+    RecordBatch::try_new(Arc::new(schema), columns).map_err(|source| Error::Arrow { source })
+}
+
+/// Acknowledges a message after its flow completed.
+///
+/// A failed ack is logged rather than returned: the flow already ran, and
+/// JetStream redelivers the message once `ack_wait` expires.
+async fn ack_message(message: &async_nats::jetstream::Message) {
+    if let Err(e) = message.ack().await {
+        warn!(error = %e, "Failed to acknowledge message, it will be redelivered");
+    }
+}
+
 /// Event handler for processing NATS messages.
 pub struct EventHandler {
-    consumer: jetstream::consumer::Consumer<jetstream::consumer::pull::Config>,
+    consumer: Consumer,
     tx: Option<Sender<Event>>,
     task_id: usize,
     config: Arc<super::config::Subscriber>,
     task_type: &'static str,
     task_context: Arc<flowgen_core::task::context::TaskContext>,
+    /// Keeps the connection health check registered while the handler runs.
+    _health_check: Option<flowgen_core::health::HealthCheckHandle>,
 }
 
 impl EventHandler {
@@ -121,27 +258,32 @@ impl EventHandler {
                     .to_event(self.task_type, self.task_id)
                     .map_err(|source| Error::MessageConversion { source })?;
 
+                if self.config.metadata_columns {
+                    if let EventData::ArrowRecordBatch(batch) = &e.data {
+                        let metadata = MessageMetadata::from_message(&message)?;
+                        e.data =
+                            EventData::ArrowRecordBatch(add_metadata_columns(batch, &metadata)?);
+                    }
+                }
+
                 e.completion_tx = Some(completion_state);
 
                 e.send_with_logging(self.tx.as_ref())
                     .await
                     .map_err(|source| Error::SendMessage { source })?;
+                self.task_context.metrics.record_received();
 
                 // Wait for flow completion with configured ack_timeout.
                 // Failed flows skip message acknowledgment, allowing JetStream to automatically redeliver.
                 match self.config.ack_timeout {
                     Some(timeout) => match tokio::time::timeout(timeout, completion_rx).await {
-                        Ok(Ok(Ok(_))) => {
-                            message.ack().await.ok();
-                        }
+                        Ok(Ok(Ok(_))) => ack_message(&message).await,
                         Ok(Ok(Err(_))) | Ok(Err(_)) | Err(_) => {
                             warn!("Flow completion failed or timed out");
                         }
                     },
                     None => match completion_rx.await {
-                        Ok(Ok(_)) => {
-                            message.ack().await.ok();
-                        }
+                        Ok(Ok(_)) => ack_message(&message).await,
                         Ok(Err(_)) | Err(_) => {
                             warn!("Flow completion failed or timed out");
                         }
@@ -156,6 +298,56 @@ impl EventHandler {
 
     /// Processes messages from the NATS JetStream consumer.
     async fn handle(self) -> Result<(), Error> {
+        match &self.consumer {
+            Consumer::Pull(consumer) => self.handle_pull(consumer).await,
+            Consumer::Push(consumer) => self.handle_push(consumer).await,
+        }
+    }
+
+    /// Processes messages pushed by the server to the consumer's deliver subject.
+    async fn handle_push(
+        &self,
+        consumer: &jetstream::consumer::Consumer<jetstream::consumer::push::Config>,
+    ) -> Result<(), Error> {
+        let messages = consumer
+            .messages()
+            .await
+            .map_err(|source| Error::PushMessages { source })?
+            .map(|message_result| {
+                message_result.map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
+            });
+
+        match self.config.throttle {
+            Some(throttle_duration) => {
+                let throttled = messages.throttle(throttle_duration);
+                pin!(throttled);
+                while let Some(message_result) = throttled.next().await {
+                    if self.task_context.cancellation_token.is_cancelled() {
+                        return Ok(());
+                    }
+                    self.process_message(message_result).await?;
+                }
+            }
+            None => {
+                pin!(messages);
+                while let Some(message_result) = messages.next().await {
+                    if self.task_context.cancellation_token.is_cancelled() {
+                        return Ok(());
+                    }
+                    self.process_message(message_result).await?;
+                }
+            }
+        }
+
+        // Push message streams only end when the subscription is lost.
+        Err(Error::StreamEnded)
+    }
+
+    /// Fetches and processes message batches from a pull consumer.
+    async fn handle_pull(
+        &self,
+        consumer: &jetstream::consumer::Consumer<jetstream::consumer::pull::Config>,
+    ) -> Result<(), Error> {
         loop {
             if self.task_context.cancellation_token.is_cancelled() {
                 return Ok(());
@@ -167,15 +359,13 @@ impl EventHandler {
 
             // Fetch messages with / without max_messages setting.
             let messages = match self.config.max_messages {
-                Some(max_messages) => self
-                    .consumer
+                Some(max_messages) => consumer
                     .fetch()
                     .max_messages(max_messages)
                     .messages()
                     .await
                     .map_err(|e| Error::ConsumerBatch { source: e })?,
-                None => self
-                    .consumer
+                None => consumer
                     .fetch()
                     .messages()
                     .await
@@ -240,15 +430,34 @@ impl flowgen_core::task::runner::Runner for Subscriber {
             .render(&serde_json::json!({}))
             .map_err(|source| Error::ConfigRender { source })?;
 
-        let client = crate::client::ClientBuilder::new()
-            .credentials_path(init_config.credentials_path.clone())
+        let mut client_builder = crate::client::ClientBuilder::new();
+        client_builder
             .url(init_config.url.clone())
+            .tls(init_config.tls.clone().unwrap_or_default());
+        if let Some(credentials_path) = &init_config.credentials_path {
+            client_builder.credentials_path(credentials_path.clone());
+        }
+        let client = client_builder
             .build()
             .map_err(|source| Error::Client { source })?
             .connect()
             .await
             .map_err(|source| Error::Client { source })?;
 
+        let health_check = match (&self.task_context.http_server, &client.nats_client) {
+            (Some(http_server), Some(nats_client)) => Some(
+                http_server
+                    .register_health_check(
+                        format!("{}.{}", self.task_context.flow.name, self.config.name),
+                        Arc::new(crate::client::ConnectionHealth::new(vec![
+                            nats_client.clone()
+                        ])),
+                    )
+                    .await,
+            ),
+            _ => None,
+        };
+
         if let Some(mut jetstream) = client.jetstream {
             let stream_opts = self
                 .config
@@ -278,54 +487,81 @@ impl flowgen_core::task::runner::Runner for Subscriber {
                 .as_ref()
                 .ok_or_else(|| Error::MissingDurableName)?;
 
-            let mut consumer_config = jetstream::consumer::pull::Config {
-                durable_name: Some(durable_name.clone()),
-                filter_subject: init_config.subject.clone(),
-                ..Default::default()
-            };
+            let consumer = match init_config.consumer_type {
+                super::config::ConsumerType::Pull => {
+                    let mut consumer_config = jetstream::consumer::pull::Config {
+                        durable_name: Some(durable_name.clone()),
+                        filter_subject: init_config.subject.clone(),
+                        ..Default::default()
+                    };
 
-            if let Some(ack_timeout) = init_config.ack_timeout {
-                consumer_config.ack_wait = ack_timeout;
-            }
+                    if let Some(ack_timeout) = init_config.ack_timeout {
+                        consumer_config.ack_wait = ack_timeout;
+                    }
 
-            if let Some(max_ack_pending) = init_config.max_ack_pending {
-                consumer_config.max_ack_pending = max_ack_pending;
-            }
+                    if let Some(max_ack_pending) = init_config.max_ack_pending {
+                        consumer_config.max_ack_pending = max_ack_pending;
+                    }
 
-            if let Some(max_waiting) = init_config.max_waiting {
-                consumer_config.max_waiting = max_waiting;
-            }
+                    if let Some(max_waiting) = init_config.max_waiting {
+                        consumer_config.max_waiting = max_waiting;
+                    }
 
-            if let Some(max_deliver) = init_config.max_deliver {
-                consumer_config.max_deliver = max_deliver;
-            }
+                    if let Some(max_deliver) = init_config.max_deliver {
+                        consumer_config.max_deliver = max_deliver;
+                    }
 
-            if !init_config.backoff.is_empty() {
-                consumer_config.backoff = init_config.backoff.clone();
-            }
+                    if !init_config.backoff.is_empty() {
+                        consumer_config.backoff = init_config.backoff.clone();
+                    }
+
+                    Consumer::Pull(
+                        get_or_create_consumer(
+                            &stream,
+                            durable_name,
+                            &init_config.subject,
+                            consumer_config,
+                        )
+                        .await?,
+                    )
+                }
+                super::config::ConsumerType::Push => {
+                    let mut consumer_config = jetstream::consumer::push::Config {
+                        durable_name: Some(durable_name.clone()),
+                        deliver_subject: init_config
+                            .push_deliver_subject()
+                            .ok_or_else(|| Error::MissingDurableName)?,
+                        deliver_group: init_config.deliver_group.clone(),
+                        filter_subject: init_config.subject.clone(),
+                        ..Default::default()
+                    };
+
+                    if let Some(ack_timeout) = init_config.ack_timeout {
+                        consumer_config.ack_wait = ack_timeout;
+                    }
 
-            let consumer = match stream.get_consumer(durable_name).await {
-                Ok(mut existing_consumer) => {
-                    let consumer_info = existing_consumer
-                        .info()
-                        .await
-                        .map_err(|_| Error::ConsumerInfoFailed)?;
-                    let current_filter = consumer_info.config.filter_subject.clone();
-
-                    if current_filter != init_config.subject {
-                        return Err(Error::ConsumerFilterMismatch {
-                            consumer: durable_name.clone(),
-                            existing: current_filter,
-                            expected: init_config.subject.clone(),
-                        });
-                    } else {
-                        existing_consumer
+                    if let Some(max_ack_pending) = init_config.max_ack_pending {
+                        consumer_config.max_ack_pending = max_ack_pending;
                     }
+
+                    if let Some(max_deliver) = init_config.max_deliver {
+                        consumer_config.max_deliver = max_deliver;
+                    }
+
+                    if !init_config.backoff.is_empty() {
+                        consumer_config.backoff = init_config.backoff.clone();
+                    }
+
+                    Consumer::Push(
+                        get_or_create_consumer(
+                            &stream,
+                            durable_name,
+                            &init_config.subject,
+                            consumer_config,
+                        )
+                        .await?,
+                    )
                 }
-                Err(_) => stream
-                    .create_consumer(consumer_config)
-                    .await
-                    .map_err(|e| Error::Consumer { source: e })?,
             };
 
             Ok(EventHandler {
@@ -335,6 +571,7 @@ impl flowgen_core::task::runner::Runner for Subscriber {
                 config: Arc::clone(&self.config),
                 task_type: self.task_type,
                 task_context: Arc::clone(&self.task_context),
+                _health_check: health_check,
             })
         } else {
             Err(Error::Other(
@@ -456,10 +693,17 @@ impl SubscriberBuilder {
     }
 
     pub async fn build(self) -> Result<Subscriber, Error> {
+        let config = self
+            .config
+            .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?;
+        // The subject is shared with the publisher config, where it may be left
+        // empty, so a missing subject only surfaces here.
+        if config.subject.is_empty() {
+            return Err(Error::MissingSubject);
+        }
+
         Ok(Subscriber {
-            config: self
-                .config
-                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            config,
             tx: self.tx,
             task_id: self.task_id,
             task_context: self
@@ -504,11 +748,76 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_add_metadata_columns() {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef],
+        )
+        .unwrap();
+        let metadata = MessageMetadata {
+            sequence: 42,
+            subject: "orders.created".to_string(),
+            timestamp_ns: 1_700_000_000_000_000_000,
+            stream: "ORDERS".to_string(),
+        };
+
+        let result = add_metadata_columns(&batch, &metadata).unwrap();
+
+        assert_eq!(result.num_rows(), 3);
+        assert_eq!(result.num_columns(), 5);
+        let sequence = result
+            .column_by_name("_nats_sequence")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert!(sequence.iter().all(|value| value == Some(42)));
+        let subject = result
+            .column_by_name("_nats_subject")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert!(subject.iter().all(|value| value == Some("orders.created")));
+        let timestamp = result
+            .column_by_name("_nats_timestamp_ns")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(timestamp.value(0), 1_700_000_000_000_000_000);
+        let stream = result
+            .column_by_name("_nats_stream")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(stream.value(2), "ORDERS");
+    }
+
+    #[test]
+    fn test_add_metadata_columns_empty_batch() {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+        let batch = RecordBatch::new_empty(schema);
+        let metadata = MessageMetadata {
+            sequence: 1,
+            subject: "orders.created".to_string(),
+            timestamp_ns: 0,
+            stream: "ORDERS".to_string(),
+        };
+
+        let result = add_metadata_columns(&batch, &metadata).unwrap();
+        assert_eq!(result.num_rows(), 0);
+        assert_eq!(result.num_columns(), 5);
+    }
+
     #[tokio::test]
     async fn test_subscriber_builder() {
         let config = Arc::new(super::super::config::Subscriber {
             name: "test_subscriber".to_string(),
-            credentials_path: PathBuf::from("/test/creds.jwt"),
+            credentials_path: Some(PathBuf::from("/test/creds.jwt")),
             subject: "test.subject".to_string(),
             stream: Some(super::super::config::StreamOptions {
                 name: "test_stream".to_string(),
@@ -554,7 +863,7 @@ mod tests {
     async fn test_subscriber_builder_build_missing_task_context() {
         let config = Arc::new(super::super::config::Subscriber {
             name: "test_subscriber".to_string(),
-            credentials_path: PathBuf::from("/test/creds.jwt"),
+            credentials_path: Some(PathBuf::from("/test/creds.jwt")),
             subject: "test.subject".to_string(),
             stream: Some(super::super::config::StreamOptions {
                 name: "test_stream".to_string(),
@@ -584,4 +893,22 @@ mod tests {
             matches!(result.unwrap_err(), Error::MissingBuilderAttribute(attr) if attr == "task_context")
         );
     }
+
+    #[tokio::test]
+    async fn test_subscriber_builder_build_missing_subject() {
+        let config = Arc::new(super::super::config::Subscriber {
+            name: "test_subscriber".to_string(),
+            durable_name: Some("test_consumer".to_string()),
+            ..Default::default()
+        });
+
+        let result = SubscriberBuilder::new()
+            .config(config)
+            .task_type("test_task")
+            .task_context(create_mock_task_context())
+            .build()
+            .await;
+
+        assert!(matches!(result.unwrap_err(), Error::MissingSubject));
+    }
 }
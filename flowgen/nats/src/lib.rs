@@ -1,7 +1,8 @@
 //! NATS client integration for the flowgen worker system.
 //!
 //! This crate provides NATS messaging capabilities including JetStream support
-//! for reliable message publishing and consuming. It handles authentication,
+//! for reliable message publishing and consuming, and plain core NATS
+//! subscriptions for servers without JetStream. It handles authentication,
 //! connection management, and provides both publisher and subscriber implementations
 //! that integrate with the flowgen event system.
 
@@ -11,6 +12,17 @@ pub mod cache;
 /// NATS client connection and authentication management.
 pub mod client;
 
+/// Arrow IPC encoding for record batches sent over NATS.
+pub mod ipc;
+
+/// Core NATS (non-JetStream) functionality for plain subject subscriptions.
+pub mod core {
+    /// Configuration structures for core NATS tasks.
+    pub mod config;
+    /// Core NATS subscriber implementation for at-most-once message consumption.
+    pub mod subscriber;
+}
+
 /// JetStream specific functionality for reliable messaging.
 pub mod jetstream {
     /// Configuration structures for JetStream publishers and subscribers.
@@ -13,8 +13,14 @@ pub const DEFAULT_AVRO_EXTENSION: &str = "avro";
 pub const DEFAULT_CSV_EXTENSION: &str = "csv";
 /// File extension for JSON format files.
 pub const DEFAULT_JSON_EXTENSION: &str = "json";
+/// File extension for newline-delimited JSON format files.
+pub const DEFAULT_NDJSON_EXTENSION: &str = "ndjson";
 /// File extension for Parquet format files.
 pub const DEFAULT_PARQUET_EXTENSION: &str = "parquet";
+/// File extension appended to gzip-compressed files.
+pub const DEFAULT_GZIP_EXTENSION: &str = "gz";
+/// File extension appended to zstd-compressed files.
+pub const DEFAULT_ZSTD_EXTENSION: &str = "zst";
 
 /// Object store operation type.
 #[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
@@ -99,6 +105,9 @@ pub struct Processor {
     pub has_header: Option<bool>,
     /// CSV delimiter character, defaults to comma (read only).
     pub delimiter: Option<String>,
+    /// Number of CSV rows scanned to infer column types, defaults to the
+    /// whole file (read only).
+    pub infer_schema_max_records: Option<usize>,
     /// Delete the file after successfully reading it (read only).
     pub delete_after_read: Option<bool>,
 
@@ -108,6 +117,19 @@ pub struct Processor {
     pub format: WriteFormat,
     /// Hive-style partitioning configuration (write only).
     pub hive_partition_options: Option<HivePartitionOptions>,
+    /// Compression applied to the written file, which also gains the
+    /// codec's extension, e.g. `.csv.gz` (write only).
+    #[serde(default)]
+    pub compression: Option<Compression>,
+    /// Compression level, defaults to the codec's default level. Gzip
+    /// accepts 0-9, zstd up to 22 (write only).
+    #[serde(default)]
+    pub compression_level: Option<i32>,
+    /// Name under which the schema of written Arrow batches is registered in the
+    /// global schema registry, so other tasks can reference it (write only).
+    /// The first written schema is kept.
+    #[serde(default)]
+    pub schema_name: Option<String>,
 
     // --- Move-specific fields ---
     /// Source path pattern with wildcard support (move only).
@@ -131,6 +153,9 @@ pub struct Processor {
     /// Optional retry configuration (overrides app-level retry config).
     #[serde(default)]
     pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
 }
 
 impl ConfigExt for Processor {}
@@ -148,8 +173,21 @@ pub enum WriteFormat {
     Csv,
     /// Apache Avro binary format.
     Avro,
-    /// Newline-delimited JSON.
+    /// JSON document, written as-is.
     Json,
+    /// Newline-delimited JSON, one object per record or row.
+    #[serde(alias = "jsonl")]
+    Ndjson,
+}
+
+/// Compression codec for written files.
+#[derive(PartialEq, Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Compression {
+    /// Gzip compression (`.gz`).
+    Gzip,
+    /// Zstandard compression (`.zst`).
+    Zstd,
 }
 
 /// Configuration for Hive-style directory partitioning.
@@ -216,6 +254,29 @@ mod tests {
         assert_eq!(config, deserialized);
     }
 
+    #[test]
+    fn test_write_config_compression_deserialization() {
+        let json = r#"{
+            "name": "archive",
+            "operation": "write",
+            "path": "gs://my-bucket/archive/",
+            "format": "csv",
+            "compression": "zstd",
+            "compression_level": 9
+        }"#;
+        let config: Processor = serde_json::from_str(json).unwrap();
+        assert_eq!(config.compression, Some(Compression::Zstd));
+        assert_eq!(config.compression_level, Some(9));
+    }
+
+    #[test]
+    fn test_write_format_ndjson_accepts_jsonl_alias() {
+        let ndjson: WriteFormat = serde_json::from_str(r#""ndjson""#).unwrap();
+        let jsonl: WriteFormat = serde_json::from_str(r#""jsonl""#).unwrap();
+        assert_eq!(ndjson, WriteFormat::Ndjson);
+        assert_eq!(jsonl, WriteFormat::Ndjson);
+    }
+
     #[test]
     fn test_write_config() {
         let config = Processor {
@@ -1,7 +1,3 @@
-use super::config::{
-    DEFAULT_AVRO_EXTENSION, DEFAULT_CSV_EXTENSION, DEFAULT_JSON_EXTENSION,
-    DEFAULT_PARQUET_EXTENSION,
-};
 use bytes::BytesMut;
 use flowgen_core::buffer::{ContentType, FromReader};
 use flowgen_core::config::ConfigExt;
@@ -90,6 +86,31 @@ pub enum Error {
     },
 }
 
+/// Applies the configured batch size and CSV options to a detected content type.
+fn apply_read_options(content_type: ContentType, config: &super::config::Processor) -> ContentType {
+    let batch_size = config.batch_size.unwrap_or(DEFAULT_BATCH_SIZE);
+    match content_type {
+        ContentType::Csv {
+            infer_schema_max_records,
+            ..
+        } => ContentType::Csv {
+            batch_size,
+            has_header: config.has_header.unwrap_or(DEFAULT_HAS_HEADER),
+            delimiter: config
+                .delimiter
+                .as_ref()
+                .and_then(|d| d.as_bytes().first().copied()),
+            infer_schema_max_records: config.infer_schema_max_records.or(infer_schema_max_records),
+        },
+        ContentType::Parquet { .. } => ContentType::Parquet { batch_size },
+        ContentType::Compressed { inner, codec } => ContentType::Compressed {
+            inner: Box::new(apply_read_options(*inner, config)),
+            codec,
+        },
+        other => other,
+    }
+}
+
 /// Handles processing of individual events by writing them to object storage.
 pub struct EventHandler {
     /// Writer configuration settings.
@@ -192,37 +213,17 @@ impl EventHandler {
 
             let result = result.map_err(|e| Error::ObjectStore { source: e })?;
 
-            let extension = result
-                .meta
-                .location
-                .extension()
-                .ok_or_else(|| Error::NoFileExtension)?;
-
-            // Determine content type from file extension.
-            let content_type = match extension {
-                DEFAULT_JSON_EXTENSION => ContentType::Json,
-                DEFAULT_CSV_EXTENSION => {
-                    let batch_size = self.config.batch_size.unwrap_or(DEFAULT_BATCH_SIZE);
-                    let has_header = self.config.has_header.unwrap_or(DEFAULT_HAS_HEADER);
-                    let delimiter = self
-                        .config
-                        .delimiter
-                        .as_ref()
-                        .and_then(|d| d.as_bytes().first().copied());
-                    ContentType::Csv {
-                        batch_size,
-                        has_header,
-                        delimiter,
-                        infer_schema_max_records: None,
-                    }
-                }
-                DEFAULT_AVRO_EXTENSION => ContentType::Avro,
-                DEFAULT_PARQUET_EXTENSION => {
-                    let batch_size = self.config.batch_size.unwrap_or(DEFAULT_BATCH_SIZE);
-                    ContentType::Parquet { batch_size }
-                }
-                _ => {
-                    warn!("Unsupported file extension: {}", extension);
+            let location = std::path::Path::new(result.meta.location.as_ref());
+            if location.extension().is_none() {
+                return Err(Error::NoFileExtension);
+            }
+
+            // Determine content type from file extension, then apply the configured
+            // CSV and batch options.
+            let content_type = match ContentType::from_path(location) {
+                Some(content_type) => apply_read_options(content_type, &self.config),
+                None => {
+                    warn!("Unsupported file extension: {}", location.display());
                     return Ok(());
                 }
             };
@@ -595,4 +596,31 @@ mod tests {
             Error::MissingBuilderAttribute(_)
         ));
     }
+
+    #[test]
+    fn test_apply_read_options_to_compressed_csv() {
+        let config = crate::config::Processor {
+            batch_size: Some(500),
+            has_header: Some(false),
+            delimiter: Some(";".to_string()),
+            infer_schema_max_records: Some(100),
+            ..Default::default()
+        };
+        let detected = ContentType::from_path(std::path::Path::new("orders.csv.gz")).unwrap();
+
+        let content_type = apply_read_options(detected, &config);
+
+        assert_eq!(
+            content_type,
+            ContentType::Compressed {
+                inner: Box::new(ContentType::Csv {
+                    batch_size: 500,
+                    has_header: false,
+                    delimiter: Some(b';'),
+                    infer_schema_max_records: Some(100),
+                }),
+                codec: flowgen_core::buffer::CompressionCodec::Gzip,
+            }
+        );
+    }
 }
@@ -1,6 +1,7 @@
 use super::config::{
-    WriteFormat, DEFAULT_AVRO_EXTENSION, DEFAULT_CSV_EXTENSION, DEFAULT_JSON_EXTENSION,
-    DEFAULT_PARQUET_EXTENSION,
+    Compression, WriteFormat, DEFAULT_AVRO_EXTENSION, DEFAULT_CSV_EXTENSION,
+    DEFAULT_GZIP_EXTENSION, DEFAULT_JSON_EXTENSION, DEFAULT_NDJSON_EXTENSION,
+    DEFAULT_PARQUET_EXTENSION, DEFAULT_ZSTD_EXTENSION,
 };
 use bytes::Bytes;
 use chrono::{DateTime, Datelike, Timelike, Utc};
@@ -11,6 +12,7 @@ use flowgen_core::event::{Event, EventBuilder, EventData, EventExt};
 use futures_util::future;
 use object_store::{ObjectStoreExt, PutPayload};
 use serde::{Deserialize, Serialize};
+use std::io::Write;
 use std::sync::Arc;
 use tokio::sync::{mpsc::Receiver, Mutex};
 use tracing::{error, Instrument};
@@ -69,6 +71,11 @@ pub enum Error {
         #[source]
         source: serde_json::Error,
     },
+    #[error("Failed to convert event data to JSON: {source}")]
+    EventConversion {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
     #[error("Object store error: {source}")]
     ObjectStore {
         #[source]
@@ -95,6 +102,8 @@ pub enum Error {
     MissingBuilderAttribute(String),
     #[error("Object store write operation requires a path.")]
     MissingPath,
+    #[error("Invalid {codec:?} compression level {level}")]
+    InvalidCompressionLevel { codec: Compression, level: i32 },
     #[error("Task failed after all retry attempts: {source}")]
     RetryExhausted {
         #[source]
@@ -102,6 +111,76 @@ pub enum Error {
     },
 }
 
+/// Writes event data as newline-delimited JSON.
+///
+/// Arrow batches are written one row object per line. Other data is converted
+/// to JSON first; arrays are written one element per line, any other value as
+/// a single line.
+fn write_ndjson(data: &EventData, writer: &mut Vec<u8>) -> Result<(), Error> {
+    match data {
+        EventData::ArrowRecordBatch(batch) => {
+            let mut json_writer = arrow_json::LineDelimitedWriter::new(writer);
+            json_writer
+                .write(batch)
+                .map_err(|source| Error::Arrow { source })?;
+            json_writer
+                .finish()
+                .map_err(|source| Error::Arrow { source })?;
+        }
+        _ => {
+            let value = serde_json::Value::try_from(data)
+                .map_err(|source| Error::EventConversion { source })?;
+            let records = match value {
+                serde_json::Value::Array(records) => records,
+                value => vec![value],
+            };
+            for record in records {
+                serde_json::to_writer(&mut *writer, &record)
+                    .map_err(|source| Error::SerdeJson { source })?;
+                writer.push(b'\n');
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Compresses written file content, returning it with the codec's file extension.
+fn compress(
+    data: Vec<u8>,
+    codec: Compression,
+    level: Option<i32>,
+) -> Result<(Vec<u8>, &'static str), Error> {
+    let invalid_level = |level| Error::InvalidCompressionLevel { codec, level };
+    match codec {
+        Compression::Gzip => {
+            let level = match level {
+                Some(level) => u32::try_from(level)
+                    .ok()
+                    .filter(|level| *level <= 9)
+                    .map(flate2::Compression::new)
+                    .ok_or_else(|| invalid_level(level))?,
+                None => flate2::Compression::default(),
+            };
+            let mut encoder = flate2::write::GzEncoder::new(Vec::new(), level);
+            encoder
+                .write_all(&data)
+                .map_err(|source| Error::IO { source })?;
+            let compressed = encoder.finish().map_err(|source| Error::IO { source })?;
+            Ok((compressed, DEFAULT_GZIP_EXTENSION))
+        }
+        Compression::Zstd => {
+            let level = match level {
+                Some(level) if zstd::compression_level_range().contains(&level) => level,
+                Some(level) => return Err(invalid_level(level)),
+                None => zstd::DEFAULT_COMPRESSION_LEVEL,
+            };
+            let compressed =
+                zstd::encode_all(data.as_slice(), level).map_err(|source| Error::IO { source })?;
+            Ok((compressed, DEFAULT_ZSTD_EXTENSION))
+        }
+    }
+}
+
 /// Handles processing of individual events by writing them to object storage.
 pub struct EventHandler {
     /// Writer configuration settings.
@@ -191,6 +270,7 @@ impl EventHandler {
                 WriteFormat::Csv => (WriteFormat::Csv, DEFAULT_CSV_EXTENSION),
                 WriteFormat::Avro => (WriteFormat::Avro, DEFAULT_AVRO_EXTENSION),
                 WriteFormat::Json => (WriteFormat::Json, DEFAULT_JSON_EXTENSION),
+                WriteFormat::Ndjson => (WriteFormat::Ndjson, DEFAULT_NDJSON_EXTENSION),
             };
 
             // Write data in the appropriate format.
@@ -198,6 +278,13 @@ impl EventHandler {
                 flowgen_core::event::EventData::ArrowRecordBatch(batch) => Some(batch.num_rows()),
                 _ => None,
             };
+            if let (Some(name), flowgen_core::event::EventData::ArrowRecordBatch(batch)) =
+                (&config.schema_name, &event.data)
+            {
+                flowgen_core::schema::SchemaRegistry::global()
+                    .register_if_absent(name, batch.schema());
+            }
+
             let mut writer = Vec::new();
             match (&event.data, &format) {
                 (flowgen_core::event::EventData::ArrowRecordBatch(batch), WriteFormat::Parquet) => {
@@ -226,6 +313,7 @@ impl EventHandler {
                         source: arrow::error::ArrowError::ExternalError(Box::new(e)),
                     })?;
                 }
+                (data, WriteFormat::Ndjson) => write_ndjson(data, &mut writer)?,
                 _ => {
                     // Use default to_writer for other formats.
                     event
@@ -236,7 +324,15 @@ impl EventHandler {
                 }
             }
 
-            let object_path = path.join(format!("{filename}.{extension}"));
+            let (writer, object_name) = match config.compression {
+                Some(codec) => {
+                    let (writer, codec_extension) =
+                        compress(writer, codec, config.compression_level)?;
+                    (writer, format!("{filename}.{extension}.{codec_extension}"))
+                }
+                None => (writer, format!("{filename}.{extension}")),
+            };
+            let object_path = path.join(object_name);
 
             // Upload processed data to object store.
             // Automatically reconnects on auth failure to refresh expired credentials.
@@ -432,8 +528,10 @@ impl flowgen_core::task::runner::Runner for WriteProcessor {
                 Some(event) => {
                     let event_handler = Arc::clone(&event_handler);
                     let retry_strategy = retry_config.strategy();
+                    let metrics = self.task_context.metrics.clone();
                     let handle = tokio::spawn(
                         async move {
+                            let bytes = event.data_size() as u64;
                             let result = tokio_retry::Retry::spawn(retry_strategy, || async {
                                 match event_handler.handle(event.clone()).await {
                                     Ok(result) => Ok(result),
@@ -445,7 +543,12 @@ impl flowgen_core::task::runner::Runner for WriteProcessor {
                             })
                             .await;
 
+                            if result.is_ok() {
+                                metrics.record_processed(bytes);
+                            }
+
                             if let Err(err) = result {
+                                metrics.record_failed();
                                 error!(error = %err, "Write failed after all retry attempts");
                                 // Emit error event downstream for error handling.
                                 let mut error_event = event.clone();
@@ -558,6 +661,84 @@ mod tests {
     use std::path::PathBuf;
     use tokio::sync::mpsc;
 
+    #[test]
+    fn test_write_ndjson_record_batch() {
+        use arrow::array::{Int64Array, StringArray};
+        use arrow::datatypes::{DataType, Field, Schema};
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, false),
+        ]));
+        let batch = arrow::record_batch::RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec!["Acme", "Globex"])),
+            ],
+        )
+        .unwrap();
+        let mut writer = Vec::new();
+
+        write_ndjson(&EventData::ArrowRecordBatch(batch), &mut writer).unwrap();
+
+        assert_eq!(
+            String::from_utf8(writer).unwrap(),
+            "{\"id\":1,\"name\":\"Acme\"}\n{\"id\":2,\"name\":\"Globex\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_write_ndjson_json_array() {
+        let data = EventData::Json(serde_json::json!([{"id": 1}, {"id": 2}]));
+        let mut writer = Vec::new();
+
+        write_ndjson(&data, &mut writer).unwrap();
+
+        assert_eq!(
+            String::from_utf8(writer).unwrap(),
+            "{\"id\":1}\n{\"id\":2}\n"
+        );
+    }
+
+    #[test]
+    fn test_compress_gzip() {
+        let data = b"id,name\n1,Acme\n".to_vec();
+
+        let (compressed, extension) = compress(data.clone(), Compression::Gzip, Some(9)).unwrap();
+
+        assert_eq!(extension, "gz");
+        let mut decompressed = Vec::new();
+        std::io::Read::read_to_end(
+            &mut flate2::read::GzDecoder::new(compressed.as_slice()),
+            &mut decompressed,
+        )
+        .unwrap();
+        assert_eq!(decompressed, data);
+    }
+
+    #[test]
+    fn test_compress_zstd() {
+        let data = b"id,name\n1,Acme\n".to_vec();
+
+        let (compressed, extension) = compress(data.clone(), Compression::Zstd, None).unwrap();
+
+        assert_eq!(extension, "zst");
+        assert_eq!(zstd::decode_all(compressed.as_slice()).unwrap(), data);
+    }
+
+    #[test]
+    fn test_compress_invalid_level() {
+        assert!(matches!(
+            compress(Vec::new(), Compression::Gzip, Some(10)),
+            Err(Error::InvalidCompressionLevel { level: 10, .. })
+        ));
+        assert!(matches!(
+            compress(Vec::new(), Compression::Zstd, Some(100)),
+            Err(Error::InvalidCompressionLevel { level: 100, .. })
+        ));
+    }
+
     /// Creates a mock TaskContext for testing.
     fn create_mock_task_context() -> Arc<flowgen_core::task::context::TaskContext> {
         let mut labels = Map::new();
@@ -0,0 +1,12 @@
+//! Redis integration for flowgen workers.
+//!
+//! Provides a Redis Streams publisher that appends one stream entry per event
+//! row with `XADD`, using a multiplexed connection that reconnects automatically.
+
+/// Redis Streams functionality.
+pub mod streams {
+    /// Configuration structures for Redis Streams tasks.
+    pub mod config;
+    /// Redis Streams publisher appending entries with `XADD`.
+    pub mod publisher;
+}
@@ -0,0 +1,81 @@
+//! Configuration for the `redis_streams_publisher` task.
+
+use flowgen_core::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the Redis Streams publisher.
+///
+/// # Example YAML
+///
+/// ```yaml
+/// - redis_streams_publisher:
+///     name: publish_orders
+///     url: "redis://:{{env.REDIS_PASSWORD}}@redis:6379/0"
+///     stream_key: "orders.{{event.data.region}}"
+///     max_len: 100000
+///     id_field: entry_id
+/// ```
+#[derive(PartialEq, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Publisher {
+    /// The unique name / identifier of the task.
+    pub name: String,
+    /// Redis connection URL (e.g., "redis://localhost:6379" or "rediss://" for TLS).
+    /// Credentials and database number can be set in the URL.
+    pub url: String,
+    /// Stream key entries are appended to. Supports templates rendered per event.
+    pub stream_key: String,
+    /// Optional approximate maximum stream length, applied as `MAXLEN ~` on every `XADD`.
+    #[serde(default)]
+    pub max_len: Option<usize>,
+    /// Optional row field whose value is used as the entry ID instead of `*`.
+    /// IDs must be greater than the last ID in the stream.
+    #[serde(default)]
+    pub id_field: Option<String>,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    #[serde(default)]
+    pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Publisher {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publisher_config_defaults() {
+        let json = r#"{
+            "name": "publish_orders",
+            "url": "redis://localhost:6379",
+            "stream_key": "orders"
+        }"#;
+        let config: Publisher = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.stream_key, "orders");
+        assert!(config.max_len.is_none());
+        assert!(config.id_field.is_none());
+    }
+
+    #[test]
+    fn test_publisher_config_full() {
+        let json = r#"{
+            "name": "publish_orders",
+            "url": "redis://localhost:6379",
+            "stream_key": "orders.{{event.data.region}}",
+            "max_len": 1000,
+            "id_field": "entry_id"
+        }"#;
+        let config: Publisher = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.max_len, Some(1000));
+        assert_eq!(config.id_field, Some("entry_id".to_string()));
+    }
+}
@@ -0,0 +1,520 @@
+//! Redis Streams publisher appending entries with `XADD`.
+//!
+//! Each event row becomes one stream entry whose fields are the row's columns.
+//! String values are written as-is, other values as JSON, and null values are
+//! omitted. All entries of an event are sent in a single `MULTI`/`EXEC`
+//! transaction, so a retried event is not partially appended. Rows without
+//! any non-null value are rejected since an entry needs at least one field.
+//! The stream key is rendered per event, so rows can be routed to different
+//! streams.
+
+use flowgen_core::{
+    config::ConfigExt,
+    event::{Event, EventBuilder, EventData, EventExt},
+};
+use redis::aio::ConnectionManager;
+use serde_json::{Map, Value as JsonValue};
+use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tracing::{error, Instrument};
+
+/// Entry ID that lets Redis generate the ID.
+const AUTO_ID: &str = "*";
+
+/// Errors that can occur while publishing to Redis Streams.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Error reading event data: {source}")]
+    EventData {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("JSON error: {source}")]
+    SerdeJson {
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("Arrow error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+    #[error("Config template rendering error: {source}")]
+    ConfigRender {
+        #[source]
+        source: flowgen_core::config::Error,
+    },
+    #[error("Redis error: {source}")]
+    Redis {
+        #[source]
+        source: redis::RedisError,
+    },
+    #[error("Row is not a JSON object: {0}")]
+    InvalidRow(String),
+    #[error("Row has no usable value for id field: {0}")]
+    MissingIdField(String),
+    #[error("Row has no non-null fields: {0}")]
+    EmptyRow(String),
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+}
+
+/// Converts event data into JSON object rows.
+///
+/// Record batches produce one row per record. JSON arrays produce one row per
+/// element and any other JSON value is treated as a single row.
+fn event_rows(event: &Event) -> Result<Vec<Map<String, JsonValue>>, Error> {
+    let rows = match &event.data {
+        EventData::ArrowRecordBatch(batch) => {
+            let mut json_writer = arrow_json::ArrayWriter::new(Vec::new());
+            json_writer
+                .write(batch)
+                .map_err(|source| Error::Arrow { source })?;
+            json_writer
+                .finish()
+                .map_err(|source| Error::Arrow { source })?;
+            serde_json::from_slice(&json_writer.into_inner())
+                .map_err(|source| Error::SerdeJson { source })?
+        }
+        _ => match event
+            .data_as_json()
+            .map_err(|source| Error::EventData { source })?
+        {
+            JsonValue::Array(rows) => rows,
+            row => vec![row],
+        },
+    };
+
+    rows.into_iter()
+        .map(|row| match row {
+            JsonValue::Object(map) => Ok(map),
+            row => Err(Error::InvalidRow(row.to_string())),
+        })
+        .collect()
+}
+
+/// Returns a JSON value as a stream field value, or `None` for null.
+fn field_value(value: &JsonValue) -> Option<String> {
+    match value {
+        JsonValue::Null => None,
+        JsonValue::String(s) => Some(s.clone()),
+        value => Some(value.to_string()),
+    }
+}
+
+/// Returns the entry ID for a row, using the configured field or `*`.
+fn entry_id(row: &Map<String, JsonValue>, id_field: Option<&str>) -> Result<String, Error> {
+    match id_field {
+        None => Ok(AUTO_ID.to_string()),
+        Some(field) => row
+            .get(field)
+            .and_then(field_value)
+            .ok_or_else(|| Error::MissingIdField(field.to_string())),
+    }
+}
+
+/// Builds the `XADD` command for a single row.
+///
+/// # Errors
+/// Returns `Error::EmptyRow` if the row has no non-null values.
+fn xadd_command(
+    stream_key: &str,
+    max_len: Option<usize>,
+    id: &str,
+    row: &Map<String, JsonValue>,
+) -> Result<redis::Cmd, Error> {
+    let fields: Vec<(&String, String)> = row
+        .iter()
+        .filter_map(|(field, value)| field_value(value).map(|value| (field, value)))
+        .collect();
+    if fields.is_empty() {
+        return Err(Error::EmptyRow(JsonValue::Object(row.clone()).to_string()));
+    }
+
+    let mut cmd = redis::cmd("XADD");
+    cmd.arg(stream_key);
+    if let Some(max_len) = max_len {
+        cmd.arg("MAXLEN").arg("~").arg(max_len);
+    }
+    cmd.arg(id);
+    for (field, value) in fields {
+        cmd.arg(field).arg(value);
+    }
+    Ok(cmd)
+}
+
+/// Event handler for appending event rows to a stream.
+pub struct EventHandler {
+    connection: ConnectionManager,
+    task_id: usize,
+    tx: Option<Sender<Event>>,
+    config: Arc<super::config::Publisher>,
+    task_type: &'static str,
+}
+
+impl EventHandler {
+    #[tracing::instrument(skip(self, event), name = "task.handle")]
+    async fn handle(&self, event: Event) -> Result<(), Error> {
+        let event = Arc::new(event);
+        let completion_tx_arc = Arc::clone(&event).completion_tx.clone();
+
+        flowgen_core::event::with_event_context(&Arc::clone(&event), async move {
+            // Render config to support templates in the stream key.
+            let event_value = JsonValue::try_from(event.as_ref())
+                .map_err(|source| Error::EventData { source })?;
+            let config = self
+                .config
+                .render(&event_value)
+                .map_err(|source| Error::ConfigRender { source })?;
+
+            let rows = event_rows(&event)?;
+            let mut pipeline = redis::pipe();
+            pipeline.atomic();
+            for row in &rows {
+                let id = entry_id(row, config.id_field.as_deref())?;
+                pipeline.add_command(xadd_command(&config.stream_key, config.max_len, &id, row)?);
+            }
+
+            let ids: Vec<String> = if rows.is_empty() {
+                Vec::new()
+            } else {
+                let mut connection = self.connection.clone();
+                pipeline
+                    .query_async(&mut connection)
+                    .await
+                    .map_err(|source| Error::Redis { source })?
+            };
+
+            let result = serde_json::json!({
+                "stream_key": config.stream_key,
+                "entries_added": ids.len(),
+                "last_id": ids.last(),
+            });
+
+            let mut result_event = EventBuilder::new()
+                .data(EventData::Json(result))
+                .subject(format!("{}.{}", event.subject, config.name))
+                .task_id(self.task_id)
+                .task_type(self.task_type)
+                .build()
+                .map_err(|source| Error::EventBuilder { source })?;
+
+            // Signal completion or pass through to next task.
+            match self.tx {
+                None => {
+                    // Leaf task: signal completion.
+                    if let Some(arc) = completion_tx_arc.as_ref() {
+                        arc.signal_completion(result_event.data_as_json().ok());
+                    }
+                }
+                Some(_) => {
+                    // Pass through completion_tx to next task.
+                    result_event.completion_tx = completion_tx_arc.clone();
+                }
+            }
+
+            result_event
+                .send_with_logging(self.tx.as_ref())
+                .context("num_records", ids.len())
+                .await
+                .map_err(|source| Error::SendMessage { source })?;
+
+            Ok(())
+        })
+        .await
+    }
+}
+
+/// Redis Streams publisher that appends event rows with `XADD`.
+#[derive(Debug)]
+pub struct Publisher {
+    /// Publisher configuration including connection URL and stream key.
+    config: Arc<super::config::Publisher>,
+    /// Receiver for incoming events to process.
+    rx: Receiver<Event>,
+    /// Channel sender for result events.
+    tx: Option<Sender<Event>>,
+    /// Current task identifier for event filtering.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl flowgen_core::task::runner::Runner for Publisher {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Initializes the publisher by opening a managed Redis connection.
+    async fn init(&self) -> Result<EventHandler, Error> {
+        let init_config = self
+            .config
+            .render(&serde_json::json!({}))
+            .map_err(|source| Error::ConfigRender { source })?;
+
+        let client = redis::Client::open(init_config.url.as_str())
+            .map_err(|source| Error::Redis { source })?;
+        let connection = client
+            .get_connection_manager()
+            .await
+            .map_err(|source| Error::Redis { source })?;
+
+        Ok(EventHandler {
+            connection,
+            task_id: self.task_id,
+            tx: self.tx.clone(),
+            config: Arc::clone(&self.config),
+            task_type: self.task_type,
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(mut self) -> Result<(), Self::Error> {
+        let retry_config =
+            flowgen_core::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        let event_handler = match tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+            match self.init().await {
+                Ok(handler) => Ok(handler),
+                Err(e) => {
+                    error!(error = %e, "Failed to initialize Redis Streams publisher");
+                    Err(tokio_retry::RetryError::transient(e))
+                }
+            }
+        })
+        .await
+        {
+            Ok(handler) => Arc::new(handler),
+            Err(e) => {
+                return Err(e);
+            }
+        };
+
+        loop {
+            match self.rx.recv().await {
+                Some(event) => {
+                    let event_handler = Arc::clone(&event_handler);
+                    let retry_strategy = retry_config.strategy();
+                    let metrics = self.task_context.metrics.clone();
+                    tokio::spawn(
+                        async move {
+                            let bytes = event.data_size() as u64;
+                            let result = tokio_retry::Retry::spawn(retry_strategy, || async {
+                                match event_handler.handle(event.clone()).await {
+                                    Ok(result) => Ok(result),
+                                    // Malformed rows fail on every attempt.
+                                    Err(e @ (Error::InvalidRow(_) | Error::MissingIdField(_))) => {
+                                        error!(error = %e, "Non-retriable publish error");
+                                        Err(tokio_retry::RetryError::permanent(e))
+                                    }
+                                    Err(e) => {
+                                        error!(error = %e, "Failed to publish to Redis stream");
+                                        Err(tokio_retry::RetryError::transient(e))
+                                    }
+                                }
+                            })
+                            .await;
+
+                            match result {
+                                Ok(()) => metrics.record_processed(bytes),
+                                Err(e) => {
+                                    metrics.record_failed();
+                                    error!(error = %e, "Failed to publish to Redis stream after all retry attempts");
+                                    // Emit error event downstream for error handling.
+                                    let mut error_event = event;
+                                    error_event.error = Some(e.to_string());
+                                    if let Some(ref tx) = event_handler.tx {
+                                        tx.send(error_event).await.ok();
+                                    }
+                                }
+                            }
+                        }
+                        .instrument(tracing::Span::current()),
+                    );
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Builder for creating Redis Streams publisher instances.
+pub struct PublisherBuilder {
+    config: Option<Arc<super::config::Publisher>>,
+    rx: Option<Receiver<Event>>,
+    tx: Option<Sender<Event>>,
+    task_id: Option<usize>,
+    task_context: Option<Arc<flowgen_core::task::context::TaskContext>>,
+    task_type: Option<&'static str>,
+}
+
+impl PublisherBuilder {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            rx: None,
+            tx: None,
+            task_id: None,
+            task_context: None,
+            task_type: None,
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Publisher>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn receiver(mut self, rx: Receiver<Event>) -> Self {
+        self.rx = Some(rx);
+        self
+    }
+
+    pub fn sender(mut self, tx: Sender<Event>) -> Self {
+        self.tx = Some(tx);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = Some(task_id);
+        self
+    }
+
+    pub fn task_context(
+        mut self,
+        task_context: Arc<flowgen_core::task::context::TaskContext>,
+    ) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Publisher, Error> {
+        Ok(Publisher {
+            config: self
+                .config
+                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            rx: self
+                .rx
+                .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
+            tx: self.tx,
+            task_id: self
+                .task_id
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_id".to_string()))?,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+impl Default for PublisherBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn row(value: JsonValue) -> Map<String, JsonValue> {
+        match value {
+            JsonValue::Object(map) => map,
+            _ => unreachable!(),
+        }
+    }
+
+    fn command_args(cmd: &redis::Cmd) -> Vec<String> {
+        cmd.args_iter()
+            .map(|arg| match arg {
+                redis::Arg::Simple(bytes) => String::from_utf8_lossy(bytes).into_owned(),
+                redis::Arg::Cursor => String::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_xadd_command() {
+        let row = row(json!({"order_id": "o-1", "note": null}));
+        let cmd = xadd_command("orders", Some(1000), "*", &row).unwrap();
+
+        assert_eq!(
+            command_args(&cmd),
+            vec!["XADD", "orders", "MAXLEN", "~", "1000", "*", "order_id", "o-1"]
+        );
+    }
+
+    #[test]
+    fn test_xadd_command_without_max_len() {
+        let row = row(json!({"n": 1}));
+        let cmd = xadd_command("orders", None, "5-0", &row).unwrap();
+        assert_eq!(command_args(&cmd), vec!["XADD", "orders", "5-0", "n", "1"]);
+    }
+
+    #[test]
+    fn test_xadd_command_rejects_empty_row() {
+        assert!(matches!(
+            xadd_command("orders", None, "*", &row(json!({}))),
+            Err(Error::EmptyRow(_))
+        ));
+        assert!(matches!(
+            xadd_command("orders", None, "*", &row(json!({"note": null}))),
+            Err(Error::EmptyRow(_))
+        ));
+    }
+
+    #[test]
+    fn test_entry_id() {
+        let row = row(json!({"entry_id": "1700000000000-0", "seq": 7}));
+
+        assert_eq!(entry_id(&row, None).unwrap(), AUTO_ID);
+        assert_eq!(entry_id(&row, Some("entry_id")).unwrap(), "1700000000000-0");
+        assert_eq!(entry_id(&row, Some("seq")).unwrap(), "7");
+        assert!(matches!(
+            entry_id(&row, Some("missing")),
+            Err(Error::MissingIdField(field)) if field == "missing"
+        ));
+    }
+
+    #[test]
+    fn test_event_rows_rejects_non_object() {
+        let event = EventBuilder::new()
+            .data(EventData::Json(json!(["a"])))
+            .subject("test".to_string())
+            .task_id(0)
+            .task_type("test")
+            .build()
+            .unwrap();
+        assert!(matches!(event_rows(&event), Err(Error::InvalidRow(_))));
+    }
+
+    #[tokio::test]
+    async fn test_publisher_builder_missing_config() {
+        let result = PublisherBuilder::new().build().await;
+        assert!(
+            matches!(result.unwrap_err(), Error::MissingBuilderAttribute(attr) if attr == "config")
+        );
+    }
+}
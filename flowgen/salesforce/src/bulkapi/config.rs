@@ -1,6 +1,7 @@
 use flowgen_core::config::ConfigExt;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// Default batch size for CSV parsing (10,000 rows per RecordBatch).
 const fn default_batch_size() -> usize {
@@ -12,6 +13,16 @@ const fn default_has_header() -> bool {
     true
 }
 
+/// Default poll interval for job status checks (5 seconds).
+const fn default_poll_interval() -> Duration {
+    Duration::from_secs(5)
+}
+
+/// Default maximum poll duration (30 minutes).
+const fn default_max_poll_duration() -> Duration {
+    Duration::from_secs(1800)
+}
+
 /// Query job operations.
 #[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -20,6 +31,8 @@ pub enum QueryJobOperation {
     Create,
     /// Get job status and metadata.
     Get,
+    /// Poll job status until the job completes, fails or is aborted.
+    Wait,
     /// Delete a job (removes job metadata and results).
     Delete,
     /// Abort a running job.
@@ -54,6 +67,18 @@ pub enum QueryJobOperation {
 ///   job_id: "{{event.data.id}}"
 /// ```
 ///
+/// Wait for the job to complete:
+/// ```yaml
+/// salesforce_bulkapi_query_job:
+///   name: wait_for_job
+///   operation: wait
+///   credentials_path: /path/to/salesforce_creds.json
+///   job_id: "{{event.data.id}}"
+///   poll_interval: 5s
+///   max_poll_duration: 30m
+///   emit_progress: true
+/// ```
+///
 /// Get job results:
 /// ```yaml
 /// salesforce_bulkapi_query_job:
@@ -83,18 +108,31 @@ pub struct QueryJob {
     /// Output file format (create only).
     #[serde(default)]
     pub content_type: Option<ContentType>,
-    /// Column separator for CSV output (create only).
+    /// Column separator for CSV output (create), also used to parse downloaded results (get_results).
     #[serde(default)]
     pub column_delimiter: Option<ColumnDelimiter>,
     /// Line termination style (create only).
     #[serde(default)]
     pub line_ending: Option<LineEnding>,
 
-    // Fields for get, delete, abort, get_results operations.
-    /// Job ID for get/delete/abort/get_results operations.
+    // Fields for get, wait, delete, abort, get_results operations.
+    /// Job ID for get/wait/delete/abort/get_results operations.
     #[serde(default)]
     pub job_id: Option<String>,
 
+    // Fields for wait operation.
+    /// Polling interval between status checks (wait only).
+    #[serde(default = "default_poll_interval", with = "humantime_serde")]
+    pub poll_interval: Duration,
+    /// Maximum duration to poll before giving up (wait only).
+    #[serde(default = "default_max_poll_duration", with = "humantime_serde")]
+    pub max_poll_duration: Duration,
+    /// Emit the job info of every poll that has not reached a terminal state,
+    /// in addition to the final event (wait only). Progress events have the
+    /// subject `<name>.progress`; the final event has the subject `<name>`.
+    #[serde(default)]
+    pub emit_progress: bool,
+
     // Fields for get_results operation.
     /// Number of rows per Arrow RecordBatch when parsing CSV results (get_results only).
     #[serde(default = "default_batch_size")]
@@ -111,6 +149,9 @@ pub struct QueryJob {
     /// Optional retry configuration (overrides app-level retry config).
     #[serde(default)]
     pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
 }
 
 impl ConfigExt for QueryJob {}
@@ -174,6 +215,18 @@ impl ColumnDelimiter {
             ColumnDelimiter::Backquote => "BACKQUOTE",
         }
     }
+
+    /// Returns the delimiter as a single CSV byte.
+    pub fn as_byte(&self) -> u8 {
+        match self {
+            ColumnDelimiter::Comma => b',',
+            ColumnDelimiter::Tab => b'\t',
+            ColumnDelimiter::Semicolon => b';',
+            ColumnDelimiter::Pipe => b'|',
+            ColumnDelimiter::Caret => b'^',
+            ColumnDelimiter::Backquote => b'`',
+        }
+    }
 }
 
 /// Line ending styles for cross-platform compatibility.
@@ -242,6 +295,10 @@ mod tests {
             serde_json::to_string(&QueryJobOperation::Get).unwrap(),
             "\"get\""
         );
+        assert_eq!(
+            serde_json::to_string(&QueryJobOperation::Wait).unwrap(),
+            "\"wait\""
+        );
         assert_eq!(
             serde_json::to_string(&QueryJobOperation::Delete).unwrap(),
             "\"delete\""
@@ -266,6 +323,10 @@ mod tests {
             serde_json::from_str::<QueryJobOperation>("\"get\"").unwrap(),
             QueryJobOperation::Get
         );
+        assert_eq!(
+            serde_json::from_str::<QueryJobOperation>("\"wait\"").unwrap(),
+            QueryJobOperation::Wait
+        );
         assert_eq!(
             serde_json::from_str::<QueryJobOperation>("\"delete\"").unwrap(),
             QueryJobOperation::Delete
@@ -361,6 +422,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_column_delimiter_as_byte() {
+        assert_eq!(ColumnDelimiter::Comma.as_byte(), b',');
+        assert_eq!(ColumnDelimiter::Tab.as_byte(), b'\t');
+        assert_eq!(ColumnDelimiter::Semicolon.as_byte(), b';');
+        assert_eq!(ColumnDelimiter::Pipe.as_byte(), b'|');
+        assert_eq!(ColumnDelimiter::Caret.as_byte(), b'^');
+        assert_eq!(ColumnDelimiter::Backquote.as_byte(), b'`');
+    }
+
     #[test]
     fn test_line_ending_default() {
         let line_ending = LineEnding::default();
@@ -411,4 +482,28 @@ mod tests {
             .len();
         assert_eq!(unique_count, delimiters.len());
     }
+
+    #[test]
+    fn test_query_job_poll_defaults() {
+        let job: QueryJob = serde_json::from_value(serde_json::json!({
+            "name": "wait_for_job",
+            "operation": "wait",
+            "credentials_path": "/test/creds.json",
+            "job_id": "750xx0000000001"
+        }))
+        .unwrap();
+        assert_eq!(job.poll_interval, Duration::from_secs(5));
+        assert_eq!(job.max_poll_duration, Duration::from_secs(1800));
+
+        let job: QueryJob = serde_json::from_value(serde_json::json!({
+            "name": "wait_for_job",
+            "operation": "wait",
+            "credentials_path": "/test/creds.json",
+            "poll_interval": "10s",
+            "max_poll_duration": "1h"
+        }))
+        .unwrap();
+        assert_eq!(job.poll_interval, Duration::from_secs(10));
+        assert_eq!(job.max_poll_duration, Duration::from_secs(3600));
+    }
 }
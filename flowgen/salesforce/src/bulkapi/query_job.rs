@@ -1,6 +1,6 @@
 //! Salesforce Bulk API Query Job processor.
 //!
-//! Handles query job operations: create, get, delete, abort, and get_results.
+//! Handles query job operations: create, get, wait, delete, abort, and get_results.
 
 use flowgen_core::buffer::{ContentType, FromReader};
 use flowgen_core::config::ConfigExt;
@@ -14,7 +14,10 @@ use std::io::Cursor;
 use std::sync::Arc;
 use tokio::sync::mpsc::{Receiver, Sender};
 use tokio_stream::StreamExt;
-use tracing::{error, Instrument};
+use tracing::{error, info, warn, Instrument};
+
+/// Suffix appended to the task name to form the subject of progress events.
+const PROGRESS_SUBJECT_SUFFIX: &str = "progress";
 
 /// Response for delete job operation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -74,6 +77,17 @@ pub enum Error {
     },
     #[error("Operation requires job_id")]
     MissingJobId,
+    #[error("Bulk query job {job_id} ended in state {state}: {error_message}")]
+    JobFailed {
+        job_id: String,
+        state: String,
+        error_message: String,
+    },
+    #[error("Bulk query job {job_id} did not complete within {duration:?}")]
+    PollTimeout {
+        job_id: String,
+        duration: std::time::Duration,
+    },
     #[error("Create operation requires query")]
     MissingQuery,
     #[error("Failed to build Bulk API client: {source}")]
@@ -118,6 +132,72 @@ fn to_sdk_line_ending(le: &super::config::LineEnding) -> Option<SdkLineEnding> {
     })
 }
 
+/// Polls a bulk query job until it reaches a terminal state.
+///
+/// Logs every state transition (e.g. `UploadComplete` -> `InProgress` ->
+/// `JobComplete`) and returns the final job info once the job is complete.
+/// The job info of every non-terminal poll is passed to `on_progress`, which
+/// cannot fail the poll.
+/// `Failed` and `Aborted` jobs are returned as [`Error::JobFailed`].
+async fn poll_until_complete<F, Fut, P, PFut>(
+    job_id: &str,
+    poll_interval: std::time::Duration,
+    max_poll_duration: std::time::Duration,
+    mut get_job: F,
+    mut on_progress: P,
+) -> Result<serde_json::Value, Error>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<serde_json::Value, Error>>,
+    P: FnMut(serde_json::Value) -> PFut,
+    PFut: std::future::Future<Output = ()>,
+{
+    let start_time = tokio::time::Instant::now();
+    let mut last_state: Option<String> = None;
+
+    loop {
+        let job_info = get_job().await?;
+        let state = job_info
+            .get("state")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+
+        if last_state.as_deref() != Some(state.as_str()) {
+            info!(job_id, state = %state, "Bulk query job state changed");
+            last_state = Some(state.clone());
+        }
+
+        match state.as_str() {
+            "JobComplete" => return Ok(job_info),
+            "Failed" | "Aborted" => {
+                let error_message = job_info
+                    .get("errorMessage")
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or_default()
+                    .to_string();
+                return Err(Error::JobFailed {
+                    job_id: job_id.to_string(),
+                    state,
+                    error_message,
+                });
+            }
+            _ => on_progress(job_info).await,
+        }
+
+        // Check if we've exceeded max poll duration.
+        if start_time.elapsed() >= max_poll_duration {
+            return Err(Error::PollTimeout {
+                job_id: job_id.to_string(),
+                duration: max_poll_duration,
+            });
+        }
+
+        // Wait before next poll attempt.
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
 /// Event handler for processing individual query job operation requests.
 pub struct EventHandler {
     client: Arc<salesforce_core::bulkapi::Client>,
@@ -176,6 +256,9 @@ impl EventHandler {
                 super::config::QueryJobOperation::Get => {
                     self.get_job(&config, completion_tx_arc).await?
                 }
+                super::config::QueryJobOperation::Wait => {
+                    self.wait_job(&config, completion_tx_arc).await?
+                }
                 super::config::QueryJobOperation::Delete => {
                     self.delete_job(&config, completion_tx_arc).await?
                 }
@@ -284,6 +367,73 @@ impl EventHandler {
         self.send_final_event(e, &completion_tx_arc).await
     }
 
+    /// Waits for a Salesforce bulk query job to complete and emits its final status.
+    async fn wait_job(
+        &self,
+        config: &super::config::QueryJob,
+        completion_tx_arc: Option<flowgen_core::event::SharedCompletionTx>,
+    ) -> Result<(), Error> {
+        let job_id = config.job_id.as_ref().ok_or(Error::MissingJobId)?;
+
+        // Poll job info using SDK until the job reaches a terminal state.
+        let query_client = self.client.query();
+        let resp = poll_until_complete(
+            job_id,
+            config.poll_interval,
+            config.max_poll_duration,
+            || async {
+                let job_info =
+                    query_client
+                        .get_job(job_id)
+                        .await
+                        .map_err(|e| Error::BulkApiQuery {
+                            source: Box::new(e),
+                        })?;
+                serde_json::to_value(&job_info).map_err(|e| Error::SerdeExt {
+                    source: flowgen_core::serde::Error::Serde { source: e },
+                })
+            },
+            |job_info| async move {
+                // Progress events carry no completion signal; only the final
+                // event completes the flow for the incoming event. They use
+                // their own subject so downstream tasks can tell them apart.
+                if !config.emit_progress || self.tx.is_none() {
+                    return;
+                }
+                let e = match EventBuilder::new()
+                    .data(EventData::Json(job_info))
+                    .subject(format!("{}.{PROGRESS_SUBJECT_SUFFIX}", config.name))
+                    .id(job_id.clone())
+                    .task_id(self.current_task_id)
+                    .task_type(self.task_type)
+                    .build()
+                {
+                    Ok(e) => e,
+                    Err(e) => {
+                        warn!(job_id, error = %e, "Failed to build bulk query job progress event");
+                        return;
+                    }
+                };
+                // A lost progress update must not abort waiting for the job.
+                if let Err(e) = e.send_with_logging(self.tx.as_ref()).await {
+                    warn!(job_id, error = %e, "Failed to send bulk query job progress event");
+                }
+            },
+        )
+        .await?;
+
+        // Emit event with the completed job response.
+        let e = EventBuilder::new()
+            .data(EventData::Json(resp))
+            .subject(config.name.to_owned())
+            .id(job_id.clone())
+            .task_id(self.current_task_id)
+            .task_type(self.task_type)
+            .build()?;
+
+        self.send_final_event(e, &completion_tx_arc).await
+    }
+
     /// Deletes a Salesforce bulk query job using the SDK.
     async fn delete_job(
         &self,
@@ -387,7 +537,10 @@ impl EventHandler {
         let content_type = ContentType::Csv {
             batch_size: config.batch_size,
             has_header: config.has_header,
-            delimiter: None,
+            delimiter: config
+                .column_delimiter
+                .as_ref()
+                .map(super::config::ColumnDelimiter::as_byte),
             infer_schema_max_records: None,
         };
 
@@ -432,6 +585,7 @@ impl EventHandler {
                 ));
                 EventBuilder::new()
                     .data(EventData::ArrowRecordBatch(empty_batch))
+                    .allow_empty(true)
                     .subject(config.name.to_owned())
                     .id(job_id.clone())
                     .task_id(self.current_task_id)
@@ -701,6 +855,91 @@ mod tests {
         assert!(response.deleted);
     }
 
+    #[tokio::test]
+    async fn test_poll_until_complete_returns_completed_job() {
+        let mut progress = Vec::new();
+        let mut states =
+            vec!["UploadComplete", "InProgress", "InProgress", "JobComplete"].into_iter();
+        let job_info = poll_until_complete(
+            "750xx000000XXXX",
+            std::time::Duration::from_millis(1),
+            std::time::Duration::from_secs(5),
+            || {
+                let state = states.next().unwrap();
+                async move { Ok(serde_json::json!({"id": "750xx000000XXXX", "state": state})) }
+            },
+            |job_info| {
+                progress.push(job_info["state"].as_str().unwrap_or_default().to_string());
+                async {}
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(job_info["state"], "JobComplete");
+        assert!(states.next().is_none());
+        assert_eq!(progress, vec!["UploadComplete", "InProgress", "InProgress"]);
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_complete_failed_job() {
+        let result = poll_until_complete(
+            "750xx000000XXXX",
+            std::time::Duration::from_millis(1),
+            std::time::Duration::from_secs(5),
+            || async {
+                Ok(serde_json::json!({
+                    "id": "750xx000000XXXX",
+                    "state": "Failed",
+                    "errorMessage": "INVALID_FIELD: No such column"
+                }))
+            },
+            |_| async {},
+        )
+        .await;
+
+        match result {
+            Err(Error::JobFailed {
+                job_id,
+                state,
+                error_message,
+            }) => {
+                assert_eq!(job_id, "750xx000000XXXX");
+                assert_eq!(state, "Failed");
+                assert_eq!(error_message, "INVALID_FIELD: No such column");
+            }
+            other => panic!("expected JobFailed, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_complete_aborted_job() {
+        let result = poll_until_complete(
+            "750xx000000XXXX",
+            std::time::Duration::from_millis(1),
+            std::time::Duration::from_secs(5),
+            || async { Ok(serde_json::json!({"state": "Aborted"})) },
+            |_| async {},
+        )
+        .await;
+
+        assert!(matches!(result, Err(Error::JobFailed { state, .. }) if state == "Aborted"));
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_complete_times_out() {
+        let result = poll_until_complete(
+            "750xx000000XXXX",
+            std::time::Duration::from_millis(1),
+            std::time::Duration::ZERO,
+            || async { Ok(serde_json::json!({"state": "InProgress"})) },
+            |_| async {},
+        )
+        .await;
+
+        assert!(matches!(result, Err(Error::PollTimeout { .. })));
+    }
+
     #[tokio::test]
     async fn test_processor_builder_new() {
         let builder = ProcessorBuilder::new();
@@ -736,10 +975,14 @@ mod tests {
             column_delimiter: Some(super::super::config::ColumnDelimiter::Comma),
             line_ending: Some(super::super::config::LineEnding::Lf),
             job_id: None,
+            poll_interval: std::time::Duration::from_secs(5),
+            max_poll_duration: std::time::Duration::from_secs(1800),
+            emit_progress: false,
             batch_size: 5000,
             has_header: true,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
 
         let builder = ProcessorBuilder::new().config(config.clone());
@@ -781,7 +1024,9 @@ mod tests {
             http_server: None,
             mcp_server: None,
             cancellation_token: tokio_util::sync::CancellationToken::new(),
+            metrics: flowgen_core::metrics::FlowMetrics::default(),
             leaf_count: 1,
+            dry_run: false,
         });
 
         let result = ProcessorBuilder::new()
@@ -813,10 +1058,14 @@ mod tests {
             column_delimiter: Some(super::super::config::ColumnDelimiter::Comma),
             line_ending: Some(super::super::config::LineEnding::Lf),
             job_id: None,
+            poll_interval: std::time::Duration::from_secs(5),
+            max_poll_duration: std::time::Duration::from_secs(1800),
+            emit_progress: false,
             batch_size: 5000,
             has_header: true,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
 
         let (tx, _rx) = tokio::sync::mpsc::channel(1);
@@ -839,7 +1088,9 @@ mod tests {
             http_server: None,
             mcp_server: None,
             cancellation_token: tokio_util::sync::CancellationToken::new(),
+            metrics: flowgen_core::metrics::FlowMetrics::default(),
             leaf_count: 1,
+            dry_run: false,
         });
 
         let result = ProcessorBuilder::new()
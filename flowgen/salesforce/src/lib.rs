@@ -22,10 +22,11 @@ pub mod bulkapi {
     pub mod query_job;
 }
 
-/// Salesforce REST API operations (create, get, update, upsert, delete, search).
+/// Salesforce REST API operations (create, get, update, upsert, delete, query, search).
 pub mod restapi {
     pub mod composite;
     pub mod config;
+    pub mod query;
     pub mod search;
     pub mod sobject;
 }
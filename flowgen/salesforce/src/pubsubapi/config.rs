@@ -117,6 +117,23 @@ pub struct Subscriber {
     /// If not specified, waits indefinitely for flow completion.
     #[serde(default, with = "humantime_serde")]
     pub ack_timeout: Option<std::time::Duration>,
+    /// Timeout for establishing the gRPC connection (e.g., "10s").
+    /// Defaults to 30 seconds.
+    #[serde(default, with = "humantime_serde")]
+    pub connect_timeout: Option<std::time::Duration>,
+    /// Per-request timeout applied to every gRPC call (e.g., "30s").
+    /// For the subscribe stream this only bounds the time until the stream opens.
+    /// If not specified, requests have no timeout.
+    #[serde(default, with = "humantime_serde")]
+    pub request_timeout: Option<std::time::Duration>,
+    /// Maximum time to wait for the next batch on an open subscribe stream (e.g., "5m").
+    /// When exceeded, the stream is considered stalled and the subscriber reconnects.
+    /// If not specified, waits indefinitely.
+    #[serde(default, with = "humantime_serde")]
+    pub stream_idle_timeout: Option<std::time::Duration>,
+    /// Data format of the emitted events. Defaults to `avro`.
+    #[serde(default)]
+    pub format: EventFormat,
     /// Optional list of upstream task names this task depends on.
     /// When set, this task only receives events from the named tasks.
     /// When not set, the task receives from the previous task in the list (linear chain).
@@ -125,6 +142,20 @@ pub struct Subscriber {
     /// Optional retry configuration (overrides app-level retry config).
     #[serde(default)]
     pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
+}
+
+/// Data format of events emitted by the subscriber.
+#[derive(PartialEq, Eq, Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventFormat {
+    /// Forward the Avro payload together with its schema.
+    #[default]
+    Avro,
+    /// Decode the payload into a single-row Arrow record batch using the topic schema.
+    Arrow,
 }
 
 /// Configuration structure for Salesforce Pub/Sub topic settings.
@@ -260,6 +291,14 @@ pub struct Publisher {
     pub payload: Payload,
     /// Optional Salesforce Pub/Sub endpoint (e.g., "api.pubsub.salesforce.com:7443" or "api.deu.pubsub.salesforce.com:7443").
     pub endpoint: Option<String>,
+    /// Timeout for establishing the gRPC connection (e.g., "10s").
+    /// Defaults to 30 seconds.
+    #[serde(default, with = "humantime_serde")]
+    pub connect_timeout: Option<std::time::Duration>,
+    /// Timeout applied to each `publish`, `get_topic` and `get_schema` call (e.g., "30s").
+    /// If not specified, requests have no timeout.
+    #[serde(default, with = "humantime_serde")]
+    pub request_timeout: Option<std::time::Duration>,
     /// Optional list of upstream task names this task depends on.
     /// When set, this task only receives events from the named tasks.
     /// When not set, the task receives from the previous task in the list (linear chain).
@@ -268,6 +307,9 @@ pub struct Publisher {
     /// Optional retry configuration (overrides app-level retry config).
     #[serde(default)]
     pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
 }
 
 /// Replay preset options for Salesforce Pub/Sub subscriptions.
@@ -333,7 +375,8 @@ impl ReplayPreset {
 ///     "enabled": true,
 ///     "managed_subscription": false,
 ///     "name": "CustomEventProcessor_v2",
-///     "replay_preset": "EARLIEST"
+///     "replay_preset": "EARLIEST",
+///     "max_redeliveries": 5
 /// }
 /// ```
 ///
@@ -357,6 +400,10 @@ pub struct DurableConsumerOptions {
     /// Where to start reading events when no replay ID is cached (defaults to Latest).
     #[serde(default)]
     pub replay_preset: ReplayPreset,
+    /// Number of times a batch that did not complete is redelivered before the
+    /// subscriber stores its replay ID and moves on (defaults to 3).
+    /// Only applies when `managed_subscription` is false.
+    pub max_redeliveries: Option<u32>,
 }
 
 impl ConfigExt for Publisher {}
@@ -375,6 +422,19 @@ mod tests {
         assert_eq!(subscriber.topic, Topic::default());
         assert_eq!(subscriber.endpoint, None);
         assert_eq!(subscriber.retry, None);
+        assert_eq!(subscriber.format, EventFormat::Avro);
+    }
+
+    #[test]
+    fn test_subscriber_format_deserialization() {
+        let json = r#"{
+            "name": "orders",
+            "credentials_path": "creds.json",
+            "topic": {"name": "/event/Order__e"},
+            "format": "arrow"
+        }"#;
+        let subscriber: Subscriber = serde_json::from_str(json).unwrap();
+        assert_eq!(subscriber.format, EventFormat::Arrow);
     }
 
     #[test]
@@ -389,13 +449,19 @@ mod tests {
                     managed_subscription: false,
                     name: "TestConsumer".to_string(),
                     replay_preset: ReplayPreset::Latest,
+                    max_redeliveries: None,
                 }),
                 num_requested: Some(50),
             },
             endpoint: Some("api.pubsub.salesforce.com:7443".to_string()),
             ack_timeout: None,
+            connect_timeout: None,
+            request_timeout: None,
+            stream_idle_timeout: None,
+            format: EventFormat::default(),
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         let json = serde_json::to_string(&subscriber).unwrap();
@@ -420,6 +486,7 @@ mod tests {
                 managed_subscription: true,
                 name: "AccountProcessor".to_string(),
                 replay_preset: ReplayPreset::Latest,
+                max_redeliveries: None,
             }),
             num_requested: Some(100),
         };
@@ -450,8 +517,11 @@ mod tests {
             topic: "/event/Order_Status__e".to_string(),
             payload: Payload::Fields(fields),
             endpoint: Some("api.pubsub.salesforce.com:7443".to_string()),
+            connect_timeout: None,
+            request_timeout: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         let json = serde_json::to_string(&publisher).unwrap();
@@ -467,8 +537,11 @@ mod tests {
             topic: "/event/Order_Status__e".to_string(),
             payload: Payload::FromEvent { from_event: true },
             endpoint: None,
+            connect_timeout: None,
+            request_timeout: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         let json = serde_json::to_string(&publisher).unwrap();
@@ -482,6 +555,7 @@ mod tests {
         assert!(!options.enabled);
         assert!(!options.managed_subscription);
         assert_eq!(options.name, "");
+        assert_eq!(options.max_redeliveries, None);
     }
 
     #[test]
@@ -491,6 +565,7 @@ mod tests {
             managed_subscription: true,
             name: "TestDurableConsumer".to_string(),
             replay_preset: ReplayPreset::Latest,
+            max_redeliveries: None,
         };
 
         assert!(options.enabled);
@@ -510,8 +585,13 @@ mod tests {
             },
             endpoint: None,
             ack_timeout: None,
+            connect_timeout: None,
+            request_timeout: None,
+            stream_idle_timeout: None,
+            format: EventFormat::default(),
             depends_on: None,
             retry: None,
+            error_policy: None,
         };
 
         let cloned = subscriber.clone();
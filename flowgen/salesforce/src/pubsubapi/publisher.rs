@@ -21,6 +21,14 @@ fn is_auth_error(error: &PubSubError) -> bool {
     false
 }
 
+/// Checks if a gRPC error is due to the underlying channel being unavailable.
+fn is_transport_error(error: &PubSubError) -> bool {
+    if let PubSubError::Tonic(status) = error {
+        return status.code() == tonic::Code::Unavailable;
+    }
+    false
+}
+
 /// Errors that can occur during Salesforce Pub/Sub publishing operations.
 #[derive(thiserror::Error, Debug)]
 #[non_exhaustive]
@@ -100,9 +108,37 @@ pub struct EventHandler {
     tx: Option<tokio::sync::mpsc::Sender<Event>>,
     /// Task type for event categorization and logging.
     task_type: &'static str,
+    /// gRPC service the Pub/Sub client runs on, used to re-create the channel.
+    service: Arc<Mutex<flowgen_core::service::Service>>,
+    /// Credentials used to re-authenticate after the channel is re-created.
+    credentials_path: std::path::PathBuf,
 }
 
 impl EventHandler {
+    /// Re-creates the gRPC channel and the Pub/Sub client on top of it.
+    async fn reconnect_service(&self) -> Result<(), Error> {
+        let mut service = self.service.lock().await;
+        service
+            .reconnect()
+            .await
+            .map_err(|e| Error::Service { source: e })?;
+        let channel = service.channel.clone().ok_or_else(|| Error::Service {
+            source: flowgen_core::service::Error::MissingEndpoint(),
+        })?;
+
+        let sfdc_client = salesforce_core::client::Builder::new()
+            .credentials_path(self.credentials_path.clone())
+            .build()
+            .map_err(|e| Error::Auth { source: e })?
+            .connect()
+            .await
+            .map_err(|e| Error::Auth { source: e })?;
+
+        *self.pubsub.lock().await = salesforce_core::pubsubapi::Client::new(channel, sfdc_client)
+            .map_err(|e| Error::PubSub { source: e })?;
+        Ok(())
+    }
+
     /// Processes an event by publishing it to Salesforce Pub/Sub.
     #[tracing::instrument(skip(self, event), name = "task.handle")]
     async fn handle(&self, event: Event) -> Result<(), Error> {
@@ -244,19 +280,26 @@ impl flowgen_core::task::runner::Runner for Publisher {
     async fn init(&self) -> Result<EventHandler, Error> {
         let init_config = self.config.render(&serde_json::json!({}))?;
 
-        let service = flowgen_core::service::ServiceBuilder::new()
-            .endpoint(format!(
-                "{}:{}",
-                super::config::DEFAULT_PUBSUB_URL,
-                super::config::DEFAULT_PUBSUB_PORT
-            ))
+        let mut service_builder = flowgen_core::service::ServiceBuilder::new();
+        service_builder.endpoint(format!(
+            "{}:{}",
+            super::config::DEFAULT_PUBSUB_URL,
+            super::config::DEFAULT_PUBSUB_PORT
+        ));
+        if let Some(timeout) = init_config.connect_timeout {
+            service_builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = init_config.request_timeout {
+            service_builder.request_timeout(timeout);
+        }
+        let service = service_builder
             .build()
             .map_err(|e| Error::Service { source: e })?
             .connect()
             .await
             .map_err(|e| Error::Service { source: e })?;
 
-        let channel = service.channel.ok_or_else(|| Error::Service {
+        let channel = service.channel.clone().ok_or_else(|| Error::Service {
             source: flowgen_core::service::Error::MissingEndpoint(),
         })?;
 
@@ -305,6 +348,8 @@ impl flowgen_core::task::runner::Runner for Publisher {
             task_id: self.task_id,
             tx: self.tx.clone(),
             task_type: self.task_type,
+            service: Arc::new(Mutex::new(service)),
+            credentials_path: init_config.credentials_path.clone(),
         };
 
         Ok(event_handler)
@@ -345,14 +390,16 @@ impl flowgen_core::task::runner::Runner for Publisher {
                     if Some(event.task_id) == event_handler.task_id.checked_sub(1) {
                         let event_handler = Arc::clone(&event_handler);
                         let retry_strategy = retry_config.strategy();
+                        let metrics = self.task_context.metrics.clone();
                         let handle = tokio::spawn(
                             async move {
+                                let bytes = event.data_size() as u64;
                                 let result = tokio_retry::Retry::spawn(retry_strategy, || async {
                                     match event_handler.handle(event.clone()).await {
                                         Ok(result) => Ok(result),
                                         Err(e) => {
                                             error!(error = %e, "Failed to publish message");
-                                            // Check if reconnect is needed (gRPC auth errors).
+                                            // Check if reconnect is needed (gRPC auth or transport errors).
                                             if let Error::PubSub { ref source } = e {
                                                 if is_auth_error(source) {
                                                     let mut pubsub =
@@ -364,6 +411,12 @@ impl flowgen_core::task::runner::Runner for Publisher {
                                                             source: reconnect_err,
                                                         }));
                                                     }
+                                                } else if is_transport_error(source) {
+                                                    if let Err(reconnect_err) =
+                                                        event_handler.reconnect_service().await
+                                                    {
+                                                        return Err(tokio_retry::RetryError::transient(reconnect_err));
+                                                    }
                                                 }
                                             }
                                             Err(tokio_retry::RetryError::transient(e))
@@ -372,7 +425,12 @@ impl flowgen_core::task::runner::Runner for Publisher {
                                 })
                                 .await;
 
+                                if result.is_ok() {
+                                    metrics.record_processed(bytes);
+                                }
+
                                 if let Err(err) = result {
+                                    metrics.record_failed();
                                     error!(error = %err, "Failed to publish message after all retry attempts");
                                 }
                             }
@@ -502,8 +560,11 @@ mod tests {
             topic: "/event/Test__e".to_string(),
             payload: config::Payload::Fields(serde_json::Map::new()),
             endpoint: None,
+            connect_timeout: None,
+            request_timeout: None,
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
         let (tx, rx) = mpsc::channel::<Event>(10);
 
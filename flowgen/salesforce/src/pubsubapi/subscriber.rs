@@ -1,18 +1,26 @@
 use flowgen_core::{
     client::Client,
     config::ConfigExt,
-    event::{new_completion_channel, AvroData, Event, EventBuilder, EventData, EventExt},
+    event::{
+        new_completion_channel, AvroData, Event, EventBuilder, EventData, EventExt, META_SCHEMA_ID,
+    },
+    health::{HealthResult, HealthState, HealthStatus},
 };
 use salesforce_core::pubsubapi::{
     eventbus::v1::{ConsumerEvent, ManagedFetchRequest, SchemaInfo},
     FetchRequest, PubSubError, SchemaRequest, TopicRequest,
 };
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
-use tokio::sync::{mpsc::Sender, Mutex};
+use tokio::sync::mpsc::Sender;
 use tokio_stream::StreamExt;
-use tracing::{error, warn, Instrument};
+use tracing::{error, info, warn, Instrument};
 
 const DEFAULT_NUM_REQUESTED: i32 = 200;
+/// Default number of times an incomplete batch is redelivered before the
+/// subscriber advances past it.
+const DEFAULT_MAX_REDELIVERIES: u32 = 3;
 const DEFAULT_TOPIC_PREFIX_DATA: &str = "/data/";
 const DEFAULT_TOPIC_PREFIX_EVENT: &str = "/event/";
 
@@ -22,6 +30,74 @@ fn sanitize_topic_name(topic_name: &str) -> String {
     topic_name.trim_matches('/').replace('/', "-")
 }
 
+/// Avro schemas of a topic keyed by schema ID.
+///
+/// Salesforce can bump the schema version of a topic while a subscription is
+/// open. Every event carries the ID of the schema it was encoded with, so
+/// events are decoded with their own schema and unknown IDs are fetched on demand.
+struct TopicSchemas {
+    /// Schema JSON by schema ID.
+    schemas: HashMap<String, String>,
+    /// ID of the most recently added schema, used to detect version changes.
+    latest_id: String,
+}
+
+impl TopicSchemas {
+    /// Creates the cache from the schema returned for the topic at subscribe time.
+    fn new(schema_info: SchemaInfo) -> Self {
+        let latest_id = schema_info.schema_id.clone();
+        let schemas = HashMap::from([(schema_info.schema_id, schema_info.schema_json)]);
+        Self { schemas, latest_id }
+    }
+
+    /// Returns the schema JSON for a schema ID, if already known.
+    /// Events without a schema ID resolve to the latest schema.
+    fn get(&self, schema_id: &str) -> Option<&String> {
+        match schema_id {
+            "" => self.schemas.get(&self.latest_id),
+            _ => self.schemas.get(schema_id),
+        }
+    }
+
+    /// Adds a newly fetched schema and returns the fields of the previous
+    /// schema that it removes or changes the type of.
+    fn insert(&mut self, schema_info: SchemaInfo) -> Vec<String> {
+        let incompatible = self
+            .schemas
+            .get(&self.latest_id)
+            .map(|previous| incompatible_fields(previous, &schema_info.schema_json))
+            .unwrap_or_default();
+        self.latest_id = schema_info.schema_id.clone();
+        self.schemas
+            .insert(schema_info.schema_id, schema_info.schema_json);
+        incompatible
+    }
+}
+
+/// Returns the record fields of `previous` that are missing from `current` or
+/// have a different type there. Schemas that fail to parse are not compared.
+fn incompatible_fields(previous: &str, current: &str) -> Vec<String> {
+    let (Ok(apache_avro::Schema::Record(previous)), Ok(apache_avro::Schema::Record(current))) = (
+        apache_avro::Schema::parse_str(previous),
+        apache_avro::Schema::parse_str(current),
+    ) else {
+        return Vec::new();
+    };
+
+    previous
+        .fields
+        .iter()
+        .filter(|field| {
+            current
+                .fields
+                .iter()
+                .find(|candidate| candidate.name == field.name)
+                .is_none_or(|candidate| candidate.schema != field.schema)
+        })
+        .map(|field| field.name.clone())
+        .collect()
+}
+
 /// Errors that can occur during Salesforce Pub/Sub subscription operations.
 #[derive(thiserror::Error, Debug)]
 #[non_exhaustive]
@@ -75,6 +151,12 @@ pub enum Error {
     },
     #[error("Stream ended unexpectedly, connection may have been lost")]
     StreamEnded,
+    #[error("No events received on stream within {timeout:?}, connection may have stalled")]
+    StreamIdleTimeout { timeout: std::time::Duration },
+    #[error("Flow did not complete for event {event_id}, completion failed or timed out")]
+    FlowIncomplete { event_id: String },
+    #[error("Not all events in the batch completed for topic {topic}, resubscribing from the last stored replay ID")]
+    BatchIncomplete { topic: String },
     #[error("Config template rendering error: {source}")]
     ConfigRender {
         #[source]
@@ -88,9 +170,12 @@ pub enum Error {
 ///
 /// Subscribes to a topic, deserializes Avro payloads, and forwards events
 /// to the event channel. Supports durable consumers with replay ID caching.
+///
+/// Each topic subscriber owns its Pub/Sub client, built on its own clone of
+/// the gRPC channel, so topic subscriptions never wait on each other.
 pub struct EventHandler {
-    /// Salesforce Pub/Sub client context
-    pubsub: Arc<Mutex<salesforce_core::pubsubapi::Client>>,
+    /// Salesforce Pub/Sub client owned by this topic subscription.
+    pubsub: salesforce_core::pubsubapi::Client,
     /// Subscriber configuration
     config: Arc<super::config::Subscriber>,
     /// Channel sender for processed events
@@ -101,6 +186,15 @@ pub struct EventHandler {
     task_type: &'static str,
     /// Task execution context providing metadata and runtime configuration.
     task_context: Arc<flowgen_core::task::context::TaskContext>,
+    /// gRPC service the Pub/Sub client runs on, used to re-create the channel.
+    service: flowgen_core::service::Service,
+    /// Credentials used to re-authenticate after the channel is re-created.
+    credentials_path: std::path::PathBuf,
+    /// Health reported on the readiness endpoint.
+    health: HealthState,
+    /// Consecutive incomplete batches since the replay ID was last stored,
+    /// shared across resubscribes.
+    incomplete_batches: Arc<AtomicU32>,
 }
 
 /// Checks if a gRPC error is due to an invalid/corrupted replay ID.
@@ -128,13 +222,105 @@ fn is_auth_error(error: &PubSubError) -> bool {
     false
 }
 
+/// Checks if a gRPC error is due to the underlying channel being unavailable.
+fn is_transport_error(error: &PubSubError) -> bool {
+    if let PubSubError::Tonic(status) = error {
+        return status.code() == tonic::Code::Unavailable;
+    }
+    false
+}
+
 impl EventHandler {
+    /// Re-creates the gRPC channel and the Pub/Sub client on top of it.
+    async fn reconnect_service(&mut self) -> Result<(), Error> {
+        self.service
+            .reconnect()
+            .await
+            .map_err(|e| Error::Service { source: e })?;
+        let channel = self.service.channel.clone().ok_or_else(|| Error::Service {
+            source: flowgen_core::service::Error::MissingEndpoint(),
+        })?;
+
+        let sfdc_client = salesforce_core::client::Builder::new()
+            .credentials_path(self.credentials_path.clone())
+            .build()
+            .map_err(|e| Error::Auth { source: e })?
+            .connect()
+            .await
+            .map_err(|e| Error::Auth { source: e })?;
+
+        self.pubsub = salesforce_core::pubsubapi::Client::new(channel, sfdc_client)
+            .map_err(|e| Error::PubSub { source: e })?;
+        Ok(())
+    }
+
+    /// Awaits the next item on a subscribe stream, bounded by `stream_idle_timeout` if configured.
+    async fn next_with_idle_timeout<S>(&self, stream: &mut S) -> Result<Option<S::Item>, Error>
+    where
+        S: tokio_stream::Stream + Unpin,
+    {
+        match self.config.stream_idle_timeout {
+            Some(timeout) => tokio::time::timeout(timeout, stream.next())
+                .await
+                .map_err(|_| Error::StreamIdleTimeout { timeout }),
+            None => Ok(stream.next().await),
+        }
+    }
+
+    /// Returns the schema JSON an event was encoded with, fetching and caching
+    /// it when the topic schema changed since the subscription was opened.
+    ///
+    /// Schema changes that remove fields or change their type are logged as
+    /// warnings; events are still decoded with their own schema.
+    async fn schema_for(
+        &mut self,
+        schema_id: &str,
+        schemas: &mut TopicSchemas,
+        topic_name: &str,
+    ) -> Result<String, Error> {
+        if let Some(schema) = schemas.get(schema_id) {
+            return Ok(schema.clone());
+        }
+
+        let schema_info = self
+            .pubsub
+            .get_schema(SchemaRequest {
+                schema_id: schema_id.to_string(),
+            })
+            .await
+            .map_err(|e| Error::PubSub { source: e })?
+            .into_inner();
+        let schema = schema_info.schema_json.clone();
+        let previous_schema_id = schemas.latest_id.clone();
+
+        let incompatible = schemas.insert(schema_info);
+        if incompatible.is_empty() {
+            info!(
+                topic = topic_name,
+                previous_schema_id = %previous_schema_id,
+                schema_id = schema_id,
+                "Topic schema changed, decoding events with the new schema"
+            );
+        } else {
+            warn!(
+                topic = topic_name,
+                previous_schema_id = %previous_schema_id,
+                schema_id = schema_id,
+                fields = %incompatible.join(", "),
+                "Topic schema changed incompatibly, fields were removed or changed type"
+            );
+        }
+
+        Ok(schema)
+    }
+
     /// Processes a batch of events from Salesforce Pub/Sub.
-    /// Events are processed concurrently, but replay_id is only cached after ALL events succeed.
+    /// Events are processed concurrently, but replay_id is only cached after ALL events succeed,
+    /// or once the batch has been redelivered `max_redeliveries` times.
     async fn process_events(
-        &self,
+        &mut self,
         events: Vec<ConsumerEvent>,
-        schema_info: &SchemaInfo,
+        schemas: &mut TopicSchemas,
         topic_name: &str,
     ) -> Result<(), Error> {
         if events.is_empty() {
@@ -155,7 +341,14 @@ impl EventHandler {
                 let tx = self.tx.clone();
                 let task_id = self.task_id;
                 let task_type = self.task_type;
-                let schema = schema_info.schema_json.clone();
+                let schema = self
+                    .schema_for(&event.schema_id, schemas, topic_name)
+                    .await?;
+                // Events without a schema ID are decoded with the latest schema.
+                let schema_id = match event.schema_id.as_str() {
+                    "" => schemas.latest_id.clone(),
+                    schema_id => schema_id.to_string(),
+                };
                 let event_id = event.id.clone();
 
                 // Normalize topic name by removing data/ or event/ prefix.
@@ -168,6 +361,7 @@ impl EventHandler {
                 let config = Arc::clone(&self.config);
                 let cancellation_token = self.task_context.cancellation_token.clone();
                 let leaf_count = self.task_context.leaf_count;
+                let metrics = self.task_context.metrics.clone();
 
                 // Spawn a task for each event to process them concurrently.
                 let handle = tokio::spawn(
@@ -187,10 +381,17 @@ impl EventHandler {
                             schema,
                             raw_bytes: event.payload[..].to_vec(),
                         };
+                        let data = match config.format {
+                            super::config::EventFormat::Avro => EventData::Avro(data),
+                            super::config::EventFormat::Arrow => EventData::ArrowRecordBatch(
+                                data.to_record_batch()
+                                    .map_err(|source| Error::Event { source })?,
+                            ),
+                        };
 
                         // Build and send event.
                         let mut e = EventBuilder::new()
-                            .data(EventData::Avro(data))
+                            .data(data)
                             .subject(subject)
                             .id(event.id)
                             .task_id(task_id)
@@ -198,6 +399,7 @@ impl EventHandler {
                             .build()
                             .map_err(|e| Error::Event { source: e })?;
 
+                        e.set_meta(META_SCHEMA_ID, serde_json::Value::String(schema_id));
                         e.completion_tx = Some(completion_state);
 
                         // Check cancellation before sending to prevent "receiver dropped" errors.
@@ -208,6 +410,7 @@ impl EventHandler {
                         e.send_with_logging(tx.as_ref())
                             .await
                             .map_err(|source| Error::SendMessage { source })?;
+                        metrics.record_received();
 
                         // Wait for flow completion.
                         // Failed flows skip replay_id advancement, allowing next fetch to retry from same position.
@@ -220,10 +423,7 @@ impl EventHandler {
                         };
 
                         if !success {
-                            warn!(
-                                event_id = ?event_id,
-                                "Flow completion failed or timed out"
-                            );
+                            return Err(Error::FlowIncomplete { event_id });
                         }
 
                         Ok::<(), Error>(())
@@ -251,29 +451,46 @@ impl EventHandler {
             }
         }
 
-        // Only cache replay_id if all events in the batch succeeded.
-        if all_succeeded {
-            if let Some(replay_id) = last_replay_id {
-                if self
-                    .config
-                    .topic
-                    .durable_consumer_options
-                    .as_ref()
-                    .is_some_and(|opts| opts.enabled && !opts.managed_subscription)
-                {
-                    let sanitized_topic = sanitize_topic_name(topic_name);
-                    let cache_key = format!("flow.{flow_name}.replay_id.{sanitized_topic}");
-                    if let Err(e) = cache.put(&cache_key, replay_id.into(), None).await {
-                        error!(
-                            "Failed to cache replay_id for flow {flow_name} topic {topic_name}: {e}"
-                        );
-                    }
-                }
+        // Only durable consumers that store their own replay ID can have a
+        // failed batch redelivered; the failed events were logged above.
+        let Some(durable_consumer_opts) = self
+            .config
+            .topic
+            .durable_consumer_options
+            .as_ref()
+            .filter(|opts| opts.enabled && !opts.managed_subscription)
+        else {
+            return Ok(());
+        };
+
+        if !all_succeeded {
+            let attempts = self.incomplete_batches.fetch_add(1, Ordering::SeqCst) + 1;
+            let max_redeliveries = durable_consumer_opts
+                .max_redeliveries
+                .unwrap_or(DEFAULT_MAX_REDELIVERIES);
+            if attempts <= max_redeliveries {
+                // Later batches must not store a replay ID past the failed
+                // event, so stop here and let the subscriber resubscribe from
+                // the last stored replay ID.
+                return Err(Error::BatchIncomplete {
+                    topic: topic_name.to_string(),
+                });
             }
-        } else {
-            warn!("Not all events succeeded in batch, replay_id not advanced");
+            error!(
+                topic = topic_name,
+                attempts,
+                "Batch did not complete after {max_redeliveries} redeliveries, advancing past it"
+            );
         }
+        self.incomplete_batches.store(0, Ordering::SeqCst);
 
+        if let Some(replay_id) = last_replay_id {
+            let sanitized_topic = sanitize_topic_name(topic_name);
+            let cache_key = format!("flow.{flow_name}.replay_id.{sanitized_topic}");
+            if let Err(e) = cache.put(&cache_key, replay_id.into(), None).await {
+                error!("Failed to cache replay_id for flow {flow_name} topic {topic_name}: {e}");
+            }
+        }
         Ok(())
     }
 
@@ -281,15 +498,14 @@ impl EventHandler {
     ///
     /// Fetches topic and schema info, establishes subscription with optional
     /// replay ID, then processes incoming events in a loop.
-    async fn handle(self) -> Result<(), Error> {
+    async fn handle(mut self) -> Result<(), Error> {
         // Get cache from task context.
-        let cache = &self.task_context.cache;
-        let flow_name = &self.task_context.flow.name;
+        let task_context = Arc::clone(&self.task_context);
+        let cache = &task_context.cache;
+        let flow_name = &task_context.flow.name;
         // Get topic metadata.
         let topic_info = self
             .pubsub
-            .lock()
-            .await
             .get_topic(TopicRequest {
                 topic_name: self.config.topic.name.clone(),
             })
@@ -300,14 +516,13 @@ impl EventHandler {
         // Get schema for message deserialization.
         let schema_info = self
             .pubsub
-            .lock()
-            .await
             .get_schema(SchemaRequest {
                 schema_id: topic_info.schema_id,
             })
             .await
             .map_err(|e| Error::PubSub { source: e })?
             .into_inner();
+        let mut schemas = TopicSchemas::new(schema_info);
 
         // Set batch size for event fetching.
         let num_requested = match self.config.topic.num_requested {
@@ -344,15 +559,15 @@ impl EventHandler {
 
             let mut stream = self
                 .pubsub
-                .lock()
-                .await
                 .managed_subscribe(managed_request)
                 .await
                 .map_err(|e| Error::PubSub { source: e })?
                 .into_inner();
+            self.health
+                .set(HealthResult::new(HealthStatus::Ok).detail("topic", topic_name));
 
             // Process managed subscription events.
-            while let Some(event) = stream.next().await {
+            while let Some(event) = self.next_with_idle_timeout(&mut stream).await? {
                 if self.task_context.cancellation_token.is_cancelled() {
                     return Ok(());
                 }
@@ -366,7 +581,7 @@ impl EventHandler {
                     }
                 };
 
-                self.process_events(events, &schema_info, topic_name)
+                self.process_events(events, &mut schemas, topic_name)
                     .await?;
             }
 
@@ -408,34 +623,26 @@ impl EventHandler {
             }
         }
 
-        let mut stream = match self
-            .pubsub
-            .lock()
-            .await
-            .subscribe(fetch_request.clone())
-            .await
-        {
+        let mut stream = match self.pubsub.subscribe(fetch_request.clone()).await {
             Ok(response) => response.into_inner(),
             Err(e) => {
-                // Check if reconnect is needed (gRPC auth errors).
-                if is_auth_error(&e) {
-                    // Reconnect to get fresh OAuth token.
-                    {
-                        let mut pubsub = self.pubsub.lock().await;
-                        pubsub
+                // Check if reconnect is needed (gRPC auth or transport errors).
+                let auth_error = is_auth_error(&e);
+                if auth_error || is_transport_error(&e) {
+                    if auth_error {
+                        // Reconnect to get fresh OAuth token.
+                        self.pubsub
                             .reconnect()
                             .await
                             .map_err(|e| Error::PubSub { source: e })?;
+                    } else {
+                        // Re-create the dropped gRPC channel.
+                        warn!(error = %e, "Pub/Sub channel unavailable, reconnecting");
+                        self.reconnect_service().await?;
                     }
 
-                    // Retry subscribe with new credentials.
-                    match self
-                        .pubsub
-                        .lock()
-                        .await
-                        .subscribe(fetch_request.clone())
-                        .await
-                    {
+                    // Retry subscribe on the refreshed connection.
+                    match self.pubsub.subscribe(fetch_request.clone()).await {
                         Ok(response) => response.into_inner(),
                         Err(e) => {
                             // If it still fails, return error to trigger full retry.
@@ -473,7 +680,10 @@ impl EventHandler {
             }
         };
 
-        while let Some(event) = stream.next().await {
+        self.health
+            .set(HealthResult::new(HealthStatus::Ok).detail("topic", topic_name));
+
+        while let Some(event) = self.next_with_idle_timeout(&mut stream).await? {
             if self.task_context.cancellation_token.is_cancelled() {
                 return Ok(());
             }
@@ -487,7 +697,7 @@ impl EventHandler {
                 }
             };
 
-            self.process_events(events, &schema_info, topic_name)
+            self.process_events(events, &mut schemas, topic_name)
                 .await?;
         }
 
@@ -511,6 +721,10 @@ pub struct Subscriber {
     task_context: Arc<flowgen_core::task::context::TaskContext>,
     /// Task type for event categorization and logging.
     task_type: &'static str,
+    /// Health reported on the readiness endpoint, shared with each event handler.
+    health: HealthState,
+    /// Consecutive incomplete batches, shared with each event handler.
+    incomplete_batches: Arc<AtomicU32>,
 }
 
 #[async_trait::async_trait]
@@ -541,15 +755,22 @@ impl flowgen_core::task::runner::Runner for Subscriber {
         };
 
         // Create gRPC service connection.
-        let service = flowgen_core::service::ServiceBuilder::new()
-            .endpoint(endpoint)
+        let mut service_builder = flowgen_core::service::ServiceBuilder::new();
+        service_builder.endpoint(endpoint);
+        if let Some(timeout) = init_config.connect_timeout {
+            service_builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = init_config.request_timeout {
+            service_builder.request_timeout(timeout);
+        }
+        let service = service_builder
             .build()
             .map_err(|e| Error::Service { source: e })?
             .connect()
             .await
             .map_err(|e| Error::Service { source: e })?;
 
-        let channel = service.channel.ok_or_else(|| Error::Service {
+        let channel = service.channel.clone().ok_or_else(|| Error::Service {
             source: flowgen_core::service::Error::MissingEndpoint(),
         })?;
 
@@ -562,10 +783,9 @@ impl flowgen_core::task::runner::Runner for Subscriber {
             .await
             .map_err(|e| Error::Auth { source: e })?;
 
-        // Create Pub/Sub context.
+        // Create the Pub/Sub client owned by this topic subscription.
         let pubsub = salesforce_core::pubsubapi::Client::new(channel, sfdc_client)
             .map_err(|e| Error::PubSub { source: e })?;
-        let pubsub = Arc::new(Mutex::new(pubsub));
 
         // Create event handler.
         Ok(EventHandler {
@@ -575,6 +795,10 @@ impl flowgen_core::task::runner::Runner for Subscriber {
             pubsub,
             task_type: self.task_type,
             task_context: Arc::clone(&self.task_context),
+            service,
+            credentials_path: init_config.credentials_path.clone(),
+            health: self.health.clone(),
+            incomplete_batches: Arc::clone(&self.incomplete_batches),
         })
     }
 
@@ -583,8 +807,24 @@ impl flowgen_core::task::runner::Runner for Subscriber {
         let retry_config =
             flowgen_core::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
 
+        // The subscription state is tracked instead of probing the Pub/Sub API,
+        // so readiness probes don't consume API calls.
+        let health_check = match &self.task_context.http_server {
+            Some(http_server) => Some(
+                http_server
+                    .register_health_check(
+                        format!("{}.{}", self.task_context.flow.name, self.config.name),
+                        Arc::new(self.health.clone()),
+                    )
+                    .await,
+            ),
+            None => None,
+        };
+
         tokio::spawn(
             async move {
+                // Deregisters the health check when the subscriber ends.
+                let _health_check = health_check;
                 // Infinite retry loop: subscribers must maintain connectivity indefinitely.
                 loop {
                     // Initialize with circuit breaker to detect permanent errors (bad credentials, etc.).
@@ -602,6 +842,9 @@ impl flowgen_core::task::runner::Runner for Subscriber {
                         Ok(handler) => handler,
                         Err(e) => {
                             error!(error = %e, "Subscriber initialization exhausted retry attempts, will retry after backoff");
+                            self.health.set(
+                                HealthResult::new(HealthStatus::Down).detail("error", e.to_string()),
+                            );
                             tokio::time::sleep(retry_config.initial_backoff).await;
                             continue;
                         }
@@ -614,12 +857,16 @@ impl flowgen_core::task::runner::Runner for Subscriber {
                                 return;
                             }
                             warn!("Subscriber lost connectivity, reinitializing");
+                            self.health.set(HealthResult::new(HealthStatus::Degraded));
                         }
                         Err(e) => {
                             if self.task_context.cancellation_token.is_cancelled() {
                                 return;
                             }
                             error!(error = %e, "Subscriber lost connectivity, reinitializing");
+                            self.health.set(
+                                HealthResult::new(HealthStatus::Degraded).detail("error", e.to_string()),
+                            );
                         }
                     }
 
@@ -703,6 +950,8 @@ impl SubscriberBuilder {
             task_type: self
                 .task_type
                 .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+            health: HealthState::new(HealthStatus::Down),
+            incomplete_batches: Arc::new(AtomicU32::new(0)),
         })
     }
 }
@@ -773,8 +1022,13 @@ mod tests {
             },
             endpoint: None,
             ack_timeout: None,
+            connect_timeout: None,
+            request_timeout: None,
+            stream_idle_timeout: None,
+            format: config::EventFormat::default(),
             depends_on: None,
             retry: None,
+            error_policy: None,
         });
         let (tx, _) = mpsc::channel::<Event>(10);
 
@@ -801,4 +1055,57 @@ mod tests {
             Error::MissingBuilderAttribute(_)
         ));
     }
+
+    fn record_schema(fields: &str) -> String {
+        format!(r#"{{"type": "record", "name": "Order__e", "fields": [{fields}]}}"#)
+    }
+
+    fn schema_info(schema_id: &str, schema_json: String) -> SchemaInfo {
+        SchemaInfo {
+            schema_id: schema_id.to_string(),
+            schema_json,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_incompatible_fields_detects_removed_and_retyped_fields() {
+        let previous = record_schema(
+            r#"{"name": "Id", "type": "string"}, {"name": "Amount", "type": "double"}, {"name": "Note", "type": "string"}"#,
+        );
+        let current = record_schema(
+            r#"{"name": "Id", "type": "string"}, {"name": "Amount", "type": "string"}, {"name": "Extra", "type": "long"}"#,
+        );
+
+        assert_eq!(
+            incompatible_fields(&previous, &current),
+            vec!["Amount".to_string(), "Note".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_incompatible_fields_allows_added_fields() {
+        let previous = record_schema(r#"{"name": "Id", "type": "string"}"#);
+        let current = record_schema(
+            r#"{"name": "Id", "type": "string"}, {"name": "Extra", "type": ["null", "long"]}"#,
+        );
+
+        assert!(incompatible_fields(&previous, &current).is_empty());
+    }
+
+    #[test]
+    fn test_topic_schemas_tracks_versions() {
+        let v1 = record_schema(r#"{"name": "Id", "type": "string"}"#);
+        let v2 = record_schema(r#"{"name": "Key", "type": "string"}"#);
+        let mut schemas = TopicSchemas::new(schema_info("v1", v1.clone()));
+
+        assert_eq!(schemas.get("v1"), Some(&v1));
+        assert_eq!(schemas.get(""), Some(&v1));
+        assert!(schemas.get("v2").is_none());
+
+        assert_eq!(schemas.insert(schema_info("v2", v2.clone())), vec!["Id"]);
+        assert_eq!(schemas.latest_id, "v2");
+        assert_eq!(schemas.get("v2"), Some(&v2));
+        assert_eq!(schemas.get("v1"), Some(&v1));
+    }
 }
@@ -127,6 +127,9 @@ pub struct SObject {
     /// Optional retry configuration (overrides app-level retry config).
     #[serde(default)]
     pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
 }
 
 impl ConfigExt for SObject {}
@@ -181,6 +184,9 @@ pub struct Composite {
 
     #[serde(default)]
     pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
 }
 
 impl ConfigExt for Composite {}
@@ -209,10 +215,73 @@ pub struct Search {
     /// Optional retry configuration (overrides app-level retry config).
     #[serde(default)]
     pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
 }
 
 impl ConfigExt for Search {}
 
+/// Default Salesforce REST API version used for SOQL queries.
+pub const DEFAULT_API_VERSION: &str = "v62.0";
+
+/// Default API version function for serde.
+fn default_api_version() -> String {
+    DEFAULT_API_VERSION.to_string()
+}
+
+/// Configuration for Salesforce REST API SOQL query operations.
+///
+/// Results are paged through `nextRecordsUrl` and each page is emitted as a
+/// record batch. Schedule re-querying with an upstream `generate` task.
+///
+/// # Examples
+///
+/// ```yaml
+/// salesforce_restapi_query:
+///   name: fetch_accounts
+///   credentials_path: /path/to/salesforce_creds.json
+///   query: "SELECT Id, Name FROM Account WHERE LastModifiedDate > {{event.data.since}}"
+/// ```
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct Query {
+    /// Unique task identifier.
+    pub name: String,
+    /// Path to Salesforce authentication credentials.
+    pub credentials_path: PathBuf,
+    /// SOQL query string (supports Handlebars templating).
+    pub query: String,
+    /// REST API version (e.g., "v62.0").
+    #[serde(default = "default_api_version")]
+    pub api_version: String,
+    /// Include deleted and archived records by using the `queryAll` resource.
+    #[serde(default)]
+    pub include_deleted: bool,
+    /// Optional list of upstream task names this task depends on.
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    #[serde(default)]
+    pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Query {}
+
+impl Query {
+    /// Returns the path of the query resource, relative to the instance URL.
+    pub fn resource_path(&self) -> String {
+        let resource = if self.include_deleted {
+            "queryAll"
+        } else {
+            "query"
+        };
+        format!("/services/data/{}/{resource}", self.api_version)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,6 +314,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_query_config_defaults() {
+        let json = r#"{
+            "name": "fetch_accounts",
+            "credentials_path": "/creds.json",
+            "query": "SELECT Id FROM Account"
+        }"#;
+        let config: Query = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.api_version, DEFAULT_API_VERSION);
+        assert!(!config.include_deleted);
+        assert_eq!(config.resource_path(), "/services/data/v62.0/query");
+    }
+
+    #[test]
+    fn test_query_resource_path_include_deleted() {
+        let json = r#"{
+            "name": "fetch_accounts",
+            "credentials_path": "/creds.json",
+            "query": "SELECT Id FROM Account",
+            "api_version": "v60.0",
+            "include_deleted": true
+        }"#;
+        let config: Query = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.resource_path(), "/services/data/v60.0/queryAll");
+    }
+
     #[test]
     fn test_sobject_operation_deserialization() {
         assert_eq!(
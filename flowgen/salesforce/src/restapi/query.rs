@@ -0,0 +1,550 @@
+//! Salesforce SOQL query processor.
+//!
+//! Executes SOQL queries via the REST API, follows `nextRecordsUrl` until the
+//! result set is exhausted and emits each page of records as a record batch.
+//! When a page fails, the retry resumes from that page's `nextRecordsUrl`.
+
+use flowgen_core::config::ConfigExt;
+use flowgen_core::event::{Event, EventBuilder, EventData, EventExt};
+use oauth2::TokenResponse;
+use serde::Deserialize;
+use serde_json::Value;
+use std::sync::Arc;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tracing::{error, Instrument};
+
+/// Errors for Salesforce SOQL query operations.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Failed to send event message to next task: {source}")]
+    SendMessage {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+    #[error("Salesforce authentication error: {source}")]
+    SalesforceAuth {
+        #[source]
+        source: salesforce_core::client::Error,
+    },
+    #[error("Salesforce client has no access token")]
+    NoAccessToken,
+    #[error("Salesforce rejected the session (HTTP {status})")]
+    SessionExpired { status: reqwest::StatusCode },
+    #[error("SOQL query request failed: {source}")]
+    Request {
+        #[source]
+        source: reqwest::Error,
+    },
+    #[error("SOQL query failed with HTTP {status}: {body}")]
+    QueryFailed {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+    #[error("Failed to convert query records to a record batch: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+    #[error(transparent)]
+    EventError(#[from] flowgen_core::event::Error),
+    #[error("Failed to render configuration template: {source}")]
+    ConfigRender {
+        #[source]
+        source: flowgen_core::config::Error,
+    },
+    #[error("Task failed after all retry attempts: {source}")]
+    RetryExhausted {
+        #[source]
+        source: Box<Error>,
+    },
+}
+
+/// A single page of a SOQL query result.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct QueryPage {
+    /// Total number of records matched by the query.
+    total_size: usize,
+    /// Relative URL of the next page, absent on the last page.
+    next_records_url: Option<String>,
+    /// Records on this page.
+    records: Vec<Value>,
+}
+
+/// Position in a paginated query result.
+///
+/// Kept across the retries of one event, so a failure in the middle of the
+/// result set resumes from the stored `nextRecordsUrl` instead of running the
+/// query again and emitting its first pages twice.
+#[derive(Default)]
+struct QueryCursor {
+    /// Relative URL of the next page to fetch, `None` before the first page.
+    next_records_url: Option<String>,
+    /// Index of the next emitted page, used in event IDs.
+    page_index: usize,
+    /// Last emitted page and its record count, held back so it can carry
+    /// the completion channel.
+    pending_event: Option<(Event, usize)>,
+    /// Whether every page has been fetched.
+    exhausted: bool,
+}
+
+/// Removes the `attributes` metadata Salesforce adds to every record and
+/// related record, leaving only the queried fields.
+fn strip_attributes(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.remove("attributes");
+            map.values_mut().for_each(strip_attributes);
+        }
+        Value::Array(items) => items.iter_mut().for_each(strip_attributes),
+        _ => {}
+    }
+}
+
+/// Converts a page of records into a record batch with an inferred schema.
+fn records_to_batch(mut records: Vec<Value>) -> Result<arrow::array::RecordBatch, Error> {
+    records.iter_mut().for_each(strip_attributes);
+
+    let schema = arrow_json::reader::infer_json_schema_from_iterator(records.iter().map(Ok))
+        .map_err(|source| Error::Arrow { source })?;
+    let schema = Arc::new(schema);
+    let mut decoder = arrow_json::ReaderBuilder::new(Arc::clone(&schema))
+        .with_batch_size(records.len().max(1))
+        .build_decoder()
+        .map_err(|source| Error::Arrow { source })?;
+    decoder
+        .serialize(&records)
+        .map_err(|source| Error::Arrow { source })?;
+    Ok(decoder
+        .flush()
+        .map_err(|source| Error::Arrow { source })?
+        .unwrap_or_else(|| arrow::array::RecordBatch::new_empty(schema)))
+}
+
+/// Event handler for processing individual SOQL query requests.
+pub struct EventHandler {
+    http: reqwest::Client,
+    config: Arc<super::config::Query>,
+    tx: Option<Sender<Event>>,
+    current_task_id: usize,
+    sfdc_client: Arc<tokio::sync::Mutex<salesforce_core::client::Client>>,
+    task_type: &'static str,
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+}
+
+impl EventHandler {
+    /// Returns the instance URL and access token of the current session.
+    async fn session(&self) -> Result<(String, String), Error> {
+        let sfdc_client = self.sfdc_client.lock().await;
+        let token = sfdc_client
+            .token_result
+            .as_ref()
+            .ok_or(Error::NoAccessToken)?;
+        Ok((
+            sfdc_client.instance_url.trim_end_matches('/').to_string(),
+            token.access_token().secret().to_string(),
+        ))
+    }
+
+    /// Fetches one page of query results.
+    async fn fetch_page(
+        &self,
+        request: reqwest::RequestBuilder,
+        access_token: &str,
+    ) -> Result<QueryPage, Error> {
+        let response = request
+            .bearer_auth(access_token)
+            .send()
+            .await
+            .map_err(|source| Error::Request { source })?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::UNAUTHORIZED {
+            return Err(Error::SessionExpired { status });
+        }
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(Error::QueryFailed { status, body });
+        }
+
+        response
+            .json::<QueryPage>()
+            .await
+            .map_err(|source| Error::Request { source })
+    }
+
+    async fn send_final_event(
+        &self,
+        mut event: Event,
+        completion_tx_arc: &Option<flowgen_core::event::SharedCompletionTx>,
+    ) -> Result<(), Error> {
+        match self.tx {
+            Some(_) => {
+                event.completion_tx = completion_tx_arc.clone();
+            }
+            None => {
+                if let Some(arc) = completion_tx_arc.as_ref() {
+                    arc.signal_completion(event.data_as_json().ok());
+                }
+            }
+        }
+        event
+            .send_with_logging(self.tx.as_ref())
+            .await
+            .map_err(|e| Error::SendMessage { source: e })?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, event, cursor), name = "task.handle")]
+    async fn handle(
+        &self,
+        event: Event,
+        cursor: &tokio::sync::Mutex<QueryCursor>,
+    ) -> Result<(), Error> {
+        if self.task_context.cancellation_token.is_cancelled() {
+            return Ok(());
+        }
+
+        let event = Arc::new(event);
+        let completion_tx_arc = Arc::clone(&event).completion_tx.clone();
+
+        flowgen_core::event::with_event_context(&Arc::clone(&event), async move {
+            let event_value = serde_json::value::Value::try_from(event.as_ref())?;
+            let config = self
+                .config
+                .render(&event_value)
+                .map_err(|e| Error::ConfigRender { source: e })?;
+
+            let (instance_url, access_token) = self.session().await?;
+            let mut cursor = cursor.lock().await;
+
+            // Emit pages one at a time. Hold the previous event so we can
+            // attach completion_tx to the final one (page count unknown upfront).
+            while !cursor.exhausted {
+                let request = match &cursor.next_records_url {
+                    Some(next) => self.http.get(format!("{instance_url}{next}")),
+                    None => self
+                        .http
+                        .get(format!("{instance_url}{}", config.resource_path()))
+                        .query(&[("q", config.query.as_str())]),
+                };
+                let page = self.fetch_page(request, &access_token).await?;
+
+                if !page.records.is_empty() {
+                    let num_records = page.records.len();
+                    let batch = records_to_batch(page.records)?;
+
+                    // Cleared only once sent, so a retry sends it again.
+                    if let Some((prev, prev_records)) = &cursor.pending_event {
+                        prev.clone()
+                            .send_with_logging(self.tx.as_ref())
+                            .context("num_records", *prev_records)
+                            .context("total_size", page.total_size)
+                            .await
+                            .map_err(|e| Error::SendMessage { source: e })?;
+                    }
+                    cursor.pending_event = None;
+
+                    let e = EventBuilder::new()
+                        .data(EventData::ArrowRecordBatch(batch))
+                        .subject(config.name.to_owned())
+                        .id(format!("{}-{}", config.name, cursor.page_index))
+                        .task_id(self.current_task_id)
+                        .task_type(self.task_type)
+                        .build()?;
+                    cursor.pending_event = Some((e, num_records));
+                    cursor.page_index += 1;
+                }
+
+                cursor.exhausted = page.next_records_url.is_none();
+                cursor.next_records_url = page.next_records_url;
+            }
+
+            // Send the final event (or an empty batch if no records) with completion_tx.
+            let final_event = match &cursor.pending_event {
+                Some((e, _num_records)) => e.clone(),
+                None => EventBuilder::new()
+                    .data(EventData::ArrowRecordBatch(
+                        arrow::array::RecordBatch::new_empty(Arc::new(
+                            arrow::datatypes::Schema::empty(),
+                        )),
+                    ))
+                    .allow_empty(true)
+                    .subject(config.name.to_owned())
+                    .task_id(self.current_task_id)
+                    .task_type(self.task_type)
+                    .build()?,
+            };
+
+            self.send_final_event(final_event, &completion_tx_arc).await
+        })
+        .await
+    }
+}
+
+/// Salesforce SOQL query processor.
+#[derive(Debug)]
+pub struct Processor {
+    config: Arc<super::config::Query>,
+    rx: Receiver<Event>,
+    tx: Option<Sender<Event>>,
+    task_id: usize,
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl flowgen_core::task::runner::Runner for Processor {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    async fn init(&self) -> Result<EventHandler, Error> {
+        let init_config = self
+            .config
+            .render(&serde_json::json!({}))
+            .map_err(|e| Error::ConfigRender { source: e })?;
+
+        let sfdc_client = salesforce_core::client::Builder::new()
+            .credentials_path(init_config.credentials_path.clone())
+            .build()
+            .map_err(|e| Error::SalesforceAuth { source: e })?
+            .connect()
+            .await
+            .map_err(|e| Error::SalesforceAuth { source: e })?;
+
+        let event_handler = EventHandler {
+            http: reqwest::Client::new(),
+            config: Arc::clone(&self.config),
+            current_task_id: self.task_id,
+            tx: self.tx.clone(),
+            sfdc_client: Arc::new(tokio::sync::Mutex::new(sfdc_client)),
+            task_type: self.task_type,
+            task_context: Arc::clone(&self.task_context),
+        };
+        Ok(event_handler)
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(mut self) -> Result<(), Self::Error> {
+        let retry_config =
+            flowgen_core::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        let event_handler = match tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+            match self.init().await {
+                Ok(handler) => Ok(handler),
+                Err(e) => {
+                    error!(error = %e, "Failed to initialize SOQL query processor.");
+                    Err(tokio_retry::RetryError::transient(e))
+                }
+            }
+        })
+        .await
+        {
+            Ok(handler) => Arc::new(handler),
+            Err(e) => {
+                return Err(e);
+            }
+        };
+
+        loop {
+            match self.rx.recv().await {
+                Some(event) => {
+                    if Some(event.task_id) == event_handler.current_task_id.checked_sub(1) {
+                        let event_handler = Arc::clone(&event_handler);
+                        let retry_strategy = retry_config.strategy();
+                        let event_clone = event.clone();
+                        tokio::spawn(
+                            async move {
+                                let cursor = tokio::sync::Mutex::new(QueryCursor::default());
+                                let result = tokio_retry::Retry::spawn(retry_strategy, || async {
+                                    match event_handler.handle(event_clone.clone(), &cursor).await {
+                                        Ok(result) => Ok(result),
+                                        Err(e) => {
+                                            error!(error = %e, "Failed to process SOQL query.");
+                                            let needs_reconnect = matches!(
+                                                &e,
+                                                Error::SalesforceAuth { .. }
+                                                    | Error::SessionExpired { .. }
+                                                    | Error::NoAccessToken
+                                            );
+
+                                            if needs_reconnect {
+                                                let mut sfdc_client =
+                                                    event_handler.sfdc_client.lock().await;
+                                                if let Err(reconnect_err) =
+                                                    (*sfdc_client).reconnect().await
+                                                {
+                                                    error!(error = %reconnect_err, "Failed to reconnect.");
+                                                    return Err(tokio_retry::RetryError::transient(Error::SalesforceAuth {
+                                                        source: reconnect_err,
+                                                    }));
+                                                }
+                                            }
+                                            Err(tokio_retry::RetryError::transient(e))
+                                        }
+                                    }
+                                })
+                                .await;
+
+                                if let Err(err) = result {
+                                    error!(error = %err, "SOQL query failed after all retry attempts.");
+                                    let mut error_event = event_clone.clone();
+                                    error_event.error = Some(err.to_string());
+                                    if let Some(ref tx) = event_handler.tx {
+                                        tx.send(error_event).await.ok();
+                                    }
+                                }
+                            }
+                            .instrument(tracing::Span::current()),
+                        );
+                    }
+                }
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Builder for creating query Processor instances.
+pub struct ProcessorBuilder {
+    config: Option<Arc<super::config::Query>>,
+    rx: Option<Receiver<Event>>,
+    tx: Option<Sender<Event>>,
+    task_id: Option<usize>,
+    task_context: Option<Arc<flowgen_core::task::context::TaskContext>>,
+    task_type: Option<&'static str>,
+}
+
+impl Default for ProcessorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProcessorBuilder {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            rx: None,
+            tx: None,
+            task_id: None,
+            task_context: None,
+            task_type: None,
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Query>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn receiver(mut self, receiver: Receiver<Event>) -> Self {
+        self.rx = Some(receiver);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = Some(task_id);
+        self
+    }
+
+    pub fn task_context(
+        mut self,
+        task_context: Arc<flowgen_core::task::context::TaskContext>,
+    ) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub fn build(self) -> Result<Processor, Error> {
+        Ok(Processor {
+            config: self
+                .config
+                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            rx: self
+                .rx
+                .ok_or_else(|| Error::MissingBuilderAttribute("receiver".to_string()))?,
+            tx: self.tx,
+            task_id: self
+                .task_id
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_id".to_string()))?,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_query_page_deserialization() {
+        let page: QueryPage = serde_json::from_value(json!({
+            "totalSize": 3,
+            "done": false,
+            "nextRecordsUrl": "/services/data/v62.0/query/01gD0000002HU6KIAW-2000",
+            "records": [{"Id": "001"}]
+        }))
+        .unwrap();
+
+        assert_eq!(page.total_size, 3);
+        assert_eq!(
+            page.next_records_url.as_deref(),
+            Some("/services/data/v62.0/query/01gD0000002HU6KIAW-2000")
+        );
+        assert_eq!(page.records.len(), 1);
+    }
+
+    #[test]
+    fn test_records_to_batch_strips_attributes() {
+        let records = vec![
+            json!({
+                "attributes": {"type": "Contact", "url": "/services/data/v62.0/sobjects/Contact/003"},
+                "Id": "003",
+                "Account": {
+                    "attributes": {"type": "Account", "url": "/services/data/v62.0/sobjects/Account/001"},
+                    "Name": "Acme"
+                }
+            }),
+            json!({
+                "attributes": {"type": "Contact", "url": "/services/data/v62.0/sobjects/Contact/004"},
+                "Id": "004",
+                "Account": null
+            }),
+        ];
+
+        let batch = records_to_batch(records).unwrap();
+
+        assert_eq!(batch.num_rows(), 2);
+        let schema = batch.schema();
+        let field_names: Vec<&str> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(field_names, vec!["Id", "Account"]);
+        match schema.field_with_name("Account").unwrap().data_type() {
+            arrow::datatypes::DataType::Struct(fields) => {
+                assert_eq!(fields.len(), 1);
+                assert_eq!(fields[0].name(), "Name");
+            }
+            other => panic!("Expected struct field, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! Configuration for the `websocket_subscriber` task.
+//!
+//! Defines the endpoint, handshake headers, keepalive and reconnect settings,
+//! and how received frames are decoded into event data.
+
+use flowgen_core::config::ConfigExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Default delay before reconnecting after the connection is lost.
+pub const DEFAULT_RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+fn default_reconnect_delay() -> Duration {
+    DEFAULT_RECONNECT_DELAY
+}
+
+/// Configuration for the WebSocket subscriber.
+///
+/// # Example YAML
+///
+/// ```yaml
+/// - websocket_subscriber:
+///     name: market_ticks
+///     url: wss://stream.example.com/v1/ticks
+///     headers:
+///       Authorization: "Bearer {{env.FEED_TOKEN}}"
+///     ping_interval: 30s
+///     reconnect_delay: 5s
+///     format: arrow
+/// ```
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct Subscriber {
+    /// Unique task name.
+    pub name: String,
+    /// WebSocket endpoint (`ws://` or `wss://`).
+    pub url: String,
+    /// HTTP headers sent with the opening handshake, typically for authentication.
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// Interval between keepalive pings. No pings are sent when omitted.
+    #[serde(default, with = "humantime_serde")]
+    pub ping_interval: Option<Duration>,
+    /// Delay before reconnecting after the connection is closed or lost.
+    #[serde(default = "default_reconnect_delay", with = "humantime_serde")]
+    pub reconnect_delay: Duration,
+    /// How received frames are decoded into event data.
+    #[serde(default)]
+    pub format: MessageFormat,
+    /// Optional list of upstream task names this task depends on.
+    /// When set, this task only receives events from the named tasks.
+    /// When not set, the task receives from the previous task in the list (linear chain).
+    #[serde(default)]
+    pub depends_on: Option<Vec<String>>,
+    /// Optional retry configuration (overrides app-level retry config).
+    #[serde(default)]
+    pub retry: Option<flowgen_core::retry::RetryConfig>,
+    /// Optional error recovery policy applied when the task fails.
+    #[serde(default)]
+    pub error_policy: Option<flowgen_core::error_policy::ErrorPolicy>,
+}
+
+impl ConfigExt for Subscriber {}
+
+/// Decoding applied to each received text or binary frame.
+#[derive(PartialEq, Eq, Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageFormat {
+    /// Parse the frame as JSON and emit it as-is.
+    #[default]
+    Json,
+    /// Parse the frame as a JSON object or array of objects and emit an Arrow RecordBatch.
+    Arrow,
+    /// Emit the frame as a JSON string without parsing.
+    Text,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscriber_config_defaults() {
+        let json = r#"{"name": "ticks", "url": "wss://example.com/ticks"}"#;
+        let config: Subscriber = serde_json::from_str(json).unwrap();
+
+        assert!(config.headers.is_empty());
+        assert_eq!(config.ping_interval, None);
+        assert_eq!(config.reconnect_delay, DEFAULT_RECONNECT_DELAY);
+        assert_eq!(config.format, MessageFormat::Json);
+    }
+
+    #[test]
+    fn test_subscriber_config_full() {
+        let json = r#"{
+            "name": "ticks",
+            "url": "wss://example.com/ticks",
+            "headers": {"Authorization": "Bearer token"},
+            "ping_interval": "30s",
+            "reconnect_delay": "1s",
+            "format": "arrow"
+        }"#;
+        let config: Subscriber = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            config.headers.get("Authorization"),
+            Some(&"Bearer token".to_string())
+        );
+        assert_eq!(config.ping_interval, Some(Duration::from_secs(30)));
+        assert_eq!(config.reconnect_delay, Duration::from_secs(1));
+        assert_eq!(config.format, MessageFormat::Arrow);
+    }
+}
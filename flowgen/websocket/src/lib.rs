@@ -0,0 +1,10 @@
+//! WebSocket integration for flowgen workers.
+//!
+//! Provides a subscriber that connects to `ws://` or `wss://` endpoints and
+//! turns each received frame into a flowgen event. Connections are re-established
+//! automatically when the server closes them or the network drops.
+
+/// Configuration structures for WebSocket tasks.
+pub mod config;
+/// WebSocket subscriber for consuming real-time feeds.
+pub mod subscriber;
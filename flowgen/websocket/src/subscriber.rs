@@ -0,0 +1,453 @@
+//! WebSocket subscriber that emits one event per received frame.
+//!
+//! Connects to the configured endpoint with optional handshake headers, decodes
+//! text and binary frames according to the configured format, and forwards them
+//! downstream. When the connection closes or fails the subscriber waits for the
+//! reconnect delay and connects again until the flow is cancelled.
+
+use super::config::MessageFormat;
+use flowgen_core::{
+    config::ConfigExt,
+    event::{Event, EventBuilder, EventData, EventExt},
+};
+use futures_util::{SinkExt, StreamExt};
+use serde_json::Value;
+use std::sync::Arc;
+use tokio::net::TcpStream;
+use tokio::sync::mpsc::Sender;
+use tokio::time::Interval;
+use tokio_tungstenite::tungstenite::{
+    client::IntoClientRequest,
+    http::{HeaderName, HeaderValue},
+    Message,
+};
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+use tracing::{error, warn};
+
+type Stream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Errors that can occur during WebSocket subscription.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("Error sending event to channel: {source}")]
+    SendMessage {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Error building event: {source}")]
+    EventBuilder {
+        #[source]
+        source: flowgen_core::event::Error,
+    },
+    #[error("Config template rendering error: {source}")]
+    ConfigRender {
+        #[source]
+        source: flowgen_core::config::Error,
+    },
+    #[error("WebSocket error: {source}")]
+    WebSocket {
+        #[source]
+        source: tokio_tungstenite::tungstenite::Error,
+    },
+    #[error("Invalid header name '{name}': {source}")]
+    InvalidHeaderName {
+        name: String,
+        #[source]
+        source: tokio_tungstenite::tungstenite::http::header::InvalidHeaderName,
+    },
+    #[error("Invalid value for header '{name}': {source}")]
+    InvalidHeaderValue {
+        name: String,
+        #[source]
+        source: tokio_tungstenite::tungstenite::http::header::InvalidHeaderValue,
+    },
+    #[error("JSON error: {source}")]
+    SerdeJson {
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("Arrow conversion error: {source}")]
+    Arrow {
+        #[source]
+        source: arrow::error::ArrowError,
+    },
+    #[error("Expected a JSON object or array of objects for Arrow conversion")]
+    InvalidArrowInput,
+    #[error("Connection closed by server")]
+    ConnectionClosed,
+    #[error("Missing required builder attribute: {}", _0)]
+    MissingBuilderAttribute(String),
+}
+
+/// Decodes a frame payload into event data according to the configured format.
+fn frame_to_event_data(payload: &[u8], format: &MessageFormat) -> Result<EventData, Error> {
+    match format {
+        MessageFormat::Json => serde_json::from_slice(payload)
+            .map(EventData::Json)
+            .map_err(|source| Error::SerdeJson { source }),
+        MessageFormat::Text => Ok(EventData::Json(Value::String(
+            String::from_utf8_lossy(payload).into_owned(),
+        ))),
+        MessageFormat::Arrow => {
+            let rows = match serde_json::from_slice(payload)
+                .map_err(|source| Error::SerdeJson { source })?
+            {
+                Value::Array(rows) => rows,
+                row @ Value::Object(_) => vec![row],
+                _ => return Err(Error::InvalidArrowInput),
+            };
+            if rows.is_empty() || !rows.iter().all(Value::is_object) {
+                return Err(Error::InvalidArrowInput);
+            }
+
+            let schema = arrow_json::reader::infer_json_schema_from_iterator(rows.iter().map(Ok))
+                .map_err(|source| Error::Arrow { source })?;
+            let mut decoder = arrow_json::ReaderBuilder::new(Arc::new(schema))
+                .build_decoder()
+                .map_err(|source| Error::Arrow { source })?;
+            decoder
+                .serialize(&rows)
+                .map_err(|source| Error::Arrow { source })?;
+            decoder
+                .flush()
+                .map_err(|source| Error::Arrow { source })?
+                .map(EventData::ArrowRecordBatch)
+                .ok_or(Error::InvalidArrowInput)
+        }
+    }
+}
+
+/// Waits for the next ping tick, or forever when pings are disabled.
+async fn next_ping(interval: &mut Option<Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Event handler owning a single WebSocket connection.
+pub struct EventHandler {
+    stream: Stream,
+    tx: Option<Sender<Event>>,
+    task_id: usize,
+    config: Arc<super::config::Subscriber>,
+    task_type: &'static str,
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+}
+
+impl EventHandler {
+    /// Reads frames until the connection closes, fails, or the flow is cancelled.
+    async fn handle(self) -> Result<(), Error> {
+        let (mut write, mut read) = self.stream.split();
+        let cancellation_token = self.task_context.cancellation_token.clone();
+
+        let mut ping = self.config.ping_interval.map(|period| {
+            let mut interval = tokio::time::interval(period);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            interval
+        });
+        // The first tick completes immediately; skip it so the first ping waits a full period.
+        if let Some(interval) = ping.as_mut() {
+            interval.tick().await;
+        }
+
+        loop {
+            let message = tokio::select! {
+                _ = cancellation_token.cancelled() => {
+                    write.send(Message::Close(None)).await.ok();
+                    return Ok(());
+                }
+                _ = next_ping(&mut ping) => {
+                    write
+                        .send(Message::Ping(Vec::new().into()))
+                        .await
+                        .map_err(|source| Error::WebSocket { source })?;
+                    continue;
+                }
+                message = read.next() => message,
+            };
+
+            let payload = match message {
+                Some(Ok(Message::Text(text))) => text.as_str().as_bytes().to_vec(),
+                Some(Ok(Message::Binary(data))) => data.to_vec(),
+                Some(Ok(Message::Close(frame))) => {
+                    warn!(frame = ?frame, "WebSocket closed by server");
+                    return Err(Error::ConnectionClosed);
+                }
+                // Pings are answered by the library; pongs and raw frames need no handling.
+                Some(Ok(_)) => continue,
+                Some(Err(source)) => return Err(Error::WebSocket { source }),
+                None => return Err(Error::ConnectionClosed),
+            };
+
+            let data = match frame_to_event_data(&payload, &self.config.format) {
+                Ok(data) => data,
+                Err(e) => {
+                    // A malformed frame should not tear down the connection.
+                    warn!(error = %e, "Failed to decode WebSocket frame, skipping");
+                    continue;
+                }
+            };
+
+            let e = EventBuilder::new()
+                .data(data)
+                .subject(self.config.name.to_owned())
+                .task_id(self.task_id)
+                .task_type(self.task_type)
+                .build()
+                .map_err(|source| Error::EventBuilder { source })?;
+            e.send_with_logging(self.tx.as_ref())
+                .await
+                .map_err(|source| Error::SendMessage { source })?;
+            self.task_context.metrics.record_received();
+        }
+    }
+}
+
+/// WebSocket subscriber that consumes frames and converts them to flowgen events.
+#[derive(Debug)]
+pub struct Subscriber {
+    /// Subscriber configuration including endpoint and reconnect settings.
+    config: Arc<super::config::Subscriber>,
+    /// Sender for forwarding converted events.
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event tagging.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Arc<flowgen_core::task::context::TaskContext>,
+    /// Task type for event categorization and logging.
+    task_type: &'static str,
+}
+
+#[async_trait::async_trait]
+impl flowgen_core::task::runner::Runner for Subscriber {
+    type Error = Error;
+    type EventHandler = EventHandler;
+
+    /// Opens the WebSocket connection with the configured handshake headers.
+    async fn init(&self) -> Result<EventHandler, Error> {
+        let init_config = self
+            .config
+            .render(&serde_json::json!({}))
+            .map_err(|source| Error::ConfigRender { source })?;
+
+        let mut request = init_config
+            .url
+            .as_str()
+            .into_client_request()
+            .map_err(|source| Error::WebSocket { source })?;
+
+        for (name, value) in &init_config.headers {
+            let header_name = HeaderName::from_bytes(name.as_bytes()).map_err(|source| {
+                Error::InvalidHeaderName {
+                    name: name.clone(),
+                    source,
+                }
+            })?;
+            let header_value =
+                HeaderValue::from_str(value).map_err(|source| Error::InvalidHeaderValue {
+                    name: name.clone(),
+                    source,
+                })?;
+            request.headers_mut().insert(header_name, header_value);
+        }
+
+        let (stream, _response) = tokio_tungstenite::connect_async(request)
+            .await
+            .map_err(|source| Error::WebSocket { source })?;
+
+        Ok(EventHandler {
+            stream,
+            tx: self.tx.clone(),
+            task_id: self.task_id,
+            config: Arc::clone(&self.config),
+            task_type: self.task_type,
+            task_context: Arc::clone(&self.task_context),
+        })
+    }
+
+    #[tracing::instrument(skip(self), name = "task.run", fields(task = %self.config.name, task_id = self.task_id, task_type = %self.task_type))]
+    async fn run(self) -> Result<(), Error> {
+        let retry_config =
+            flowgen_core::retry::RetryConfig::merge(&self.task_context.retry, &self.config.retry);
+
+        // Reconnect loop: transient failures are retried indefinitely, while
+        // permanent ones are returned so the task's error policy applies.
+        loop {
+            let event_handler = match tokio_retry::Retry::spawn(retry_config.strategy(), || async {
+                match self.init().await {
+                    Ok(handler) => Ok(handler),
+                    Err(e) => {
+                        if is_permanent(&e) {
+                            error!(error = %e, "Permanent initialization error");
+                            Err(tokio_retry::RetryError::permanent(e))
+                        } else {
+                            error!(error = %e, "WebSocket connection failed");
+                            Err(tokio_retry::RetryError::transient(e))
+                        }
+                    }
+                }
+            })
+            .await
+            {
+                Ok(handler) => handler,
+                Err(e) if is_permanent(&e) => return Err(e),
+                Err(e) => {
+                    error!(error = %e, "Subscriber initialization exhausted retry attempts, will retry after backoff");
+                    tokio::time::sleep(self.config.reconnect_delay).await;
+                    continue;
+                }
+            };
+
+            // Read frames until the connection drops, then reconnect.
+            match event_handler.handle().await {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    if self.task_context.cancellation_token.is_cancelled() {
+                        return Ok(());
+                    }
+                    warn!(error = %e, "WebSocket connection lost, reconnecting");
+                }
+            }
+
+            tokio::select! {
+                _ = self.task_context.cancellation_token.cancelled() => return Ok(()),
+                _ = tokio::time::sleep(self.config.reconnect_delay) => {}
+            }
+        }
+    }
+}
+
+/// Returns whether an initialization error cannot be fixed by reconnecting.
+fn is_permanent(error: &Error) -> bool {
+    matches!(
+        error,
+        Error::InvalidHeaderName { .. } | Error::InvalidHeaderValue { .. }
+    )
+}
+
+/// Builder for configuring and creating WebSocket subscribers.
+#[derive(Default)]
+pub struct SubscriberBuilder {
+    /// Optional subscriber configuration.
+    config: Option<Arc<super::config::Subscriber>>,
+    /// Optional event sender.
+    tx: Option<Sender<Event>>,
+    /// Task identifier for event processing.
+    task_id: usize,
+    /// Task execution context providing metadata and runtime configuration.
+    task_context: Option<Arc<flowgen_core::task::context::TaskContext>>,
+    /// Task type for event categorization.
+    task_type: Option<&'static str>,
+}
+
+impl SubscriberBuilder {
+    pub fn new() -> SubscriberBuilder {
+        SubscriberBuilder {
+            ..Default::default()
+        }
+    }
+
+    pub fn config(mut self, config: Arc<super::config::Subscriber>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn sender(mut self, sender: Sender<Event>) -> Self {
+        self.tx = Some(sender);
+        self
+    }
+
+    pub fn task_id(mut self, task_id: usize) -> Self {
+        self.task_id = task_id;
+        self
+    }
+
+    pub fn task_context(
+        mut self,
+        task_context: Arc<flowgen_core::task::context::TaskContext>,
+    ) -> Self {
+        self.task_context = Some(task_context);
+        self
+    }
+
+    pub fn task_type(mut self, task_type: &'static str) -> Self {
+        self.task_type = Some(task_type);
+        self
+    }
+
+    pub async fn build(self) -> Result<Subscriber, Error> {
+        Ok(Subscriber {
+            config: self
+                .config
+                .ok_or_else(|| Error::MissingBuilderAttribute("config".to_string()))?,
+            tx: self.tx,
+            task_id: self.task_id,
+            task_context: self
+                .task_context
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_context".to_string()))?,
+            task_type: self
+                .task_type
+                .ok_or_else(|| Error::MissingBuilderAttribute("task_type".to_string()))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Array, Int64Array};
+
+    #[test]
+    fn test_frame_to_event_data_json() {
+        let data = frame_to_event_data(br#"{"price": 10}"#, &MessageFormat::Json).unwrap();
+        assert!(matches!(data, EventData::Json(v) if v == serde_json::json!({"price": 10})));
+    }
+
+    #[test]
+    fn test_frame_to_event_data_text() {
+        let data = frame_to_event_data(b"hello", &MessageFormat::Text).unwrap();
+        assert!(matches!(data, EventData::Json(Value::String(s)) if s == "hello"));
+    }
+
+    #[test]
+    fn test_frame_to_event_data_arrow() {
+        let payload = br#"[{"price": 10, "symbol": "A"}, {"price": 12, "symbol": "B"}]"#;
+        let data = frame_to_event_data(payload, &MessageFormat::Arrow).unwrap();
+
+        let EventData::ArrowRecordBatch(batch) = data else {
+            panic!("expected record batch");
+        };
+        assert_eq!(batch.num_rows(), 2);
+        let prices = batch
+            .column_by_name("price")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(prices.value(1), 12);
+    }
+
+    #[test]
+    fn test_frame_to_event_data_arrow_rejects_scalars() {
+        let result = frame_to_event_data(b"42", &MessageFormat::Arrow);
+        assert!(matches!(result, Err(Error::InvalidArrowInput)));
+    }
+
+    #[test]
+    fn test_frame_to_event_data_invalid_json() {
+        let result = frame_to_event_data(b"not json", &MessageFormat::Json);
+        assert!(matches!(result, Err(Error::SerdeJson { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_subscriber_builder_missing_config() {
+        let result = SubscriberBuilder::new().task_type("test").build().await;
+        assert!(
+            matches!(result.unwrap_err(), Error::MissingBuilderAttribute(attr) if attr == "config")
+        );
+    }
+}